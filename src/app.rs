@@ -1,17 +1,67 @@
-mod app;
+mod activity;
 mod app_runner;
 mod app_state;
+mod application;
+mod board_lock;
 mod card_editor;
 mod card_selector;
+mod close_split_prompt;
+mod column_settings;
+mod command;
+mod conflict_prompt;
+mod crash;
+mod csv_import;
 mod event_handler;
+mod export_dialog;
+mod feed;
+mod focus_view;
+mod health_popup;
 mod help;
+mod help_search;
+mod history;
+mod history_browser;
+mod idle_prompt;
+mod inline_title;
+mod invariant_guard;
+mod journal;
+mod keymap;
+mod lock_prompt;
 mod logger;
+mod notes_panel;
+mod operations;
+mod pomodoro;
+mod quit_prompt;
+mod recovery_prompt;
+mod rename_column;
 mod save_to_file;
+mod search_panel;
+mod search_picker;
+mod session;
+mod settings_editor;
+mod split_column;
+mod standup_popup;
+mod startup_wizard;
+mod stats_popup;
+mod sync_prompt;
+mod tag_manager;
+mod test_driver;
 mod text_widget;
+mod timer;
+mod toast;
+mod tutorial;
+mod view;
 mod widget_utils;
 
-use app::App;
 pub use app_runner::AppRunner;
 use app_state::AppState;
+pub(crate) use application::write_backup;
+pub use application::App;
+pub(crate) use board_lock::{BoardLock, LockError};
 use card_selector::CardSelector;
+use history::{BranchSummary, History};
+use invariant_guard::BoardInvariantGuard;
+pub(crate) use journal::{file_revision, replay, Journal, JournalEntry};
 use logger::Logger;
+pub use operations::{AppOperations, InsertPosition};
+pub use test_driver::TestDriver;
+pub(crate) use view::{print_board, DEFAULT_PRINT_WIDTH};
@@ -0,0 +1,155 @@
+use std::{fmt, time::Duration};
+
+use crate::utils::time;
+
+/// Something done during the session, recorded for the summary shown on quit and observed by the tutorial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Activity {
+    CardSelected,
+    CardCreated,
+    CardEdited,
+    CardMoved,
+    CardCompleted,
+    PriorityChanged,
+    ChangeUndone,
+    TimeTracked(Duration),
+    BoardSaved(String),
+}
+
+/// Activities of the session, undone changes are recorded as reverted rather than forgotten
+#[derive(Debug, Default)]
+pub struct ActivityLog {
+    events: Vec<(Activity, bool)>,
+}
+
+impl ActivityLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, activity: Activity) {
+        self.events.push((activity, false));
+    }
+
+    /// Cancels an activity, when the change that caused it is undone
+    pub fn revert(&mut self, activity: Activity) {
+        self.events.push((activity, true));
+    }
+
+    /// Moves the activities of another log into this one
+    pub fn append(&mut self, other: &mut ActivityLog) {
+        self.events.append(&mut other.events);
+    }
+
+    pub fn summary(&self) -> SessionSummary {
+        let mut summary = SessionSummary::default();
+        for (activity, reverted) in &self.events {
+            let count = |count: usize| if *reverted { count.saturating_sub(1) } else { count + 1 };
+            match activity {
+                Activity::CardCreated => summary.cards_created = count(summary.cards_created),
+                Activity::CardEdited => summary.cards_edited = count(summary.cards_edited),
+                Activity::CardCompleted => summary.cards_completed = count(summary.cards_completed),
+                Activity::TimeTracked(duration) => summary.time_tracked += *duration,
+                Activity::BoardSaved(file_name) => {
+                    if !summary.saved_files.contains(file_name) {
+                        summary.saved_files.push(file_name.clone());
+                    }
+                }
+                Activity::CardSelected | Activity::CardMoved | Activity::PriorityChanged | Activity::ChangeUndone => {}
+            }
+        }
+
+        summary
+    }
+}
+
+/// What was done during the session
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionSummary {
+    pub cards_created: usize,
+    pub cards_edited: usize,
+    pub cards_completed: usize,
+    pub time_tracked: Duration,
+    pub saved_files: Vec<String>,
+}
+
+impl SessionSummary {
+    pub fn is_empty(&self) -> bool {
+        *self == SessionSummary::default()
+    }
+
+    /// Adds the activities of another board, like the one in a split
+    pub fn merge(mut self, other: SessionSummary) -> Self {
+        self.cards_created += other.cards_created;
+        self.cards_edited += other.cards_edited;
+        self.cards_completed += other.cards_completed;
+        self.time_tracked += other.time_tracked;
+        for file_name in other.saved_files {
+            if !self.saved_files.contains(&file_name) {
+                self.saved_files.push(file_name);
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for SessionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Session summary:")?;
+        writeln!(
+            f,
+            "  {} cards created, {} edited, {} completed",
+            self.cards_created, self.cards_edited, self.cards_completed
+        )?;
+        if !self.time_tracked.is_zero() {
+            writeln!(f, "  {} tracked", time::pretty_duration(self.time_tracked))?;
+        }
+        match self.saved_files.is_empty() {
+            true => write!(f, "  Nothing saved"),
+            false => write!(f, "  Saved {}", self.saved_files.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Result, time::Duration};
+
+    use super::{Activity, ActivityLog};
+
+    #[test]
+    fn summary_of_activities() -> Result<()> {
+        let mut log = ActivityLog::new();
+        assert!(log.summary().is_empty());
+
+        log.record(Activity::CardCreated);
+        log.record(Activity::CardCreated);
+        log.revert(Activity::CardCreated);
+        log.record(Activity::CardEdited);
+        log.record(Activity::TimeTracked(Duration::from_secs(600)));
+        log.record(Activity::BoardSaved("board.json".into()));
+        log.record(Activity::BoardSaved("board.json".into()));
+
+        let summary = log.summary();
+        assert_eq!(
+            (1, 1, 0),
+            (summary.cards_created, summary.cards_edited, summary.cards_completed)
+        );
+        assert_eq!(
+            "Session summary:\n  1 cards created, 1 edited, 0 completed\n  10m tracked\n  Saved board.json",
+            summary.to_string()
+        );
+
+        let mut other = ActivityLog::new();
+        other.record(Activity::CardCompleted);
+        other.record(Activity::BoardSaved("other.md".into()));
+        let summary = summary.merge(other.summary());
+        assert_eq!(1, summary.cards_completed);
+        assert_eq!(
+            vec!["board.json".to_string(), "other.md".to_string()],
+            summary.saved_files
+        );
+
+        Ok(())
+    }
+}
@@ -1,33 +1,168 @@
-use std::io::Result;
+use std::{
+    io::{stdout, Error, Result},
+    time::Duration,
+};
 
-use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::{
+    event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyEventKind},
+    execute,
+};
 use ratatui::{DefaultTerminal, Frame};
 
-use crate::app::App;
-use crate::app::AppState;
+use crate::app::{
+    board_lock::{BoardLock, LockError},
+    crash::{self, CrashReport},
+    session::Session,
+    App, AppState,
+};
+use crate::board::{Board, Template};
+use crate::config::Settings;
+use crate::utils::{
+    clipboard,
+    time::{self, DateFormatter},
+};
+
+pub const TICK_RATE: Duration = Duration::from_millis(250);
 
 #[derive(Debug)]
 pub struct AppRunner<'a> {
     app: App,
     state: AppState<'a>,
+    crash_report: Option<CrashReport>,
 }
 
 impl<'a> AppRunner<'a> {
     pub fn new(file_name: String) -> AppRunner<'a> {
+        Self::start(file_name, None)
+    }
+
+    /// Starts on the board already read, or on the one read from the file, or on a new board
+    fn start(file_name: String, board: Option<Board>) -> AppRunner<'a> {
+        let (settings, error) = load_settings();
+        let mut app = match board {
+            Some(board) => App::with_board(file_name.clone(), board, settings),
+            None => App::with_settings(file_name.clone(), settings),
+        };
+        if let Some(e) = error {
+            app.log(format!("Cannot read settings because {}, using defaults", e));
+        }
+
+        let mut session = Session::load();
+        app.set_layout(session.layout());
+        let mut state = if file_name.is_empty() {
+            AppState::startup(session)
+        } else {
+            session.add_recent_board(&file_name);
+            if let Err(e) = session.save() {
+                app.log(format!("Cannot save session because {}", e));
+            }
+            match app.health_check() {
+                findings if findings.is_empty() => AppState::new(),
+                findings => AppState::health(findings),
+            }
+        };
+
+        if !file_name.is_empty() {
+            match BoardLock::acquire(&file_name) {
+                Ok(lock) => {
+                    app.set_lock(lock);
+                    // A board locked by another instance may be recovered from there, not here
+                    if let Some(recovery_file) = crash::find_recovery_file(&file_name) {
+                        state = AppState::recovery(recovery_file);
+                    }
+                }
+                Err(LockError::Locked(owner)) => state = AppState::locked(owner.to_string()),
+                Err(LockError::Io(e)) => app.log(format!("Cannot lock {} because {}", file_name, e)),
+            }
+        }
+        app.enable_journal();
+
         Self {
-            app: App::new(file_name),
+            app,
+            state,
+            crash_report: None,
+        }
+    }
+
+    /// Opens a board that must be readable, instead of starting a new board in its place
+    ///
+    /// A mistyped file name is reported right away, rather than leading to an empty board written over the
+    /// intended file later on.
+    pub fn strict(file_name: String) -> std::result::Result<AppRunner<'a>, String> {
+        let board = Board::open(&file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+        Ok(Self::start(file_name, Some(board)))
+    }
+
+    /// Opens a board just created from a template
+    pub fn created(file_name: String, template: Template) -> AppRunner<'a> {
+        let mut runner = Self::new(file_name.clone());
+        runner
+            .app
+            .log(format!("Created {} from the {} template", file_name, template.name()));
+        runner
+    }
+
+    /// Runs the tutorial, on a board of its own
+    pub fn tutorial() -> AppRunner<'a> {
+        let (settings, error) = load_settings();
+        let mut app = App::tutorial(settings);
+        if let Some(e) = error {
+            app.log(format!("Cannot read settings because {}, using defaults", e));
+        }
+        app.set_layout(Session::load().layout());
+
+        Self {
+            app,
             state: AppState::new(),
+            crash_report: None,
         }
     }
 
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        execute!(stdout(), EnableBracketedPaste)?;
+        crash::install_panic_hook();
+        let result = crash::catch(|| self.event_loop(terminal)).unwrap_or_else(|message| {
+            self.crash_report = Some(self.app.crash_report(message));
+            Ok(())
+        });
+        execute!(stdout(), DisableBracketedPaste)?;
+        self.save_layout()?;
+
+        result
+    }
+
+    /// Keeps the sizes of the panes for the next runs
+    fn save_layout(&self) -> Result<()> {
+        let mut session = Session::load();
+        if session.layout() == self.app.layout() {
+            return Ok(());
+        }
+
+        session.set_layout(self.app.layout());
+        session.save()
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
         while self.state.should_continue() {
             terminal.draw(|frame| self.draw(frame))?;
 
+            if !event::poll(TICK_RATE)? {
+                self.app.tick();
+                continue;
+            }
+
             match event::read()? {
                 Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                     self.state.handle_events(&mut self.app, key_event);
+                    if let Some(text) = self.app.take_clipboard() {
+                        if let Err(e) = clipboard::copy(terminal.backend_mut(), &text) {
+                            self.app.log(format!("Cannot copy to the clipboard: {}", e));
+                        }
+                    }
                 }
+                Event::Paste(text) => self.state.handle_paste(&self.app, &text),
+                // Drawn again at the new size right away, from scratch so nothing is left of the previous layout
+                Event::Resize(_, _) => terminal.autoresize()?,
                 _ => {}
             };
         }
@@ -35,7 +170,43 @@ impl<'a> AppRunner<'a> {
         Ok(())
     }
 
+    /// What happened if the application crashed, to print once the terminal is restored
+    pub fn crash_report(&self) -> Option<String> {
+        self.crash_report.as_ref().map(|report| report.to_string())
+    }
+
+    /// Summary of the session to print once the terminal is restored, when enabled in the settings
+    pub fn quit_summary(&self) -> Option<String> {
+        self.app.quit_summary().map(|summary| summary.to_string())
+    }
+
     fn draw(&self, frame: &mut Frame) {
         self.state.render(&self.app, frame)
     }
 }
+
+/// Loads the user settings and sets up the date formats, falling back to the defaults on error
+fn load_settings() -> (Settings, Option<Error>) {
+    let (settings, error) = match Settings::load() {
+        Ok(settings) => (settings, None),
+        Err(e) => (Settings::default(), Some(e)),
+    };
+    time::init(DateFormatter::new(&settings.dates));
+
+    (settings, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::AppRunner;
+
+    #[test]
+    fn strict_on_missing_board() -> Result<()> {
+        let error = AppRunner::strict("res/missing.json".to_string()).unwrap_err();
+        assert!(error.starts_with("cannot read res/missing.json"));
+
+        Ok(())
+    }
+}
@@ -1,31 +1,137 @@
+use std::{cell::Cell, time::Instant};
+
+use chrono::Local;
 use crossterm::event::KeyEvent;
-use ratatui::Frame;
+use ratatui::{layout::Constraint, Frame};
+
+use crate::board::Finding;
 
 use super::{
-    app::App,
+    application::App,
     card_editor::CardEditor,
-    event_handler::{edit, normal, save},
+    close_split_prompt::CloseSplitPrompt,
+    column_settings::ColumnSettings,
+    conflict_prompt::ConflictPrompt,
+    csv_import::CsvImportWizard,
+    event_handler::{
+        column_settings, conflict, csv_import, edit, export, feed, goto, health, help, history, idle, locked,
+        move_card, normal, notes, quit, recovery, rename, save, search, search_picker, settings, split_column, standup,
+        startup, sync, tags, title, window,
+    },
+    export_dialog::ExportDialog,
+    health_popup::HealthPopup,
     help::Help,
+    help_search::HelpSearch,
+    history_browser::HistoryBrowser,
+    idle_prompt::IdlePrompt,
+    inline_title::InlineTitle,
+    keymap::{self, Action},
+    lock_prompt::LockPrompt,
+    notes_panel::NotesPanel,
+    operations::AppOperations,
+    quit_prompt::QuitPrompt,
+    recovery_prompt::RecoveryPrompt,
+    rename_column::RenameColumn,
     save_to_file::Save,
+    search_panel::SearchPanel,
+    search_picker::SearchPicker,
+    session::Session,
+    settings_editor::SettingsEditor,
+    split_column::SplitColumn,
+    standup_popup::StandUpPopup,
+    startup_wizard::StartupWizard,
+    stats_popup::{StatsPopup, TREND_WEEKS},
+    sync_prompt::SyncPrompt,
+    tag_manager::TagManager,
+    view::TooSmall,
+    widget_utils::centered_popup_area,
 };
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum State<'a> {
     Normal,
     Save { save: Save<'a> },
+    Export { dialog: ExportDialog<'a> },
+    ImportCsv { wizard: CsvImportWizard<'a> },
     Edit { editor: CardEditor },
-    Help,
+    EditTitle { title: InlineTitle<'a> },
+    RenameColumn { rename: RenameColumn<'a> },
+    SplitColumn { split: SplitColumn<'a> },
+    ColumnSettings { settings: ColumnSettings },
+    Notes { panel: NotesPanel },
+    Help { help: Help },
+    HelpSearch { search: HelpSearch<'a> },
+    Stats,
+    Feed,
+    MoveCard,
+    StandUp,
+    Search { search: Box<SearchPanel<'a>> },
+    SavedSearches { picker: SearchPicker },
+    Tags { manager: TagManager<'a> },
+    Settings { editor: SettingsEditor<'a> },
+    Locked { prompt: LockPrompt },
+    Recovery { prompt: RecoveryPrompt },
+    Health { popup: HealthPopup },
+    History { browser: HistoryBrowser },
+    SaveConflict { prompt: ConflictPrompt },
+    Sync { prompt: SyncPrompt },
+    Idle { prompt: IdlePrompt },
+    Startup { wizard: StartupWizard<'a> },
+    Window,
+    Goto,
+    GotoNumber { number: usize },
+    OpenSplit { path: Save<'a> },
+    CloseSplit { prompt: CloseSplitPrompt },
+    OpenBoard { path: Save<'a> },
+    QuitPrompt { prompt: QuitPrompt },
     Quit,
 }
 
 #[derive(Debug)]
 pub struct AppState<'a> {
     state: State<'a>,
+    /// Whether the last render only showed that the terminal is too small
+    too_small: Cell<bool>,
 }
 
 impl<'a> AppState<'a> {
     pub fn new() -> Self {
-        Self { state: State::Normal }
+        Self::with_state(State::Normal)
+    }
+
+    /// Starts by asking what to do with a board locked by another instance
+    pub fn locked(owner: String) -> Self {
+        Self::with_state(State::Locked {
+            prompt: LockPrompt::new(owner),
+        })
+    }
+
+    /// Starts by asking what to do with the board saved after a crash
+    pub fn recovery(recovery_file: String) -> Self {
+        Self::with_state(State::Recovery {
+            prompt: RecoveryPrompt::new(recovery_file),
+        })
+    }
+
+    /// Starts by listing what looks wrong on the board
+    pub fn health(findings: Vec<Finding>) -> Self {
+        Self::with_state(State::Health {
+            popup: HealthPopup::new(findings),
+        })
+    }
+
+    /// Starts with the wizard to pick or create a board
+    pub fn startup(session: Session) -> Self {
+        Self::with_state(State::Startup {
+            wizard: StartupWizard::new(session),
+        })
+    }
+
+    fn with_state(state: State<'a>) -> Self {
+        Self {
+            state,
+            too_small: Cell::new(false),
+        }
     }
 
     pub fn should_continue(&self) -> bool {
@@ -33,23 +139,168 @@ impl<'a> AppState<'a> {
     }
 
     pub fn handle_events(&mut self, app: &mut App, event: KeyEvent) {
+        // Keys would act on a board hidden behind the message, only quitting goes through
+        if self.too_small.get() {
+            if keymap::action(&event) == Some(Action::Quit) {
+                self.state = State::Quit;
+            }
+            return;
+        }
+
+        // The first key after an idle period only brings up the question about it
+        if let Some(idle) = app.register_input(Instant::now()) {
+            if self.state == State::Normal {
+                self.state = State::Idle {
+                    prompt: IdlePrompt::new(idle),
+                };
+                return;
+            }
+        }
+
+        match &self.state {
+            State::Window => self.state = window::handler(app, event),
+            State::Idle { prompt } => self.state = idle::handler(prompt.clone(), app, event),
+            State::OpenSplit { path } => self.state = window::open_split_handler(path.clone(), app, event),
+            State::CloseSplit { prompt } => self.state = window::close_split_handler(prompt.clone(), app, event),
+            State::QuitPrompt { prompt } => self.state = quit::handler(prompt.clone(), app, event),
+            State::Normal => {
+                self.state = match window::intercept(app, event) {
+                    Some(state) => state,
+                    None => normal::handler(app.focused_mut(), event),
+                };
+                // Every open board is checked, not only the one of the focused pane
+                if self.state == State::Quit {
+                    self.state = quit::check(app);
+                }
+            }
+            _ => self.handle_pane_events(app.focused_mut(), event),
+        }
+    }
+
+    /// Handles the events of the states acting on the focused pane only
+    fn handle_pane_events(&mut self, app: &mut App, event: KeyEvent) {
         match &self.state {
-            State::Normal => self.state = normal::handler(app, event),
             State::Save { save } => self.state = save::handler(save.clone(), app, event),
+            State::Export { dialog } => self.state = export::handler(dialog.clone(), app, event),
+            State::ImportCsv { wizard } => self.state = csv_import::handler(wizard.clone(), app, event),
             State::Edit { editor } => self.state = edit::handler(editor.clone(), app, event),
-            State::Help => self.state = State::Normal,
-            State::Quit => {}
+            State::EditTitle { title } => self.state = title::handler(title.clone(), app, event),
+            State::RenameColumn { rename } => self.state = rename::handler(rename.clone(), app, event),
+            State::SplitColumn { split } => self.state = split_column::handler(split.clone(), app, event),
+            State::ColumnSettings { settings } => self.state = column_settings::handler(settings.clone(), app, event),
+            State::Notes { panel } => self.state = notes::handler(panel.clone(), app, event),
+            State::Search { search } => self.state = search::handler(search.clone(), app, event),
+            State::SavedSearches { picker } => self.state = search_picker::handler(picker.clone(), app, event),
+            State::Tags { manager } => self.state = tags::handler(manager.clone(), app, event),
+            State::Settings { editor } => self.state = settings::handler(editor.clone(), app, event),
+            State::Help { help } => self.state = help::handler(help.clone(), event),
+            State::HelpSearch { search } => self.state = help::search_handler(search.clone(), event),
+            State::Stats => self.state = State::Normal,
+            State::Feed => self.state = feed::handler(app, event),
+            State::MoveCard => self.state = move_card::handler(app, event),
+            State::StandUp => self.state = standup::handler(app, event),
+            State::Goto => self.state = goto::handler(app, event),
+            State::GotoNumber { number } => self.state = goto::number_handler(*number, app, event),
+            State::Locked { prompt } => self.state = locked::handler(prompt.clone(), app, event),
+            State::Recovery { prompt } => self.state = recovery::handler(prompt.clone(), app, event),
+            State::Health { popup } => self.state = health::handler(popup.clone(), app, event),
+            State::History { browser } => self.state = history::handler(browser.clone(), app, event),
+            State::SaveConflict { prompt } => self.state = conflict::handler(prompt.clone(), app, event),
+            State::Sync { prompt } => self.state = sync::handler(prompt.clone(), app, event),
+            State::Startup { wizard } => self.state = startup::handler(wizard.clone(), app, event),
+            State::OpenBoard { path } => self.state = window::open_board_handler(path.clone(), app, event),
+            State::Normal
+            | State::Window
+            | State::OpenSplit { .. }
+            | State::CloseSplit { .. }
+            | State::QuitPrompt { .. }
+            | State::Idle { .. }
+            | State::Quit => {}
+        }
+    }
+
+    /// Handles text pasted in the terminal, only text fields accept it
+    pub fn handle_paste(&mut self, app: &App, text: &str) {
+        match &mut self.state {
+            State::Search { search } => search.paste(text, &app.focused().board()),
+            State::Edit { editor } => editor.paste(text),
+            State::EditTitle { title } => title.paste(text),
+            State::Save { save } => save.paste(text),
+            State::Export { dialog } => dialog.paste(text),
+            State::ImportCsv { wizard } => wizard.paste(text),
+            State::RenameColumn { rename } => rename.paste(text),
+            State::SplitColumn { split } => split.paste(text),
+            State::ColumnSettings { settings } => settings.paste(text),
+            State::Notes { panel } => panel.paste(text),
+            State::Tags { manager } => manager.paste(text),
+            State::Settings { editor } => editor.paste(text),
+            State::HelpSearch { search } => search.paste(text),
+            State::Startup { wizard } => wizard.paste(text),
+            State::OpenSplit { path } | State::OpenBoard { path } => path.paste(text),
+            _ => {}
         }
     }
 
     pub fn render(&self, app: &App, frame: &mut Frame) {
+        self.too_small.set(!TooSmall::fits(frame.area()));
+        if self.too_small.get() {
+            frame.render_widget(TooSmall, frame.area());
+            return;
+        }
+
         frame.render_widget(app, frame.area());
 
         match &self.state {
-            State::Normal => {}
+            State::Normal | State::Feed | State::MoveCard => {}
             State::Save { save } => frame.render_widget(save, frame.area()),
+            State::Export { dialog } => frame.render_widget(dialog, frame.area()),
+            State::ImportCsv { wizard } => frame.render_widget(wizard, frame.area()),
             State::Edit { editor } => frame.render_widget(editor, frame.area()),
-            State::Help => frame.render_widget(Help, frame.area()),
+            State::EditTitle { title } => {
+                let area = app.focused().selected_card_area().unwrap_or_else(|| {
+                    centered_popup_area(frame.area(), Constraint::Length(48), Constraint::Length(3))
+                });
+                frame.render_widget(title, area);
+            }
+            State::RenameColumn { rename } => frame.render_widget(rename, frame.area()),
+            State::SplitColumn { split } => frame.render_widget(split, frame.area()),
+            State::ColumnSettings { settings } => frame.render_widget(settings, frame.area()),
+            State::Notes { panel } => {
+                let area = app.focused().notes_area().unwrap_or_else(|| {
+                    centered_popup_area(frame.area(), Constraint::Length(72), Constraint::Length(20))
+                });
+                frame.render_widget(panel, area);
+            }
+            State::Help { help } => frame.render_widget(help, frame.area()),
+            State::HelpSearch { search } => frame.render_widget(search, frame.area()),
+            State::Stats => {
+                let trend = app
+                    .focused()
+                    .metrics()
+                    .weekly_trend(TREND_WEEKS, Local::now().date_naive());
+                let popup = StatsPopup::new(app.focused().statistics()).trend(trend);
+                frame.render_widget(&popup, frame.area());
+            }
+            State::StandUp => {
+                let popup = StandUpPopup::new(app.focused().standup());
+                frame.render_widget(&popup, frame.area());
+            }
+            State::Search { search } => frame.render_widget(search.as_ref(), frame.area()),
+            State::SavedSearches { picker } => frame.render_widget(picker, frame.area()),
+            State::Tags { manager } => frame.render_widget(manager, frame.area()),
+            State::Settings { editor } => frame.render_widget(editor, frame.area()),
+            State::Locked { prompt } => frame.render_widget(prompt, frame.area()),
+            State::Recovery { prompt } => frame.render_widget(prompt, frame.area()),
+            State::Health { popup } => frame.render_widget(popup, frame.area()),
+            State::History { browser } => frame.render_widget(browser, frame.area()),
+            State::SaveConflict { prompt } => frame.render_widget(prompt, frame.area()),
+            State::Sync { prompt } => frame.render_widget(prompt, frame.area()),
+            State::Idle { prompt } => frame.render_widget(prompt, frame.area()),
+            State::Startup { wizard } => frame.render_widget(wizard, frame.area()),
+            State::Window | State::Goto | State::GotoNumber { .. } => {}
+            State::OpenSplit { path } | State::OpenBoard { path } => frame.render_widget(path, frame.area()),
+            State::CloseSplit { prompt } => frame.render_widget(prompt, frame.area()),
+            State::QuitPrompt { prompt } => frame.render_widget(prompt, frame.area()),
             State::Quit => {}
         }
     }
@@ -57,11 +308,15 @@ impl<'a> AppState<'a> {
 
 #[cfg(test)]
 mod tests {
-    use std::io::Result;
+    use std::{env, fs, io::Result, path::Path, time::Duration};
 
     use crossterm::event::KeyCode;
 
-    use crate::app::app_state::State;
+    use crate::{
+        app::{app_state::State, crash, AppOperations},
+        board::{metrics_file_name, Board, BoardBuilder},
+        config::Settings,
+    };
 
     use super::*;
 
@@ -78,6 +333,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn quit_with_changes_not_written() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-quit-prompt.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+        let mut state = AppState::new();
+
+        for key in ['j', 'x'] {
+            state.handle_events(&mut app, KeyCode::Char(key).into());
+        }
+        state.handle_events(&mut app, KeyCode::Char('q').into());
+        assert!(matches!(state.state, State::QuitPrompt { .. }));
+        state.handle_events(&mut app, KeyCode::Esc.into());
+        assert_eq!(State::Normal, state.state);
+
+        state.handle_events(&mut app, KeyCode::Char('q').into());
+        state.handle_events(&mut app, KeyCode::Char('w').into());
+        assert_eq!(State::Quit, state.state);
+        assert_eq!(2, Board::open(&file_name)?.column(0).size());
+
+        fs::remove_file(&file_name)
+    }
+
     #[test]
     fn toggle_help_popup() -> Result<()> {
         let mut app = App::new("".into());
@@ -85,11 +363,203 @@ mod tests {
         assert_eq!(State::Normal, state.state);
 
         state.handle_events(&mut app, KeyCode::Char('?').into());
-        assert_eq!(State::Help, state.state);
+        assert!(matches!(state.state, State::Help { .. }));
 
         state.handle_events(&mut app, KeyCode::Char('q').into());
         assert_eq!(State::Normal, state.state);
 
         Ok(())
     }
+
+    #[test]
+    fn jump_to_card_in_long_column() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| (1..=12).fold(c, |c, n| c.card(&format!("Task {}", n))))
+            .build();
+        let file_name = env::temp_dir().join("rustyban-jump-to-card.json").display().to_string();
+        board.to_file(&file_name)?;
+        let mut app = App::new(file_name.clone());
+        let mut state = AppState::new();
+        let selected = |app: &App| app.get_selected_card().unwrap().short_description().clone();
+
+        for key in ['\'', '1'] {
+            state.handle_events(&mut app, KeyCode::Char(key).into());
+        }
+        assert_eq!(State::GotoNumber { number: 1 }, state.state, "waits for a second digit");
+        state.handle_events(&mut app, KeyCode::Char('2').into());
+        assert_eq!(State::Normal, state.state);
+        assert_eq!("Task 12", selected(&app));
+
+        for key in ['\'', '1'] {
+            state.handle_events(&mut app, KeyCode::Char(key).into());
+        }
+        state.handle_events(&mut app, KeyCode::Enter.into());
+        assert_eq!("Task 1", selected(&app));
+
+        for key in ['\'', '3'] {
+            state.handle_events(&mut app, KeyCode::Char(key).into());
+        }
+        assert_eq!("Task 3", selected(&app));
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn locked_board_prompt() -> Result<()> {
+        let mut app = App::new("".into());
+
+        let mut state = AppState::locked("1234@host".into());
+        state.handle_events(&mut app, KeyCode::Char('x').into());
+        assert!(matches!(state.state, State::Locked { .. }));
+        state.handle_events(&mut app, KeyCode::Char('r').into());
+        assert_eq!(State::Normal, state.state);
+        assert!(app.is_read_only());
+
+        let mut state = AppState::locked("1234@host".into());
+        state.handle_events(&mut app, KeyCode::Char('q').into());
+        assert_eq!(State::Quit, state.state);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recovery_prompt() -> Result<()> {
+        let file_name = env::temp_dir()
+            .join("rustyban-recovery-prompt.json")
+            .display()
+            .to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+        app.select_next_card();
+        app.remove_card();
+        app.crash_report("Out of cheese".into());
+        let mut app = App::new(file_name.clone());
+        let recovery_file = crash::find_recovery_file(&file_name).expect("recovery file is newer");
+
+        let mut state = AppState::recovery(recovery_file.clone());
+        state.handle_events(&mut app, KeyCode::Char('d').into());
+        let State::Recovery { prompt } = &state.state else {
+            panic!("expected the recovery prompt, got {:?}", state.state);
+        };
+        assert!(prompt.is_showing_changes());
+
+        state.handle_events(&mut app, KeyCode::Esc.into());
+        assert_eq!(State::Normal, state.state);
+        assert_eq!("Buy milk", app.board().card(0, 0).short_description());
+
+        let mut state = AppState::recovery(recovery_file.clone());
+        state.handle_events(&mut app, KeyCode::Char('r').into());
+        assert_eq!(State::Normal, state.state);
+        assert_eq!("Buy eggs", app.board().card(0, 0).short_description());
+
+        app.write();
+        assert!(
+            !Path::new(&recovery_file).exists(),
+            "obsolete once the board is written"
+        );
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn health_popup() -> Result<()> {
+        let mut app = App::new("res/test_board.json".into());
+        let findings = app.health_check();
+        assert!(
+            matches!(findings[..], [Finding::OldInProgress { column_index: 1, .. }]),
+            "Cook dinner is in Doing since 2024"
+        );
+
+        let mut state = AppState::health(findings.clone());
+        state.handle_events(&mut app, KeyCode::Char('j').into());
+        assert!(matches!(state.state, State::Health { .. }));
+        state.handle_events(&mut app, KeyCode::Enter.into());
+        assert_eq!(State::Normal, state.state);
+        assert_eq!("Cook dinner", app.get_selected_card().unwrap().short_description());
+
+        let mut state = AppState::health(findings);
+        state.handle_events(&mut app, KeyCode::Esc.into());
+        assert_eq!(State::Normal, state.state);
+
+        Ok(())
+    }
+
+    #[test]
+    fn switch_history_branch() -> Result<()> {
+        let mut app = App::new("res/test_board.json".into());
+        let mut state = AppState::new();
+        state.handle_events(&mut app, KeyCode::Char('V').into());
+        assert_eq!(State::Normal, state.state, "no branch without history.tree");
+
+        let mut settings = Settings::default();
+        settings.history.tree = true;
+        let mut app = App::with_settings("res/test_board.json".into(), settings);
+        for key in ['j', 'x', 'u', 'j', 'x'] {
+            state.handle_events(&mut app, KeyCode::Char(key).into());
+        }
+        assert_eq!("Buy bread", app.get_selected_card().unwrap().short_description());
+
+        state.handle_events(&mut app, KeyCode::Char('V').into());
+        assert!(matches!(state.state, State::History { .. }));
+        state.handle_events(&mut app, KeyCode::Enter.into());
+        assert_eq!(State::Normal, state.state);
+        let board = app.board();
+        let titles: Vec<_> = board
+            .column(0)
+            .cards_iter()
+            .map(|card| card.short_description())
+            .collect();
+        assert_eq!(vec!["Buy eggs", "Buy bread"], titles);
+
+        state.handle_events(&mut app, KeyCode::Char('V').into());
+        assert!(matches!(state.state, State::History { .. }), "the other branch is kept");
+        state.handle_events(&mut app, KeyCode::Esc.into());
+        assert_eq!(State::Normal, state.state);
+
+        Ok(())
+    }
+
+    #[test]
+    fn startup_wizard_opens_board() -> Result<()> {
+        let mut app = App::new("".into());
+        let mut session = Session::default();
+        session.add_recent_board("res/test_board.json");
+
+        let mut state = AppState::startup(session);
+        state.handle_events(&mut app, KeyCode::Char('j').into());
+        state.handle_events(&mut app, KeyCode::Char('k').into());
+        assert!(matches!(state.state, State::Startup { .. }));
+
+        state.handle_events(&mut app, KeyCode::Enter.into());
+        assert_eq!(State::Normal, state.state);
+        assert_eq!("Buy milk", app.board().card(0, 0).short_description());
+
+        let mut state = AppState::startup(Session::default());
+        state.handle_events(&mut app, KeyCode::Esc.into());
+        assert_eq!(State::Normal, state.state);
+
+        Ok(())
+    }
+
+    #[test]
+    fn idle_prompt_after_idle_timer() -> Result<()> {
+        let mut app = App::new("res/test_board.json".into());
+        let mut state = AppState::new();
+
+        state.handle_events(&mut app, KeyCode::Char('j').into());
+        state.handle_events(&mut app, KeyCode::Char('t').into());
+        app.check_idle(Instant::now() + Duration::from_secs(11 * 60));
+
+        state.handle_events(&mut app, KeyCode::Char('j').into());
+        assert!(matches!(state.state, State::Idle { .. }));
+        state.handle_events(&mut app, KeyCode::Char('x').into());
+        assert!(matches!(state.state, State::Idle { .. }));
+        state.handle_events(&mut app, KeyCode::Char('d').into());
+        assert_eq!(State::Normal, state.state);
+
+        assert_eq!("Buy milk", app.get_selected_card().unwrap().short_description());
+        assert_eq!(Some((0, 0)), app.board().timed_card());
+
+        Ok(())
+    }
 }
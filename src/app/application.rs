@@ -0,0 +1,4410 @@
+use std::{
+    cell::{Cell, RefCell},
+    cmp::min,
+    collections::{HashMap, HashSet},
+    env, fs, io, mem,
+    path::Path,
+    process,
+    rc::Rc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::app::{
+    activity::{Activity, ActivityLog, SessionSummary},
+    app_runner::TICK_RATE,
+    board_lock::{BoardLock, LockError},
+    command::{
+        ArchiveCardsCommand, ChangePriorityCommand, ColumnDirection, Command, CompositeCommand, InsertCardCommand,
+        InsertColumnCommand, Mark, MarkCardCommand, MoveCardCommand, MoveCardsCommand, MoveColumnCommand, Priority,
+        RemoveCardCommand, RenameColumnCommand, RollOverCardsCommand, SortColumnCommand, UpdateCardCommand,
+        UpdateColumnCommand, UpdateNotesCommand,
+    },
+    crash::{self, CrashReport},
+    feed::{Feed, FeedEvent},
+    file_revision,
+    focus_view::FocusView,
+    notes_panel,
+    operations::{AppOperations, InsertPosition},
+    pomodoro::{self, Phase, Pomodoro},
+    session::PaneLayout,
+    timer::Timer,
+    toast::Toast,
+    tutorial::Tutorial,
+    view::{BoardView, CardPreview, Theme},
+    BranchSummary, History, Journal, JournalEntry, Logger,
+};
+use crate::board::{
+    self, append_stats_row, csv_cards, Board, BoardChange, BoardStatistics, BurnUp, ColumnOptions, CsvMapping,
+    ExportScope, FileSyncAdapter, Finding, MetricsHistory, SaveOptions, SavedSearch, SearchMatch, StandUp, StatsRow,
+    SyncAdapter, SyncPlan, Template,
+};
+use crate::config::{self, Density, Settings};
+use crate::utils::time::{self, DateFormatter};
+use crate::{
+    app::{BoardInvariantGuard, CardSelector},
+    board::Card,
+};
+
+const BOARD_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const TUTORIAL_FILE_NAME: &str = "rustyban-tutorial.json";
+const DEFAULT_RECOVERY_FILE_NAME: &str = "board.json";
+const CRASH_LOG_TAIL: usize = 5;
+const PREVIEW_DELAY_TICKS: u8 = 2;
+const FLASH_TICKS: u8 = 3;
+const BACKUP_EXTENSION: &str = "bak";
+const BACKUP_DATE_FORMAT: &str = "%Y%m%d-%H%M%S";
+const MAX_LOGGED_CHANGES: usize = 10;
+const SIDE_PANEL_WIDTH: u16 = 44;
+const BURN_UP_WIDTH: usize = 10;
+/// Longest grace period after a deletion, whatever the settings, so that its end can always be computed
+const MAX_DELETION_GRACE_PERIOD: Duration = Duration::from_secs(24 * 3600);
+
+#[derive(Debug)]
+pub struct App {
+    /// The board shown and what goes with it, exchanged as a whole with the alternate board
+    current: BoardState,
+    logger: Logger,
+    guard: BoardInvariantGuard,
+    focus_mode: bool,
+    card_numbers: bool,
+    /// Cards of the last column completed before this date are hidden from view, when hiding old done cards
+    hide_done_before: Option<DateTime<Local>>,
+    /// Only starred cards are shown, as a short list of what to focus on
+    starred_only: bool,
+    /// How much of each card is shown, switched at runtime
+    density: Density,
+    /// Sections of columns shown collapsed, by name
+    collapsed_sections: HashSet<String>,
+    settings: Settings,
+    theme: Theme,
+    toast: Option<Toast>,
+    /// Ticks since the selection last moved, the preview of the selected card shows after a short while
+    preview_ticks: u8,
+    /// Where the selected card was last drawn, for the popups shown over it
+    selected_card_area: Cell<Option<Rect>>,
+    /// Whether changes are journaled next to the board file before being applied, only when run interactively
+    journaling: bool,
+    split: Option<Split>,
+    /// Board opened before the current one in this pane, kept as it was to switch back to it
+    alternate: Option<Box<BoardState>>,
+    last_input: Instant,
+    activity: ActivityLog,
+    /// Whether the recent changes to the board are shown next to it
+    feed_visible: bool,
+    feed_focused: bool,
+    /// Whether the notes of the board are shown next to it, they are edited in place from there
+    notes_visible: bool,
+    /// Where the notes were drawn on the last render, if they were visible
+    notes_area: Cell<Option<Rect>>,
+    tutorial: Option<Tutorial>,
+    layout: PaneLayout,
+    /// Text to copy to the clipboard, written through the terminal by the runner
+    to_clipboard: Option<String>,
+}
+
+/// Second board shown next to the main one
+#[derive(Debug)]
+struct Split {
+    other: Box<App>,
+    other_focused: bool,
+}
+
+/// What belongs to the board shown in a pane, from its file to its selection and history
+#[derive(Debug)]
+struct BoardState {
+    file_name: String,
+    board: Rc<RefCell<Board>>,
+    selector: CardSelector,
+    history: History,
+    last_board_check: Instant,
+    archived_today: (NaiveDate, usize),
+    /// Card whose priority just changed and the ticks left to highlight it, so it can be followed
+    flashed_card: Option<((usize, usize), u8)>,
+    deletion_grace_until: Option<Instant>,
+    lock: Option<BoardLock>,
+    read_only: bool,
+    journal: Option<Journal>,
+    /// Revision of the board file and the board it held when last read or written, to notice changes made elsewhere
+    on_disk: Option<(u64, Board)>,
+    /// Board pushed by the last sync, kept as the last synced version once the board file is written
+    synced: Option<Board>,
+    /// Recovery file the board was restored from, removed once the restored board is written
+    recovered_from: Option<String>,
+    /// Daily snapshots of the columns, kept next to the board file
+    metrics: MetricsHistory,
+    /// Query of the last search run, whose results can be exported
+    last_search: Option<String>,
+    timer: Option<Timer>,
+    pomodoro: Option<Pomodoro>,
+    /// Recent changes to the board, shown next to it when `feed_visible`
+    feed: Feed,
+    /// Column and index where the selected card lands, while it is being moved
+    move_target: Option<(usize, usize)>,
+}
+
+impl BoardState {
+    fn new(file_name: String, mut board: Board, settings: &Settings) -> Self {
+        board.set_author(settings.user_name());
+        let board = Rc::new(RefCell::new(board));
+        let selector = CardSelector::new(Rc::clone(&board));
+
+        Self {
+            file_name,
+            board,
+            selector,
+            history: new_history(settings),
+            last_board_check: Instant::now(),
+            archived_today: (Local::now().date_naive(), 0),
+            flashed_card: None,
+            deletion_grace_until: None,
+            lock: None,
+            read_only: false,
+            journal: None,
+            on_disk: None,
+            synced: None,
+            recovered_from: None,
+            metrics: MetricsHistory::default(),
+            last_search: None,
+            timer: None,
+            pomodoro: None,
+            feed: Feed::default(),
+            move_target: None,
+        }
+    }
+
+    /// Whether two boards would be written the same to the board file, boards that cannot be written differ
+    fn same_revision(&self, board: &Board, other: &Board) -> bool {
+        match (board.revision(&self.file_name), other.revision(&self.file_name)) {
+            (Ok(revision), Ok(other)) => revision == other,
+            _ => false,
+        }
+    }
+
+    /// Whether the board differs from its file as last read or written
+    fn has_unwritten_changes(&self) -> bool {
+        match &self.on_disk {
+            Some((_, board)) => !self.same_revision(board, &self.board.borrow()),
+            None => true,
+        }
+    }
+
+    /// Whether quitting would lose changes, a board whose file does not exist yet only counts once it is not a
+    /// new board anymore
+    fn loses_changes_on_quit(&self) -> bool {
+        match &self.on_disk {
+            Some(_) => self.has_unwritten_changes(),
+            None => !self.file_name.is_empty() && !self.same_revision(&Board::new(), &self.board.borrow()),
+        }
+    }
+
+    /// Writes the board as it is in memory to `<file name>.crash`, without touching the board file
+    fn write_recovery_file(&self) -> Result<String, String> {
+        let file_name = match self.file_name.as_str() {
+            "" => crash::recovery_file_name(DEFAULT_RECOVERY_FILE_NAME),
+            file_name => crash::recovery_file_name(file_name),
+        };
+        let board = self.board.try_borrow().map_err(|e| e.to_string())?;
+        board.to_file(&file_name).map_err(|e| e.to_string())?;
+
+        Ok(file_name)
+    }
+}
+
+impl App {
+    pub fn new(file_name: String) -> Self {
+        Self::with_settings(file_name, Settings::default())
+    }
+
+    pub fn with_settings(file_name: String, settings: Settings) -> Self {
+        let mut logger = Logger::new();
+        let board = if !file_name.is_empty() {
+            match Board::open(&file_name) {
+                Ok(board) => board,
+                Err(e) => {
+                    logger.log(format!(
+                        "Cannot read file {} because {}, creating a new board",
+                        file_name, e
+                    ));
+                    Board::new()
+                }
+            }
+        } else {
+            logger.log("No file to open, creating a new board".to_string());
+            Board::new()
+        };
+
+        Self::from_board(file_name, board, logger, settings)
+    }
+
+    /// Starts on a board already read from the file
+    pub fn with_board(file_name: String, board: Board, settings: Settings) -> Self {
+        Self::from_board(file_name, board, Logger::new(), settings)
+    }
+
+    /// Opens the tutorial board, whose steps are checked off as the user performs them
+    pub fn tutorial(settings: Settings) -> Self {
+        let file_name = env::temp_dir().join(TUTORIAL_FILE_NAME).display().to_string();
+        let mut app = Self::from_board(file_name, Tutorial::board(), Logger::new(), settings);
+        app.tutorial = Some(Tutorial::new());
+        app.log("Welcome! Follow the cards of the first column, they are checked off as you go".to_string());
+        app
+    }
+
+    fn from_board(file_name: String, board: Board, logger: Logger, settings: Settings) -> Self {
+        let mut app = App {
+            current: BoardState::new(file_name, board, &settings),
+            logger,
+            guard: BoardInvariantGuard::new(settings.debug.check_invariants),
+            focus_mode: false,
+            card_numbers: false,
+            hide_done_before: None,
+            starred_only: false,
+            density: settings.view.density,
+            collapsed_sections: HashSet::new(),
+            settings,
+            theme: Theme::default(),
+            toast: None,
+            preview_ticks: 0,
+            selected_card_area: Cell::new(None),
+            journaling: false,
+            split: None,
+            alternate: None,
+            last_input: Instant::now(),
+            activity: ActivityLog::new(),
+            feed_visible: false,
+            feed_focused: false,
+            notes_visible: false,
+            notes_area: Cell::new(None),
+            tutorial: None,
+            layout: PaneLayout::default(),
+            to_clipboard: None,
+        };
+        app.open_current_board();
+
+        app
+    }
+
+    /// Reads what goes with the board just opened, next to its file
+    fn open_current_board(&mut self) {
+        self.checkpoint();
+        self.remember_disk_version();
+        self.load_metrics();
+        self.check_board();
+    }
+
+    /// Called regularly by the runner when no event happens
+    pub fn tick(&mut self) {
+        if let Some(split) = self.split.as_mut() {
+            split.other.tick();
+        }
+        self.with_alternate(|app| app.tick_board(Instant::now()));
+
+        time::refresh_age_labels();
+        self.preview_ticks = self.preview_ticks.saturating_add(1);
+        if let Some(toast) = self.toast.as_mut() {
+            if !toast.tick() {
+                self.toast = None;
+            }
+        }
+        self.tick_board(Instant::now());
+    }
+
+    /// Keeps the board up to date and its timers running, whether it is shown or not
+    fn tick_board(&mut self, now: Instant) {
+        self.current.flashed_card = self
+            .current
+            .flashed_card
+            .and_then(|(position, ticks)| (ticks > 1).then_some((position, ticks - 1)));
+        if self.current.last_board_check.elapsed() >= BOARD_CHECK_INTERVAL && !self.is_in_deletion_grace_period(now) {
+            self.check_board();
+        }
+        if !self.is_in_deletion_grace_period(now) {
+            self.roll_over_cards(Local::now());
+        }
+
+        self.check_idle(now);
+        self.check_pomodoro(now);
+    }
+
+    /// Whether a card was deleted too recently for irreversible follow-ups, like archiving, to happen
+    pub fn is_in_deletion_grace_period(&self, now: Instant) -> bool {
+        self.current.deletion_grace_until.is_some_and(|until| now < until)
+    }
+
+    /// Starts the timer on the selected card, or stops it when it already runs on that card
+    ///
+    /// The time counted on another card is added to it before switching. A running pomodoro is stopped instead.
+    pub fn toggle_timer(&mut self) {
+        if self.current.pomodoro.is_some() {
+            self.stop_pomodoro();
+            return;
+        }
+        let (Some(card), Some(position)) = (self.get_selected_card(), self.current.selector.get()) else {
+            self.log("No card selected".to_string());
+            return;
+        };
+
+        self.stop_timer();
+        if card.is_timed() {
+            return;
+        }
+
+        self.current.board.borrow_mut().set_timed_card(Some(position));
+        self.current.timer = Some(Timer::start(Instant::now()));
+        self.notify(format!("Started timer on '{}'", card.short_description()));
+    }
+
+    /// Stops the timer and adds the time counted to its card
+    pub fn stop_timer(&mut self) {
+        if self.current.timer.is_none() {
+            return;
+        }
+
+        self.flush_timer(Instant::now());
+        self.current.timer = None;
+        let mut board = self.current.board.borrow_mut();
+        let Some((column_index, card_index)) = board.timed_card() else {
+            drop(board);
+            self.log("The timed card is not on the board anymore, its time is lost".to_string());
+            return;
+        };
+
+        let card = board.card(column_index, card_index);
+        let message = format!(
+            "Stopped timer on '{}', {} spent on it",
+            card.short_description(),
+            time::pretty_duration(card.time_spent())
+        );
+        board.set_timed_card(None);
+        drop(board);
+        self.notify(message);
+    }
+
+    /// Starts a pomodoro with the timer on the selected card, or stops the running one
+    pub fn toggle_pomodoro(&mut self) {
+        if self.current.pomodoro.is_some() {
+            self.stop_pomodoro();
+            return;
+        }
+        let Some(card) = self.get_selected_card() else {
+            self.log("No card selected".to_string());
+            return;
+        };
+
+        let settings = &self.settings.pomodoro;
+        let pomodoro = pomodoro::minutes(settings.work_minutes)
+            .zip(pomodoro::minutes(settings.break_minutes))
+            .and_then(|(work, rest)| Pomodoro::start(Instant::now(), work, rest));
+        let Some(pomodoro) = pomodoro else {
+            self.log(format!("A pomodoro of {} minutes is too long", settings.work_minutes));
+            return;
+        };
+
+        let work_minutes = settings.work_minutes;
+        if !card.is_timed() {
+            self.toggle_timer();
+        }
+        self.current.pomodoro = Some(pomodoro);
+        self.log(format!("Pomodoro of {} minutes started", work_minutes));
+    }
+
+    /// Stops the pomodoro and its timer, the time counted is added to the card
+    fn stop_pomodoro(&mut self) {
+        let Some(pomodoro) = self.current.pomodoro.take() else {
+            return;
+        };
+
+        match pomodoro.phase() {
+            Phase::Work => self.stop_timer(),
+            Phase::Break => self.current.board.borrow_mut().set_timed_card(None),
+        }
+        self.log("Pomodoro stopped".to_string());
+    }
+
+    /// Switches between the work and the break of the pomodoro when their time is over, checked at each tick
+    ///
+    /// The timer stops during the break, and a completed pomodoro is counted on the card.
+    fn check_pomodoro(&mut self, now: Instant) {
+        let Some(phase) = self
+            .current
+            .pomodoro
+            .as_mut()
+            .and_then(|pomodoro| pomodoro.advance(now))
+        else {
+            return;
+        };
+
+        let tracked = match phase {
+            Phase::Break => {
+                self.flush_timer(now);
+                self.current.timer = None;
+                self.current.board.borrow_mut().track_pomodoro()
+            }
+            Phase::Work => self.current.board.borrow().timed_card().is_some(),
+        };
+        let board = self.current.board.borrow();
+        let Some((column_index, card_index)) = board.timed_card().filter(|_| tracked) else {
+            drop(board);
+            self.current.pomodoro = None;
+            self.current.timer = None;
+            self.log("The timed card is not on the board anymore, pomodoro stopped".to_string());
+            return;
+        };
+
+        let card = board.card(column_index, card_index);
+        let message = match phase {
+            Phase::Break => format!(
+                "Pomodoro {} completed on '{}', take a {} minutes break",
+                card.pomodoros(),
+                card.short_description(),
+                self.settings.pomodoro.break_minutes
+            ),
+            Phase::Work => format!("Break over, back to '{}'", card.short_description()),
+        };
+        drop(board);
+        if phase == Phase::Work {
+            self.current.timer = Some(Timer::start(now));
+        }
+        match self.settings.pomodoro.notify {
+            true => self.notify(message),
+            false => self.log(message),
+        }
+    }
+
+    /// Adds the time counted so far to the timed card, the timer keeps running
+    fn flush_timer(&mut self, now: Instant) {
+        if let Some(timer) = self.current.timer.as_mut() {
+            let duration = timer.take(now);
+            if self.current.board.borrow_mut().track_time(duration) {
+                self.record_activity(Activity::TimeTracked(duration));
+            }
+        }
+    }
+
+    /// Records a key press, returns the idle period of a timer paused while waiting for it, if any
+    pub fn register_input(&mut self, now: Instant) -> Option<Duration> {
+        self.last_input = now;
+        let other_idle = self.split.as_mut().and_then(|split| split.other.register_input(now));
+        let alternate_idle = self
+            .alternate
+            .as_mut()
+            .and_then(|alternate| alternate.timer.as_mut())
+            .and_then(|timer| timer.wake_up(now));
+
+        self.current
+            .timer
+            .as_mut()
+            .and_then(|timer| timer.wake_up(now))
+            .or(other_idle)
+            .or(alternate_idle)
+    }
+
+    /// What was done since the application started, including in the split and with the timers running on the
+    /// boards
+    pub fn session_summary(&self) -> SessionSummary {
+        let mut summary = self.activity.summary();
+        if let Some(timer) = self.current.timer.as_ref() {
+            summary.time_tracked += timer.elapsed(Instant::now());
+        }
+        if let Some(timer) = self.alternate.as_ref().and_then(|alternate| alternate.timer.as_ref()) {
+            summary.time_tracked += timer.elapsed(Instant::now());
+        }
+
+        match &self.split {
+            Some(split) => summary.merge(split.other.session_summary()),
+            None => summary,
+        }
+    }
+
+    /// The summary to print when quitting, when enabled in the settings and something was done
+    pub fn quit_summary(&self) -> Option<SessionSummary> {
+        let summary = self.session_summary();
+        (self.settings.session.summary_on_quit && !summary.is_empty()).then_some(summary)
+    }
+
+    /// Keeps or discards the idle periods of the timers, on the user's return
+    pub fn resolve_idle(&mut self, keep: bool) {
+        if let Some(split) = self.split.as_mut() {
+            split.other.resolve_idle(keep);
+        }
+        self.with_alternate(|app| app.resolve_timer_idle(keep));
+        self.resolve_timer_idle(keep);
+    }
+
+    fn resolve_timer_idle(&mut self, keep: bool) {
+        let Some(idle) = self.current.timer.as_mut().and_then(|timer| timer.resolve_idle(keep)) else {
+            return;
+        };
+        let message = match keep {
+            true => format!("Kept {} of idle time", time::pretty_duration(idle)),
+            false => format!("Discarded {} of idle time", time::pretty_duration(idle)),
+        };
+        self.notify(message);
+    }
+
+    /// Pauses the timer when no key has been pressed for the configured idle time, checked at each tick
+    pub fn check_idle(&mut self, now: Instant) {
+        let idle_minutes = self.settings.timer.idle_minutes;
+        let Some(timer) = self.current.timer.as_mut() else {
+            return;
+        };
+        if idle_minutes == 0 || timer.is_idle() {
+            return;
+        }
+
+        if now.saturating_duration_since(self.last_input) >= Duration::from_secs(idle_minutes as u64 * 60) {
+            timer.pause_idle(self.last_input);
+            self.log(format!("Timer paused after {} minutes without activity", idle_minutes));
+        }
+    }
+
+    pub fn statistics(&self) -> BoardStatistics {
+        BoardStatistics::compute(
+            &self.current.board.as_ref().borrow(),
+            &self.settings.stale_days,
+            Local::now(),
+        )
+    }
+
+    /// What looks wrong on the board, checked when opening it unless disabled in the settings
+    pub fn health_check(&self) -> Vec<Finding> {
+        if !self.settings.health.check_on_open {
+            return vec![];
+        }
+
+        board::health_check(
+            &self.current.board.borrow(),
+            &self.settings.stale_days,
+            &self.wip_limits(),
+            Local::now(),
+        )
+    }
+
+    /// Maximum number of cards per column header, the limit set on a column replaces the one of the configuration
+    fn wip_limits(&self) -> HashMap<String, usize> {
+        let mut wip_limits = self.settings.wip_limits.clone();
+        for column in self.current.board.borrow().columns_iter() {
+            if let Some(limit) = column.wip_limit() {
+                wip_limits.insert(column.header().to_string(), limit);
+            }
+        }
+        wip_limits
+    }
+
+    /// What moved into the columns since the day set for stand-ups, like the last business day
+    pub fn standup(&self) -> StandUp {
+        let since = self.settings.standup.since.day(Local::now().date_naive());
+        StandUp::compute(&self.current.board.borrow(), since)
+    }
+
+    /// Copies the stand-up summary to the clipboard, as text to paste in a chat
+    pub fn copy_standup(&mut self) {
+        self.to_clipboard = Some(self.standup().to_text());
+        self.log("Stand-up summary copied to the clipboard".to_string());
+    }
+
+    /// Copies the whole board to the clipboard as markdown, to paste in a pull request or a chat
+    pub fn copy_board_as_markdown(&mut self) {
+        self.to_clipboard = Some(self.current.board.borrow().to_markdown());
+        self.notify("Board copied to the clipboard as markdown".to_string());
+    }
+
+    /// Text copied from any of the panes, for the runner to send to the clipboard through the terminal
+    pub fn take_clipboard(&mut self) -> Option<String> {
+        let from_split = self.split.as_mut().and_then(|split| split.other.take_clipboard());
+        self.to_clipboard.take().or(from_split)
+    }
+
+    /// Inserts a scratch card at the top of the selected column and selects it, returns the new card
+    ///
+    /// Scratch cards are reminders for the working session, they are never written to the board file.
+    pub fn insert_scratch_card(&mut self) -> Option<Card> {
+        self.insert_new_card(InsertPosition::Top, true)
+    }
+
+    fn insert_new_card(&mut self, position: InsertPosition, scratch: bool) -> Option<Card> {
+        // No card to edit when it cannot be inserted, whether a card is selected in the column or not
+        if !self.check_writable(&[self.current.selector.column()]) {
+            return None;
+        }
+
+        self.with_selected_column(|this, column_index, card_index| {
+            let card_index = match position {
+                InsertPosition::Current => card_index,
+                InsertPosition::Next => card_index + 1,
+                InsertPosition::Top => 0,
+                InsertPosition::Bottom => this.current.board.as_ref().borrow().column(column_index).size(),
+            };
+
+            let card_index = min(
+                card_index,
+                this.current.board.as_ref().borrow().column(column_index).size(),
+            );
+            let mut card = this
+                .current
+                .board
+                .as_ref()
+                .borrow()
+                .column(column_index)
+                .card_template()
+                .new_card(Local::now());
+            card.set_scratch(scratch);
+            this.execute(InsertCardCommand::new(column_index, card_index, card))
+        });
+
+        self.get_selected_card()
+    }
+
+    /// The selected card, to open in the editor, unless its column is read-only
+    pub fn card_to_edit(&mut self) -> Option<Card> {
+        let card = self.get_selected_card()?;
+        self.check_writable(&[self.current.selector.column()]).then_some(card)
+    }
+
+    /// Where the selected card was drawn on the last render, if it was visible
+    pub fn selected_card_area(&self) -> Option<Rect> {
+        self.selected_card_area.get()
+    }
+
+    /// Changes the short description of the selected card, leaving the rest of it as it is
+    pub fn update_card_title(&mut self, title: &str) {
+        let Some(mut card) = self.get_selected_card() else {
+            self.log("No card selected".to_string());
+            return;
+        };
+        if card.short_description() != title {
+            card.update_short_description(title);
+            self.update_card(card);
+        }
+    }
+
+    /// Length of short descriptions beyond which the card editor warns
+    pub fn title_soft_limit(&self) -> usize {
+        self.settings.editor.title_soft_limit
+    }
+
+    /// The effective value of every setting, as dotted paths and values written as in the configuration file
+    pub fn settings_entries(&self) -> Vec<(String, String)> {
+        self.settings.entries()
+    }
+
+    /// Changes a setting and writes it to the configuration file, an empty value brings back the default
+    ///
+    /// Settings read as they are used, like WIP limits, and date formats apply at once, the others, like the size of
+    /// the history, at the next start.
+    pub fn change_setting(&mut self, path: &str, value: &str) -> Result<(), String> {
+        let settings = self.settings.with_setting(path, value)?;
+        let file = config::persist(path, value).map_err(|e| e.to_string())?;
+        if settings.dates != self.settings.dates {
+            time::init(DateFormatter::new(&settings.dates));
+        }
+        self.settings = settings;
+        self.log(format!("Setting {} written to {}", path, file.display()));
+        Ok(())
+    }
+
+    /// Header of the selected column and number of its cards beyond the maximum, when it can be split
+    pub fn column_split(&mut self) -> Option<(String, usize)> {
+        let Some(max_cards) = self.settings.split.max_cards else {
+            self.log("No maximum number of cards per column, set split.max_cards".to_string());
+            return None;
+        };
+        let Some((column_index, _)) = self.current.selector.insertion_point() else {
+            self.log("No column selected".to_string());
+            return None;
+        };
+
+        let (header, size) = {
+            let board = self.current.board.borrow();
+            let column = board.column(column_index);
+            (column.header().to_string(), column.size())
+        };
+        if size <= max_cards {
+            self.log(format!("{} has no more than {} cards", header, max_cards));
+            return None;
+        }
+        Some((header, size - max_cards))
+    }
+
+    /// Moves the cards of the selected column beyond the maximum to a new column after it, as a single change
+    pub fn split_column(&mut self, header: &str) {
+        let Some(max_cards) = self.settings.split.max_cards else {
+            return;
+        };
+        self.with_selected_column(|this, column_index, _| {
+            if this.check_writable(&[column_index]) {
+                this.backup_before("Split column");
+            }
+            this.execute(CompositeCommand::new(
+                "Split column",
+                vec![
+                    Box::new(InsertColumnCommand::new(column_index + 1, header)),
+                    Box::new(MoveCardsCommand::new(column_index, max_cards, column_index + 1)),
+                ],
+            ))
+        });
+    }
+
+    /// Offers to split the first column a change took beyond the maximum number of cards
+    fn offer_split(&mut self, sizes: &[(usize, usize)]) {
+        let Some(max_cards) = self.settings.split.max_cards else {
+            return;
+        };
+        let crossed = sizes.iter().find_map(|&(column_index, size)| {
+            let board = self.current.board.borrow();
+            let column = board.columns_iter().nth(column_index)?;
+            (size <= max_cards && column.size() > max_cards).then(|| column.header().to_string())
+        });
+        if let Some(header) = crossed {
+            self.log(format!("{} has more than {} cards, <P> splits it", header, max_cards));
+        }
+    }
+
+    /// Texts inserted in the card editor by name
+    pub fn snippets(&self) -> &HashMap<String, String> {
+        &self.settings.snippets
+    }
+
+    pub fn metrics(&self) -> &MetricsHistory {
+        &self.current.metrics
+    }
+
+    fn load_metrics(&mut self) {
+        if self.current.file_name.is_empty() {
+            self.current.metrics = MetricsHistory::default();
+            return;
+        }
+        self.current.metrics = match MetricsHistory::open(&self.current.file_name) {
+            Ok(metrics) => metrics,
+            Err(e) => {
+                self.log(format!(
+                    "Cannot read the metrics of {} because {}",
+                    self.current.file_name, e
+                ));
+                MetricsHistory::default()
+            }
+        };
+    }
+
+    /// Takes the snapshot of the day on the first write of the day
+    fn record_metrics(&mut self) {
+        if !self.current.metrics.record(
+            &self.current.board.borrow().without_scratch_cards(),
+            Local::now().date_naive(),
+        ) {
+            return;
+        }
+        if let Err(e) = self.current.metrics.to_file(&self.current.file_name) {
+            self.log(format!(
+                "Cannot write the metrics of {} because {}",
+                self.current.file_name, e
+            ));
+        }
+    }
+
+    /// Appends the counts of the board just written to its statistics log, when enabled
+    fn log_statistics(&mut self, board: &Board) {
+        if !self.settings.stats_log.enabled {
+            return;
+        }
+        let row = StatsRow::compute(&board.without_scratch_cards(), Local::now());
+        if let Err(e) = append_stats_row(&self.current.file_name, &row) {
+            self.log(format!(
+                "Cannot log the statistics of {} because {}",
+                self.current.file_name, e
+            ));
+        }
+    }
+
+    /// Archives old completed cards, rolls the unfinished cards of the day over and reports stale ones
+    fn check_board(&mut self) {
+        self.current.last_board_check = Instant::now();
+        self.archive_done_cards();
+        self.roll_over_cards(Local::now());
+        self.check_stale_cards();
+        self.check_balance();
+    }
+
+    fn archive_done_cards(&mut self) {
+        let Some(after_days) = self.settings.archive.after_days else {
+            return;
+        };
+        if self.current.read_only
+            || self
+                .current
+                .board
+                .borrow()
+                .columns_iter()
+                .last()
+                .is_none_or(|column| column.is_read_only())
+        {
+            return;
+        }
+
+        let now = Local::now();
+        if self.current.archived_today.0 != now.date_naive() {
+            self.current.archived_today = (now.date_naive(), 0);
+        }
+
+        let mut board = self.current.board.borrow().clone();
+        let archived = board.archive_done_cards(after_days, now);
+        if self.current.same_revision(&board, &self.current.board.borrow()) {
+            return;
+        }
+        self.backup_before("Archive done cards");
+        let (selected_column, selected_card) = self.current.selector.position();
+        self.execute(ArchiveCardsCommand::new(after_days, now));
+        self.current.selector.set(selected_column, selected_card);
+
+        if archived.is_empty() {
+            return;
+        }
+        self.current.archived_today.1 += archived.len();
+
+        let cards: Vec<String> = archived
+            .iter()
+            .map(|card| format!("'{}'", card.short_description()))
+            .collect();
+        self.notify(format!("Archived {} cards: {}", cards.len(), cards.join(", ")));
+    }
+
+    /// Moves the cards which entered the daily planning column before the last rollover time back to their column
+    fn roll_over_cards(&mut self, now: DateTime<Local>) {
+        let settings = &self.settings.rollover;
+        let Some(header) = settings.column.as_deref() else {
+            return;
+        };
+        let today_at = time::first_valid_time(&Local, now.date_naive().and_time(settings.at));
+        let before = match today_at <= now {
+            true => today_at,
+            false => today_at - TimeDelta::days(1),
+        };
+
+        let board = self.current.board.borrow();
+        let position = |header: &str| board.columns_iter().position(|column| column.header() == header);
+        let Some(column_index) = position(header) else {
+            return;
+        };
+        let new_column_index = match settings.to.as_deref() {
+            Some(to) => match position(to) {
+                Some(index) => index,
+                None => return,
+            },
+            None => 0,
+        };
+        let column = board.column(column_index);
+        if self.current.read_only
+            || column_index == new_column_index
+            || column.is_read_only()
+            || board.column(new_column_index).is_read_only()
+            || column.cards_iter().all(|card| card.entered_column_date() >= before)
+        {
+            return;
+        }
+        let cards: Vec<String> = column
+            .cards_iter()
+            .filter(|card| card.entered_column_date() < before)
+            .map(|card| format!("'{}'", card.short_description()))
+            .collect();
+        drop(board);
+
+        let (selected_column, selected_card) = self.current.selector.position();
+        self.execute(RollOverCardsCommand::new(column_index, new_column_index, before, now));
+        self.current.selector.set(selected_column, selected_card);
+
+        let board = self.current.board.borrow();
+        let message = format!(
+            "Rolled {} unfinished cards over from {} to {}: {}",
+            cards.len(),
+            board.column(column_index).header(),
+            board.column(new_column_index).header(),
+            cards.join(", ")
+        );
+        drop(board);
+        self.notify(message);
+    }
+
+    fn check_stale_cards(&mut self) {
+        let statistics = self.statistics();
+        let stale_cards = statistics.stale_cards();
+        if stale_cards.is_empty() {
+            return;
+        }
+
+        let cards: Vec<String> = stale_cards
+            .iter()
+            .map(|stale| {
+                format!(
+                    "'{}' in {} ({} days)",
+                    stale.short_description, statistics.columns[stale.column_index].header, stale.age_in_days
+                )
+            })
+            .collect();
+        self.log(format!("{} stale cards: {}", cards.len(), cards.join(", ")));
+    }
+
+    /// Logs the suggestions to balance the columns, in advisory mode
+    fn check_balance(&mut self) {
+        if !self.settings.advice.enabled {
+            return;
+        }
+
+        let found = board::suggestions(
+            &self.current.board.borrow(),
+            &self.statistics(),
+            &self.current.metrics,
+            &self.wip_limits(),
+            Local::now(),
+        );
+        for suggestion in found {
+            self.log(format!("Suggestion: {}", suggestion));
+        }
+    }
+
+    /// Shows the feed of recent changes next to the board and moves the focus to it
+    pub fn focus_feed(&mut self) {
+        self.feed_visible = true;
+        self.feed_focused = true;
+        self.current.feed.select_first();
+    }
+
+    /// Gives the focus back to the board, the feed stays visible
+    pub fn leave_feed(&mut self) {
+        self.feed_focused = false;
+    }
+
+    pub fn hide_feed(&mut self) {
+        self.feed_visible = false;
+        self.feed_focused = false;
+    }
+
+    pub fn feed_mut(&mut self) -> &mut Feed {
+        &mut self.current.feed
+    }
+
+    /// Selects the card of the selected event of the feed, found by its title and creation date
+    pub fn select_feed_card(&mut self) {
+        let Some(card) = self.current.feed.selected().and_then(|event| event.card()).cloned() else {
+            self.log("No card for this event".to_string());
+            return;
+        };
+
+        let position = board::find_card(&self.current.board.borrow(), &card);
+        match position {
+            Some((column_index, card_index)) => self.select_card(column_index, card_index),
+            None => self.log(format!("'{}' is no longer on the board", card.short_description())),
+        }
+    }
+
+    /// Branches of the undo history that can be switched to, oldest first
+    pub fn history_branches(&self) -> Vec<BranchSummary> {
+        self.current.history.branches()
+    }
+
+    /// Goes back to where a branch of the undo history starts and redoes its changes
+    pub fn switch_history_branch(&mut self, index: usize) {
+        let Some(columns) = self.current.history.branch_columns(index) else {
+            self.log("No such branch in the history".to_string());
+            return;
+        };
+        if !self.check_writable(&columns) {
+            return;
+        }
+
+        let position = self
+            .current
+            .history
+            .switch_branch(index, &mut self.current.board.as_ref().borrow_mut());
+        let Some((column_index, card_index)) = position else {
+            self.log("No such branch in the history".to_string());
+            return;
+        };
+
+        self.journal(JournalEntry::Board { board: self.board() });
+        self.current.selector.set(column_index, card_index);
+        self.check_invariants(Some("switch history branch".to_string()));
+        let description = self.current.history.last_redo_description().unwrap_or_default();
+        let text = format!("Switched to the branch ending with: {}", description);
+        self.notify(text.clone());
+        self.record_change(text, None, None);
+    }
+
+    pub fn toggle_focus_mode(&mut self) {
+        self.focus_mode = !self.focus_mode;
+    }
+
+    /// Shows the number of each card in its column, to jump to a card with `'` and its number, or hides them
+    pub fn toggle_card_numbers(&mut self) {
+        self.card_numbers = !self.card_numbers;
+    }
+
+    /// Switches to the next board density, from a single line per card to most of its fields
+    pub fn cycle_density(&mut self) {
+        self.density = self.density.next();
+        self.log(format!("Showing cards with {} density", self.density.name()));
+    }
+
+    /// Collapses the section of the selected column into a narrow strip, or expands it when it is collapsed
+    pub fn toggle_section(&mut self) {
+        let column_index = self.current.selector.column();
+        let Some(section) = self
+            .current
+            .board
+            .borrow()
+            .column(column_index)
+            .section()
+            .map(String::from)
+        else {
+            self.log("The selected column is not in a section".to_string());
+            return;
+        };
+
+        if self.collapsed_sections.remove(&section) {
+            self.log(format!("Expanded section {}", section));
+        } else {
+            self.collapsed_sections.insert(section.clone());
+            self.log(format!("Collapsed section {}", section));
+        }
+        self.current.selector.collapse_sections(self.collapsed_sections.clone());
+    }
+
+    /// Hides the cards of the last column completed long ago, or shows them again, they stay on the board
+    pub fn toggle_old_done_cards(&mut self) {
+        let days = self.settings.view.hide_done_after_days;
+        self.hide_done_before = match self.hide_done_before {
+            Some(_) => None,
+            None => Some(Local::now() - TimeDelta::days(days as i64)),
+        };
+        self.current.selector.hide_done_before(self.hide_done_before);
+
+        match self.hide_done_before {
+            Some(_) => self.log(format!("Hiding done cards completed more than {} days ago", days)),
+            None => self.log("Showing all done cards".to_string()),
+        }
+    }
+
+    /// Stars the selected card to keep an eye on it, or unstars it
+    pub fn toggle_star(&mut self) {
+        let Some((column_index, _)) = self.current.selector.get() else {
+            self.log("No card selected".to_string());
+            return;
+        };
+        let Some(mut card) = self.get_selected_card() else {
+            self.log("No card selected".to_string());
+            return;
+        };
+        if !self.check_writable(&[column_index]) {
+            return;
+        }
+
+        card.set_starred(!card.is_starred());
+        let message = match card.is_starred() {
+            true => format!("Starred '{}'", card.short_description()),
+            false => format!("Unstarred '{}'", card.short_description()),
+        };
+        self.update_card(card);
+        self.current.selector.starred_only(self.starred_only);
+        self.notify(message);
+    }
+
+    pub fn toggle_starred_only(&mut self) {
+        self.starred_only = !self.starred_only;
+        self.current.selector.starred_only(self.starred_only);
+
+        match self.starred_only {
+            true => self.log("Showing starred cards only".to_string()),
+            false => self.log("Showing all cards".to_string()),
+        }
+    }
+
+    /// Opens a board in a split next to the current one and focuses it, replacing any board already split
+    pub fn open_split(&mut self, file_name: String) {
+        let mut other = App::with_settings(file_name, self.settings.clone());
+        other.acquire_lock();
+
+        self.split = Some(Split {
+            other: Box::new(other),
+            other_focused: true,
+        });
+    }
+
+    pub fn close_split(&mut self) {
+        match self.split.take() {
+            Some(mut split) => self.activity.append(&mut split.other.activity),
+            None => self.log("No split to close".to_string()),
+        }
+    }
+
+    /// File of the board in the split, when it has changes not written
+    pub fn split_with_unwritten_changes(&self) -> Option<String> {
+        self.split
+            .as_ref()
+            .filter(|split| split.other.current.has_unwritten_changes())
+            .map(|split| split.other.current.file_name.clone())
+    }
+
+    /// Files of the open boards, shown or not, whose changes would be lost by quitting
+    pub fn boards_with_unwritten_changes(&self) -> Vec<String> {
+        let tutorial_file_name = env::temp_dir().join(TUTORIAL_FILE_NAME).display().to_string();
+        let mut file_names: Vec<String> = std::iter::once(&self.current)
+            .chain(self.alternate.as_deref())
+            .filter(|board| board.file_name != tutorial_file_name && board.loses_changes_on_quit())
+            .map(|board| board.file_name.clone())
+            .collect();
+        if let Some(split) = self.split.as_ref() {
+            file_names.extend(split.other.boards_with_unwritten_changes());
+        }
+
+        file_names
+    }
+
+    /// Writes every open board that has changes not written, shown or not
+    pub fn write_all(&mut self) {
+        if self.current.loses_changes_on_quit() {
+            self.write();
+        }
+        self.with_alternate(|app| {
+            if app.current.loses_changes_on_quit() {
+                app.write();
+            }
+        });
+        if let Some(split) = self.split.as_mut() {
+            split.other.write_all();
+        }
+    }
+
+    /// Writes the board in the split and closes it, the split stays open when the board could not be written
+    pub fn write_and_close_split(&mut self) {
+        let Some(split) = self.split.as_mut() else {
+            self.log("No split to close".to_string());
+            return;
+        };
+
+        split.other.write();
+        if split.other.current.has_unwritten_changes() {
+            let message = format!("{} not written, the split stays open", split.other.current.file_name);
+            self.log(message);
+            return;
+        }
+        self.close_split();
+    }
+
+    pub fn layout(&self) -> PaneLayout {
+        self.layout
+    }
+
+    pub fn set_layout(&mut self, layout: PaneLayout) {
+        self.layout = layout;
+    }
+
+    /// Grows or shrinks the log pane by one line
+    pub fn resize_log(&mut self, grow: bool) {
+        let resized = if grow {
+            self.layout.grow_log()
+        } else {
+            self.layout.shrink_log()
+        };
+        if !resized {
+            self.log("The log pane cannot be resized further".to_string());
+        }
+    }
+
+    pub fn toggle_pane_focus(&mut self) {
+        match self.split.as_mut() {
+            Some(split) => split.other_focused = !split.other_focused,
+            None => self.log("No split to switch to".to_string()),
+        }
+    }
+
+    /// The pane receiving the operations, either this board or the one split next to it
+    pub fn focused(&self) -> &App {
+        match &self.split {
+            Some(split) if split.other_focused => &split.other,
+            _ => self,
+        }
+    }
+
+    pub fn focused_mut(&mut self) -> &mut App {
+        match self.split.as_ref().map(|split| split.other_focused) {
+            Some(true) => self
+                .split
+                .as_mut()
+                .map(|split| &mut *split.other)
+                .expect("split is open"),
+            _ => self,
+        }
+    }
+
+    /// Moves the selected card of the focused pane to the top of the selected column of the other pane
+    pub fn send_card_to_other_pane(&mut self) {
+        let Some(mut split) = self.split.take() else {
+            self.log("No split to send the card to".to_string());
+            return;
+        };
+
+        let (from, to) = if split.other_focused {
+            (&mut *split.other, &mut *self)
+        } else {
+            (&mut *self, &mut *split.other)
+        };
+
+        match from.get_selected_card() {
+            Some(_) if from.current.has_unwritten_changes() || to.current.has_unwritten_changes() => {
+                from.log("Write both boards before sending a card from one to the other".to_string())
+            }
+            Some(_) if !from.check_writable(&[from.current.selector.column()]) => {}
+            Some(_) if !to.check_writable(&[to.receiving_column()]) => {}
+            Some(mut card) => {
+                if card.is_timed() {
+                    from.stop_timer();
+                    card = from.get_selected_card().unwrap_or(card);
+                }
+                let description = card.short_description().clone();
+                // Undoing the move in a single pane would lose or duplicate the card, so it is made outside of the
+                // histories and both files are written together
+                from.send_card();
+                to.receive_card(card);
+                from.write();
+                to.write();
+                from.notify(format!(
+                    "Sent '{}' to {}, the undo history of both boards starts over",
+                    description, to.current.file_name
+                ));
+            }
+            None => from.log("No card selected".to_string()),
+        }
+
+        self.split = Some(split);
+    }
+
+    /// Column a card sent from the other pane goes to, the selected one or the first one
+    fn receiving_column(&self) -> usize {
+        self.current
+            .selector
+            .insertion_point()
+            .map_or(0, |(column_index, _)| column_index)
+    }
+
+    /// Removes the selected card sent to the other pane, see `change_outside_history`
+    fn send_card(&mut self) {
+        let Some((column_index, card_index)) = self.current.selector.get() else {
+            return;
+        };
+        let title = self
+            .current
+            .board
+            .borrow()
+            .card(column_index, card_index)
+            .short_description()
+            .clone();
+        self.change_outside_history(|board| {
+            board.remove_card(column_index, card_index);
+        });
+        self.current.selector.set(column_index, card_index);
+        self.record_change(format!("Sent '{}' to the other pane", title), None, None);
+    }
+
+    /// Inserts a card sent from the other pane at the top of the receiving column, see `change_outside_history`
+    fn receive_card(&mut self, mut card: Card) {
+        card.set_timed(false);
+        let column_index = self.receiving_column();
+        let header = self.current.board.borrow().column(column_index).header().to_string();
+        card.enter_column_by(&header, Local::now(), self.settings.user_name().as_deref());
+        let title = card.short_description().clone();
+        self.change_outside_history(|board| board.insert_card(column_index, 0, card));
+        self.select_card(column_index, 0);
+        self.record_change(format!("Received '{}' from the other pane", title), None, None);
+    }
+
+    /// Changes the board without going through the history, which starts over as the positions it recorded may
+    /// not hold anymore
+    fn change_outside_history(&mut self, change: impl FnOnce(&mut Board)) {
+        change(&mut self.current.board.borrow_mut());
+        self.journal(JournalEntry::Board { board: self.board() });
+        self.current.history = new_history(&self.settings);
+        self.current.deletion_grace_until = None;
+        self.check_invariants(Some("change outside the history".to_string()));
+    }
+
+    /// Moves the selection to a card, like one found by a search
+    pub fn select_card(&mut self, column_index: usize, card_index: usize) {
+        self.current.selector.enable_selection();
+        self.current.selector.set(column_index, card_index);
+    }
+
+    /// Moves the selection to the first card of the selected column
+    pub fn select_top_card(&mut self) {
+        self.select_card(self.current.selector.column(), 0);
+    }
+
+    /// Moves the selection to the last card of the selected column
+    pub fn select_bottom_card(&mut self) {
+        self.select_card(self.current.selector.column(), usize::MAX);
+    }
+
+    /// Moves the selection to the first card of a column
+    pub fn select_column(&mut self, column_index: usize) {
+        if column_index >= self.current.board.borrow().columns_count() {
+            self.log(format!("No column {}", column_index + 1));
+            return;
+        }
+
+        self.select_card(column_index, 0);
+    }
+
+    /// Number of cards in the selected column, the highest card number to jump to
+    pub fn selected_column_size(&self) -> usize {
+        self.current
+            .board
+            .borrow()
+            .column(self.current.selector.column())
+            .size()
+    }
+
+    /// Moves the selection to a card of the selected column by its number, starting from 1
+    pub fn select_card_number(&mut self, number: usize) {
+        let column_index = self.current.selector.column();
+        if number == 0 || number > self.selected_column_size() {
+            let header = self.current.board.borrow().column(column_index).header().to_string();
+            self.log(format!("No card {} in {}", number, header));
+            return;
+        }
+
+        self.select_card(column_index, number - 1);
+    }
+
+    /// Moves the selection to the first card of the next column, or of the previous one
+    pub fn select_adjacent_column(&mut self, next: bool) {
+        let column_index = match next {
+            true => self.current.selector.column() + 1,
+            false => self.current.selector.column().saturating_sub(1),
+        };
+        let last_column = self.current.board.borrow().columns_count() - 1;
+        self.select_card(column_index.min(last_column), 0);
+    }
+
+    /// Starts moving the selected card, a ghost of it shows where it lands until the move is finished or cancelled
+    pub fn start_moving_card(&mut self) -> bool {
+        let Some((column_index, card_index)) = self.current.selector.get() else {
+            self.log("No card selected".to_string());
+            return false;
+        };
+        if !self.check_writable(&[column_index]) {
+            return false;
+        }
+
+        self.current.move_target = Some((column_index, card_index));
+        true
+    }
+
+    /// Moves where the card lands by a number of columns and of cards, a sorted column only has one place for it
+    pub fn move_target_by(&mut self, columns: isize, cards: isize) {
+        let (Some((column_index, card_index)), Some((target_column, target_index))) =
+            (self.current.selector.get(), self.current.move_target)
+        else {
+            return;
+        };
+
+        let board = self.current.board.borrow();
+        let target_column = target_column
+            .saturating_add_signed(columns)
+            .min(board.columns_count() - 1);
+        let column = board.column(target_column);
+        let target_index = if target_column == column_index {
+            match column.sort_policy().is_manual() {
+                true => target_index.saturating_add_signed(cards).min(column.size() - 1),
+                false => card_index,
+            }
+        } else {
+            let requested = target_index.saturating_add_signed(cards).min(column.size());
+            column.insertion_index(board.card(column_index, card_index), requested)
+        };
+        drop(board);
+
+        self.current.move_target = Some((target_column, target_index));
+    }
+
+    /// Column and slot before which the ghost of the card being moved is drawn, counting the card when it stays in
+    /// its column
+    fn move_slot(&self) -> Option<(usize, usize, usize, usize)> {
+        let (column_index, card_index) = self.current.selector.get()?;
+        let (target_column, target_index) = self.current.move_target?;
+        let board = self.current.board.borrow();
+        if target_column >= board.columns_count() || target_index > board.column(target_column).size() {
+            return None;
+        }
+        let slot = match target_column == column_index && target_index > card_index {
+            true => target_index + 1,
+            false => target_index,
+        };
+        Some((column_index, card_index, target_column, slot))
+    }
+
+    /// Moves the card where its ghost is
+    pub fn finish_move(&mut self) {
+        let Some((column_index, card_index, target_column, slot)) = self.move_slot() else {
+            return;
+        };
+        self.current.move_target = None;
+        if (target_column, slot) == (column_index, card_index) {
+            return;
+        }
+
+        self.execute(MoveCardCommand::new(column_index, card_index, target_column, slot));
+        self.flash_moved_card();
+    }
+
+    /// Leaves the card where it is
+    pub fn cancel_move(&mut self) {
+        self.current.move_target = None;
+    }
+
+    /// Replaces the current board with the one read from a file, locking it for this instance
+    pub fn open_board(&mut self, file_name: &str) {
+        match Board::open(file_name) {
+            Ok(board) => {
+                self.replace_board(file_name, board);
+                self.log(format!("Opened {}", file_name));
+                self.check_board();
+            }
+            Err(e) => self.log(format!("Cannot read file {} because {}", file_name, e)),
+        }
+    }
+
+    /// Creates a new board from a template and writes it right away
+    pub fn new_board(&mut self, template: Template, file_name: &str) {
+        self.replace_board(file_name, template.build());
+        self.write();
+    }
+
+    /// Puts another board in place of the current one, its changes are made by the same user
+    fn set_board(&mut self, mut board: Board) {
+        board.set_author(self.settings.user_name());
+        *self.current.board.borrow_mut() = board;
+    }
+
+    fn replace_board(&mut self, file_name: &str, board: Board) {
+        self.set_board(board);
+        self.current.file_name = file_name.to_string();
+        self.reset_selector();
+        self.current.history = new_history(&self.settings);
+        self.checkpoint();
+        self.remember_disk_version();
+        self.current.read_only = false;
+        self.current.lock = None;
+        self.current.timer = None;
+        self.current.pomodoro = None;
+        self.load_metrics();
+        self.acquire_lock();
+        self.open_journal();
+    }
+
+    /// Locks the board file for this instance, the board is opened read-only when another instance holds the lock
+    fn acquire_lock(&mut self) {
+        match BoardLock::acquire(&self.current.file_name) {
+            Ok(lock) => self.current.lock = Some(lock),
+            Err(LockError::Locked(owner)) => {
+                self.current.read_only = true;
+                self.log(format!(
+                    "{} is locked by {}, opened read-only",
+                    self.current.file_name, owner
+                ));
+            }
+            Err(LockError::Io(e)) => self.log(format!("Cannot lock {} because {}", self.current.file_name, e)),
+        }
+    }
+
+    /// Opens another board in this pane, the current one is kept as it is to switch back to it with
+    /// `toggle_alternate_board`
+    ///
+    /// Only the last two boards are kept, the board opened before them must have been written.
+    pub fn open_other_board(&mut self, file_name: String) {
+        if file_name == self.current.file_name {
+            self.log(format!("{} is already open", file_name));
+            return;
+        }
+        if self
+            .alternate
+            .as_ref()
+            .is_some_and(|alternate| alternate.file_name == file_name)
+        {
+            self.toggle_alternate_board();
+            return;
+        }
+        if let Some(alternate) = self
+            .alternate
+            .as_ref()
+            .filter(|alternate| alternate.has_unwritten_changes())
+        {
+            let message = format!(
+                "{} has changes not written, switch to it with <B> and write it first",
+                alternate.file_name
+            );
+            self.log(message);
+            return;
+        }
+
+        let board = match Board::open(&file_name) {
+            Ok(board) => board,
+            Err(e) => {
+                self.log(format!("Cannot read file {} because {}", file_name, e));
+                return;
+            }
+        };
+        let mut other = Box::new(BoardState::new(file_name, board, &self.settings));
+        self.swap_boards(&mut other);
+        self.alternate = Some(other);
+        self.open_current_board();
+        self.acquire_lock();
+        self.open_journal();
+        self.log(format!(
+            "Opened {}, <B> goes back to {}",
+            self.current.file_name,
+            self.alternate_file_name()
+        ));
+    }
+
+    /// Switches to the board opened before the current one in this pane, each keeps its selection and history
+    pub fn toggle_alternate_board(&mut self) {
+        let Some(mut other) = self.alternate.take() else {
+            self.log("No other board opened, use <b> to open one".to_string());
+            return;
+        };
+
+        self.swap_boards(&mut other);
+        self.alternate = Some(other);
+        self.log(format!("Switched to {}", self.current.file_name));
+    }
+
+    fn alternate_file_name(&self) -> &str {
+        self.alternate.as_ref().map_or("", |alternate| &alternate.file_name)
+    }
+
+    /// Exchanges the board shown with another one, with everything that goes with it like the selection, the
+    /// history and the lock, while the rest belongs to the session and stays
+    fn swap_boards(&mut self, other: &mut BoardState) {
+        mem::swap(&mut self.current, other);
+        // The filters of the view apply to whichever board is shown
+        self.current.selector.hide_done_before(self.hide_done_before);
+        self.current.selector.starred_only(self.starred_only);
+    }
+
+    /// Acts on the alternate board as if it was shown, like to keep its timers running
+    fn with_alternate(&mut self, action: impl FnOnce(&mut Self)) {
+        let Some(mut alternate) = self.alternate.take() else {
+            return;
+        };
+        mem::swap(&mut self.current, &mut alternate);
+        action(self);
+        mem::swap(&mut self.current, &mut alternate);
+        self.alternate = Some(alternate);
+    }
+
+    /// Reads the board again from its file
+    ///
+    /// When the file matches a revision of the board in the history, like when it was last written, the history
+    /// goes back to that point and the later changes can be redone. Otherwise the history is reset.
+    pub fn reload(&mut self) {
+        let board = match Board::open(&self.current.file_name) {
+            Ok(board) => board,
+            Err(e) => {
+                self.log(format!("Cannot read file {} because {}", self.current.file_name, e));
+                return;
+            }
+        };
+        // The board now matches its file, whatever was journaled
+        self.clear_journal();
+        if self.current.same_revision(&board, &self.current.board.borrow()) {
+            self.remember_disk_version();
+            self.log(format!("{} did not change", self.current.file_name));
+            return;
+        }
+
+        let rewound = match board.revision(&self.current.file_name) {
+            Ok(revision) => {
+                self.current
+                    .history
+                    .rewind(revision, &mut self.current.board.borrow_mut())
+                    && self.current.same_revision(&board, &self.current.board.borrow())
+            }
+            Err(_) => false,
+        };
+        self.remember_disk_version();
+        if rewound {
+            let text = format!("Reloaded {}, later changes can be redone", self.current.file_name);
+            self.notify(text.clone());
+            self.record_change(text, None, None);
+            return;
+        }
+
+        self.set_board(board);
+        self.reset_selector();
+        self.current.history = new_history(&self.settings);
+        self.checkpoint();
+        self.remember_disk_version();
+        self.current.timer = None;
+        self.current.pomodoro = None;
+        let text = format!(
+            "Reloaded {}, it changed on disk so undo history was reset",
+            self.current.file_name
+        );
+        self.notify(text.clone());
+        self.record_change(text, None, None);
+    }
+
+    /// Reads the board again from its file like `reload`, logs how the file differs from the board in memory, and
+    /// keeps the selection on the same card when it is still on the board
+    pub fn reload_board(&mut self) {
+        let selected_card = self.current.selector.get_selected_card();
+        let selection = self.current.selector.insertion_point();
+        let before = self.current.board.borrow().clone();
+        self.reload();
+
+        let changes = BoardChange::between(&before, &self.current.board.borrow());
+        if !changes.is_empty() {
+            let shown: Vec<String> = changes
+                .iter()
+                .take(MAX_LOGGED_CHANGES)
+                .map(|change| change.to_string())
+                .collect();
+            let more = match changes.len() > MAX_LOGGED_CHANGES {
+                true => format!(" and {} more", changes.len() - MAX_LOGGED_CHANGES),
+                false => String::new(),
+            };
+            self.log(format!(
+                "{} change(s) from {}: {}{}",
+                changes.len(),
+                self.current.file_name,
+                shown.join(", "),
+                more
+            ));
+        }
+
+        let found = selected_card.and_then(|card| board::find_card(&self.current.board.borrow(), &card));
+        if let Some((column_index, card_index)) = found.or(selection) {
+            self.select_card(column_index, card_index);
+        }
+    }
+
+    /// Opens a link found in a card with the configured command, or the default browser
+    pub fn open_url(&mut self, url: &str) {
+        let mut command = match &self.settings.links.opener {
+            Some(opener) => process::Command::new(opener),
+            None => default_opener(),
+        };
+        let opened = command
+            .arg(url)
+            .stdin(process::Stdio::null())
+            .stdout(process::Stdio::null())
+            .stderr(process::Stdio::null())
+            .spawn();
+        match opened {
+            Ok(mut child) => {
+                // Waited for aside, so that the opener does not linger as a zombie process once it exits
+                thread::spawn(move || child.wait());
+                self.log(format!("Opened {}", url))
+            }
+            Err(e) => self.log(format!("Cannot open {} because {}", url, e)),
+        }
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.current.file_name
+    }
+
+    /// Whether the board file changed on disk since it was read or written, writing it would lose those changes
+    pub fn has_save_conflict(&self) -> bool {
+        match &self.current.on_disk {
+            Some((hash, _)) => file_revision(&self.current.file_name).is_some_and(|current| current != *hash),
+            None => false,
+        }
+    }
+
+    /// Writes the board even though its file changed on disk
+    pub fn overwrite(&mut self) {
+        self.current.on_disk = None;
+        self.write();
+    }
+
+    /// Reads the board file changed on disk and brings the changes made since it was last read or written into it
+    pub fn merge_from_disk(&mut self) {
+        let Some((_, base)) = self.current.on_disk.clone() else {
+            self.reload();
+            return;
+        };
+        let theirs = match Board::open(&self.current.file_name) {
+            Ok(board) => board,
+            Err(e) => {
+                self.log(format!("Cannot read file {} because {}", self.current.file_name, e));
+                return;
+            }
+        };
+
+        self.backup_before("Merge the changes made on disk");
+        let merged = board::merge(&base, &self.current.board.borrow(), &theirs);
+        // The changes journaled so far were made on the file as it was, the merged board replaces them
+        self.current.on_disk = file_revision(&self.current.file_name).map(|revision| (revision, theirs));
+        self.clear_journal();
+        self.replace_with_merged(merged);
+        self.notify(format!(
+            "Merged the changes made to {} on disk, use <w> to write the board",
+            self.current.file_name
+        ));
+        self.record_change(
+            format!("Merged the changes made to {} on disk", self.current.file_name),
+            None,
+            None,
+        );
+    }
+
+    /// Replaces the board with a merged one, keeping the selection where it was
+    fn replace_with_merged(&mut self, board: Board) {
+        let selection = self.current.selector.insertion_point();
+        self.journal(JournalEntry::Board { board: board.clone() });
+        self.set_board(board);
+        self.reset_selector();
+        if let Some((column_index, card_index)) = selection {
+            self.current.selector.set(column_index, card_index);
+        }
+        // Positions recorded in the history may not be valid anymore
+        self.current.history = new_history(&self.settings);
+        self.current.timer = None;
+        self.current.pomodoro = None;
+    }
+
+    /// The adapter syncing the board with the remote of the settings
+    fn sync_adapter(&self) -> Option<Box<dyn SyncAdapter>> {
+        let remote = self.settings.sync.remote.as_ref()?;
+        (!self.current.file_name.is_empty())
+            .then(|| Box::new(FileSyncAdapter::new(&self.current.file_name, remote)) as Box<dyn SyncAdapter>)
+    }
+
+    /// What syncing the board with its remote would change, to review before applying it
+    pub fn plan_sync(&mut self) -> Option<SyncPlan> {
+        let Some(mut adapter) = self.sync_adapter() else {
+            self.log("No remote to sync with, set sync.remote to a board file".to_string());
+            return None;
+        };
+
+        let local = self.current.board.borrow().without_scratch_cards().into_owned();
+        match adapter.plan(&local) {
+            Ok(plan) if plan.is_empty() => {
+                self.log(format!("Already in sync with {}", plan.remote));
+                None
+            }
+            Ok(plan) => Some(plan),
+            Err(e) => {
+                self.log(format!("Cannot sync with {} because {}", adapter.name(), e));
+                None
+            }
+        }
+    }
+
+    /// Pushes the merged board of a plan to the remote and brings in the changes pulled from it
+    ///
+    /// Changes made to the board since the plan, like scratch cards, are kept. Nothing is pushed when the remote
+    /// changed since the plan. The board file is only written with `<w>`, the merged board becomes the last synced
+    /// version then.
+    pub fn apply_sync(&mut self, plan: &SyncPlan) {
+        let Some(mut adapter) = self.sync_adapter() else {
+            return;
+        };
+        match adapter.changed_since(plan) {
+            Ok(false) => {}
+            Ok(true) => {
+                self.log(format!(
+                    "{} changed since the sync was planned, sync again",
+                    plan.remote
+                ));
+                return;
+            }
+            Err(e) => {
+                self.log(format!("Cannot sync with {} because {}", plan.remote, e));
+                return;
+            }
+        }
+        if let Err(e) = adapter.push_local_changes(&plan.merged) {
+            self.log(format!("Cannot sync with {} because {}", plan.remote, e));
+            return;
+        }
+        self.current.synced = Some(plan.merged.clone());
+
+        if !plan.pulled.is_empty() {
+            self.backup_before(&format!("Sync with {}", plan.remote));
+            let merged = board::merge(&plan.local, &self.current.board.borrow(), &plan.merged);
+            self.replace_with_merged(merged);
+            let text = format!("Pulled {} change(s) from {}", plan.pulled.len(), plan.remote);
+            self.record_change(text, None, None);
+        }
+        self.notify(format!(
+            "Synced with {}: {} change(s) pulled, {} pushed",
+            plan.remote,
+            plan.pulled.len(),
+            plan.pushed.len()
+        ));
+    }
+
+    /// Writes the board as it is to a timestamped backup next to its file, before a change touching many cards
+    ///
+    /// Undo only lasts as long as the session, the backup keeps the board as it was even after quitting.
+    fn backup_before(&mut self, change: &str) {
+        if !self.settings.backup.before_bulk_changes || self.current.file_name.is_empty() {
+            return;
+        }
+
+        let written = write_backup(
+            &self.current.file_name,
+            &self.current.board.borrow(),
+            self.settings.backup.keep,
+        );
+        match written {
+            Ok(backup_file) => self.log(format!("{}: board backed up to {}", change, backup_file)),
+            Err(e) => self.log(format!("Cannot back up the board because {}", e)),
+        }
+    }
+
+    /// Keeps the board pushed by the last sync as the last synced version, once the board file is written
+    fn remember_synced(&mut self) {
+        let (Some(board), Some(mut adapter)) = (self.current.synced.take(), self.sync_adapter()) else {
+            return;
+        };
+        if let Err(e) = adapter.remember_synced(&board) {
+            self.log(format!(
+                "Cannot keep the version synced with {} because {}",
+                adapter.name(),
+                e
+            ));
+        }
+    }
+
+    /// Records the content of the board file as matching the board, once read or written
+    fn remember_disk_version(&mut self) {
+        self.current.on_disk = file_revision(&self.current.file_name)
+            .map(|hash| (hash, self.current.board.borrow().without_scratch_cards().into_owned()));
+    }
+
+    /// Marks the board in the history as matching the content of its file
+    fn checkpoint(&mut self) {
+        if let Ok(revision) = self.current.board.borrow().revision(&self.current.file_name) {
+            self.current.history.checkpoint(revision);
+        }
+    }
+
+    /// Replaces the board with the one saved after a crash, the board file is only written with `<w>`
+    pub fn restore_recovery_file(&mut self, recovery_file: &str) {
+        match Board::open(recovery_file) {
+            Ok(board) => {
+                self.journal(JournalEntry::Board { board: board.clone() });
+                self.set_board(board);
+                self.reset_selector();
+                self.current.history = new_history(&self.settings);
+                self.current.timer = None;
+                self.current.pomodoro = None;
+                self.current.recovered_from = Some(recovery_file.to_string());
+                self.log(format!(
+                    "Restored the board from {}, use <w> to write it",
+                    recovery_file
+                ));
+                self.record_change(format!("Restored the board from {}", recovery_file), None, None);
+            }
+            Err(e) => self.log(format!("Cannot read {} because {}", recovery_file, e)),
+        }
+    }
+
+    pub fn discard_recovery_file(&mut self, recovery_file: &str) {
+        match fs::remove_file(recovery_file) {
+            Ok(_) => self.log(format!("Discarded {}", recovery_file)),
+            Err(e) => self.log(format!("Cannot remove {} because {}", recovery_file, e)),
+        }
+    }
+
+    /// What restoring the recovery file would change on the board
+    pub fn recovery_changes(&self, recovery_file: &str) -> io::Result<Vec<BoardChange>> {
+        let recovered = Board::open(recovery_file)?;
+        Ok(BoardChange::between(&self.current.board.borrow(), &recovered))
+    }
+
+    pub fn remember_search(&mut self, query: &str) {
+        self.current.last_search = Some(query.to_string()).filter(|query| !query.trim().is_empty());
+    }
+
+    /// Parts of the board that can be exported, with their label
+    pub fn export_scopes(&self) -> Vec<(String, ExportScope)> {
+        let board = self.current.board.borrow();
+        let column_index = self.current.selector.column();
+        let mut scopes = vec![
+            ("Whole board".to_string(), ExportScope::Board),
+            (
+                format!("Column {}", board.column(column_index).header()),
+                ExportScope::Column(column_index),
+            ),
+        ];
+        if let Some(query) = &self.current.last_search {
+            let positions: Vec<(usize, usize)> = SearchMatch::find(&board, query)
+                .into_iter()
+                .map(|result| (result.column_index, result.card_index))
+                .collect();
+            scopes.push((
+                format!("Results of the search \"{}\", {} cards", query, positions.len()),
+                ExportScope::Cards(positions),
+            ));
+        }
+        scopes
+    }
+
+    /// Writes part of the board to another file, the board keeps its own file
+    ///
+    /// The file of an open board is never written over, other existing files are only once confirmed.
+    pub fn export(&mut self, file_name: &str, scope: &ExportScope) {
+        let open_files =
+            std::iter::once(&self.current.file_name).chain(self.alternate.as_ref().map(|other| &other.file_name));
+        if let Some(open_file) = open_files.into_iter().find(|open_file| same_file(open_file, file_name)) {
+            self.log(format!(
+                "Cannot export to {}, it is the file of an open board",
+                open_file
+            ));
+            return;
+        }
+        let excerpt = self.current.board.borrow().excerpt(scope);
+        if excerpt.columns_count() == 0 {
+            self.log(format!("Nothing to export to {}", file_name));
+            return;
+        }
+        let options = SaveOptions {
+            canonical: self.settings.files.canonical_json,
+            layout: self.settings.files.layout_for(None),
+        };
+        match excerpt.to_file_with(file_name, options) {
+            Ok(_) => self.notify(format!(
+                "Exported {} cards to {}",
+                excerpt.cards_with_positions().count(),
+                file_name
+            )),
+            Err(e) => self.log(format!("Cannot export to {} because {}", file_name, e)),
+        }
+    }
+
+    pub fn saved_searches(&self) -> Vec<SavedSearch> {
+        self.current.board.borrow().saved_searches().to_vec()
+    }
+
+    /// Saves a search with the board, it is kept in the file the next time the board is written
+    pub fn save_search(&mut self, name: &str, query: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            self.log("A saved search needs a name".to_string());
+            return;
+        }
+
+        self.current.board.borrow_mut().save_search(name, query);
+        self.notify(format!("Saved search '{}', use <w> to write it", name));
+    }
+
+    pub fn remove_saved_search(&mut self, name: &str) {
+        if self.current.board.borrow_mut().remove_saved_search(name) {
+            self.log(format!("Removed saved search '{}'", name));
+        }
+    }
+
+    /// Renames a tag on all the cards of the board, as a single change that can be undone
+    pub fn rename_tag(&mut self, tag: &str, new_name: &str) {
+        let description = format!("Rename tag #{} to #{}", tag, new_name);
+        self.change_tags(tag, &description, Some(new_name));
+    }
+
+    /// Replaces a tag by another one on all the cards of the board, cards with both keep only one
+    pub fn merge_tags(&mut self, tag: &str, into: &str) {
+        let description = format!("Merge tag #{} into #{}", tag, into);
+        self.change_tags(tag, &description, Some(into));
+    }
+
+    /// Removes a tag from all the cards of the board
+    pub fn remove_tag(&mut self, tag: &str) {
+        let description = format!("Remove tag #{}", tag);
+        self.change_tags(tag, &description, None);
+    }
+
+    /// Replaces a tag on the cards using it, or removes it when there is no replacement
+    fn change_tags(&mut self, tag: &str, description: &str, replacement: Option<&str>) {
+        let board = self.board();
+        let commands: Vec<Box<dyn Command>> = board
+            .cards_with_positions()
+            .filter(|(_, _, card)| card.tags().iter().any(|t| t == tag))
+            .map(|(column_index, card_index, card)| {
+                let mut tags: Vec<String> = vec![];
+                for t in card.tags() {
+                    let t = if t == tag { replacement } else { Some(t.as_str()) };
+                    if let Some(t) = t.filter(|t| !tags.iter().any(|kept| kept == t)) {
+                        tags.push(t.to_string());
+                    }
+                }
+                let mut card = card.clone();
+                card.update_tags(tags);
+                Box::new(UpdateCardCommand::new(column_index, card_index, card)) as Box<dyn Command>
+            })
+            .collect();
+
+        if commands.is_empty() {
+            self.log(format!("No card tagged #{}", tag));
+            return;
+        }
+        let count = commands.len();
+        let command = CompositeCommand::new(description, commands);
+        if !self.check_writable(&command.columns()) {
+            return;
+        }
+        self.backup_before(description);
+        self.execute(command);
+        self.log(format!("{}: {} card(s) updated", description, count));
+    }
+
+    /// Deletes several cards at once, like the results of a search, as a single change that can be undone
+    pub fn remove_cards(&mut self, positions: &[(usize, usize)]) {
+        if positions.is_empty() {
+            self.log("No card to delete".to_string());
+            return;
+        }
+
+        // From the last card, so that the positions of the others still hold
+        let mut positions = positions.to_vec();
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+        positions.dedup();
+        let count = positions.len();
+        let commands: Vec<Box<dyn Command>> = positions
+            .into_iter()
+            .map(|(column_index, card_index)| {
+                Box::new(RemoveCardCommand::new(column_index, card_index)) as Box<dyn Command>
+            })
+            .collect();
+
+        let description = format!("Delete {} cards", count);
+        let command = CompositeCommand::new(&description, commands);
+        if !self.check_writable(&command.columns()) {
+            return;
+        }
+        self.backup_before(&description);
+        self.execute(command);
+        self.notify(format!("{} card(s) deleted — press u to undo", count));
+    }
+
+    /// Inserts a card per data row of a CSV file at the bottom of its column, as a single change that can be undone
+    pub fn import_csv(&mut self, rows: &[Vec<String>], mapping: &CsvMapping, source: &str) {
+        if self.current.board.borrow().columns_count() == 0 {
+            self.log(format!("No column to import the cards of {} into", source));
+            return;
+        }
+        let (cards, problems) = csv_cards(&self.current.board.borrow(), rows, mapping, Local::now());
+        for problem in problems {
+            self.log(format!("{}: {}", source, problem));
+        }
+        if cards.is_empty() {
+            self.log(format!("No card to import from {}", source));
+            return;
+        }
+
+        let mut sizes: Vec<usize> = self
+            .current
+            .board
+            .borrow()
+            .columns_iter()
+            .map(|column| column.size())
+            .collect();
+        let count = cards.len();
+        let commands: Vec<Box<dyn Command>> = cards
+            .into_iter()
+            .map(|(column_index, card)| {
+                let card_index = sizes[column_index];
+                sizes[column_index] += 1;
+                Box::new(InsertCardCommand::new(column_index, card_index, card)) as Box<dyn Command>
+            })
+            .collect();
+
+        let description = format!("Import {}", source);
+        let command = CompositeCommand::new(&description, commands);
+        if !self.check_writable(&command.columns()) {
+            return;
+        }
+        self.backup_before(&description);
+        self.execute(command);
+        self.notify(format!("{} card(s) imported from {}", count, source));
+    }
+
+    pub fn notes(&self) -> String {
+        self.current.board.borrow().notes().to_string()
+    }
+
+    /// Shows the notes of the board next to it, before they are edited there
+    pub fn show_notes(&mut self) {
+        self.notes_visible = true;
+    }
+
+    /// Shows or hides the notes of the board next to it
+    pub fn toggle_notes(&mut self) {
+        self.notes_visible = !self.notes_visible;
+    }
+
+    /// Where the notes were drawn on the last render, if they were visible
+    pub fn notes_area(&self) -> Option<Rect> {
+        self.notes_area.get()
+    }
+
+    /// Replaces the notes of the board, as a change that can be undone
+    pub fn update_notes(&mut self, notes: &str) {
+        if self.current.board.borrow().notes() == notes {
+            return;
+        }
+
+        let (column_index, card_index) = self
+            .current
+            .selector
+            .insertion_point()
+            .unwrap_or((self.current.selector.column(), 0));
+        self.execute(UpdateNotesCommand::new(column_index, card_index, notes));
+    }
+
+    pub fn set_lock(&mut self, lock: BoardLock) {
+        self.current.lock = Some(lock);
+    }
+
+    /// Journals the changes to the board file from now on, so they can be replayed after a crash
+    pub fn enable_journal(&mut self) {
+        self.journaling = true;
+        self.open_journal();
+    }
+
+    /// Starts the journal of the board file, as long as this instance holds its lock
+    ///
+    /// A journal left behind by a crash is kept untouched for `rustyban replay`, and no change is journaled
+    /// meanwhile.
+    fn open_journal(&mut self) {
+        self.current.journal = None;
+        if !self.journaling || self.current.file_name.is_empty() || self.current.lock.is_none() {
+            return;
+        }
+
+        let journal = Journal::new(&self.current.file_name);
+        let base = self.current.on_disk.as_ref().map(|(revision, _)| *revision);
+        match journal.len() {
+            Ok(0) => {}
+            Ok(_) if !journal.matches(base).unwrap_or(true) => {
+                // Like when the board was written but the journal not cleared, replaying it would apply the
+                // changes twice
+                if let Err(e) = journal.clear() {
+                    self.log(format!("Cannot remove {} because {}", journal.file_name(), e));
+                    return;
+                }
+                self.log(format!(
+                    "Removed {}, its changes were made on another version of {}",
+                    journal.file_name(),
+                    self.current.file_name
+                ));
+            }
+            Ok(count) => {
+                self.log(format!(
+                    "{} holds {} changes not written to {}, quit and run `rustyban replay {}` to recover them",
+                    journal.file_name(),
+                    count,
+                    self.current.file_name,
+                    self.current.file_name
+                ));
+                return;
+            }
+            Err(e) => {
+                self.log(format!("Cannot read {} because {}", journal.file_name(), e));
+                return;
+            }
+        }
+        self.current.journal = Some(journal);
+
+        // Changes made while opening the board, like archiving, are not described by any command, and the
+        // scratch cards of the session are not in the file
+        let board = self.current.board.borrow().clone();
+        let on_disk = self
+            .current
+            .on_disk
+            .as_ref()
+            .is_some_and(|(_, on_disk)| self.current.same_revision(on_disk, &board));
+        if !on_disk || board.has_scratch_cards() {
+            self.journal(JournalEntry::Board { board });
+        }
+    }
+
+    /// Records a change in the journal before it is applied to the board
+    fn journal(&mut self, entry: JournalEntry) {
+        let Some(journal) = self.current.journal.as_ref().filter(|_| !self.current.read_only) else {
+            return;
+        };
+        let base = self.current.on_disk.as_ref().map(|(revision, _)| *revision);
+        if let Err(e) = journal.append(base, &entry) {
+            let message = format!("Cannot write to {} because {}", journal.file_name(), e);
+            self.log(message);
+        }
+    }
+
+    fn clear_journal(&mut self) {
+        let Some(journal) = self.current.journal.as_ref() else {
+            return;
+        };
+        if let Err(e) = journal.clear() {
+            let message = format!("Cannot remove {} because {}", journal.file_name(), e);
+            self.log(message);
+        }
+    }
+
+    pub fn take_over_lock(&mut self) {
+        match BoardLock::take_over(&self.current.file_name) {
+            Ok(lock) => {
+                self.current.lock = Some(lock);
+                self.log(format!("Took over the lock on {}", self.current.file_name));
+            }
+            Err(e) => {
+                self.current.read_only = true;
+                self.log(format!("Cannot take over the lock because {}, opened read-only", e));
+            }
+        }
+    }
+
+    pub fn open_read_only(&mut self) {
+        self.current.read_only = true;
+        self.log(format!("{} opened read-only", self.current.file_name));
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.current.read_only
+    }
+
+    fn with_selected_card<F>(&mut self, mut action: F)
+    where
+        F: FnMut(&mut Self, usize, usize) -> (usize, usize),
+    {
+        match self.current.selector.get() {
+            Some((column_index, card_index)) => {
+                let (column_index, card_index) = action(self, column_index, card_index);
+                self.current.selector.set(column_index, card_index);
+            }
+            None => self.log("No card selected".to_string()),
+        }
+    }
+
+    /// Like `with_selected_card`, for changes to the selected column that do not need a card shown in it
+    fn with_selected_column<F>(&mut self, mut action: F)
+    where
+        F: FnMut(&mut Self, usize, usize) -> (usize, usize),
+    {
+        match self.current.selector.insertion_point() {
+            Some((column_index, card_index)) => {
+                let (column_index, card_index) = action(self, column_index, card_index);
+                self.current.selector.set(column_index, card_index);
+            }
+            None => self.log("No column selected".to_string()),
+        }
+    }
+
+    fn execute<C: Command + 'static>(&mut self, command: C) -> (usize, usize) {
+        match self.try_execute(command) {
+            Some(position) => position,
+            None => self
+                .current
+                .selector
+                .insertion_point()
+                .unwrap_or((self.current.selector.column(), 0)),
+        }
+    }
+
+    /// Executes a command like `execute`, returns None when it was refused or changed nothing
+    fn try_execute<C: Command + 'static>(&mut self, command: C) -> Option<(usize, usize)> {
+        let columns = command.target_columns(&self.current.board.borrow());
+        if !self.check_writable(&columns) {
+            return None;
+        }
+
+        let sizes: Vec<(usize, usize)> = columns
+            .iter()
+            .map(|column_index| (*column_index, self.current.board.borrow().column(*column_index).size()))
+            .collect();
+        self.journal(command.journal_entry());
+        let (column_index, card_index) = self
+            .current
+            .history
+            .execute(Box::new(command), &mut self.current.board.as_ref().borrow_mut())?;
+        self.current.selector.set(column_index, card_index);
+        self.check_invariants(self.current.history.last_redo_description());
+        let activity = self.current.history.last_redo_activity();
+        let description = self.current.history.last_redo_description().unwrap_or_default();
+        self.record_change(description, activity, Some((column_index, card_index)));
+        self.offer_split(&sizes);
+
+        Some((column_index, card_index))
+    }
+
+    /// Whether the columns can be changed, logs the first read-only one otherwise
+    fn check_writable(&mut self, columns: &[usize]) -> bool {
+        let read_only = columns.iter().find_map(|column_index| {
+            let board = self.current.board.borrow();
+            let column = board.column(*column_index);
+            column.is_read_only().then(|| column.header().to_string())
+        });
+        match read_only {
+            Some(header) => {
+                self.log(format!("Column {} is read-only", header));
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// Whether the cards of the selected column can be reordered by hand, logs the sort of the column otherwise
+    fn check_manual_order(&mut self) -> bool {
+        let policy = self
+            .current
+            .board
+            .borrow()
+            .column(self.current.selector.column())
+            .sort_policy();
+        if !policy.is_manual() {
+            self.log(format!(
+                "Column sorted by {}, press <o> to sort it manually",
+                policy.name()
+            ));
+        }
+        policy.is_manual()
+    }
+
+    /// Logs the broken invariants of the board after a change, the tests stop right away on them
+    fn check_invariants(&mut self, change: Option<String>) {
+        let change = change.unwrap_or_default();
+        let dump = self.guard.check(
+            &self.current.board.as_ref().borrow(),
+            self.current.selector.get(),
+            &change,
+        );
+        #[cfg(test)]
+        debug_assert!(dump.is_none(), "{}", dump.as_deref().unwrap_or_default());
+        if let Some(dump) = dump {
+            self.log(dump);
+        }
+    }
+
+    /// Starts a new selection on the board, after it was replaced
+    fn reset_selector(&mut self) {
+        self.current.selector = CardSelector::new(Rc::clone(&self.current.board));
+        self.current.selector.hide_done_before(self.hide_done_before);
+        self.current.selector.starred_only(self.starred_only);
+    }
+
+    fn card_selection<F>(&mut self, mut action: F)
+    where
+        F: FnMut(&mut Self) -> (usize, usize),
+    {
+        action(self);
+        self.preview_ticks = 0;
+        self.current.flashed_card = None;
+        self.observe(&Activity::CardSelected);
+    }
+
+    /// Highlights the selected card for a few ticks, after its priority changed
+    fn flash_moved_card(&mut self) {
+        self.current.flashed_card = self.current.selector.get().map(|position| (position, FLASH_TICKS));
+    }
+
+    /// Records every change made to the board, from a command or not, in the activity of the session and in the
+    /// feed, told by what it did to the card left selected when it concerns a card
+    fn record_change(&mut self, description: String, activity: Option<Activity>, position: Option<(usize, usize)>) {
+        let board = self.current.board.borrow();
+        let card = position
+            .filter(|(column_index, _)| *column_index < board.columns_count())
+            .and_then(|(column_index, card_index)| {
+                let column = board.column(column_index);
+                (card_index < column.size()).then(|| (column.header().to_string(), column.get_card(card_index).clone()))
+            });
+        let text = match (&activity, &card) {
+            (Some(Activity::CardCreated), Some((_, card))) => format!("Created '{}'", card.short_description()),
+            (Some(Activity::CardEdited), Some((_, card))) => format!("Edited '{}'", card.short_description()),
+            (Some(Activity::CardMoved | Activity::CardCompleted), Some((header, card))) => {
+                format!("Moved '{}' to {}", card.short_description(), header)
+            }
+            (Some(Activity::PriorityChanged), Some((_, card))) => {
+                format!("Changed the priority of '{}'", card.short_description())
+            }
+            _ => description,
+        };
+        let card = card.map(|(_, card)| card).filter(|_| activity.is_some());
+        drop(board);
+
+        if let Some(activity) = activity {
+            self.record_activity(activity);
+        }
+        self.current.feed.push(FeedEvent::new(Local::now(), text, card));
+    }
+
+    fn record_activity(&mut self, activity: Activity) {
+        self.observe(&activity);
+        self.activity.record(activity);
+    }
+
+    /// Checks off the tutorial step performed by the activity, if any
+    fn observe(&mut self, activity: &Activity) {
+        let Some(tutorial) = self.tutorial.as_mut() else {
+            return;
+        };
+        let Some(title) = tutorial.observe(activity) else {
+            return;
+        };
+
+        let complete = tutorial.is_complete();
+        Tutorial::mark_step_done(&mut self.current.board.borrow_mut(), title);
+        match complete {
+            true => self.notify("Tutorial complete, you are ready to use Rustyban!".to_string()),
+            false => self.notify(format!("Well done: {}", title)),
+        }
+    }
+
+    /// Logs a message and shows it in a toast, for messages that should not be missed
+    fn notify(&mut self, msg: String) {
+        self.toast = Some(Toast::new(msg.clone()));
+        self.log(msg);
+    }
+
+    pub fn log(&mut self, msg: String) {
+        self.logger.log(msg);
+    }
+
+    /// Saves the boards of all panes next to their files after a crash, and reports where they went
+    pub fn crash_report(&self, message: String) -> CrashReport {
+        let log_tail = self.focused().logger.last(CRASH_LOG_TAIL).cloned().collect();
+        let mut report = CrashReport::new(message).log_tail(log_tail);
+        report = report.recovery_file(self.current.write_recovery_file());
+        if let Some(split) = &self.split {
+            report = report.recovery_file(split.other.current.write_recovery_file());
+        }
+        if let Some(alternate) = &self.alternate {
+            report = report.recovery_file(alternate.write_recovery_file());
+        }
+
+        report
+    }
+}
+
+impl AppOperations for App {
+    fn select_next_column(&mut self) {
+        self.card_selection(|this| this.current.selector.select_next_column())
+    }
+
+    fn select_prev_column(&mut self) {
+        self.card_selection(|this| this.current.selector.select_prev_column())
+    }
+
+    fn select_next_card(&mut self) {
+        self.card_selection(|this| this.current.selector.select_next_card())
+    }
+
+    fn select_prev_card(&mut self) {
+        self.card_selection(|this| this.current.selector.select_prev_card())
+    }
+
+    fn disable_selection(&mut self) {
+        self.current.selector.disable_selection();
+    }
+
+    fn board(&self) -> Board {
+        self.current.board.as_ref().borrow().clone()
+    }
+
+    fn get_selected_card(&self) -> Option<Card> {
+        self.current.selector.get_selected_card()
+    }
+
+    fn update_card(&mut self, card: Card) {
+        self.with_selected_card(|this, column_index, card_index| {
+            this.execute(UpdateCardCommand::new(column_index, card_index, card.clone()))
+        });
+    }
+
+    fn insert_card(&mut self, position: InsertPosition) -> Option<Card> {
+        self.insert_new_card(position, false)
+    }
+
+    fn remove_card(&mut self) {
+        if self.current.selector.get().is_none() {
+            self.log("No card selected".to_string());
+            return;
+        }
+
+        let mut removed = false;
+        self.with_selected_card(|this, column_index, card_index| {
+            match this.try_execute(RemoveCardCommand::new(column_index, card_index)) {
+                Some(position) => {
+                    removed = true;
+                    position
+                }
+                None => (column_index, card_index),
+            }
+        });
+        if !removed {
+            return;
+        }
+
+        let grace_period = Duration::from_secs(self.settings.deletion.grace_seconds).min(MAX_DELETION_GRACE_PERIOD);
+        self.current.deletion_grace_until = Some(Instant::now() + grace_period);
+        let ticks = (grace_period.as_millis() / TICK_RATE.as_millis()).min(u8::MAX as u128) as u8;
+        let message = "Card deleted — press u to undo".to_string();
+        self.toast = Some(Toast::lasting(message.clone(), ticks));
+        self.log(message);
+    }
+
+    fn increase_priority(&mut self) {
+        if !self.check_manual_order() {
+            return;
+        }
+        self.with_selected_card(|this, column_index, card_index| {
+            this.execute(ChangePriorityCommand::new(column_index, card_index, Priority::Increase))
+        });
+        self.flash_moved_card();
+    }
+
+    fn decrease_priority(&mut self) {
+        if !self.check_manual_order() {
+            return;
+        }
+        self.with_selected_card(|this, column_index, card_index| {
+            this.execute(ChangePriorityCommand::new(column_index, card_index, Priority::Decrease))
+        });
+        self.flash_moved_card();
+    }
+
+    fn mark_card_done(&mut self) {
+        self.with_selected_card(|this, column_index, card_index| {
+            this.execute(MarkCardCommand::new(column_index, card_index, Mark::Done))
+        });
+    }
+
+    fn mark_card_undone(&mut self) {
+        self.with_selected_card(|this, column_index, card_index| {
+            this.execute(MarkCardCommand::new(column_index, card_index, Mark::Undone))
+        });
+    }
+
+    fn move_column_left(&mut self) {
+        self.with_selected_column(|this, column_index, card_index| {
+            this.execute(MoveColumnCommand::new(column_index, card_index, ColumnDirection::Left))
+        });
+    }
+
+    fn move_column_right(&mut self) {
+        self.with_selected_column(|this, column_index, card_index| {
+            this.execute(MoveColumnCommand::new(column_index, card_index, ColumnDirection::Right))
+        });
+    }
+
+    fn selected_column_header(&self) -> Option<String> {
+        self.current.selector.insertion_point().map(|(column_index, _)| {
+            self.current
+                .board
+                .as_ref()
+                .borrow()
+                .column(column_index)
+                .header()
+                .to_string()
+        })
+    }
+
+    fn rename_column(&mut self, header: &str) {
+        self.with_selected_column(|this, column_index, card_index| {
+            this.execute(RenameColumnCommand::new(column_index, card_index, header))
+        });
+    }
+
+    fn selected_column_options(&self) -> Option<ColumnOptions> {
+        self.current
+            .selector
+            .insertion_point()
+            .map(|(column_index, _)| self.current.board.as_ref().borrow().column(column_index).options())
+    }
+
+    fn update_column_options(&mut self, options: ColumnOptions) {
+        self.with_selected_column(|this, column_index, card_index| {
+            let sorted = this.current.board.as_ref().borrow().column(column_index).sort_policy() != options.sort;
+            if sorted {
+                this.backup_before(&format!("Sort column by {}", options.sort.name()));
+            }
+            this.execute(UpdateColumnCommand::new(column_index, card_index, options.clone()))
+        });
+    }
+
+    fn cycle_sort_policy(&mut self) {
+        self.with_selected_column(|this, column_index, card_index| {
+            let policy = this
+                .current
+                .board
+                .as_ref()
+                .borrow()
+                .column(column_index)
+                .sort_policy()
+                .next();
+            if !this.check_writable(&[column_index]) {
+                return (column_index, card_index);
+            }
+            this.backup_before(&format!("Sort column by {}", policy.name()));
+            match this.try_execute(SortColumnCommand::new(column_index, card_index, policy)) {
+                Some(position) => {
+                    this.notify(format!("Column sorted by {}", policy.name()));
+                    position
+                }
+                None => (column_index, card_index),
+            }
+        });
+    }
+
+    fn undo(&mut self) {
+        let position = if self.settings.history.per_column {
+            let column_index = self.current.selector.column();
+            self.current
+                .history
+                .undo_in_column(column_index, &mut self.current.board.as_ref().borrow_mut())
+        } else {
+            self.current.history.undo(&mut self.current.board.as_ref().borrow_mut())
+        };
+
+        match position {
+            Some((column_index, card_index)) => {
+                self.journal(JournalEntry::Board { board: self.board() });
+                self.current.selector.set(column_index, card_index);
+                self.check_invariants(
+                    self.current
+                        .history
+                        .last_undo_description()
+                        .map(|d| format!("undo {}", d)),
+                );
+                self.current.deletion_grace_until = None;
+                let description = self.current.history.last_undo_description().unwrap_or_default();
+                let text = format!("Undid: {}", description);
+                self.notify(text.clone());
+                if let Some(activity) = self.current.history.last_undo_activity() {
+                    self.activity.revert(activity);
+                }
+                self.record_change(text, Some(Activity::ChangeUndone), Some((column_index, card_index)));
+            }
+            None if self.settings.history.per_column => self.log("Nothing to undo in this column".to_string()),
+            None => self.log("Nothing to undo".to_string()),
+        }
+    }
+
+    fn redo(&mut self) {
+        let position = if self.settings.history.per_column {
+            let column_index = self.current.selector.column();
+            self.current
+                .history
+                .redo_in_column(column_index, &mut self.current.board.as_ref().borrow_mut())
+        } else {
+            self.current.history.redo(&mut self.current.board.as_ref().borrow_mut())
+        };
+
+        match position {
+            Some((column_index, card_index)) => {
+                self.journal(JournalEntry::Board { board: self.board() });
+                self.current.selector.set(column_index, card_index);
+                self.check_invariants(
+                    self.current
+                        .history
+                        .last_redo_description()
+                        .map(|d| format!("redo {}", d)),
+                );
+                let description = self.current.history.last_redo_description().unwrap_or_default();
+                let text = format!("Redid: {}", description);
+                self.notify(text.clone());
+                let activity = self.current.history.last_redo_activity();
+                self.record_change(text, activity, Some((column_index, card_index)));
+            }
+            None if self.settings.history.per_column => self.log("Nothing to redo in this column".to_string()),
+            None => self.log("Nothing to redo".to_string()),
+        }
+    }
+
+    fn write(&mut self) {
+        if self.current.read_only {
+            self.log("Board opened read-only, use <W> to write it to another file".to_string());
+            return;
+        }
+        if self.has_save_conflict() {
+            self.log(format!(
+                "Not written, {} changed on disk since it was read",
+                self.current.file_name
+            ));
+            return;
+        }
+
+        self.flush_timer(Instant::now());
+        let board = self.current.board.as_ref().borrow().clone();
+        let options = SaveOptions {
+            canonical: self.settings.files.canonical_json,
+            layout: self.settings.files.layout_for(board.file_layout()),
+        };
+        match board.to_file_with(&self.current.file_name, options) {
+            Ok(_) => {
+                // The restored board is now on disk, other crash dumps are kept until recovered or discarded
+                if let Some(recovery_file) = self.current.recovered_from.take() {
+                    let _ = fs::remove_file(recovery_file);
+                }
+                self.clear_journal();
+                if board.has_scratch_cards() {
+                    // The journal is replayed on the file, which has no scratch card
+                    self.journal(JournalEntry::Board { board: board.clone() });
+                }
+                self.checkpoint();
+                self.remember_disk_version();
+                self.remember_synced();
+                self.record_metrics();
+                self.log_statistics(&board);
+                self.log(format!("Board written to {}", self.current.file_name));
+                self.record_activity(Activity::BoardSaved(self.current.file_name.clone()));
+            }
+            Err(e) => self.log(format!("Error writing to file: {}", e)),
+        }
+    }
+
+    fn write_to_file(&mut self, file_name: String) {
+        let lock = match self.current.lock.take() {
+            Some(lock) if lock.is_for(&file_name) => Ok(lock),
+            lock => {
+                self.current.lock = lock;
+                BoardLock::acquire(&file_name)
+            }
+        };
+        let lock = match lock {
+            Ok(lock) => Some(lock),
+            Err(LockError::Locked(owner)) => {
+                self.log(format!("{} is opened by {}, not written", file_name, owner));
+                return;
+            }
+            Err(LockError::Io(_)) => None,
+        };
+
+        // Writing over another file is what the user asked for
+        self.current.on_disk = None;
+        self.clear_journal();
+        self.current.file_name = file_name;
+        self.current.read_only = false;
+        self.current.lock = lock;
+        self.load_metrics();
+        self.open_journal();
+        self.write();
+    }
+}
+
+/// Command opening a link in the default browser of the system
+///
+/// On Windows, the link is handed to the URL handler directly rather than through `cmd /C start`, which would run
+/// what follows a `&` in the link as another command.
+fn default_opener() -> process::Command {
+    if cfg!(target_os = "macos") {
+        process::Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = process::Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        process::Command::new("xdg-open")
+    }
+}
+
+/// Where the board is backed up before a change touching many cards, written as JSON whatever the format of the board
+fn backup_file_name(file_name: &str, date: DateTime<Local>) -> String {
+    format!(
+        "{}.{}.{}",
+        file_name.trim_end_matches('/'),
+        date.format(BACKUP_DATE_FORMAT),
+        BACKUP_EXTENSION
+    )
+}
+
+/// Writes a board to a new backup of its file, see [`backup_file_name`], then removes the oldest backups past
+/// `keep`, returns the backup written
+pub(crate) fn write_backup(file_name: &str, board: &Board, keep: usize) -> io::Result<String> {
+    let backup_file = backup_file_name(file_name, Local::now());
+    board.to_file(&backup_file)?;
+    prune_backups(file_name, keep)?;
+
+    Ok(backup_file)
+}
+
+/// Removes the oldest backups of a board file past `keep`, files only named like them are left alone
+fn prune_backups(file_name: &str, keep: usize) -> io::Result<()> {
+    let path = Path::new(file_name.trim_end_matches('/'));
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return Ok(());
+    };
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let is_backup = |entry: &str| {
+        entry
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .and_then(|rest| rest.strip_suffix(BACKUP_EXTENSION))
+            .and_then(|rest| rest.strip_suffix('.'))
+            .is_some_and(|date| NaiveDateTime::parse_from_str(date, BACKUP_DATE_FORMAT).is_ok())
+    };
+    let mut backups: Vec<String> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|entry| is_backup(entry))
+        .collect();
+    // The dates sort in the order the backups were written
+    backups.sort();
+    let outdated = backups.len().saturating_sub(keep.max(1));
+    for backup in &backups[..outdated] {
+        fs::remove_file(directory.join(backup))?;
+    }
+
+    Ok(())
+}
+
+/// Whether two paths name the same file, even when written differently
+fn same_file(file_name: &str, other: &str) -> bool {
+    match (fs::canonicalize(file_name), fs::canonicalize(other)) {
+        (Ok(path), Ok(other)) => path == other,
+        _ => file_name == other,
+    }
+}
+
+/// An empty history, bounded as configured
+fn new_history(settings: &Settings) -> History {
+    History::new()
+        .max_commands(settings.history.max_commands)
+        .max_bytes(settings.history.max_kilobytes * 1024)
+        .tree(settings.history.tree)
+}
+
+impl App {
+    /// The timed card and the time spent on it, including the time counted since the last flush
+    fn timer_status(&self) -> Option<String> {
+        let timer = self.current.timer.as_ref()?;
+        let board = self.current.board.borrow();
+        let card = board
+            .timed_card()
+            .map(|(column_index, card_index)| board.card(column_index, card_index))?;
+
+        let spent = time::pretty_duration(card.time_spent() + timer.elapsed(Instant::now()));
+        let paused = if timer.is_idle() { " (paused)" } else { "" };
+        Some(format!("timer on '{}': {}{}", card.short_description(), spent, paused))
+    }
+
+    /// Cards completed this week against the weekly target of the board, with a gauge
+    fn burn_up_status(&self) -> Option<Line<'static>> {
+        let burn_up = BurnUp::compute(&self.current.board.borrow(), Local::now())?;
+        let filled = burn_up.filled(BURN_UP_WIDTH);
+        let gauge = format!("{}{}", "█".repeat(filled), "░".repeat(BURN_UP_WIDTH - filled));
+        let gauge = if burn_up.is_reached() {
+            gauge.green()
+        } else {
+            gauge.blue()
+        };
+        Some(Line::from(vec![
+            "- this week: ".dim(),
+            gauge,
+            format!(" {}/{} done ", burn_up.done, burn_up.target).dim(),
+        ]))
+    }
+
+    /// The phase of the pomodoro and the time left in it
+    fn pomodoro_status(&self) -> Option<String> {
+        let pomodoro = self.current.pomodoro.as_ref()?;
+        let left = pomodoro.remaining(Instant::now()).as_secs();
+        let left = format!("{:02}:{:02}", left / 60, left % 60);
+        match pomodoro.phase() {
+            Phase::Work => Some(format!("pomodoro: {} left, {}", left, self.timer_status()?)),
+            Phase::Break => Some(format!("break: {} left", left)),
+        }
+    }
+
+    fn render_board(&self, area: Rect, buf: &mut Buffer) {
+        let board = self.current.board.as_ref().borrow();
+        self.selected_card_area.set(None);
+        if self.focus_mode {
+            let column_index = self
+                .current
+                .selector
+                .insertion_point()
+                .map_or(0, |(column_index, _)| column_index);
+            let selected_card = self.current.selector.get().map(|(_, card_index)| card_index);
+            FocusView::new(&board, column_index, selected_card).render(area, buf);
+        } else {
+            let board_view = BoardView::new(&board, &self.theme)
+                .selection(self.current.selector.get())
+                .flashed_card(self.current.flashed_card.map(|(position, _)| position))
+                .committed_cards(self.settings.priorities.committed_cards)
+                .numbered(self.card_numbers)
+                .tag_colors(&self.settings.view.tag_colors)
+                .hide_done_before(self.hide_done_before)
+                .starred_only(self.starred_only)
+                .density(self.density)
+                .collapsed_sections(&self.collapsed_sections)
+                .selected_column(
+                    self.current
+                        .selector
+                        .is_on_collapsed_section()
+                        .then(|| self.current.selector.column()),
+                )
+                .ghost(self.move_slot().map(|(column_index, card_index, target_column, slot)| {
+                    let title = board.card(column_index, card_index).short_description();
+                    (target_column, slot, title.as_str())
+                }));
+            let card_area = board_view.selected_card_area(area);
+            self.selected_card_area.set(card_area);
+            board_view.render(area, buf);
+
+            let card = self
+                .current
+                .selector
+                .get()
+                .map(|(column_index, card_index)| board.card(column_index, card_index));
+            if let (Some(card), Some(card_area)) = (card, card_area) {
+                if self.preview_ticks >= PREVIEW_DELAY_TICKS && CardPreview::has_details(card) {
+                    CardPreview::new(card, card_area).render(area, buf);
+                }
+            }
+        }
+
+        if let Some(toast) = &self.toast {
+            toast.render(area, buf);
+        }
+    }
+
+    /// Renders the board with the feed on its right when it is visible
+    fn render_board_and_feed(&self, area: Rect, buf: &mut Buffer) {
+        self.notes_area.set(None);
+        if !self.feed_visible && !self.notes_visible {
+            self.render_board(area, buf);
+            return;
+        }
+
+        let [board_area, side_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(SIDE_PANEL_WIDTH)]).areas(area);
+        self.render_board(board_area, buf);
+        let [feed_area, notes_area] = match (self.feed_visible, self.notes_visible) {
+            (true, true) => Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(side_area),
+            (true, false) => [side_area, Rect::default()],
+            (false, _) => [Rect::default(), side_area],
+        };
+        if self.feed_visible {
+            self.current.feed.render(self.feed_focused, feed_area, buf);
+        }
+        if self.notes_visible {
+            self.notes_area.set(Some(notes_area));
+            notes_panel::render_notes(self.current.board.borrow().notes(), notes_area, buf);
+        }
+    }
+
+    fn render_pane(&self, focused: bool, area: Rect, buf: &mut Buffer) {
+        let [header_area, board_area] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+        let header = format!(" {} ", self.current.file_name);
+        let header = if focused {
+            header.bold().reversed()
+        } else {
+            header.dim()
+        };
+        Line::from(header).centered().render(header_area, buf);
+
+        self.render_board_and_feed(board_area, buf);
+    }
+}
+
+impl Widget for &App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [title_area, board_area, logger_area, instructions_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(self.layout.log_height()),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        let focused = self.focused();
+        let title = match (focused.current.read_only, focused.focus_mode) {
+            (true, _) => " Read-only ",
+            (false, true) => " Focus mode ",
+            (false, false) => " Welcome ",
+        };
+        let mut title = Line::from(title.bold());
+        if let (_, count @ 1..) = focused.current.archived_today {
+            title.push_span(format!("- archived today: {} ", count).dim());
+        }
+        if let Some(status) = focused.pomodoro_status().or_else(|| focused.timer_status()) {
+            title.push_span(format!("- {} ", status).dim());
+        }
+        if let Some(burn_up) = focused.burn_up_status() {
+            title.spans.extend(burn_up.spans);
+        }
+        let title = title.centered();
+        title.render(title_area, buf);
+
+        let instructions = Line::from(vec![
+            " Help ".into(),
+            "<?> ".blue().bold(),
+            "Quit ".into(),
+            "<q> ".blue().bold(),
+        ])
+        .centered();
+        instructions.render(instructions_area, buf);
+
+        match &self.split {
+            Some(split) => {
+                let [left, right] =
+                    Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(board_area);
+                self.render_pane(!split.other_focused, left, buf);
+                split.other.render_pane(split.other_focused, right, buf);
+            }
+            None => self.render_board_and_feed(board_area, buf),
+        }
+        focused.logger.render(logger_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        env, fs,
+        io::Result,
+        path::Path,
+        time::{Duration, Instant},
+    };
+
+    use chrono::{Local, TimeDelta};
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use crate::{
+        app::{
+            board_lock::BoardLock, file_revision, replay, AppOperations, InsertPosition, Journal, JournalEntry, Logger,
+        },
+        board::{
+            metrics_file_name, parse_csv, stats_log_file_name, sync_base_file_name, Board, BoardBuilder, ColumnOptions,
+            CsvMapping, JsonLayout, MetricsHistory,
+        },
+        config::{ArchiveSettings, LinkSettings, RolloverSettings, Settings, StatsLogSettings},
+    };
+
+    use super::{write_backup, App, FLASH_TICKS};
+
+    #[test]
+    fn mark_done_and_undone() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.select_next_card();
+        app.select_next_card();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Buy bread", card.short_description());
+
+        app.mark_card_done();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Buy bread", card.short_description());
+
+        app.select_next_column();
+        app.select_next_card();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Wash dishes", card.short_description());
+
+        app.mark_card_undone();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Wash dishes", card.short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn idle_time_kept_or_discarded() -> Result<()> {
+        const MINUTE: Duration = Duration::from_secs(60);
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.toggle_timer();
+        assert_eq!(Some((0, 0)), app.board().timed_card());
+
+        let now = Instant::now();
+        app.register_input(now);
+        app.check_idle(now + 9 * MINUTE);
+        assert_eq!(None, app.register_input(now + 9 * MINUTE));
+        app.check_idle(now + 20 * MINUTE);
+        assert_eq!(Some(41 * MINUTE), app.register_input(now + 50 * MINUTE));
+        app.resolve_idle(true);
+
+        app.check_idle(now + 70 * MINUTE);
+        assert_eq!(Some(20 * MINUTE), app.register_input(now + 70 * MINUTE));
+        app.resolve_idle(false);
+        assert_eq!(None, app.register_input(now + 71 * MINUTE));
+
+        app.mark_card_done();
+        app.toggle_timer();
+        let card = app.board().card(1, 0).clone();
+        assert_eq!("Buy milk", card.short_description());
+        assert_eq!(None, app.board().timed_card());
+        assert_eq!(50 * MINUTE, card.time_spent());
+
+        Ok(())
+    }
+
+    #[test]
+    fn pomodoro_on_selected_card() -> Result<()> {
+        const MINUTE: Duration = Duration::from_secs(60);
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.toggle_pomodoro();
+        assert_eq!(Some((0, 0)), app.board().timed_card());
+        assert!(app
+            .pomodoro_status()
+            .is_some_and(|status| status.starts_with("pomodoro: ")));
+
+        let now = Instant::now();
+        app.check_pomodoro(now + 25 * MINUTE);
+        assert!(app.current.timer.is_none());
+        assert_eq!(1, app.board().card(0, 0).pomodoros());
+        assert!(app.board().card(0, 0).time_spent() >= 25 * MINUTE);
+        assert!(app
+            .logger
+            .show()
+            .contains("Pomodoro 1 completed on 'Buy milk', take a 5 minutes break"));
+        assert!(app
+            .pomodoro_status()
+            .is_some_and(|status| status.starts_with("break: ")));
+
+        app.check_pomodoro(now + 31 * MINUTE);
+        assert!(app.current.timer.is_some());
+        assert!(app.logger.show().contains("Break over, back to 'Buy milk'"));
+
+        app.toggle_timer();
+        assert!(app.current.pomodoro.is_none());
+        assert_eq!(None, app.board().timed_card());
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_summary() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+        assert!(app.session_summary().is_empty());
+
+        app.select_next_card();
+        app.insert_card(InsertPosition::Current);
+        app.insert_card(InsertPosition::Current);
+        app.undo();
+        let mut card = app.get_selected_card().unwrap();
+        card.update_short_description("Buy butter");
+        app.update_card(card);
+
+        app.select_next_column();
+        app.mark_card_done();
+        app.mark_card_done();
+        app.mark_card_undone();
+
+        let summary = app.session_summary();
+        assert_eq!(1, summary.cards_created);
+        assert_eq!(1, summary.cards_edited);
+        assert_eq!(1, summary.cards_completed, "moving to the last column completes a card");
+        assert!(summary.saved_files.is_empty());
+        assert_eq!(None, app.quit_summary(), "disabled by default");
+
+        Ok(())
+    }
+
+    #[test]
+    fn tutorial_steps_checked_off() -> Result<()> {
+        let mut app = App::tutorial(Settings::default());
+        assert_eq!("Select a card with h/j/k/l", app.board().card(0, 0).short_description());
+
+        app.select_next_card();
+        assert_eq!(
+            "✔ Select a card with h/j/k/l",
+            app.board().card(0, 0).short_description()
+        );
+
+        app.decrease_priority();
+        app.undo();
+        let board = app.board();
+        assert_eq!("✔ Change a priority with K/J", board.card(0, 3).short_description());
+        assert_eq!("✔ Undo a change with u", board.card(0, 5).short_description());
+        assert_eq!("Edit a card with e", board.card(0, 2).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn insertion_does_nothing_when_no_card_selected() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        assert_eq!(None, app.insert_card(InsertPosition::Current));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_timer_on_empty_column() -> Result<()> {
+        let mut app = App::new("res/test_board_with_empty_column.json".to_string());
+
+        app.select_next_card();
+        app.select_next_column();
+        app.toggle_timer();
+        assert!(app.current.timer.is_none());
+        assert!(app.logger.show().contains("No card selected"));
+        app.toggle_pomodoro();
+        assert!(app.current.pomodoro.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn insertion_at_current_position() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.select_next_card();
+        app.select_next_card();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Buy bread", card.short_description());
+
+        let card = app.insert_card(InsertPosition::Current).unwrap();
+        assert_eq!("TODO", card.short_description());
+
+        assert_eq!(Some((0, 2)), app.current.selector.get());
+
+        app.select_next_card();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Buy bread", card.short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn insertion_at_top() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.select_next_card();
+        app.select_next_card();
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("Buy bread", card.short_description());
+
+        assert_eq!(
+            "Buy milk",
+            app.current.board.as_ref().borrow().card(0, 0).short_description()
+        );
+        let card = app.insert_card(InsertPosition::Top).unwrap();
+        assert_eq!("TODO", card.short_description());
+        assert_eq!(
+            "TODO",
+            app.current.board.as_ref().borrow().card(0, 0).short_description()
+        );
+        let card = app.get_selected_card().unwrap();
+        assert_eq!("TODO", card.short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_per_column() -> Result<()> {
+        let mut settings = Settings::default();
+        settings.history.per_column = true;
+        let mut app = App::with_settings("res/test_board.json".to_string(), settings);
+
+        app.select_next_card();
+        app.remove_card();
+        app.select_next_column();
+        app.select_next_column();
+        app.remove_card();
+        app.select_prev_column();
+        app.select_prev_column();
+
+        app.undo();
+        assert_eq!(3, app.board().column(0).size());
+        assert_eq!(1, app.board().column(2).size(), "left as it is");
+        app.undo();
+        assert!(app.logger.show().contains("Nothing to undo in this column"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_and_redo_deletion() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_card();
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!(2, app.board().column(0).size());
+        assert_eq!("Buy bread", app.get_selected_card().unwrap().short_description());
+
+        app.undo();
+        assert_eq!(3, app.board().column(0).size());
+        assert_eq!("Buy eggs", app.get_selected_card().unwrap().short_description());
+        assert_eq!(Some((0, 1)), app.current.selector.get());
+
+        app.redo();
+        assert_eq!(2, app.board().column(0).size());
+        assert_eq!("Buy bread", app.get_selected_card().unwrap().short_description());
+        assert_eq!("Redid: Remove card", app.toast.as_ref().unwrap().message());
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_card_to_the_split() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-send-card");
+        fs::create_dir_all(&directory)?;
+        let main = directory.join("main.json").display().to_string();
+        let other = directory.join("other.json").display().to_string();
+        fs::copy("res/test_board.json", &main)?;
+        fs::copy("res/test_board.json", &other)?;
+        let mut app = App::new(main.clone());
+        app.open_split(other.clone());
+        let split = app.split.as_mut().unwrap();
+
+        split.other.select_next_card();
+        split.other.remove_card();
+        app.send_card_to_other_pane();
+        let split = app.split.as_mut().unwrap();
+        assert!(split
+            .other
+            .logger
+            .show()
+            .contains("Write both boards before sending a card from one to the other"));
+        assert_eq!(3, app.board().column(0).size());
+
+        app.split.as_mut().unwrap().other.write();
+        app.send_card_to_other_pane();
+        assert_eq!(4, Board::open(&main)?.column(0).size(), "written right away");
+        assert_eq!(1, Board::open(&other)?.column(0).size());
+        assert_eq!(None, app.split_with_unwritten_changes());
+        let cards = |app: &App| app.board().cards_with_positions().count();
+        let total = cards(&app) + cards(&app.split.as_ref().unwrap().other);
+
+        let split = app.split.as_mut().unwrap();
+        split.other.undo();
+        assert_eq!(1, split.other.board().column(0).size(), "not brought back by undo");
+        assert!(split
+            .other
+            .toast
+            .as_ref()
+            .is_none_or(|toast| !toast.message().contains("Card deleted")));
+        assert!(split.other.current.deletion_grace_until.is_none());
+        app.undo();
+        assert_eq!(4, app.board().column(0).size());
+        assert_eq!(
+            total,
+            cards(&app) + cards(&app.split.as_ref().unwrap().other),
+            "the card exists once"
+        );
+
+        app.split.as_mut().unwrap().other.remove_card();
+        assert_eq!(Some(other.clone()), app.split_with_unwritten_changes());
+        app.write_and_close_split();
+        assert!(app.split.is_none());
+        assert!(Board::open(&other)?.column(0).is_empty());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn switch_to_the_alternate_board() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-alternate-board");
+        fs::create_dir_all(&directory)?;
+        let main = directory.join("main.json").display().to_string();
+        let other = directory.join("other.json").display().to_string();
+        fs::copy("res/test_board.json", &main)?;
+        fs::copy("res/test_board.json", &other)?;
+        let mut app = App::new(main.clone());
+
+        app.open_other_board(other.clone());
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!(other, app.current.file_name);
+        assert_eq!(vec![other.clone()], app.boards_with_unwritten_changes());
+
+        app.toggle_starred_only();
+        app.toggle_alternate_board();
+        assert_eq!(main, app.current.file_name);
+        assert_eq!(3, app.board().column(0).size());
+        assert!(app.logger.show().contains(&format!("Switched to {}", main)));
+        assert!(app.starred_only, "the view stays with the session");
+        app.toggle_starred_only();
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!(vec![main.clone(), other.clone()], app.boards_with_unwritten_changes());
+
+        app.write_all();
+        assert!(app.boards_with_unwritten_changes().is_empty());
+        assert_eq!(2, Board::open(&main)?.column(0).size());
+        assert_eq!(2, Board::open(&other)?.column(0).size());
+        app.toggle_alternate_board();
+        app.undo();
+        assert_eq!(other, app.current.file_name, "each board keeps its history");
+        assert_eq!(3, app.board().column(0).size());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn reload_keeping_the_selection() -> Result<()> {
+        let file_name = env::temp_dir()
+            .join("rustyban-reload-selection.json")
+            .display()
+            .to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+        app.select_next_card();
+        app.select_next_card();
+        assert_eq!("Buy eggs", app.get_selected_card().unwrap().short_description());
+
+        let mut board = Board::open(&file_name)?;
+        board.remove_card(0, 0);
+        board.mark_card_done(1, 0);
+        board.to_file(&file_name)?;
+        app.reload_board();
+
+        assert_eq!("Buy eggs", app.get_selected_card().unwrap().short_description());
+        assert_eq!(Some((0, 0)), app.current.selector.get());
+        assert!(app.logger.last(1).any(|message| message.ends_with(&format!(
+            "2 change(s) from {}: > 'Cook dinner' from Doing to Done!, - 'Buy milk' from TODO",
+            file_name
+        ))));
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn every_change_in_the_feed() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-feed.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+        let feed = |app: &App| {
+            let area = Rect::new(0, 0, 120, 12);
+            let mut buf = Buffer::empty(area);
+            app.current.feed.render(false, area, &mut buf);
+            (1..area.height - 1)
+                .map(|y| (1..area.width - 1).map(|x| buf[(x, y)].symbol()).collect::<String>())
+                .map(|line| line.trim_end().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        app.select_next_card();
+        app.mark_card_done();
+        app.undo();
+        app.redo();
+        let mut board = Board::open(&file_name)?;
+        board.remove_card(0, 0);
+        board.to_file(&file_name)?;
+        app.reload();
+
+        let events: Vec<String> = feed(&app)
+            .into_iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| line[7..].to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                format!("Reloaded {}, it changed on disk so undo history was reset", file_name),
+                "Moved 'Buy milk' to Doing".to_string(),
+                "Undid: Mark card done".to_string(),
+                "Moved 'Buy milk' to Doing".to_string(),
+            ],
+            events,
+        );
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn json_layout_of_the_settings() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-layout.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut settings = Settings::default();
+
+        App::with_settings(file_name.clone(), settings.clone()).write();
+        assert!(fs::read_to_string(&file_name)?.trim_end().contains('\n'), "kept pretty");
+
+        settings.files.json_layout = Some(JsonLayout::Compact);
+        App::with_settings(file_name.clone(), settings.clone()).write();
+        assert!(!fs::read_to_string(&file_name)?.trim_end().contains('\n'));
+        App::with_settings(file_name.clone(), Settings::default()).write();
+        assert!(
+            !fs::read_to_string(&file_name)?.trim_end().contains('\n'),
+            "kept compact"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reload_from_file() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-reload.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+
+        app.select_next_card();
+        app.mark_card_done();
+        app.write();
+        app.remove_card();
+        app.reload();
+        assert_eq!(
+            "Buy milk",
+            app.board().card(1, 0).short_description(),
+            "back to the written board"
+        );
+        assert_eq!("Reloaded", &app.toast.as_ref().unwrap().message()[..8]);
+        app.redo();
+        assert_eq!(1, app.board().column(1).size(), "the deletion can be redone");
+
+        app.reload();
+        app.undo();
+        assert_eq!(
+            "Buy milk",
+            app.board().card(0, 0).short_description(),
+            "undo still possible"
+        );
+
+        fs::copy("res/test_board.json", &file_name)?;
+        app.reload();
+        assert_eq!(
+            "Buy milk",
+            app.board().card(0, 0).short_description(),
+            "back to the opened board"
+        );
+
+        let mut board = Board::open(&file_name)?;
+        board.remove_card(0, 0);
+        board.to_file(&file_name)?;
+        app.reload();
+        assert!(app.toast.as_ref().unwrap().message().contains("undo history was reset"));
+        app.undo();
+        assert_eq!(
+            "Buy eggs",
+            app.board().card(0, 0).short_description(),
+            "nothing to undo"
+        );
+
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn write_to_locked_file() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-write-locked.json").display().to_string();
+        let other = env::temp_dir()
+            .join("rustyban-write-locked-other.json")
+            .display()
+            .to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::new(file_name.clone());
+        app.set_lock(BoardLock::acquire(&file_name).unwrap());
+
+        let lock = BoardLock::acquire(&other).unwrap();
+        app.write_to_file(other.clone());
+        assert!(app.logger.show().contains("is opened by"));
+        assert!(fs::metadata(&other).is_err());
+        assert_eq!(file_name, app.current.file_name);
+
+        drop(lock);
+        app.write_to_file(other.clone());
+        assert!(fs::metadata(&other).is_ok());
+        app.write_to_file(other.clone());
+        assert!(
+            app.logger.last(1).any(|message| message.contains("Board written")),
+            "its own lock"
+        );
+
+        fs::remove_file(metrics_file_name(&other))?;
+        fs::remove_file(&other)?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn journal_changes_until_written() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-journal.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let journal = Journal::new(&file_name);
+        journal.clear()?;
+        let mut app = App::new(file_name.clone());
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!(0, journal.len()?, "only journaled once enabled");
+
+        app.set_lock(BoardLock::acquire(&file_name).unwrap());
+        app.enable_journal();
+        assert_eq!(1, journal.len()?, "the board does not match its file anymore");
+        app.select_next_card();
+        app.increase_priority();
+        app.mark_card_done();
+        app.undo();
+        app.undo();
+        app.undo();
+        assert_eq!(6, journal.len()?, "undoing what was done before is journaled too");
+
+        let mut board = Board::open(&file_name)?;
+        replay(&mut board, journal.entries()?).unwrap();
+        assert_eq!(
+            serde_json::to_value(app.board())?,
+            serde_json::to_value(&board)?,
+            "the board is rebuilt from its file and the journal"
+        );
+        let base = file_revision(&file_name);
+        assert!(journal.matches(base)?);
+
+        app.write();
+        assert_eq!(0, journal.len()?, "cleared once written");
+
+        // Crashed after writing the board, before clearing the journal
+        journal.append(
+            base,
+            &JournalEntry::RemoveCard {
+                column_index: 0,
+                card_index: 0,
+            },
+        )?;
+        drop(app);
+        let mut app = App::new(file_name.clone());
+        app.set_lock(BoardLock::acquire(&file_name).unwrap());
+        app.enable_journal();
+        assert_eq!(0, journal.len()?, "not replayed on the written board");
+        assert!(app.logger.show().contains("changes were made on another version"));
+
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn scratch_cards_for_the_session() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-scratch.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let journal = Journal::new(&file_name);
+        journal.clear()?;
+        let mut app = App::new(file_name.clone());
+        app.set_lock(BoardLock::acquire(&file_name).unwrap());
+        app.enable_journal();
+
+        app.select_next_card();
+        let card = app.insert_scratch_card().unwrap();
+        assert!(card.is_scratch());
+        assert_eq!(4, app.board().column(0).size());
+
+        app.write();
+        assert_eq!(3, Board::open(&file_name)?.column(0).size(), "not written");
+        assert!(!app.has_save_conflict());
+        let mut board = Board::open(&file_name)?;
+        replay(&mut board, journal.entries()?).unwrap();
+        assert!(board.card(0, 0).is_scratch(), "recovered after a crash");
+
+        app.undo();
+        assert_eq!(3, app.board().column(0).size());
+
+        journal.clear()?;
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn metrics_snapshot_on_first_write_of_the_day() -> Result<()> {
+        let file_name = env::temp_dir()
+            .join("rustyban-metrics-snapshot.json")
+            .display()
+            .to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let _ = fs::remove_file(metrics_file_name(&file_name));
+        let mut app = App::new(file_name.clone());
+        assert!(app.metrics().snapshots().is_empty());
+
+        app.write();
+        app.remove_card();
+        app.write();
+        let snapshots = MetricsHistory::open(&file_name)?.snapshots().to_vec();
+        assert_eq!(1, snapshots.len(), "once a day");
+        assert_eq!(3, snapshots[0].columns[0].cards, "as first written");
+
+        let app = App::new(file_name.clone());
+        assert_eq!(snapshots, app.metrics().snapshots(), "read with the board");
+
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn statistics_logged_on_write() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-stats-logged.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let _ = fs::remove_file(stats_log_file_name(&file_name));
+
+        App::new(file_name.clone()).write();
+        assert!(!Path::new(&stats_log_file_name(&file_name)).exists(), "not enabled");
+
+        let settings = Settings {
+            stats_log: StatsLogSettings { enabled: true },
+            ..Default::default()
+        };
+        let mut app = App::with_settings(file_name.clone(), settings);
+        app.write();
+        app.select_next_card();
+        app.remove_card();
+        app.write();
+        let log = fs::read_to_string(stats_log_file_name(&file_name))?;
+        let counts: Vec<&str> = log.lines().map(|line| line.split_once(',').unwrap().1).collect();
+        assert_eq!(vec!["TODO,Doing,Done!,completed_today", "3,1,2,0", "2,1,2,0"], counts);
+
+        fs::remove_file(stats_log_file_name(&file_name))?;
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn deletion_grace_period() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+        let now = Instant::now();
+
+        app.remove_card();
+        assert!(!app.is_in_deletion_grace_period(now), "nothing deleted");
+
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!("Card deleted — press u to undo", app.toast.as_ref().unwrap().message());
+        assert!(app.is_in_deletion_grace_period(Instant::now()));
+        assert!(!app.is_in_deletion_grace_period(Instant::now() + Duration::from_secs(5)));
+        for _ in 0..19 {
+            app.tick();
+        }
+        assert!(app.toast.is_some(), "shown during the whole grace period");
+
+        app.undo();
+        assert!(!app.is_in_deletion_grace_period(Instant::now()));
+
+        let mut settings = Settings::default();
+        settings.deletion.grace_seconds = u64::MAX;
+        let mut app = App::with_settings("res/test_board.json".to_string(), settings);
+        app.select_next_card();
+        app.remove_card();
+        assert!(app.is_in_deletion_grace_period(Instant::now() + Duration::from_secs(3600)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn flash_card_after_priority_change() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+        app.select_next_card();
+        app.select_next_card();
+
+        app.increase_priority();
+        assert_eq!(Some(((0, 0), FLASH_TICKS)), app.current.flashed_card);
+        for _ in 0..FLASH_TICKS - 1 {
+            app.tick();
+        }
+        assert!(app.current.flashed_card.is_some());
+        app.tick();
+        assert_eq!(None, app.current.flashed_card, "only for a few ticks");
+
+        app.decrease_priority();
+        assert_eq!(Some(((0, 1), FLASH_TICKS)), app.current.flashed_card);
+        app.select_next_card();
+        assert_eq!(None, app.current.flashed_card, "dismissed when moving");
+
+        Ok(())
+    }
+
+    #[test]
+    fn crash_report_saves_the_board() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-crash-report.json").display().to_string();
+        let mut app = App::new(file_name.clone());
+        app.select_next_card();
+        app.insert_card(InsertPosition::Top);
+        app.log("About to crash".to_string());
+
+        let report = app.crash_report("Out of cheese".to_string()).to_string();
+        let recovery_file = format!("{}.crash", file_name);
+        assert!(report.contains(&format!("The board was saved to {}", recovery_file)));
+        assert!(report.contains("] About to crash"));
+        assert!(!Path::new(&file_name).exists(), "the board file is left untouched");
+
+        let board = Board::open(&recovery_file)?;
+        fs::remove_file(&recovery_file)?;
+        assert_eq!(1, board.column(0).size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn keep_recovery_file_until_recovered() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-recovery.json").display().to_string();
+        let recovery_file = format!("{}.crash", file_name);
+        fs::copy("res/test_board.json", &file_name)?;
+        fs::copy("res/test_board.json", &recovery_file)?;
+        let mut app = App::new(file_name.clone());
+
+        app.write();
+        assert!(Path::new(&recovery_file).exists(), "neither recovered nor discarded");
+
+        app.restore_recovery_file(&recovery_file);
+        assert!(
+            Path::new(&recovery_file).exists(),
+            "the restored board is not written yet"
+        );
+        app.write();
+        assert!(!Path::new(&recovery_file).exists());
+
+        fs::copy("res/test_board.json", &recovery_file)?;
+        app.discard_recovery_file(&recovery_file);
+        assert!(!Path::new(&recovery_file).exists());
+
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn log_stale_cards_on_load() -> Result<()> {
+        let settings = Settings {
+            stale_days: HashMap::from([("Doing".to_string(), 5)]),
+            ..Settings::default()
+        };
+        let app = App::with_settings("res/test_board.json".to_string(), settings);
+
+        assert!(app.logger.show().contains("1 stale cards: 'Cook dinner' in Doing"));
+        assert_eq!(1, app.statistics().stale_cards().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_done_cards_on_load() -> Result<()> {
+        let settings = Settings {
+            archive: ArchiveSettings { after_days: Some(0) },
+            ..Settings::default()
+        };
+        let directory = env::temp_dir().join("rustyban-archive-on-load");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let file_name = directory.join("board.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut app = App::with_settings(file_name.clone(), settings);
+
+        assert!(app
+            .logger
+            .show()
+            .contains("Archived 2 cards: 'Eat dinner', 'Wash dishes'"));
+        assert_eq!(2, fs::read_dir(&directory)?.count(), "backed up before");
+        assert!(app.board().column(2).is_empty());
+        assert_eq!(2, app.board().archived_cards().len());
+        assert_eq!(2, app.current.archived_today.1);
+
+        app.undo();
+        assert_eq!(2, app.board().column(2).size());
+        assert!(app.board().archived_cards().is_empty());
+
+        app.open_read_only();
+        app.archive_done_cards();
+        assert_eq!(2, app.board().column(2).size(), "read-only boards are left as they are");
+
+        let app = App::new(file_name);
+        assert_eq!(2, app.board().column(2).size());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn roll_unfinished_cards_over() -> Result<()> {
+        let settings = Settings {
+            rollover: RolloverSettings {
+                column: Some("Doing".to_string()),
+                ..RolloverSettings::default()
+            },
+            ..Settings::default()
+        };
+        let mut app = App::with_settings("res/test_board.json".to_string(), settings);
+
+        assert!(app
+            .logger
+            .show()
+            .contains("Rolled 1 unfinished cards over from Doing to TODO: 'Cook dinner'"));
+        assert!(app.board().column(1).is_empty());
+        assert_eq!("Cook dinner", app.board().card(0, 3).short_description());
+
+        app.select_next_card();
+        app.mark_card_done();
+        app.roll_over_cards(Local::now());
+        assert_eq!(1, app.board().column(1).size(), "moved in after the rollover time");
+
+        app.roll_over_cards(Local::now() + TimeDelta::days(1));
+        assert!(app.board().column(1).is_empty());
+
+        app.undo();
+        assert_eq!(1, app.board().column(1).size());
+        app.open_read_only();
+        app.roll_over_cards(Local::now() + TimeDelta::days(1));
+        assert_eq!(1, app.board().column(1).size(), "read-only boards are left as they are");
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy_through_the_runner() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+        assert_eq!(None, app.take_clipboard());
+
+        app.copy_board_as_markdown();
+        let copied = app.take_clipboard().unwrap();
+        assert!(copied.contains("Buy milk"));
+        assert_eq!(None, app.take_clipboard(), "copied once");
+
+        Ok(())
+    }
+
+    #[test]
+    fn log_links_that_cannot_be_opened() -> Result<()> {
+        let settings = Settings {
+            links: LinkSettings {
+                opener: Some("rustyban-no-such-opener".to_string()),
+            },
+            ..Settings::default()
+        };
+        let mut app = App::with_settings("res/test_board.json".to_string(), settings);
+
+        app.open_url("https://example.com");
+        assert!(app.logger.show().contains("Cannot open https://example.com because"));
+
+        if cfg!(unix) {
+            app.settings.links.opener = Some("true".to_string());
+            app.open_url("https://example.com/?a=1&b=2");
+            assert!(app.logger.show().contains("Opened https://example.com/?a=1&b=2"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn burn_up_in_title() -> Result<()> {
+        let now = Local::now();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Done", |c| c.card_with("Wash dishes", |card| card.completed(now)))
+            .build();
+        let area = Rect::new(0, 0, 100, 20);
+
+        let app = App::from_board(String::new(), board.clone(), Logger::new(), Settings::default());
+        let mut buf = Buffer::empty(area);
+        app.render(area, &mut buf);
+        assert!(!title_line(&buf).contains("this week"), "no target");
+
+        board.set_weekly_target(Some(4));
+        let app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+        let mut buf = Buffer::empty(area);
+        app.render(area, &mut buf);
+        assert!(title_line(&buf).contains("- this week: ██░░░░░░░░ 1/4 done"));
+
+        Ok(())
+    }
+
+    fn title_line(buf: &Buffer) -> String {
+        (0..buf.area.width).map(|x| buf[(x, 0)].symbol()).collect()
+    }
+
+    #[test]
+    fn star_cards() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.toggle_star();
+        assert!(app.logger.show().contains("No card selected"));
+        app.select_next_card();
+        app.select_next_card();
+        app.toggle_star();
+        assert!(app.get_selected_card().unwrap().is_starred());
+        assert!(app.logger.show().contains("Starred 'Buy eggs'"));
+
+        app.select_prev_card();
+        app.toggle_starred_only();
+        assert_eq!("Buy eggs", app.get_selected_card().unwrap().short_description());
+        app.select_next_card();
+        assert_eq!(
+            "Buy eggs",
+            app.get_selected_card().unwrap().short_description(),
+            "the only one shown"
+        );
+
+        app.toggle_star();
+        assert!(app.logger.show().contains("Unstarred 'Buy eggs'"));
+        assert_eq!(None, app.get_selected_card(), "hidden once unstarred");
+        app.undo();
+        assert!(app.get_selected_card().unwrap().is_starred());
+
+        app.toggle_starred_only();
+        app.select_next_card();
+        assert_eq!("Buy bread", app.get_selected_card().unwrap().short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_columns() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Inbox", |c| c.read_only().card("Imported"))
+            .column("TODO", |c| c.card("Task"))
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+
+        app.select_next_card();
+        assert_eq!(None, app.insert_card(InsertPosition::Top), "no card to edit");
+        assert_eq!(None, app.card_to_edit());
+        app.remove_card();
+        app.mark_card_done();
+        assert_eq!(1, app.board().column(0).size());
+        assert_eq!(1, app.board().column(1).size());
+        assert!(app.logger.show().contains("Column Inbox is read-only"));
+
+        app.select_next_column();
+        app.mark_card_undone();
+        assert_eq!(1, app.board().column(0).size(), "nothing moves into a read-only column");
+        app.remove_card();
+        assert!(app.board().column(1).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_sort_of_read_only_column() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Inbox", |c| c.read_only().card("Buy milk").card("Buy eggs"))
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+
+        app.select_next_card();
+        app.cycle_sort_policy();
+        assert!(app.board().column(0).sort_policy().is_manual());
+        assert!(app
+            .logger
+            .last(1)
+            .any(|message| message.ends_with("Column Inbox is read-only")));
+        assert!(!app.logger.show().contains("Column sorted by"));
+        assert!(app.toast.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_deletion_in_read_only_column() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Inbox", |c| c.read_only().card("Buy milk"))
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+
+        app.select_next_card();
+        app.remove_card();
+        assert_eq!(1, app.board().column(0).size());
+        assert!(app.current.deletion_grace_until.is_none());
+        assert!(app.toast.is_none());
+        assert!(!app.logger.show().contains("Card deleted"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_card_to_edit_in_empty_read_only_column() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Inbox", |c| c.read_only())
+            .column("TODO", |c| c.card("Task"))
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+
+        assert_eq!(None, app.insert_card(InsertPosition::Top), "nothing selected yet");
+        app.select_next_card();
+        assert_eq!(None, app.insert_card(InsertPosition::Bottom));
+        assert!(app.board().column(0).is_empty());
+        assert!(app.logger.show().contains("Column Inbox is read-only"));
+
+        app.select_next_column();
+        assert!(app.card_to_edit().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_branch_switch_over_read_only_columns() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Task"))
+            .column("Done", |c| c)
+            .build();
+        let mut settings = Settings::default();
+        settings.history.tree = true;
+        let mut app = App::from_board(String::new(), board, Logger::new(), settings);
+
+        app.select_next_card();
+        app.mark_card_done();
+        app.undo();
+        let options = app.selected_column_options().unwrap();
+        app.update_column_options(ColumnOptions {
+            read_only: true,
+            ..options
+        });
+        assert_eq!(1, app.history_branches().len());
+
+        app.switch_history_branch(0);
+        assert!(app.board().column(0).is_read_only());
+        assert_eq!(1, app.board().column(0).size());
+        assert!(app.logger.show().contains("Column TODO is read-only"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn backup_before_bulk_changes() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-backups");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let file_name = directory.join("board.json").display().to_string();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card_with("Buy milk", |card| card.tag("shop")))
+            .build();
+        let mut app = App::from_board(file_name.clone(), board.clone(), Logger::new(), Settings::default());
+
+        app.rename_tag("shop", "shopping");
+        let backups: Vec<_> = fs::read_dir(&directory)?.collect::<Result<_>>()?;
+        assert_eq!(1, backups.len());
+        let backup_file = backups[0].path().display().to_string();
+        assert!(backup_file.ends_with(".bak"));
+        assert!(app
+            .logger
+            .last(2)
+            .any(|message| message.ends_with(&format!("board backed up to {}", backup_file))));
+        assert_eq!(vec!["shop"], Board::open(&backup_file)?.card(0, 0).tags(), "as it was");
+
+        let mut settings = Settings::default();
+        settings.backup.before_bulk_changes = false;
+        fs::remove_file(&backup_file)?;
+        let mut app = App::from_board(file_name, board, Logger::new(), settings);
+        app.rename_tag("shop", "shopping");
+        assert_eq!(0, fs::read_dir(&directory)?.count(), "turned off");
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn keep_the_last_backups() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-backup-retention");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let file_name = directory.join("board.json").display().to_string();
+        let backups = ["20240601-090000", "20240602-090000", "20240603-090000"]
+            .map(|date| directory.join(format!("board.json.{}.bak", date)));
+        let others = ["board.json.notes.bak", "other.json.20240601-090000.bak"].map(|name| directory.join(name));
+        for path in backups.iter().chain(&others) {
+            fs::write(path, "{}")?;
+        }
+
+        let backup_file = write_backup(&file_name, &Board::new(), 2)?;
+        assert!(Path::new(&backup_file).exists());
+        assert!(!backups[0].exists());
+        assert!(!backups[1].exists());
+        assert!(backups[2].exists());
+        assert!(
+            others.iter().all(|path| path.exists()),
+            "only the backups of the board are removed"
+        );
+
+        write_backup(&file_name, &Board::new(), 0)?;
+        assert!(Path::new(&backup_file).exists(), "the last one is kept");
+        assert!(!backups[2].exists());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn delete_cards_at_once() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-delete-cards");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let file_name = directory.join("board.json").display().to_string();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk").card("Buy eggs").card("Cook dinner"))
+            .column("Done", |c| c.card("Buy bread"))
+            .build();
+        let mut app = App::from_board(file_name, board, Logger::new(), Settings::default());
+
+        app.remove_cards(&[(0, 0), (1, 0), (0, 1)]);
+        assert_eq!(1, app.board().column(0).size());
+        assert_eq!("Cook dinner", app.board().card(0, 0).short_description());
+        assert!(app.board().column(1).is_empty());
+        assert_eq!(1, fs::read_dir(&directory)?.count(), "backed up before");
+
+        app.undo();
+        assert_eq!(3, app.board().column(0).size(), "undone as a whole");
+        assert_eq!("Buy eggs", app.board().card(0, 1).short_description());
+        assert_eq!(1, app.board().column(1).size());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn tags_across_the_board() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Buy milk", |card| card.tag("shop").tag("home"))
+                    .card_with("Buy eggs", |card| card.tag("shopping"))
+            })
+            .column("Done", |c| c.card_with("Buy bread", |card| card.tag("shop")))
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+
+        app.rename_tag("home", "house");
+        app.merge_tags("shop", "shopping");
+        assert_eq!(
+            vec![("house".into(), 1), ("shopping".into(), 3)],
+            app.board().tag_counts()
+        );
+
+        app.remove_tag("shopping");
+        assert_eq!(vec![("house".into(), 1)], app.board().tag_counts());
+
+        app.undo();
+        app.undo();
+        assert_eq!(vec!["shop", "house"], app.board().card(0, 0).tags());
+        assert_eq!(vec!["shop"], app.board().card(1, 0).tags());
+
+        app.remove_tag("unknown");
+        assert!(app.logger.show().contains("No card tagged #unknown"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_column_beyond_max_cards() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk").card("Buy eggs"))
+            .column("Done", |c| c.card("Buy bread"))
+            .build();
+        let mut settings = Settings::default();
+        settings.split.max_cards = Some(2);
+        let mut app = App::from_board(String::new(), board, Logger::new(), settings);
+
+        app.select_next_card();
+        assert_eq!(None, app.column_split());
+        assert!(app.logger.show().contains("TODO has no more than 2 cards"));
+
+        app.select_next_card();
+        app.insert_card(InsertPosition::Next);
+        assert!(app
+            .logger
+            .last(3)
+            .any(|message| message.ends_with("TODO has more than 2 cards, <P> splits it")));
+        assert_eq!(Some(("TODO".into(), 1)), app.column_split());
+
+        app.split_column("TODO (overflow)");
+        let board = app.board();
+        let headers: Vec<&str> = board.columns_iter().map(|column| column.header()).collect();
+        assert_eq!(vec!["TODO", "TODO (overflow)", "Done"], headers);
+        assert_eq!(2, board.column(0).size());
+        assert_eq!(1, board.column(1).size());
+
+        app.undo();
+        assert_eq!(2, app.board().columns_count());
+        assert_eq!(3, app.board().column(0).size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn import_csv_as_one_change() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Done", |c| c)
+            .build();
+        let mut app = App::from_board(String::new(), board, Logger::new(), Settings::default());
+        let rows = parse_csv("Title,Status\nBuy eggs,todo\nBuy bread,Done\nCook,Done\n");
+
+        app.import_csv(&rows, &CsvMapping::guess(&rows[0]), "tasks.csv");
+        assert_eq!(2, app.board().column(0).size());
+        assert_eq!("Buy eggs", app.board().card(0, 1).short_description());
+        assert_eq!(2, app.board().column(1).size());
+        assert!(app.logger.show().contains("3 card(s) imported from tasks.csv"));
+
+        app.undo();
+        assert_eq!(1, app.board().column(0).size());
+        assert!(app.board().column(1).is_empty());
+
+        app.import_csv(&rows[..1], &CsvMapping::default(), "empty.csv");
+        assert!(app.logger.show().contains("No card to import from empty.csv"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_with_remote_file() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-app-sync");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let file_name = directory.join("board.json").display().to_string();
+        let remote_file = directory.join("shared.json").display().to_string();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Done", |c| c)
+            .build();
+        let mut remote = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy tea"))
+            .column("Done", |c| c)
+            .build();
+        remote.update_notes("Shared");
+        remote.to_file(&remote_file)?;
+
+        let mut app = App::from_board(file_name.clone(), board, Logger::new(), Settings::default());
+        assert!(app.plan_sync().is_none());
+        assert!(app.logger.show().contains("No remote to sync with"));
+
+        let mut settings = Settings::default();
+        settings.sync.remote = Some(remote_file.clone());
+        let board = app.board();
+        let mut app = App::from_board(file_name.clone(), board, Logger::new(), settings);
+        app.select_next_card();
+        app.insert_scratch_card();
+        let plan = app.plan_sync().expect("changes on both sides");
+        assert_eq!(1, plan.pulled.len());
+        assert_eq!(1, plan.pushed.len(), "scratch cards are not pushed");
+
+        let mut changed = remote.clone();
+        changed.update_notes("Changed meanwhile");
+        changed.to_file(&remote_file)?;
+        app.apply_sync(&plan);
+        assert!(app.logger.show().contains("changed since the sync was planned"));
+        assert_eq!(2, app.board().column(0).size(), "nothing pulled");
+
+        remote.to_file(&remote_file)?;
+        app.apply_sync(&plan);
+        assert!(app.logger.show().contains("1 change(s) pulled, 1 pushed"));
+        assert_eq!(3, app.board().column(0).size(), "the scratch card is kept");
+        assert_eq!(2, Board::open(&remote_file)?.column(0).size());
+        let base_file = sync_base_file_name(&file_name);
+        assert!(fs::metadata(&base_file).is_err(), "kept once the board file is written");
+        assert!(app.plan_sync().is_none());
+        assert!(app.logger.show().contains("Already in sync"));
+
+        app.write();
+        assert!(fs::metadata(&base_file).is_ok());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn deletion() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+
+        app.select_next_column();
+        app.select_next_column();
+        app.remove_card();
+        app.remove_card();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+/// Who holds the lock on a board file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockOwner {
+    pid: u32,
+    hostname: String,
+}
+
+impl LockOwner {
+    fn current() -> Self {
+        Self {
+            pid: process::id(),
+            hostname: hostname(),
+        }
+    }
+
+    fn parse(content: &str) -> Option<Self> {
+        let (pid, hostname) = content.trim().split_once('@')?;
+        Some(Self {
+            pid: pid.parse().ok()?,
+            hostname: hostname.to_string(),
+        })
+    }
+
+    /// A lock left behind by a process of this host that does not run anymore
+    fn is_dead(&self) -> bool {
+        self.hostname == hostname() && is_running(self.pid) == Some(false)
+    }
+}
+
+impl Display for LockOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.pid, self.hostname)
+    }
+}
+
+#[derive(Debug)]
+pub enum LockError {
+    Locked(LockOwner),
+    Io(io::Error),
+}
+
+impl From<io::Error> for LockError {
+    fn from(e: io::Error) -> Self {
+        LockError::Io(e)
+    }
+}
+
+/// Advisory lock preventing two instances from editing the same board, released when dropped
+///
+/// The lock is a `<board file>.lock` file containing the PID and host name of its owner.
+#[derive(Debug)]
+pub struct BoardLock {
+    path: PathBuf,
+    owner: LockOwner,
+}
+
+impl BoardLock {
+    /// Whether this is the lock of a board file
+    pub fn is_for(&self, file_name: &str) -> bool {
+        self.path == lock_path(file_name)
+    }
+
+    pub fn acquire(file_name: &str) -> Result<Self, LockError> {
+        let path = lock_path(file_name);
+        let owner = LockOwner::current();
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(owner.to_string().as_bytes())?;
+                Ok(Self { path, owner })
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                let content = fs::read_to_string(&path)?;
+                match LockOwner::parse(&content) {
+                    Some(existing) if !existing.is_dead() => Err(LockError::Locked(existing)),
+                    _ => Ok(Self::take_over(file_name)?),
+                }
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Acquires the lock even if another instance holds it
+    pub fn take_over(file_name: &str) -> io::Result<Self> {
+        let path = lock_path(file_name);
+        let owner = LockOwner::current();
+        fs::write(&path, owner.to_string())?;
+
+        Ok(Self { path, owner })
+    }
+}
+
+impl Drop for BoardLock {
+    fn drop(&mut self) {
+        let still_owned = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| LockOwner::parse(&content))
+            .is_some_and(|owner| owner == self.owner);
+
+        if still_owned {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Whether a process runs on this host, none when it cannot be told
+fn is_running(pid: u32) -> Option<bool> {
+    let proc = Path::new("/proc");
+    if proc.join("self").exists() {
+        return Some(proc.join(pid.to_string()).exists());
+    }
+
+    let output = if cfg!(windows) {
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+            .stderr(Stdio::null())
+            .output()
+            .ok()?
+    } else {
+        // ps is in POSIX, unlike /proc, and also sees the processes of other users
+        Command::new("ps")
+            .args(["-p", &pid.to_string(), "-o", "pid="])
+            .stderr(Stdio::null())
+            .output()
+            .ok()?
+    };
+    let listed = String::from_utf8_lossy(&output.stdout)
+        .split(|c: char| !c.is_ascii_digit())
+        .any(|field| field == pid.to_string());
+    Some(listed)
+}
+
+fn lock_path(file_name: &str) -> PathBuf {
+    PathBuf::from(format!("{}.lock", file_name))
+}
+
+fn hostname() -> String {
+    fs::read_to_string("/etc/hostname")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .unwrap_or_else(|| "localhost".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io::Result, path::Path};
+
+    use super::{hostname, is_running, BoardLock, LockError};
+
+    #[test]
+    fn acquire_and_release() -> Result<()> {
+        let file_name = "lock_test_board.json";
+        let lock_file = "lock_test_board.json.lock";
+        let _ = fs::remove_file(lock_file);
+
+        let lock = BoardLock::acquire(file_name).expect("lock should be free");
+        assert!(Path::new(lock_file).exists());
+
+        match BoardLock::acquire(file_name) {
+            Err(LockError::Locked(owner)) => {
+                assert_eq!(format!("{}@{}", std::process::id(), hostname()), owner.to_string())
+            }
+            _ => panic!("lock should be held"),
+        }
+
+        drop(lock);
+        assert!(!Path::new(lock_file).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn take_over_and_stale_locks() -> Result<()> {
+        let file_name = "lock_test_takeover.json";
+        let lock_file = "lock_test_takeover.json.lock";
+
+        fs::write(lock_file, "1234@another-host")?;
+        assert!(matches!(BoardLock::acquire(file_name), Err(LockError::Locked(_))));
+
+        let lock = BoardLock::take_over(file_name)?;
+        assert!(fs::read_to_string(lock_file)?.starts_with(&std::process::id().to_string()));
+        drop(lock);
+        assert!(!Path::new(lock_file).exists());
+
+        fs::write(lock_file, format!("{}@{}", u32::MAX, hostname()))?;
+        let lock = BoardLock::acquire(file_name);
+        assert!(lock.is_ok(), "lock of a dead process is taken over");
+        drop(lock);
+
+        assert_eq!(Some(true), is_running(std::process::id()));
+
+        fs::write(lock_file, "garbage")?;
+        let lock = BoardLock::acquire(file_name);
+        assert!(lock.is_ok(), "unreadable lock is taken over");
+        drop(lock);
+        assert!(!Path::new(lock_file).exists());
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Layout, Rect},
-    style::Stylize,
+    style::{Style, Stylize},
     symbols::border,
-    text::Line,
+    text::{Line, Span},
     widgets::{
         block::{Position, Title},
         Block, Clear, Paragraph, Widget,
@@ -12,14 +15,22 @@ use ratatui::{
 use tui_textarea::Input;
 
 use crate::app::widget_utils::centered_popup_area;
-use crate::board::Card;
-use crate::{app::text_widget::TextWidget, utils::time};
+use crate::board::{Card, CardColor};
+use crate::{
+    app::{
+        text_widget::TextWidget,
+        view::{card_color, CardRenderer, CardView, NormalRenderer, Theme},
+    },
+    utils::{text, time},
+};
 
 #[derive(Debug, Clone)]
 pub struct CardEditor {
     widgets: Vec<TextWidget>,
     selected: usize,
     card: Card,
+    hints: Option<Vec<String>>,
+    title_soft_limit: usize,
 }
 
 impl PartialEq for CardEditor {
@@ -45,25 +56,106 @@ impl CardEditor {
                 Constraint::Length(10),
                 false,
             ),
+            TextWidget::new(
+                "Due date".into(),
+                card.due_date()
+                    .map(|date| date.format(DUE_DATE_FORMAT).to_string())
+                    .unwrap_or_default(),
+                Constraint::Length(3),
+                false,
+            ),
+            TextWidget::new(
+                "Estimate".into(),
+                card.estimate().map(|estimate| estimate.to_string()).unwrap_or_default(),
+                Constraint::Length(3),
+                false,
+            ),
         ];
 
         Self {
             widgets,
             selected: 0,
             card,
+            hints: None,
+            title_soft_limit: 0,
         }
     }
 
+    /// Length of the short description beyond which its counter warns, 0 for no limit
+    pub fn title_soft_limit(mut self, title_soft_limit: usize) -> Self {
+        self.title_soft_limit = title_soft_limit;
+        self
+    }
+
     pub fn input(&mut self, input: Input) {
         self.widgets[self.selected].input(input);
     }
 
+    /// Inserts pasted text in the selected field, only the long description keeps line breaks
+    pub fn paste(&mut self, pasted: &str) {
+        let pasted = text::sanitize_paste(pasted);
+        let pasted = if self.selected != 1 {
+            pasted.lines().collect::<Vec<_>>().join(" ")
+        } else {
+            pasted
+        };
+
+        self.widgets[self.selected].paste(&pasted);
+    }
+
+    /// Replaces `;name` before the cursor with the snippet of that name, returns false when there is none
+    pub fn expand_snippet(&mut self, snippets: &HashMap<String, String>) -> bool {
+        let word = self.widgets[self.selected].word_before_cursor();
+        let Some(snippet) = word.strip_prefix(SNIPPET_PREFIX).and_then(|name| snippets.get(name)) else {
+            return false;
+        };
+        let snippet = if self.selected != 1 {
+            snippet.lines().collect::<Vec<_>>().join(" ")
+        } else {
+            snippet.clone()
+        };
+
+        self.widgets[self.selected].replace_before_cursor(word.chars().count(), &snippet);
+        true
+    }
+
+    pub fn cycle_color(&mut self) {
+        self.card.update_color(CardColor::cycle(self.card.color()));
+    }
+
     pub fn next_field(&mut self) {
         self.widgets[self.selected].select(false);
         self.selected = (self.selected + 1) % self.widgets.len();
         self.widgets[self.selected].select(true);
     }
 
+    /// Labels each link of the long description with a letter, returns false when there is no link
+    pub fn show_link_hints(&mut self) -> bool {
+        let urls = self.urls();
+        self.hints = (!urls.is_empty()).then_some(urls);
+        self.hints.is_some()
+    }
+
+    pub fn hide_hints(&mut self) {
+        self.hints = None;
+    }
+
+    pub fn is_showing_hints(&self) -> bool {
+        self.hints.is_some()
+    }
+
+    /// The link labelled with `hint`
+    pub fn hint_url(&self, hint: char) -> Option<String> {
+        let index = hint_labels().position(|label| label == hint)?;
+        self.hints.as_ref()?.get(index).cloned()
+    }
+
+    fn urls(&self) -> Vec<String> {
+        let description = self.widgets[1].lines().join("\n");
+        text::find_urls(&description).into_iter().map(String::from).collect()
+    }
+
+    /// The card as edited, the due date and estimate stay as they were while their fields are invalid
     pub fn get_card(&self) -> Card {
         let mut card = self.card.clone();
         let short_description = self.widgets[0].lines().join("\n");
@@ -71,17 +163,96 @@ impl CardEditor {
         card.update_short_description(&short_description);
 
         card.update_long_description(&long_description);
+        if let Ok(due_date) = self.due_date() {
+            card.update_due_date(due_date);
+        }
+        if let Ok(estimate) = self.estimate() {
+            card.update_estimate(estimate);
+        }
         card
     }
 
-    fn areas(&self, area: Rect) -> [Rect; 3] {
-        let mut constraints: Vec<Constraint> = self.widgets.iter().map(|widget| widget.constaint()).collect();
-        constraints.push(Constraint::Min(1));
-        Layout::vertical(constraints).areas(area)
+    fn field(&self, index: usize) -> String {
+        self.widgets[index].lines().join(" ").trim().to_string()
+    }
+
+    fn due_date(&self) -> Result<Option<NaiveDate>, String> {
+        match self.field(2).as_str() {
+            "" => Ok(None),
+            date => NaiveDate::parse_from_str(date, DUE_DATE_FORMAT)
+                .map(Some)
+                .map_err(|_| "Not a date like 2024-12-24".to_string()),
+        }
+    }
+
+    fn estimate(&self) -> Result<Option<u32>, String> {
+        match self.field(3).as_str() {
+            "" => Ok(None),
+            estimate => estimate.parse().map(Some).map_err(|_| "Not a whole number".to_string()),
+        }
+    }
+
+    /// What is wrong with the fields, with the index of each field, the card cannot be saved until it is fixed
+    pub fn errors(&self) -> Vec<(usize, String)> {
+        let mut errors = vec![];
+        if self.field(0).is_empty() {
+            errors.push((0, "Cannot be empty".to_string()));
+        }
+        if let Err(e) = self.due_date() {
+            errors.push((2, e));
+        }
+        if let Err(e) = self.estimate() {
+            errors.push((3, e));
+        }
+        errors
+    }
+
+    fn areas(&self, area: Rect) -> [Rect; 6] {
+        let [short_desc_area, long_desc_area, fields_area, date_area, preview_area] = Layout::vertical([
+            self.widgets[0].constaint(),
+            self.widgets[1].constaint(),
+            self.widgets[2].constaint(),
+            Constraint::Length(1),
+            Constraint::Min(PREVIEW_HEIGHT),
+        ])
+        .areas(area);
+        let [due_date_area, estimate_area] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(fields_area);
+        [
+            short_desc_area,
+            long_desc_area,
+            due_date_area,
+            estimate_area,
+            date_area,
+            preview_area,
+        ]
+    }
+
+    /// Characters of the short description, highlighted beyond the soft limit
+    fn title_counter(&self) -> Line<'static> {
+        let length = self.widgets[0].lines().join("\n").chars().count();
+        match self.title_soft_limit {
+            0 => Line::from(format!(" {} chars ", length)),
+            limit if length > limit => Line::from(format!(" ⚠ {}/{} chars ", length, limit)).yellow().bold(),
+            limit => Line::from(format!(" {}/{} chars ", length, limit)),
+        }
+    }
+
+    fn description_counter(&self) -> Line<'static> {
+        let lines = self.widgets[1].lines();
+        let chars: usize = lines.iter().map(|line| line.chars().count()).sum();
+        match lines.len() {
+            1 => Line::from(format!(" 1 line, {} chars ", chars)),
+            count => Line::from(format!(" {} lines, {} chars ", count, chars)),
+        }
     }
 }
 
-const WIDGET_HEIGHT: u16 = 16;
+const SNIPPET_PREFIX: char = ';';
+const PREVIEW_WIDTH: u16 = 30;
+const PREVIEW_HEIGHT: u16 = 6;
+const WIDGET_HEIGHT: u16 = 25;
+const DUE_DATE_FORMAT: &str = "%Y-%m-%d";
 const WIDGET_WIDTH: u16 = 64;
 
 impl Widget for &CardEditor {
@@ -98,14 +269,112 @@ impl Widget for &CardEditor {
         block.render(area, buf);
 
         let areas = self.areas(inner_area);
-        let [short_desc_area, long_desc_area, date_area] = areas;
+        let [short_desc_area, long_desc_area, due_date_area, estimate_area, date_area, preview_area] = areas;
 
         self.widgets[0].render(short_desc_area, buf);
+        render_counter(self.title_counter(), short_desc_area, buf);
         self.widgets[1].render(long_desc_area, buf);
+        render_counter(self.description_counter(), long_desc_area, buf);
+        self.widgets[2].render(due_date_area, buf);
+        self.widgets[3].render(estimate_area, buf);
+        for (index, error) in self.errors() {
+            render_error(&error, areas[index], buf);
+        }
         creation_date_widget(&self.card).render(date_area, buf);
+        render_preview(&self.get_card(), preview_area, buf);
+
+        match &self.hints {
+            Some(urls) => render_hints(urls, long_desc_area, buf),
+            None if !self.urls().is_empty() => links_label().render(long_desc_area, buf),
+            None => {}
+        }
     }
 }
 
+fn hint_labels() -> impl Iterator<Item = char> {
+    'a'..='z'
+}
+
+/// Lists the links of the long description over it, each with the letter opening it
+fn render_hints(urls: &[String], area: Rect, buf: &mut Buffer) {
+    let lines: Vec<Line> = hint_labels()
+        .zip(urls)
+        .map(|(label, url)| Line::from(vec![format!(" {} ", label).bold().yellow(), format!(" {}", url).into()]))
+        .collect();
+    let area = Rect {
+        height: (lines.len() as u16 + 2).min(area.height),
+        ..area
+    };
+    Clear.render(area, buf);
+
+    let block = Block::bordered()
+        .title(Title::from(" Links ".bold()).alignment(Alignment::Center))
+        .title(
+            Title::from(Line::from(vec![
+                " <a-z> ".bold(),
+                "Open -".into(),
+                " <ESC> ".bold(),
+                "Back ".into(),
+            ]))
+            .alignment(Alignment::Center)
+            .position(Position::Bottom),
+        )
+        .on_dark_gray()
+        .border_set(border::ROUNDED);
+    Paragraph::new(lines).block(block).render(area, buf);
+}
+
+/// The card as its column shows it, to see where a long title is cut
+fn render_preview(card: &Card, area: Rect, buf: &mut Buffer) {
+    let [label_area, card_area] =
+        Layout::horizontal([Constraint::Min(1), Constraint::Length(PREVIEW_WIDTH)]).areas(area);
+    let height = NormalRenderer.height(card, PREVIEW_WIDTH).min(card_area.height);
+    let card_area = Rect { height, ..card_area };
+
+    Paragraph::new(Line::from(" Preview: ".bold())).render(label_area, buf);
+    CardView::new(card, &Theme::default()).render(card_area, buf);
+}
+
+/// Counts of a field on the right of its bottom border, leaving its corner
+fn render_counter(counter: Line<'static>, area: Rect, buf: &mut Buffer) {
+    let area = Rect {
+        width: area.width.saturating_sub(1),
+        ..area
+    };
+    Block::new()
+        .title(
+            Title::from(counter)
+                .alignment(Alignment::Right)
+                .position(Position::Bottom),
+        )
+        .render(area, buf);
+}
+
+/// What is wrong with a field on the left of its bottom border, leaving its corner
+fn render_error(error: &str, area: Rect, buf: &mut Buffer) {
+    let area = Rect {
+        x: area.x + 1,
+        width: area.width.saturating_sub(1),
+        ..area
+    };
+    Block::new()
+        .title(
+            Title::from(format!(" ⚠ {} ", error).red().bold())
+                .alignment(Alignment::Left)
+                .position(Position::Bottom),
+        )
+        .render(area, buf);
+}
+
+/// Tells that the links of the long description can be opened, on the right of its border
+fn links_label() -> Block<'static> {
+    Block::new().title(
+        Title::from(Line::from(vec![" <Ctrl-l> ".bold(), "Links ".into()]))
+            .alignment(Alignment::Right)
+            .position(Position::Top),
+    )
+}
+
 fn surrounding_block() -> Block<'static> {
     Block::bordered()
         .title(Title::from(" Edit card ".bold()).alignment(Alignment::Center))
@@ -113,6 +382,8 @@ fn surrounding_block() -> Block<'static> {
             Title::from(Line::from(vec![
                 " <Ctrl-s> ".bold(),
                 "Save -".into(),
+                " <Ctrl-o> ".bold(),
+                "Color -".into(),
                 " <ESC> ".bold(),
                 "Discard changes ".into(),
             ]))
@@ -124,9 +395,194 @@ fn surrounding_block() -> Block<'static> {
 }
 
 fn creation_date_widget(card: &Card) -> Paragraph<'_> {
-    let creation_date_text = Line::from(vec![
+    let formatter = time::formatter();
+    let mut creation_date_text = Line::from(vec![
         " Creation date: ".bold(),
-        time::format(card.creation_date()).into(),
+        formatter.datetime(card.creation_date()).into(),
     ]);
+
+    if card.pomodoros() > 0 {
+        creation_date_text.push_span(" - Pomodoros: ".bold());
+        creation_date_text.push_span(card.pomodoros().to_string());
+    }
+
+    if let (Some(cycle_time), Some(lead_time)) = (card.cycle_time(), card.lead_time()) {
+        creation_date_text.push_span(" - Cycle time: ".bold());
+        creation_date_text.push_span(time::pretty_duration(cycle_time.to_std().unwrap_or_default()));
+        creation_date_text.push_span(" - Lead time: ".bold());
+        creation_date_text.push_span(time::pretty_duration(lead_time.to_std().unwrap_or_default()));
+    }
+
+    if let Some(by) = card.column_entries().last().and_then(|entry| entry.by.as_ref()) {
+        creation_date_text.push_span(" - Moved by: ".bold());
+        creation_date_text.push_span(by.clone());
+    }
+
+    if let Some(color) = card.color() {
+        creation_date_text.push_span(" - Color: ".bold());
+        creation_date_text.push_span(Span::styled(color.name(), Style::new().fg(card_color(color))));
+    }
+
     Paragraph::new(creation_date_text)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Result};
+
+    use chrono::{Local, NaiveDate};
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use crate::board::{Card, CardColor};
+
+    use super::CardEditor;
+
+    #[test]
+    fn paste_in_fields() -> Result<()> {
+        let mut editor = CardEditor::new(Card::new("Title", Local::now()));
+
+        editor.paste(" with\r\npasted \u{1b}[1mtext\u{1b}[0m");
+        editor.next_field();
+        editor.paste("first line\r\nsecond line\n\nlast line");
+
+        let card = editor.get_card();
+        assert_eq!("Title with pasted text", card.short_description());
+        assert_eq!("first line\nsecond line\n\nlast line", card.long_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn link_hints() -> Result<()> {
+        let mut card = Card::new("Title", Local::now());
+        card.update_long_description("See https://example.com/a, then\nhttp://example.com/b.");
+        let mut editor = CardEditor::new(card);
+
+        assert!(editor.show_link_hints());
+        assert_eq!(Some("https://example.com/a".to_string()), editor.hint_url('a'));
+        assert_eq!(Some("http://example.com/b".to_string()), editor.hint_url('b'));
+        assert_eq!(None, editor.hint_url('c'));
+
+        editor.hide_hints();
+        assert!(!editor.is_showing_hints());
+        assert_eq!(None, editor.hint_url('a'));
+
+        let mut editor = CardEditor::new(Card::new("Title", Local::now()));
+        assert!(!editor.show_link_hints());
+        assert!(!editor.is_showing_hints());
+
+        Ok(())
+    }
+
+    #[test]
+    fn expand_snippets() -> Result<()> {
+        let snippets = HashMap::from([
+            ("bug".to_string(), "Steps to reproduce:\n1. ".to_string()),
+            ("ac".to_string(), "Acceptance criteria:".to_string()),
+        ]);
+        let mut editor = CardEditor::new(Card::new("Fix ;ac", Local::now()));
+
+        assert!(editor.expand_snippet(&snippets));
+        editor.next_field();
+        editor.paste("Crashes on start ;nope");
+        assert!(!editor.expand_snippet(&snippets), "unknown snippet");
+        editor.paste("\n;bug");
+        assert!(editor.expand_snippet(&snippets));
+        editor.paste("run it");
+
+        let card = editor.get_card();
+        assert_eq!("Fix Acceptance criteria:", card.short_description());
+        assert_eq!(
+            "Crashes on start ;nope\nSteps to reproduce:\n1. run it",
+            card.long_description()
+        );
+
+        let mut editor = CardEditor::new(Card::new("bug", Local::now()));
+        assert!(!editor.expand_snippet(&snippets), "without the prefix");
+
+        Ok(())
+    }
+
+    #[test]
+    fn counters_and_preview() -> Result<()> {
+        let mut editor = CardEditor::new(Card::new("Buy milk", Local::now())).title_soft_limit(10);
+        editor.next_field();
+        editor.paste("Two\nlines");
+
+        let area = Rect::new(0, 0, 80, 30);
+        let mut buffer = Buffer::empty(area);
+        editor.render(area, &mut buffer);
+        let screen = |buffer: &Buffer| -> Vec<String> {
+            (0..30)
+                .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect())
+                .collect()
+        };
+        let contains = |screen: &[String], text: &str| screen.iter().any(|line| line.contains(text));
+
+        let lines = screen(&buffer);
+        assert!(contains(&lines, " 8/10 chars "));
+        assert!(contains(&lines, " 2 lines, 8 chars "));
+        assert!(contains(&lines, "│Buy milk"), "previewed as a card");
+
+        for _ in 0..3 {
+            editor.next_field();
+        }
+        editor.paste(" and eggs for the whole week");
+        let mut buffer = Buffer::empty(area);
+        editor.render(area, &mut buffer);
+        let lines = screen(&buffer);
+        assert!(contains(&lines, " ⚠ 36/10 chars "));
+        assert!(contains(&lines, "│Buy milk and eggs for the"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn change_color() -> Result<()> {
+        let mut editor = CardEditor::new(Card::new("Title", Local::now()));
+
+        editor.cycle_color();
+        assert_eq!(Some(CardColor::Red), editor.get_card().color());
+        editor.cycle_color();
+        assert_eq!(Some(CardColor::Yellow), editor.get_card().color());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_fields() -> Result<()> {
+        let mut card = Card::new("Title", Local::now());
+        card.update_estimate(Some(2));
+        let mut editor = CardEditor::new(card);
+        assert!(editor.errors().is_empty());
+
+        editor.next_field();
+        editor.next_field();
+        editor.paste("2024-12-24");
+        editor.next_field();
+        editor.paste("x");
+        assert_eq!(vec![(3, "Not a whole number".to_string())], editor.errors());
+        assert_eq!(Some(2), editor.get_card().estimate(), "unchanged while invalid");
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 24), editor.get_card().due_date());
+
+        let area = Rect::new(0, 0, 80, 30);
+        let mut buffer = Buffer::empty(area);
+        editor.render(area, &mut buffer);
+        let screen: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(screen.contains(" ⚠ Not a whole number "));
+
+        let mut editor = CardEditor::new(Card::new(" ", Local::now()));
+        editor.next_field();
+        editor.next_field();
+        editor.paste("24/12/2024");
+        assert_eq!(
+            vec![
+                (0, "Cannot be empty".to_string()),
+                (2, "Not a date like 2024-12-24".to_string())
+            ],
+            editor.errors()
+        );
+
+        Ok(())
+    }
+}
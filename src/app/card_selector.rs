@@ -1,4 +1,11 @@
-use std::{cell::RefCell, cmp::min, rc::Rc};
+use std::{
+    cell::RefCell,
+    cmp::min,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use chrono::{DateTime, Local};
 
 use crate::board::{Board, Card};
 
@@ -7,40 +14,111 @@ pub struct CardSelector {
     selected_column: usize,
     selected_card: usize,
     selection_enabled: bool,
+    /// Cards of the last column completed before this date are hidden, and skipped by the selection
+    hide_done_before: Option<DateTime<Local>>,
+    /// Only starred cards are shown and selected
+    starred_only: bool,
+    /// Sections shown collapsed, the selection goes over each of them as over a single column without cards
+    collapsed_sections: HashSet<String>,
+    /// Card selected last in each column left, selected again when coming back to the column, by header so that it
+    /// follows the column when columns are moved, added or removed
+    column_cursors: HashMap<String, usize>,
+    /// Header of the selected column, which may have been moved since it was selected
+    selected_header: Option<String>,
     board: Rc<RefCell<Board>>,
 }
 
 impl CardSelector {
     pub fn new(board: Rc<RefCell<Board>>) -> Self {
+        let selected_header = board
+            .as_ref()
+            .borrow()
+            .columns_iter()
+            .next()
+            .map(|c| c.header().to_string());
         Self {
             selected_column: 0,
             selected_card: 0,
             selection_enabled: false,
+            hide_done_before: None,
+            starred_only: false,
+            collapsed_sections: HashSet::new(),
+            column_cursors: HashMap::new(),
+            selected_header,
             board,
         }
     }
 
+    /// The selected card, none when the selection is disabled, on a collapsed section, on an empty column or on a
+    /// hidden card
     pub fn get(&self) -> Option<(usize, usize)> {
-        if self.selection_enabled {
+        self.insertion_point().filter(|&(column_index, card_index)| {
+            let board = self.board.as_ref().borrow();
+            !board.column(column_index).is_empty() && !self.is_hidden(&board, column_index, card_index)
+        })
+    }
+
+    /// Where a card goes in the selected column, even when no card shown there is selected
+    pub fn insertion_point(&self) -> Option<(usize, usize)> {
+        if self.selection_enabled && !self.is_collapsed(&self.board.as_ref().borrow(), self.selected_column) {
             Some((self.selected_column, self.selected_card))
         } else {
             None
         }
     }
 
+    /// The selected column, or the one selected last when the selection is disabled
+    pub fn column(&self) -> usize {
+        self.selected_column
+    }
+
+    /// The selected position, or the one selected last when the selection is disabled
+    pub fn position(&self) -> (usize, usize) {
+        (self.selected_column, self.selected_card)
+    }
+
     pub fn set(&mut self, column_index: usize, card_index: usize) {
-        let board = self.board.as_ref().borrow();
-        self.selected_column = min(column_index, board.columns_count() - 1);
-        self.selected_card = if board.column(self.selected_column).is_empty() {
-            0
-        } else {
-            min(card_index, board.column(self.selected_column).size() - 1)
+        let column_index = min(column_index, self.board.as_ref().borrow().columns_count() - 1);
+        let header = self.header(column_index);
+        if header != self.selected_header {
+            self.remember_cursor();
         }
+        self.selected_column = column_index;
+        self.selected_card = self.get_card_index(card_index);
+        self.selected_header = header;
+    }
+
+    /// Hides the cards of the last column completed before a date, the selection moves off them
+    pub fn hide_done_before(&mut self, date: Option<DateTime<Local>>) {
+        self.hide_done_before = date;
+        self.set(self.selected_column, self.selected_card);
+    }
+
+    /// Hides the cards that are not starred, the selection moves off them
+    pub fn starred_only(&mut self, starred_only: bool) {
+        self.starred_only = starred_only;
+        self.set(self.selected_column, self.selected_card);
+    }
+
+    /// Collapses sections, the selection on a column of a collapsed section moves to its first column
+    pub fn collapse_sections(&mut self, sections: HashSet<String>) {
+        self.collapsed_sections = sections;
+        let column_index = self.section_start(self.selected_column);
+        self.set(column_index, self.selected_card);
+    }
+
+    /// Whether the selection is on a collapsed section, which is then highlighted as a whole
+    pub fn is_on_collapsed_section(&self) -> bool {
+        self.selection_enabled && self.is_collapsed(&self.board.as_ref().borrow(), self.selected_column)
     }
 
     pub fn get_selected_card(&self) -> Option<Card> {
         let board = self.board.as_ref().borrow();
-        if self.selection_enabled && !board.column(self.selected_column).is_empty() {
+        if self.selection_enabled
+            && !self.is_collapsed(&board, self.selected_column)
+            && !board.column(self.selected_column).is_empty()
+            && !self.is_hidden(&board, self.selected_column, self.selected_card)
+        {
             Some(board.card(self.selected_column, self.selected_card).clone())
         } else {
             None
@@ -49,15 +127,15 @@ impl CardSelector {
 
     pub fn select_next_column(&mut self) -> (usize, usize) {
         self.select(|this| {
-            this.selected_column = this.next_column_index(this.selected_column);
-            this.selected_card = this.get_card_index(this.selected_card);
+            let column_index = this.next_column_index(this.selected_column);
+            this.enter_column(column_index);
         })
     }
 
     pub fn select_prev_column(&mut self) -> (usize, usize) {
         self.select(|this| {
-            this.selected_column = this.prev_column_index(this.selected_column);
-            this.selected_card = this.get_card_index(this.selected_card);
+            let column_index = this.prev_column_index(this.selected_column);
+            this.enter_column(column_index);
         })
     }
 
@@ -73,6 +151,10 @@ impl CardSelector {
         })
     }
 
+    pub fn enable_selection(&mut self) {
+        self.selection_enabled = true;
+    }
+
     pub fn disable_selection(&mut self) {
         self.selection_enabled = false;
     }
@@ -90,6 +172,64 @@ impl CardSelector {
         (self.selected_column, self.selected_card)
     }
 
+    /// Selects the card selected last in a column, or the card at the same index when the column was never left
+    fn enter_column(&mut self, column_index: usize) {
+        if column_index == self.selected_column {
+            return;
+        }
+
+        self.remember_cursor();
+        self.selected_header = self.header(column_index);
+        let card_index = self
+            .selected_header
+            .as_ref()
+            .and_then(|header| self.column_cursors.get(header))
+            .copied()
+            .unwrap_or(self.selected_card);
+        self.selected_column = column_index;
+        self.selected_card = self.get_card_index(card_index);
+    }
+
+    fn remember_cursor(&mut self) {
+        if let Some(header) = self.selected_header.take() {
+            self.column_cursors.insert(header, self.selected_card);
+        }
+    }
+
+    fn header(&self, column_index: usize) -> Option<String> {
+        let board = self.board.as_ref().borrow();
+        (column_index < board.columns_count()).then(|| board.column(column_index).header().to_string())
+    }
+
+    fn is_hidden(&self, board: &Board, column_index: usize, card_index: usize) -> bool {
+        let card = board.card(column_index, card_index);
+        let is_old_done = column_index + 1 == board.columns_count()
+            && self.hide_done_before.is_some_and(|date| card.completed_before(date));
+        is_old_done || (self.starred_only && !card.is_starred())
+    }
+
+    fn is_collapsed(&self, board: &Board, column_index: usize) -> bool {
+        board
+            .column(column_index)
+            .section()
+            .is_some_and(|section| self.collapsed_sections.contains(section))
+    }
+
+    /// First column of the collapsed section of a column, or the column itself
+    fn section_start(&self, column_index: usize) -> usize {
+        let board = self.board.as_ref().borrow();
+        if !self.is_collapsed(&board, column_index) {
+            return column_index;
+        }
+
+        board
+            .sections()
+            .into_iter()
+            .find(|section| section.contains(column_index))
+            .map_or(column_index, |section| section.columns.start)
+    }
+
+    /// The card at `index` in the selected column, or the closest one shown, looking up first
     fn get_card_index(&self, index: usize) -> usize {
         let board = self.board.as_ref().borrow();
         let column = board.column(self.selected_column);
@@ -98,42 +238,66 @@ impl CardSelector {
             return 0;
         }
 
-        min(index, column.size() - 1)
+        let index = min(index, column.size() - 1);
+        let is_shown = |card_index: &usize| !self.is_hidden(&board, self.selected_column, *card_index);
+        (0..=index)
+            .rev()
+            .find(is_shown)
+            .or_else(|| (index..column.size()).find(is_shown))
+            .unwrap_or(index)
     }
 
     fn next_card_index(&self) -> usize {
-        self.get_card_index(self.selected_card + 1)
+        let board = self.board.as_ref().borrow();
+        (self.selected_card + 1..board.column(self.selected_column).size())
+            .find(|card_index| !self.is_hidden(&board, self.selected_column, *card_index))
+            .unwrap_or(self.selected_card)
     }
 
     fn prev_card_index(&self) -> usize {
-        if self.selected_card == 0 {
-            return 0;
-        }
-
-        self.get_card_index(self.selected_card - 1)
+        let board = self.board.as_ref().borrow();
+        (0..self.selected_card)
+            .rev()
+            .find(|card_index| !self.is_hidden(&board, self.selected_column, *card_index))
+            .unwrap_or(self.selected_card)
     }
 
+    /// The column after the current one, past the other columns of a collapsed section
     fn next_column_index(&self, current_index: usize) -> usize {
         let board = self.board.as_ref().borrow();
-        min(current_index + 1, board.columns_count() - 1)
+        let next = match self.is_collapsed(&board, current_index) {
+            true => board
+                .sections()
+                .into_iter()
+                .find(|section| section.contains(current_index))
+                .map_or(current_index + 1, |section| section.columns.end),
+            false => current_index + 1,
+        };
+
+        match next < board.columns_count() {
+            true => next,
+            false => current_index,
+        }
     }
 
+    /// The column before the current one, the first column of its section when it is collapsed
     fn prev_column_index(&self, current_index: usize) -> usize {
-        let board = self.board.as_ref().borrow();
-
         if current_index == 0 {
             return 0;
         }
 
-        min(current_index - 1, board.columns_count() - 1)
+        let columns_count = self.board.as_ref().borrow().columns_count();
+        self.section_start(min(current_index - 1, columns_count - 1))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::RefCell, io::Result, rc::Rc};
+    use std::{cell::RefCell, collections::HashSet, io::Result, rc::Rc};
+
+    use chrono::{Local, TimeDelta};
 
-    use crate::board::Board;
+    use crate::board::{Board, BoardBuilder};
 
     use super::CardSelector;
 
@@ -167,6 +331,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn remember_the_card_of_each_column() -> Result<()> {
+        let board = create_board("res/test_board.json");
+        let mut selector = CardSelector::new(board);
+
+        selector.select_next_card();
+        selector.select_next_card();
+        assert_eq!((0, 2), selector.select_next_card());
+        assert_eq!((1, 0), selector.select_next_column());
+        assert_eq!((2, 0), selector.select_next_column());
+        assert_eq!((2, 1), selector.select_next_card());
+
+        assert_eq!((1, 0), selector.select_prev_column());
+        assert_eq!((0, 2), selector.select_prev_column(), "back where it was");
+        assert_eq!((0, 1), selector.select_prev_card());
+        assert_eq!((1, 0), selector.select_next_column());
+        assert_eq!((2, 1), selector.select_next_column());
+
+        selector.set(0, 0);
+        assert_eq!((1, 0), selector.select_next_column());
+        assert_eq!((0, 0), selector.select_prev_column());
+
+        Ok(())
+    }
+
+    #[test]
+    fn remember_the_card_of_a_moved_column() -> Result<()> {
+        let board = create_board("res/test_board.json");
+        let mut selector = CardSelector::new(Rc::clone(&board));
+
+        selector.select_next_card();
+        selector.select_next_card();
+        assert_eq!((0, 2), selector.select_next_card());
+        assert_eq!((1, 0), selector.select_next_column());
+
+        let column_index = board.borrow_mut().move_column_left(1);
+        selector.set(column_index, 0);
+        assert_eq!((1, 2), selector.select_next_column(), "TODO is now second");
+        assert_eq!((2, 1), selector.select_next_column());
+
+        board.borrow_mut().remove_column(0);
+        selector.set(1, 0);
+        assert_eq!((0, 2), selector.select_prev_column(), "TODO is first again");
+
+        Ok(())
+    }
+
     #[test]
     fn get_the_card_index() -> Result<()> {
         let board = create_board("res/test_board.json");
@@ -213,7 +424,7 @@ mod tests {
             let (column_index, card_index) = input;
             selector.set(column_index, card_index);
 
-            let output = selector.get().unwrap();
+            let output = selector.insertion_point().unwrap();
             assert_eq!(expected, output);
         }
 
@@ -228,6 +439,94 @@ mod tests {
         selector.select_next_column();
         selector.select_next_column();
         assert_eq!(None, selector.get_selected_card());
+        assert_eq!(None, selector.get());
+        assert_eq!(Some((1, 0)), selector.insertion_point());
+
+        Ok(())
+    }
+
+    #[test]
+    fn skip_hidden_cards() -> Result<()> {
+        let now = Local::now();
+        let long_ago = now - TimeDelta::days(30);
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("First").card("Second").card("Third"))
+            .column("Done", |c| {
+                c.card_with("Done long ago", |card| card.completed(long_ago))
+                    .card_with("Done today", |card| card.completed(now))
+                    .card_with("Also long ago", |card| card.completed(long_ago))
+                    .card("Never completed")
+            })
+            .build();
+        let mut selector = CardSelector::new(Rc::new(RefCell::new(board)));
+        selector.hide_done_before(Some(now - TimeDelta::days(14)));
+
+        selector.select_next_card();
+        assert_eq!((1, 1), selector.select_next_column(), "first card shown");
+        assert_eq!((1, 3), selector.select_next_card());
+        assert_eq!((1, 1), selector.select_prev_card());
+        assert_eq!((1, 1), selector.select_prev_card());
+        assert_eq!((0, 0), selector.select_prev_column(), "where it was");
+
+        selector.set(1, 2);
+        assert_eq!(Some((1, 1)), selector.get(), "moved off a hidden card");
+
+        selector.set(1, 0);
+        selector.hide_done_before(None);
+        assert_eq!((1, 0), selector.select_prev_card(), "shown again");
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_starred_cards() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card("Buy milk").card_with("Buy eggs", |card| card.starred())
+            })
+            .column("Doing", |c| c.card("Cook dinner"))
+            .build();
+        let mut selector = CardSelector::new(Rc::new(RefCell::new(board)));
+        selector.select_next_card();
+        selector.starred_only(true);
+
+        assert_eq!(Some((0, 1)), selector.get(), "moved to a starred card");
+        assert_eq!((0, 1), selector.select_prev_card());
+        selector.select_next_column();
+        assert_eq!(None, selector.get_selected_card(), "no starred card in the column");
+        assert_eq!(None, selector.get());
+        assert_eq!(Some((1, 0)), selector.insertion_point());
+
+        selector.starred_only(false);
+        assert_eq!("Cook dinner", selector.get_selected_card().unwrap().short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn collapsed_sections_selected_as_a_whole() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Backlog", |c| c.section("Later").card("Idea"))
+            .column("Ready", |c| c.section("Later").card("Plan"))
+            .column("Doing", |c| c.card("Work"))
+            .column("Later", |c| c.section("Later"))
+            .build();
+        let mut selector = CardSelector::new(Rc::new(RefCell::new(board)));
+        selector.select_next_card();
+        selector.select_next_column();
+        selector.collapse_sections(HashSet::from(["Later".to_string()]));
+
+        assert_eq!(None, selector.get(), "moved to the collapsed section");
+        assert!(selector.is_on_collapsed_section());
+        assert_eq!(None, selector.get_selected_card());
+        assert_eq!((2, 0), selector.select_next_column(), "past the collapsed section");
+        assert_eq!((3, 0), selector.select_next_column());
+        assert_eq!((2, 0), selector.select_prev_column());
+        assert_eq!((0, 0), selector.select_prev_column(), "first column of the section");
+
+        selector.collapse_sections(HashSet::new());
+        assert_eq!(Some((0, 0)), selector.get());
+        assert_eq!((1, 0), selector.select_next_column());
 
         Ok(())
     }
@@ -0,0 +1,46 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::app::widget_utils::centered_popup_area;
+
+/// Asks what to do with the changes not written of the board in the split, before closing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseSplitPrompt {
+    file_name: String,
+}
+
+impl CloseSplitPrompt {
+    pub fn new(file_name: String) -> Self {
+        Self { file_name }
+    }
+}
+
+impl Widget for &CloseSplitPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(9));
+        Clear.render(area, buf);
+
+        let text = Text::from(vec![
+            Line::from(format!(" {} has changes not written.", self.file_name)),
+            Line::from(""),
+            Line::from(vec![" <w> ".bold(), "Write the board and close the split".into()]),
+            Line::from(vec![" <d> ".bold(), "Discard the changes and close the split".into()]),
+            Line::from(vec![" <Esc> ".bold(), "Keep the split open".into()]),
+        ]);
+
+        let block = Block::bordered()
+            .title(Title::from(" Close split ".bold()).alignment(Alignment::Center))
+            .on_red()
+            .border_set(border::DOUBLE);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
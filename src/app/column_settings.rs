@@ -0,0 +1,351 @@
+use std::str::FromStr;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Stylize,
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Widget,
+    },
+};
+use tui_textarea::{Input, Key};
+
+use crate::{
+    app::{text_widget::TextWidget, widget_utils::centered_popup_area},
+    board::{CardColor, CardTemplate, ColumnOptions, SortPolicy},
+    utils::text,
+};
+
+/// Fields of the popup, in the order <Tab> goes through them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    TitlePrefix,
+    Tags,
+    Estimate,
+    WipLimit,
+    Color,
+    Sort,
+    ReadOnly,
+}
+
+const FIELDS: [Field; 7] = [
+    Field::TitlePrefix,
+    Field::Tags,
+    Field::Estimate,
+    Field::WipLimit,
+    Field::Color,
+    Field::Sort,
+    Field::ReadOnly,
+];
+
+impl Field {
+    /// Index of the text widget of the field, none for the fields chosen among a few values
+    fn widget(&self) -> Option<usize> {
+        match self {
+            Field::TitlePrefix => Some(0),
+            Field::Tags => Some(1),
+            Field::Estimate => Some(2),
+            Field::WipLimit => Some(3),
+            Field::Color | Field::Sort | Field::ReadOnly => None,
+        }
+    }
+}
+
+/// Popup to edit the settings of a column: the template of the cards inserted in it, WIP limit, color, sort policy
+/// and read-only flag
+#[derive(Debug, Clone)]
+pub struct ColumnSettings {
+    header: String,
+    widgets: Vec<TextWidget>,
+    color: Option<CardColor>,
+    sort: SortPolicy,
+    read_only: bool,
+    selected: usize,
+    error: Option<String>,
+}
+
+impl PartialEq for ColumnSettings {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ColumnSettings {}
+
+impl ColumnSettings {
+    pub fn new(header: &str, options: &ColumnOptions) -> Self {
+        let card_template = &options.card_template;
+        let wip_limit = options.wip_limit.map(|limit| limit.to_string());
+        let estimate = card_template.estimate.map(|estimate| estimate.to_string());
+        let widgets = vec![
+            TextWidget::new(
+                "Title prefix".into(),
+                card_template.title_prefix.clone(),
+                Constraint::Length(3),
+                true,
+            ),
+            TextWidget::new(
+                "Tags (space separated)".into(),
+                card_template.tags.join(" "),
+                Constraint::Length(3),
+                false,
+            ),
+            TextWidget::new(
+                "Estimate".into(),
+                estimate.unwrap_or_default(),
+                Constraint::Length(3),
+                false,
+            ),
+            TextWidget::new(
+                "WIP limit".into(),
+                wip_limit.unwrap_or_default(),
+                Constraint::Length(3),
+                false,
+            ),
+        ];
+
+        Self {
+            header: header.into(),
+            widgets,
+            color: options.color,
+            sort: options.sort,
+            read_only: options.read_only,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    fn field(&self) -> Field {
+        FIELDS[self.selected]
+    }
+
+    /// Edits the selected text field, or changes the value of the selected choice with <h/l>, the arrows or space
+    pub fn input(&mut self, input: Input) {
+        self.error = None;
+        match self.field().widget() {
+            Some(index) => self.widgets[index].input(input),
+            None if matches!(
+                input.key,
+                Key::Char('h') | Key::Char('l') | Key::Char(' ') | Key::Left | Key::Right
+            ) =>
+            {
+                self.next_value()
+            }
+            None => {}
+        }
+    }
+
+    fn next_value(&mut self) {
+        match self.field() {
+            Field::Color => self.color = CardColor::cycle(self.color),
+            Field::Sort => self.sort = self.sort.next(),
+            Field::ReadOnly => self.read_only = !self.read_only,
+            _ => {}
+        }
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        let Some(index) = self.field().widget() else {
+            return;
+        };
+        let pasted = text::sanitize_paste(pasted);
+        self.widgets[index].paste(&pasted.lines().collect::<Vec<_>>().join(" "));
+    }
+
+    pub fn next_field(&mut self) {
+        self.select(false);
+        self.selected = (self.selected + 1) % FIELDS.len();
+        self.select(true);
+    }
+
+    fn select(&mut self, selected: bool) {
+        if let Some(index) = self.field().widget() {
+            self.widgets[index].select(selected);
+        }
+    }
+
+    /// The settings as filled in, or an error to show when a field is invalid
+    pub fn get(&self) -> Result<ColumnOptions, String> {
+        let field = |field: Field| {
+            field
+                .widget()
+                .map_or(String::new(), |index| self.widgets[index].lines().join(" "))
+        };
+
+        let wip_limit = parse_number(&field(Field::WipLimit), "WIP limit")?.filter(|limit| *limit > 0);
+        let estimate = parse_number(&field(Field::Estimate), "estimate")?;
+
+        Ok(ColumnOptions {
+            wip_limit,
+            color: self.color,
+            sort: self.sort,
+            card_template: CardTemplate {
+                title_prefix: field(Field::TitlePrefix),
+                tags: field(Field::Tags)
+                    .split_whitespace()
+                    .map(|tag| tag.trim_start_matches('#').to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect(),
+                estimate,
+            },
+            read_only: self.read_only,
+        })
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    fn choice_line(&self, field: Field, label: &str, value: &str) -> Line<'static> {
+        let value = format!(" < {} > ", value);
+        let value = if self.field() == field {
+            value.reversed()
+        } else {
+            value.into()
+        };
+        Line::from(vec![format!(" {:<10}", label).bold(), value])
+    }
+}
+
+/// The number in a field, none when it is empty
+fn parse_number<T: FromStr>(field: &str, name: &str) -> Result<Option<T>, String> {
+    match field.trim() {
+        "" => Ok(None),
+        value => value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{} must be a positive number, not \"{}\"", name, value)),
+    }
+}
+
+impl Widget for &ColumnSettings {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(56), Constraint::Length(18));
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(format!(" {} settings ", self.header).bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(Line::from(vec![
+                    " <Tab> ".bold(),
+                    "Next field -".into(),
+                    " <h/l> ".bold(),
+                    "Change -".into(),
+                    " <CR> ".bold(),
+                    "Save -".into(),
+                    " <ESC> ".bold(),
+                    "Cancel ".into(),
+                ]))
+                .alignment(Alignment::Center)
+                .position(Position::Bottom),
+            )
+            .on_blue()
+            .border_set(border::PLAIN);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let constraints = FIELDS.iter().map(|field| match field.widget() {
+            Some(index) => self.widgets[index].constaint(),
+            None => Constraint::Length(1),
+        });
+        let areas = Layout::vertical(constraints.chain([Constraint::Min(1)])).split(inner_area);
+
+        for (field, area) in FIELDS.iter().zip(areas.iter()) {
+            match field {
+                Field::Color => {
+                    let color = self.color.map_or("none", |color| color.name());
+                    self.choice_line(*field, "Color", color).render(*area, buf);
+                }
+                Field::Sort => self.choice_line(*field, "Sort by", self.sort.name()).render(*area, buf),
+                Field::ReadOnly => {
+                    let read_only = if self.read_only { "yes" } else { "no" };
+                    self.choice_line(*field, "Read-only", read_only).render(*area, buf);
+                }
+                _ => {
+                    if let Some(index) = field.widget() {
+                        self.widgets[index].render(*area, buf);
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = &self.error {
+            Line::from(error.clone().red().bold()).render(areas[FIELDS.len()], buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use tui_textarea::{Input, Key};
+
+    use crate::board::{CardColor, CardTemplate, ColumnOptions, SortPolicy};
+
+    use super::ColumnSettings;
+
+    fn press(settings: &mut ColumnSettings, key: Key) {
+        settings.input(Input {
+            key,
+            ..Input::default()
+        });
+    }
+
+    #[test]
+    fn edit_column_settings() -> Result<()> {
+        let mut settings = ColumnSettings::new("Doing", &ColumnOptions::default());
+        assert_eq!(Ok(ColumnOptions::default()), settings.get());
+
+        settings.paste("[BUG] ");
+        settings.next_field();
+        settings.paste("#bug  urgent");
+        settings.next_field();
+        settings.paste("3");
+        settings.next_field();
+        settings.paste("3");
+        settings.next_field();
+        press(&mut settings, Key::Char('l'));
+        press(&mut settings, Key::Char('l'));
+        settings.next_field();
+        settings.paste("ignored");
+        press(&mut settings, Key::Right);
+        settings.next_field();
+        press(&mut settings, Key::Char(' '));
+
+        let expected = ColumnOptions {
+            wip_limit: Some(3),
+            color: Some(CardColor::Yellow),
+            sort: SortPolicy::DueDate,
+            card_template: CardTemplate {
+                title_prefix: "[BUG] ".into(),
+                tags: vec!["bug".into(), "urgent".into()],
+                estimate: Some(3),
+            },
+            read_only: true,
+        };
+        assert_eq!(Ok(expected.clone()), settings.get());
+
+        let mut settings = ColumnSettings::new("Doing", &expected);
+        settings.next_field();
+        settings.next_field();
+        settings.paste("a few");
+        assert_eq!(
+            Err("estimate must be a positive number, not \"3a few\"".to_string()),
+            settings.get()
+        );
+
+        let mut settings = ColumnSettings::new("Doing", &expected);
+        for _ in 0..3 {
+            settings.next_field();
+        }
+        press(&mut settings, Key::Backspace);
+        settings.paste("0");
+        assert_eq!(Ok(None), settings.get().map(|options| options.wip_limit), "no limit");
+
+        Ok(())
+    }
+}
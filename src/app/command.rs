@@ -0,0 +1,235 @@
+mod archive_cards;
+mod change_priority;
+mod composite;
+mod insert_card;
+mod insert_column;
+mod mark_card;
+mod move_card;
+mod move_cards;
+mod move_column;
+mod remove_card;
+mod rename_column;
+mod roll_over_cards;
+mod sort_column;
+mod update_card;
+mod update_card_template;
+mod update_column;
+mod update_notes;
+
+use std::{fmt::Debug, mem};
+
+pub use archive_cards::ArchiveCardsCommand;
+pub use change_priority::{ChangePriorityCommand, Priority};
+pub use composite::CompositeCommand;
+pub use insert_card::InsertCardCommand;
+pub use insert_column::InsertColumnCommand;
+pub use mark_card::{Mark, MarkCardCommand};
+pub use move_card::MoveCardCommand;
+pub use move_cards::MoveCardsCommand;
+pub use move_column::{ColumnDirection, MoveColumnCommand};
+pub use remove_card::RemoveCardCommand;
+pub use rename_column::RenameColumnCommand;
+pub use roll_over_cards::RollOverCardsCommand;
+pub use sort_column::SortColumnCommand;
+pub use update_card::UpdateCardCommand;
+pub use update_card_template::UpdateCardTemplateCommand;
+pub use update_column::UpdateColumnCommand;
+pub use update_notes::UpdateNotesCommand;
+
+use crate::{
+    app::{activity::Activity, journal::JournalEntry},
+    board::{Board, Card},
+};
+
+/// A reversible modification of the board
+///
+/// Both `execute` and `undo` return the position (column index, card index) the selection should move to.
+pub trait Command: Debug {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize);
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize);
+
+    fn description(&self) -> String;
+
+    /// Approximate memory used by the command, in bytes, to bound the history
+    ///
+    /// Commands keeping copies of cards count their text, which can be large.
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+    }
+
+    /// Columns whose cards or settings the command changes, none for changes to the board as a whole
+    fn columns(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    /// Columns the command would change if it was executed now, before it knows where cards end up
+    fn target_columns(&self, _board: &Board) -> Vec<usize> {
+        self.columns()
+    }
+
+    /// What is needed to execute the command again, written to the journal before it is executed
+    fn journal_entry(&self) -> JournalEntry;
+
+    /// What the command did, as reported in the session summary
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    /// Whether executing the command changed the board, the ones that did not are not kept in the history
+    fn changed(&self) -> bool {
+        true
+    }
+}
+
+/// Approximate memory used by a card, in bytes
+pub fn card_size(card: &Card) -> usize {
+    mem::size_of::<Card>()
+        + card.short_description().len()
+        + card.long_description().len()
+        + card
+            .tags()
+            .iter()
+            .map(|tag| mem::size_of::<String>() + tag.len())
+            .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+    use serde_json::Value;
+
+    use crate::{
+        app::{
+            command::{
+                ChangePriorityCommand, ColumnDirection, Command, InsertCardCommand, InsertColumnCommand, Mark,
+                MarkCardCommand, MoveCardsCommand, MoveColumnCommand, Priority, RemoveCardCommand, RenameColumnCommand,
+                SortColumnCommand, UpdateCardCommand, UpdateCardTemplateCommand, UpdateNotesCommand,
+            },
+            History,
+        },
+        board::{arbitrary_board, Board, Card, CardTemplate, SortPolicy},
+        utils::random::Random,
+    };
+
+    const CASES: u64 = 200;
+    const STEPS: usize = 40;
+
+    /// The whole board as it would be written, to compare boards exactly
+    fn snapshot(board: &Board) -> Value {
+        serde_json::to_value(board).expect("Cannot serialize board")
+    }
+
+    /// A command on a random column and card of the board, as the selection would allow
+    fn arbitrary_command(board: &Board, random: &mut Random) -> Box<dyn Command> {
+        let column_index = random.below(board.columns_count());
+        let size = board.column(column_index).size();
+        let card_index = random.below(size);
+
+        match random.below(15) {
+            0 => Box::new(InsertCardCommand::new(
+                column_index,
+                random.below(size + 1),
+                Card::new("Inserted", Local::now()),
+            )),
+            1 => Box::new(RemoveCardCommand::new(column_index, card_index)),
+            2 => Box::new(ChangePriorityCommand::new(column_index, card_index, Priority::Increase)),
+            3 => Box::new(ChangePriorityCommand::new(column_index, card_index, Priority::Decrease)),
+            4 => Box::new(MarkCardCommand::new(column_index, card_index, Mark::Done)),
+            5 => Box::new(MarkCardCommand::new(column_index, card_index, Mark::Undone)),
+            6 => Box::new(MoveColumnCommand::new(column_index, card_index, ColumnDirection::Left)),
+            7 => Box::new(MoveColumnCommand::new(column_index, card_index, ColumnDirection::Right)),
+            8 => Box::new(RenameColumnCommand::new(column_index, card_index, "Renamed")),
+            9 => {
+                let policies = [
+                    SortPolicy::Manual,
+                    SortPolicy::DueDate,
+                    SortPolicy::CreationDate,
+                    SortPolicy::Name,
+                ];
+                Box::new(SortColumnCommand::new(
+                    column_index,
+                    card_index,
+                    *random.pick(&policies),
+                ))
+            }
+            10 => Box::new(UpdateCardCommand::new(
+                column_index,
+                card_index,
+                Card::new("Updated", Local::now()),
+            )),
+            11 => Box::new(UpdateNotesCommand::new(column_index, card_index, "Updated notes")),
+            12 => Box::new(InsertColumnCommand::new(
+                random.below(board.columns_count() + 1),
+                "Inserted",
+            )),
+            13 => Box::new(MoveCardsCommand::new(
+                column_index,
+                random.below(size + 1),
+                random.below(board.columns_count()),
+            )),
+            _ => {
+                let card_template = CardTemplate {
+                    title_prefix: "Bug: ".into(),
+                    ..CardTemplate::default()
+                };
+                Box::new(UpdateCardTemplateCommand::new(column_index, card_index, card_template))
+            }
+        }
+    }
+
+    #[test]
+    fn undo_restores_and_redo_reproduces_each_command() {
+        for seed in 0..CASES {
+            let mut board = arbitrary_board(seed);
+            let mut random = Random::new(seed);
+
+            for step in 0..STEPS {
+                let mut command = arbitrary_command(&board, &mut random);
+                let before = snapshot(&board);
+                command.execute(&mut board);
+                let after = snapshot(&board);
+
+                command.undo(&mut board);
+                assert_eq!(
+                    before,
+                    snapshot(&board),
+                    "seed {}, step {}: undo {:?}",
+                    seed,
+                    step,
+                    command
+                );
+                command.execute(&mut board);
+                assert_eq!(
+                    after,
+                    snapshot(&board),
+                    "seed {}, step {}: redo {:?}",
+                    seed,
+                    step,
+                    command
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn undo_all_and_redo_all() {
+        for seed in 0..CASES {
+            let mut board = arbitrary_board(seed);
+            let mut random = Random::new(seed);
+            let mut history = History::new().max_commands(STEPS);
+            let initial = snapshot(&board);
+
+            for _ in 0..STEPS {
+                let command = arbitrary_command(&board, &mut random);
+                history.execute(command, &mut board);
+            }
+            let last = snapshot(&board);
+
+            while history.undo(&mut board).is_some() {}
+            assert_eq!(initial, snapshot(&board), "seed {}: undo all", seed);
+            while history.redo(&mut board).is_some() {}
+            assert_eq!(last, snapshot(&board), "seed {}: redo all", seed);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::mem;
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+/// Moves to the archive the cards completed at least some days ago, from the last column
+#[derive(Debug)]
+pub struct ArchiveCardsCommand {
+    after_days: u32,
+    previous: Option<(usize, Vec<Card>, usize)>,
+
+    /// When the cards are archived, redoing it archives the same cards
+    date: DateTime<Local>,
+}
+
+impl ArchiveCardsCommand {
+    pub fn new(after_days: u32, date: DateTime<Local>) -> Self {
+        Self {
+            after_days,
+            previous: None,
+            date,
+        }
+    }
+}
+
+impl Command for ArchiveCardsCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        let Some(column_index) = board.columns_count().checked_sub(1) else {
+            return (0, 0);
+        };
+        let cards = board.column(column_index).cards_iter().cloned().collect();
+        self.previous = Some((column_index, cards, board.archived_cards().len()));
+        board.archive_done_cards(self.after_days, self.date);
+
+        (column_index, 0)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        let Some((column_index, cards, archived)) = self.previous.take() else {
+            return (0, 0);
+        };
+        let policy = board.column(column_index).sort_policy();
+        board.restore_column(column_index, policy, cards);
+        board.unarchive_after(archived);
+
+        (column_index, 0)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self
+                .previous
+                .as_ref()
+                .map_or(0, |(_, cards, _)| cards.iter().map(card_size).sum())
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::Archive {
+            after_days: self.after_days,
+            date: self.date,
+        }
+    }
+
+    fn description(&self) -> String {
+        "Archive cards".into()
+    }
+
+    fn target_columns(&self, board: &Board) -> Vec<usize> {
+        board.columns_count().checked_sub(1).into_iter().collect()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        self.previous
+            .as_ref()
+            .map(|(column_index, _, _)| vec![*column_index])
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::Local;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::ArchiveCardsCommand;
+
+    #[test]
+    fn archive_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let cards = |board: &Board| board.column(2).cards_iter().cloned().collect::<Vec<_>>();
+        let done = cards(&board);
+
+        let mut command = ArchiveCardsCommand::new(0, Local::now());
+        command.execute(&mut board);
+        assert!(board.column(2).is_empty());
+        assert_eq!(done.len(), board.archived_cards().len());
+
+        command.undo(&mut board);
+        assert_eq!(done, cards(&board));
+        assert!(board.archived_cards().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{activity::Activity, command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Increase,
+    Decrease,
+}
+
+#[derive(Debug)]
+pub struct ChangePriorityCommand {
+    column_index: usize,
+    card_index: usize,
+    new_card_index: usize,
+    priority: Priority,
+}
+
+impl ChangePriorityCommand {
+    pub fn new(column_index: usize, card_index: usize, priority: Priority) -> Self {
+        Self {
+            column_index,
+            card_index,
+            new_card_index: card_index,
+            priority,
+        }
+    }
+}
+
+impl Command for ChangePriorityCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        if board.column(self.column_index).is_empty() {
+            return (self.column_index, self.card_index);
+        }
+
+        let (column_index, card_index) = match self.priority {
+            Priority::Increase => board.increase_priority(self.column_index, self.card_index),
+            Priority::Decrease => board.decrease_priority(self.column_index, self.card_index),
+        };
+        self.new_card_index = card_index;
+
+        (column_index, card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if self.new_card_index == self.card_index {
+            return (self.column_index, self.card_index);
+        }
+
+        match self.priority {
+            Priority::Increase => board.decrease_priority(self.column_index, self.new_card_index),
+            Priority::Decrease => board.increase_priority(self.column_index, self.new_card_index),
+        }
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        self.changed().then_some(Activity::PriorityChanged)
+    }
+
+    fn changed(&self) -> bool {
+        self.new_card_index != self.card_index
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::ChangePriority {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            priority: self.priority,
+        }
+    }
+
+    fn description(&self) -> String {
+        match self.priority {
+            Priority::Increase => "Increase priority".into(),
+            Priority::Decrease => "Decrease priority".into(),
+        }
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
@@ -0,0 +1,118 @@
+use std::mem;
+
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+/// Several commands executed and undone as a single change, like a tag renamed on many cards
+#[derive(Debug)]
+pub struct CompositeCommand {
+    description: String,
+    commands: Vec<Box<dyn Command>>,
+}
+
+impl CompositeCommand {
+    pub fn new(description: &str, commands: Vec<Box<dyn Command>>) -> Self {
+        Self {
+            description: description.into(),
+            commands,
+        }
+    }
+}
+
+impl Command for CompositeCommand {
+    /// Executes the commands in order, the selection goes where the last one puts it
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.commands
+            .iter_mut()
+            .map(|command| command.execute(board))
+            .last()
+            .unwrap_or_default()
+    }
+
+    /// Undoes the commands in reverse order, the selection goes where undoing the first one puts it
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        self.commands
+            .iter_mut()
+            .rev()
+            .map(|command| command.undo(board))
+            .last()
+            .unwrap_or_default()
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self) + self.commands.iter().map(|command| command.size()).sum::<usize>()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns: Vec<usize> = self.commands.iter().flat_map(|command| command.columns()).collect();
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+
+    fn target_columns(&self, board: &Board) -> Vec<usize> {
+        let mut columns: Vec<usize> = self
+            .commands
+            .iter()
+            .flat_map(|command| command.target_columns(board))
+            .collect();
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+
+    fn changed(&self) -> bool {
+        self.commands.iter().any(|command| command.changed())
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::Composite {
+            description: self.description.clone(),
+            entries: self.commands.iter().map(|command| command.journal_entry()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{
+        app::command::{Command, RemoveCardCommand, RenameColumnCommand},
+        board::BoardBuilder,
+    };
+
+    use super::CompositeCommand;
+
+    #[test]
+    fn execute_and_undo_as_one() -> Result<()> {
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("First").card("Second"))
+            .column("Done", |c| c)
+            .build();
+        let mut command = CompositeCommand::new(
+            "Clean up",
+            vec![
+                Box::new(RemoveCardCommand::new(0, 0)),
+                Box::new(RenameColumnCommand::new(1, 0, "Finished")),
+            ],
+        );
+
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!("Second", board.card(0, 0).short_description());
+        assert_eq!("Finished", board.column(1).header());
+        assert_eq!(vec![0, 1], command.columns());
+
+        assert_eq!((0, 0), command.undo(&mut board));
+        assert_eq!("First", board.card(0, 0).short_description());
+        assert_eq!("Done", board.column(1).header());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::mem;
+
+use crate::{
+    app::{
+        activity::Activity,
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+#[derive(Debug)]
+pub struct InsertCardCommand {
+    column_index: usize,
+    card_index: usize,
+    card: Card,
+}
+
+impl InsertCardCommand {
+    pub fn new(column_index: usize, card_index: usize, card: Card) -> Self {
+        Self {
+            column_index,
+            card_index,
+            card,
+        }
+    }
+}
+
+impl Command for InsertCardCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.card_index = board
+            .column(self.column_index)
+            .insertion_index(&self.card, self.card_index);
+        board.insert_card(self.column_index, self.card_index, self.card.clone());
+        (self.column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        board.remove_card(self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self) + card_size(&self.card)
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        Some(Activity::CardCreated)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::InsertCard {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            card: self.card.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Insert card".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, NaiveDate};
+
+    use crate::{
+        app::command::Command,
+        board::{BoardBuilder, Card, SortPolicy},
+    };
+
+    use super::InsertCardCommand;
+
+    #[test]
+    fn insert_honors_sort_policy() -> Result<()> {
+        let due = |day| NaiveDate::from_ymd_opt(2024, 12, day).unwrap();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Pay rent", |card| card.due(due(1)))
+                    .card_with("Buy gifts", |card| card.due(due(20)))
+            })
+            .build();
+        board.sort_column(0, SortPolicy::DueDate);
+
+        let mut card = Card::new("Book flights", Local::now());
+        card.update_due_date(Some(due(10)));
+        let mut command = InsertCardCommand::new(0, 0, card);
+
+        assert_eq!((0, 1), command.execute(&mut board));
+        assert_eq!("Book flights", board.card(0, 1).short_description());
+
+        command.undo(&mut board);
+        assert_eq!(2, board.column(0).size());
+        assert_eq!("Buy gifts", board.card(0, 1).short_description());
+
+        Ok(())
+    }
+}
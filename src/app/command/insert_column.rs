@@ -0,0 +1,84 @@
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+/// Inserts an empty column, the columns from its index on move right
+#[derive(Debug)]
+pub struct InsertColumnCommand {
+    column_index: usize,
+    header: String,
+    /// Number of columns once the column is inserted, the columns after it changed their index
+    columns_count: usize,
+}
+
+impl InsertColumnCommand {
+    pub fn new(column_index: usize, header: &str) -> Self {
+        Self {
+            column_index,
+            header: header.into(),
+            columns_count: 0,
+        }
+    }
+}
+
+impl Command for InsertColumnCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        board.insert_column(self.column_index, &self.header);
+        self.columns_count = board.columns_count();
+        (self.column_index, 0)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        board.remove_column(self.column_index);
+        (self.column_index.saturating_sub(1), 0)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::InsertColumn {
+            column_index: self.column_index,
+            header: self.header.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Insert column".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        (self.column_index..self.columns_count).collect()
+    }
+
+    /// The columns moving right keep their cards and settings, read-only ones included
+    fn target_columns(&self, _board: &Board) -> Vec<usize> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::InsertColumnCommand;
+
+    #[test]
+    fn insert_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = InsertColumnCommand::new(1, "Ready");
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!(4, board.columns_count());
+        assert_eq!("Ready", board.column(1).header());
+        assert!(board.column(1).is_empty());
+        assert_eq!("Doing", board.column(2).header());
+        assert_eq!(vec![1, 2, 3], command.columns());
+
+        assert_eq!((0, 0), command.undo(&mut board));
+        assert_eq!(3, board.columns_count());
+        assert_eq!("Doing", board.column(1).header());
+
+        Ok(())
+    }
+}
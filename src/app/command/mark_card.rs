@@ -0,0 +1,156 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{activity::Activity, command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mark {
+    Done,
+    Undone,
+}
+
+#[derive(Debug)]
+pub struct MarkCardCommand {
+    column_index: usize,
+    card_index: usize,
+    new_position: (usize, usize),
+    mark: Mark,
+    completed: bool,
+    previous_completion_date: Option<DateTime<Local>>,
+    previous_rank: f64,
+    /// When the card is moved, redoing the move records the same date
+    date: DateTime<Local>,
+}
+
+impl MarkCardCommand {
+    pub fn new(column_index: usize, card_index: usize, mark: Mark) -> Self {
+        Self {
+            column_index,
+            card_index,
+            new_position: (column_index, card_index),
+            mark,
+            completed: false,
+            previous_completion_date: None,
+            previous_rank: 0.0,
+            date: Local::now(),
+        }
+    }
+
+    /// Moves the card at a given date instead of now, like when replaying the journal
+    pub fn at(mut self, date: DateTime<Local>) -> Self {
+        self.date = date;
+        self
+    }
+}
+
+impl Command for MarkCardCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        if board.column(self.column_index).is_empty() {
+            self.new_position = (self.column_index, self.card_index);
+            return self.new_position;
+        }
+
+        let card = board.card(self.column_index, self.card_index);
+        self.previous_completion_date = card.completion_date();
+        self.previous_rank = card.rank();
+        self.new_position = match self.mark {
+            Mark::Done => board.mark_card_done_at(self.column_index, self.card_index, self.date),
+            Mark::Undone => board.mark_card_undone_at(self.column_index, self.card_index, self.date),
+        };
+        self.completed = matches!(self.mark, Mark::Done)
+            && self.new_position.0 != self.column_index
+            && self.new_position.0 + 1 == board.columns_count();
+
+        self.new_position
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        let (column_index, card_index) = self.new_position;
+        if column_index != self.column_index {
+            let mut card = board.card(column_index, card_index).clone();
+            card.cancel_column_entry();
+            card.update_completion_date(self.previous_completion_date);
+            card.set_rank(self.previous_rank);
+            board.remove_card(column_index, card_index);
+            board.restore_card(self.column_index, self.card_index, card);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        match (self.completed, self.new_position.0 != self.column_index) {
+            (true, _) => Some(Activity::CardCompleted),
+            (false, true) => Some(Activity::CardMoved),
+            (false, false) => None,
+        }
+    }
+
+    fn changed(&self) -> bool {
+        self.new_position.0 != self.column_index
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::MarkCard {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            mark: self.mark,
+            date: self.date,
+        }
+    }
+
+    fn description(&self) -> String {
+        match self.mark {
+            Mark::Done => "Mark card done".into(),
+            Mark::Undone => "Mark card undone".into(),
+        }
+    }
+
+    fn target_columns(&self, board: &Board) -> Vec<usize> {
+        let target = match self.mark {
+            Mark::Done => Some(self.column_index + 1).filter(|index| *index < board.columns_count()),
+            Mark::Undone => self.column_index.checked_sub(1),
+        };
+        [Some(self.column_index), target].into_iter().flatten().collect()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![self.column_index, self.new_position.0];
+        columns.dedup();
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{
+        app::command::{Command, Mark, MarkCardCommand},
+        board::Board,
+    };
+
+    #[test]
+    fn undo_forgets_column_entry() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = MarkCardCommand::new(1, 0, Mark::Done);
+        assert_eq!((2, 0), command.execute(&mut board));
+        let card = board.card(2, 0);
+        assert_eq!("Done!", card.column_entries()[0].header);
+        assert!(card.completion_date().is_some());
+        assert!(card.cycle_time().is_some());
+
+        assert_eq!((1, 0), command.undo(&mut board));
+        let card = board.card(1, 0);
+        assert_eq!("Cook dinner", card.short_description());
+        assert!(card.column_entries().is_empty());
+        assert_eq!(None, card.completion_date());
+
+        Ok(())
+    }
+}
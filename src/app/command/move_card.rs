@@ -0,0 +1,148 @@
+use std::mem;
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    app::{
+        activity::Activity,
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+/// Moves a card to a slot of a column, as chosen in move mode
+#[derive(Debug)]
+pub struct MoveCardCommand {
+    column_index: usize,
+    card_index: usize,
+    new_column_index: usize,
+    /// Where the card goes, counting the cards of the column before the move
+    slot: usize,
+    new_position: (usize, usize),
+    previous: Option<(Vec<Card>, Vec<Card>)>,
+
+    /// When the card is moved, redoing the move records the same date
+    date: DateTime<Local>,
+}
+
+impl MoveCardCommand {
+    pub fn new(column_index: usize, card_index: usize, new_column_index: usize, slot: usize) -> Self {
+        Self {
+            column_index,
+            card_index,
+            new_column_index,
+            slot,
+            new_position: (column_index, card_index),
+            previous: None,
+            date: Local::now(),
+        }
+    }
+
+    /// Moves the card at a given date instead of now, like when replaying the journal
+    pub fn at(mut self, date: DateTime<Local>) -> Self {
+        self.date = date;
+        self
+    }
+}
+
+impl Command for MoveCardCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        let cards = |column_index: usize| board.column(column_index).cards_iter().cloned().collect();
+        self.previous = Some((cards(self.column_index), cards(self.new_column_index)));
+        self.new_position = board.move_card_at(
+            self.column_index,
+            self.card_index,
+            self.new_column_index,
+            self.slot,
+            self.date,
+        );
+
+        self.new_position
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some((cards, new_cards)) = self.previous.take() {
+            let policy = board.column(self.new_column_index).sort_policy();
+            board.restore_column(self.new_column_index, policy, new_cards);
+            let policy = board.column(self.column_index).sort_policy();
+            board.restore_column(self.column_index, policy, cards);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self.previous.as_ref().map_or(0, |(cards, new_cards)| {
+                cards.iter().chain(new_cards.iter()).map(card_size).sum()
+            })
+    }
+
+    fn changed(&self) -> bool {
+        self.new_position != (self.column_index, self.card_index)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::MoveCard {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            new_column_index: self.new_column_index,
+            slot: self.slot,
+            date: self.date,
+        }
+    }
+
+    fn description(&self) -> String {
+        "Move card".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![self.column_index, self.new_column_index];
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        (self.new_column_index != self.column_index).then_some(Activity::CardMoved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::MoveCardCommand;
+
+    #[test]
+    fn move_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let titles = |board: &Board, column_index: usize| -> Vec<String> {
+            board
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().to_string())
+                .collect()
+        };
+        let (todo, doing) = (titles(&board, 0), titles(&board, 1));
+
+        let mut command = MoveCardCommand::new(0, 2, 1, 0);
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!(todo[..2], titles(&board, 0));
+        assert_eq!(vec!["Buy bread", "Cook dinner"], titles(&board, 1));
+
+        assert_eq!((0, 2), command.undo(&mut board));
+        assert_eq!(todo, titles(&board, 0));
+        assert_eq!(doing, titles(&board, 1));
+        assert!(board
+            .card(0, 2)
+            .column_entries()
+            .iter()
+            .all(|entry| entry.header != "Doing"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::mem;
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+/// Moves the cards of a column, from the given one to the last, to the end of another column
+#[derive(Debug)]
+pub struct MoveCardsCommand {
+    column_index: usize,
+    card_index: usize,
+    new_column_index: usize,
+    previous: Option<(Vec<Card>, Vec<Card>)>,
+
+    /// When the cards are moved, redoing the move records the same date
+    date: DateTime<Local>,
+}
+
+impl MoveCardsCommand {
+    pub fn new(column_index: usize, card_index: usize, new_column_index: usize) -> Self {
+        Self {
+            column_index,
+            card_index,
+            new_column_index,
+            previous: None,
+            date: Local::now(),
+        }
+    }
+
+    /// Moves the cards at a given date instead of now, like when replaying the journal
+    pub fn at(mut self, date: DateTime<Local>) -> Self {
+        self.date = date;
+        self
+    }
+}
+
+impl Command for MoveCardsCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        let cards = |column_index: usize| board.column(column_index).cards_iter().cloned().collect();
+        self.previous = Some((cards(self.column_index), cards(self.new_column_index)));
+        board.move_cards_at(self.column_index, self.card_index, self.new_column_index, self.date)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some((cards, new_cards)) = self.previous.take() {
+            let policy = board.column(self.new_column_index).sort_policy();
+            board.restore_column(self.new_column_index, policy, new_cards);
+            let policy = board.column(self.column_index).sort_policy();
+            board.restore_column(self.column_index, policy, cards);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self.previous.as_ref().map_or(0, |(cards, new_cards)| {
+                cards.iter().chain(new_cards.iter()).map(card_size).sum()
+            })
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::MoveCards {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            new_column_index: self.new_column_index,
+            date: self.date,
+        }
+    }
+
+    fn description(&self) -> String {
+        "Move cards".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![self.column_index, self.new_column_index];
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::MoveCardsCommand;
+
+    #[test]
+    fn move_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let titles = |board: &Board, column_index: usize| -> Vec<String> {
+            board
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().to_string())
+                .collect()
+        };
+        let (todo, done) = (titles(&board, 0), titles(&board, 2));
+
+        let mut command = MoveCardsCommand::new(0, 1, 2);
+        assert_eq!((2, 2), command.execute(&mut board));
+        assert_eq!(todo[..1], titles(&board, 0));
+        assert_eq!([&done[..], &todo[1..]].concat(), titles(&board, 2));
+        assert_eq!(
+            Some("Done!"),
+            board
+                .card(2, 3)
+                .column_entries()
+                .last()
+                .map(|entry| entry.header.as_str())
+        );
+
+        assert_eq!((0, 1), command.undo(&mut board));
+        assert_eq!(todo, titles(&board, 0));
+        assert_eq!(done, titles(&board, 2));
+
+        Ok(())
+    }
+}
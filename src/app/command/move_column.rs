@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnDirection {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct MoveColumnCommand {
+    column_index: usize,
+    card_index: usize,
+    new_column_index: usize,
+    direction: ColumnDirection,
+}
+
+impl MoveColumnCommand {
+    pub fn new(column_index: usize, card_index: usize, direction: ColumnDirection) -> Self {
+        Self {
+            column_index,
+            card_index,
+            new_column_index: column_index,
+            direction,
+        }
+    }
+}
+
+impl Command for MoveColumnCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.new_column_index = match self.direction {
+            ColumnDirection::Left => board.move_column_left(self.column_index),
+            ColumnDirection::Right => board.move_column_right(self.column_index),
+        };
+
+        (self.new_column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if self.new_column_index != self.column_index {
+            match self.direction {
+                ColumnDirection::Left => board.move_column_right(self.new_column_index),
+                ColumnDirection::Right => board.move_column_left(self.new_column_index),
+            };
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn changed(&self) -> bool {
+        self.new_column_index != self.column_index
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::MoveColumn {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            direction: self.direction,
+        }
+    }
+
+    fn description(&self) -> String {
+        match self.direction {
+            ColumnDirection::Left => "Move column left".into(),
+            ColumnDirection::Right => "Move column right".into(),
+        }
+    }
+
+    fn target_columns(&self, board: &Board) -> Vec<usize> {
+        let target = match self.direction {
+            ColumnDirection::Left => self.column_index.checked_sub(1),
+            ColumnDirection::Right => Some(self.column_index + 1).filter(|index| *index < board.columns_count()),
+        };
+        [Some(self.column_index), target].into_iter().flatten().collect()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![self.column_index, self.new_column_index];
+        columns.dedup();
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::{ColumnDirection, MoveColumnCommand};
+
+    #[test]
+    fn move_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = MoveColumnCommand::new(1, 0, ColumnDirection::Right);
+        assert_eq!((2, 0), command.execute(&mut board));
+        assert_eq!("Done!", board.column(1).header());
+        assert_eq!("Doing", board.column(2).header());
+
+        assert_eq!((1, 0), command.undo(&mut board));
+        assert_eq!("Doing", board.column(1).header());
+        assert_eq!("Done!", board.column(2).header());
+
+        let mut command = MoveColumnCommand::new(0, 2, ColumnDirection::Left);
+        assert_eq!((0, 2), command.execute(&mut board));
+        assert_eq!((0, 2), command.undo(&mut board));
+        assert_eq!("TODO", board.column(0).header());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use std::mem;
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+#[derive(Debug)]
+pub struct RemoveCardCommand {
+    column_index: usize,
+    card_index: usize,
+    removed: Option<Card>,
+}
+
+impl RemoveCardCommand {
+    pub fn new(column_index: usize, card_index: usize) -> Self {
+        Self {
+            column_index,
+            card_index,
+            removed: None,
+        }
+    }
+}
+
+impl Command for RemoveCardCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        if board.column(self.column_index).is_empty() {
+            self.removed = None;
+            return (self.column_index, self.card_index);
+        }
+
+        self.removed = Some(board.card(self.column_index, self.card_index).clone());
+        board.remove_card(self.column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some(card) = self.removed.take() {
+            board.restore_card(self.column_index, self.card_index, card);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self) + self.removed.as_ref().map_or(0, card_size)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::RemoveCard {
+            column_index: self.column_index,
+            card_index: self.card_index,
+        }
+    }
+
+    fn description(&self) -> String {
+        "Remove card".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+#[derive(Debug)]
+pub struct RenameColumnCommand {
+    column_index: usize,
+    card_index: usize,
+    header: String,
+    previous: Option<String>,
+}
+
+impl RenameColumnCommand {
+    pub fn new(column_index: usize, card_index: usize, header: &str) -> Self {
+        Self {
+            column_index,
+            card_index,
+            header: header.into(),
+            previous: None,
+        }
+    }
+}
+
+impl Command for RenameColumnCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.previous = Some(board.rename_column(self.column_index, &self.header));
+        (self.column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some(previous) = self.previous.take() {
+            board.rename_column(self.column_index, &previous);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::RenameColumn {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            header: self.header.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Rename column".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::RenameColumnCommand;
+
+    #[test]
+    fn rename_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = RenameColumnCommand::new(1, 0, "In progress");
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!("In progress", board.column(1).header());
+
+        assert_eq!((1, 0), command.undo(&mut board));
+        assert_eq!("Doing", board.column(1).header());
+
+        Ok(())
+    }
+}
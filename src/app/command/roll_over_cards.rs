@@ -0,0 +1,127 @@
+use std::mem;
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+/// Moves the cards which entered a column before a date to the end of another column
+#[derive(Debug)]
+pub struct RollOverCardsCommand {
+    column_index: usize,
+    new_column_index: usize,
+    before: DateTime<Local>,
+    previous: Option<(Vec<Card>, Vec<Card>)>,
+
+    /// When the cards are moved, redoing the rollover records the same date
+    date: DateTime<Local>,
+}
+
+impl RollOverCardsCommand {
+    pub fn new(column_index: usize, new_column_index: usize, before: DateTime<Local>, date: DateTime<Local>) -> Self {
+        Self {
+            column_index,
+            new_column_index,
+            before,
+            previous: None,
+            date,
+        }
+    }
+}
+
+impl Command for RollOverCardsCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        let cards = |column_index: usize| board.column(column_index).cards_iter().cloned().collect();
+        self.previous = Some((cards(self.column_index), cards(self.new_column_index)));
+        board.roll_over_cards(self.column_index, self.new_column_index, self.before, self.date);
+
+        (self.column_index, 0)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some((cards, new_cards)) = self.previous.take() {
+            let policy = board.column(self.new_column_index).sort_policy();
+            board.restore_column(self.new_column_index, policy, new_cards);
+            let policy = board.column(self.column_index).sort_policy();
+            board.restore_column(self.column_index, policy, cards);
+        }
+
+        (self.column_index, 0)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self.previous.as_ref().map_or(0, |(cards, new_cards)| {
+                cards.iter().chain(new_cards.iter()).map(card_size).sum()
+            })
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::Rollover {
+            column_index: self.column_index,
+            new_column_index: self.new_column_index,
+            before: self.before,
+            date: self.date,
+        }
+    }
+
+    fn description(&self) -> String {
+        "Roll cards over".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        let mut columns = vec![self.column_index, self.new_column_index];
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, TimeDelta};
+
+    use crate::{
+        app::command::Command,
+        board::{Board, BoardBuilder},
+    };
+
+    use super::RollOverCardsCommand;
+
+    #[test]
+    fn roll_over_and_undo() -> Result<()> {
+        let now = Local::now();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Today", |c| {
+                c.card_with("Buy eggs", |card| card.created(now - TimeDelta::days(1)))
+                    .card_with("Buy bread", |card| card.created(now))
+            })
+            .build();
+        let titles = |board: &Board, column_index: usize| -> Vec<String> {
+            board
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().to_string())
+                .collect()
+        };
+
+        let mut command = RollOverCardsCommand::new(1, 0, now - TimeDelta::hours(1), now);
+        command.execute(&mut board);
+        assert_eq!(["Buy bread"], titles(&board, 1)[..]);
+        assert_eq!(["Buy milk", "Buy eggs"], titles(&board, 0)[..]);
+
+        command.undo(&mut board);
+        assert_eq!(["Buy eggs", "Buy bread"], titles(&board, 1)[..]);
+        assert_eq!(["Buy milk"], titles(&board, 0)[..]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::mem;
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card, SortPolicy},
+};
+
+#[derive(Debug)]
+pub struct SortColumnCommand {
+    column_index: usize,
+    card_index: usize,
+    policy: SortPolicy,
+    previous: Option<(SortPolicy, Vec<Card>)>,
+}
+
+impl SortColumnCommand {
+    pub fn new(column_index: usize, card_index: usize, policy: SortPolicy) -> Self {
+        Self {
+            column_index,
+            card_index,
+            policy,
+            previous: None,
+        }
+    }
+}
+
+impl Command for SortColumnCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.previous = Some(board.sort_column(self.column_index, self.policy));
+        (self.column_index, 0)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some((policy, cards)) = self.previous.take() {
+            board.restore_column(self.column_index, policy, cards);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self
+                .previous
+                .as_ref()
+                .map_or(0, |(_, cards)| cards.iter().map(card_size).sum())
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::SortColumn {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            policy: self.policy,
+        }
+    }
+
+    fn description(&self) -> String {
+        format!("Sort column by {}", self.policy.name())
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{
+        app::command::Command,
+        board::{Board, SortPolicy},
+    };
+
+    use super::SortColumnCommand;
+
+    #[test]
+    fn sort_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = SortColumnCommand::new(0, 1, SortPolicy::Name);
+        assert_eq!((0, 0), command.execute(&mut board));
+        assert_eq!(SortPolicy::Name, board.column(0).sort_policy());
+        assert_eq!("Buy bread", board.card(0, 0).short_description());
+
+        assert_eq!((0, 1), command.undo(&mut board));
+        assert_eq!(SortPolicy::Manual, board.column(0).sort_policy());
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use std::mem;
+
+use crate::{
+    app::{
+        activity::Activity,
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card},
+};
+
+#[derive(Debug)]
+pub struct UpdateCardCommand {
+    column_index: usize,
+    card_index: usize,
+    card: Card,
+    previous: Option<Card>,
+}
+
+impl UpdateCardCommand {
+    pub fn new(column_index: usize, card_index: usize, card: Card) -> Self {
+        Self {
+            column_index,
+            card_index,
+            card,
+            previous: None,
+        }
+    }
+}
+
+impl Command for UpdateCardCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        if !board.column(self.column_index).is_empty() {
+            self.previous = Some(board.card(self.column_index, self.card_index).clone());
+            board.update_card(self.column_index, self.card_index, self.card.clone());
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some(card) = self.previous.take() {
+            board.update_card(self.column_index, self.card_index, card);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self) + card_size(&self.card) + self.previous.as_ref().map_or(0, card_size)
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        Some(Activity::CardEdited)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::UpdateCard {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            card: self.card.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Update card".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::{Board, CardTemplate},
+};
+
+#[derive(Debug)]
+pub struct UpdateCardTemplateCommand {
+    column_index: usize,
+    card_index: usize,
+    card_template: CardTemplate,
+}
+
+impl UpdateCardTemplateCommand {
+    pub fn new(column_index: usize, card_index: usize, card_template: CardTemplate) -> Self {
+        Self {
+            column_index,
+            card_index,
+            card_template,
+        }
+    }
+
+    fn swap(&mut self, board: &mut Board) -> (usize, usize) {
+        let card_template = std::mem::take(&mut self.card_template);
+        self.card_template = board.set_card_template(self.column_index, card_template);
+        (self.column_index, self.card_index)
+    }
+}
+
+impl Command for UpdateCardTemplateCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.swap(board)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        self.swap(board)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::UpdateCardTemplate {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            card_template: self.card_template.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Update column settings".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{
+        app::command::Command,
+        board::{Board, CardTemplate},
+    };
+
+    use super::UpdateCardTemplateCommand;
+
+    #[test]
+    fn update_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let card_template = CardTemplate {
+            title_prefix: "[BUG] ".into(),
+            ..CardTemplate::default()
+        };
+
+        let mut command = UpdateCardTemplateCommand::new(1, 0, card_template.clone());
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!(&card_template, board.column(1).card_template());
+
+        assert_eq!((1, 0), command.undo(&mut board));
+        assert!(board.column(1).card_template().is_empty());
+
+        command.execute(&mut board);
+        assert_eq!(&card_template, board.column(1).card_template());
+
+        Ok(())
+    }
+}
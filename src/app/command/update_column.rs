@@ -0,0 +1,125 @@
+use std::mem;
+
+use crate::{
+    app::{
+        command::{card_size, Command},
+        journal::JournalEntry,
+    },
+    board::{Board, Card, ColumnOptions},
+};
+
+/// Changes the settings of a column at once: WIP limit, color, sort policy, card template and read-only flag
+#[derive(Debug)]
+pub struct UpdateColumnCommand {
+    column_index: usize,
+    card_index: usize,
+    options: ColumnOptions,
+    previous: Option<(ColumnOptions, Option<Vec<Card>>)>,
+}
+
+impl UpdateColumnCommand {
+    pub fn new(column_index: usize, card_index: usize, options: ColumnOptions) -> Self {
+        Self {
+            column_index,
+            card_index,
+            options,
+            previous: None,
+        }
+    }
+}
+
+impl Command for UpdateColumnCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        let previous = board.set_column_options(self.column_index, self.options.clone());
+        let card_index = match previous.1 {
+            Some(_) => 0,
+            None => self.card_index,
+        };
+        self.previous = Some(previous);
+        (self.column_index, card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some((options, cards)) = self.previous.take() {
+            board.restore_column_options(self.column_index, options, cards);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self)
+            + self
+                .previous
+                .as_ref()
+                .and_then(|(_, cards)| cards.as_ref())
+                .map_or(0, |cards| cards.iter().map(card_size).sum())
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::UpdateColumn {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            options: self.options.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Update column settings".into()
+    }
+
+    fn columns(&self) -> Vec<usize> {
+        vec![self.column_index]
+    }
+
+    /// The settings of a read-only column can be changed, starting with the flag itself
+    fn target_columns(&self, _board: &Board) -> Vec<usize> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{
+        app::command::Command,
+        board::{Board, CardColor, ColumnOptions, SortPolicy},
+    };
+
+    use super::UpdateColumnCommand;
+
+    #[test]
+    fn update_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let options = ColumnOptions {
+            wip_limit: Some(2),
+            color: Some(CardColor::Red),
+            sort: SortPolicy::Name,
+            read_only: true,
+            ..ColumnOptions::default()
+        };
+
+        let mut command = UpdateColumnCommand::new(0, 1, options.clone());
+        assert_eq!((0, 0), command.execute(&mut board), "the cards were sorted");
+        assert_eq!(options, board.column(0).options());
+        assert_eq!("Buy bread", board.card(0, 0).short_description());
+
+        assert_eq!((0, 1), command.undo(&mut board));
+        assert_eq!(ColumnOptions::default(), board.column(0).options());
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+
+        let mut command = UpdateColumnCommand::new(
+            0,
+            1,
+            ColumnOptions {
+                color: Some(CardColor::Blue),
+                ..ColumnOptions::default()
+            },
+        );
+        assert_eq!((0, 1), command.execute(&mut board), "same order");
+        assert_eq!(Some(CardColor::Blue), board.column(0).color());
+
+        Ok(())
+    }
+}
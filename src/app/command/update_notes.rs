@@ -0,0 +1,79 @@
+use std::mem;
+
+use crate::{
+    app::{command::Command, journal::JournalEntry},
+    board::Board,
+};
+
+#[derive(Debug)]
+pub struct UpdateNotesCommand {
+    column_index: usize,
+    card_index: usize,
+    notes: String,
+    previous: Option<String>,
+}
+
+impl UpdateNotesCommand {
+    pub fn new(column_index: usize, card_index: usize, notes: &str) -> Self {
+        Self {
+            column_index,
+            card_index,
+            notes: notes.into(),
+            previous: None,
+        }
+    }
+}
+
+impl Command for UpdateNotesCommand {
+    fn execute(&mut self, board: &mut Board) -> (usize, usize) {
+        self.previous = Some(board.update_notes(&self.notes));
+        (self.column_index, self.card_index)
+    }
+
+    fn undo(&mut self, board: &mut Board) -> (usize, usize) {
+        if let Some(previous) = self.previous.take() {
+            board.update_notes(&previous);
+        }
+
+        (self.column_index, self.card_index)
+    }
+
+    fn journal_entry(&self) -> JournalEntry {
+        JournalEntry::UpdateNotes {
+            column_index: self.column_index,
+            card_index: self.card_index,
+            notes: self.notes.clone(),
+        }
+    }
+
+    fn description(&self) -> String {
+        "Update board notes".into()
+    }
+
+    fn size(&self) -> usize {
+        mem::size_of_val(self) + self.notes.len() + self.previous.as_ref().map_or(0, String::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::command::Command, board::Board};
+
+    use super::UpdateNotesCommand;
+
+    #[test]
+    fn update_and_undo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        let mut command = UpdateNotesCommand::new(1, 0, "Sprint goal: eat");
+        assert_eq!((1, 0), command.execute(&mut board));
+        assert_eq!("Sprint goal: eat", board.notes());
+
+        assert_eq!((1, 0), command.undo(&mut board));
+        assert_eq!("", board.notes());
+
+        Ok(())
+    }
+}
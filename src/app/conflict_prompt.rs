@@ -0,0 +1,51 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::app::widget_utils::centered_popup_area;
+
+/// Asks what to do when the board file changed on disk since it was read, before writing over it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictPrompt {
+    file_name: String,
+}
+
+impl ConflictPrompt {
+    pub fn new(file_name: String) -> Self {
+        Self { file_name }
+    }
+}
+
+impl Widget for &ConflictPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(64), Constraint::Length(10));
+        Clear.render(area, buf);
+
+        let text = Text::from(vec![
+            Line::from(format!(" {} changed on disk since it was read.", self.file_name)),
+            Line::from(" Writing the board would lose those changes."),
+            Line::from(""),
+            Line::from(vec![" <o> ".bold(), "Overwrite the file".into()]),
+            Line::from(vec![
+                " <m> ".bold(),
+                "Reload the file and merge the changes made here".into(),
+            ]),
+            Line::from(vec![" <s> ".bold(), "Write the board to another file".into()]),
+            Line::from(vec![" <Esc> ".bold(), "Do not write".into()]),
+        ]);
+
+        let block = Block::bordered()
+            .title(Title::from(" File changed ".bold()).alignment(Alignment::Center))
+            .on_red()
+            .border_set(border::DOUBLE);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
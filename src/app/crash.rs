@@ -0,0 +1,210 @@
+use std::{
+    any::Any,
+    fmt::{self, Display, Formatter},
+    fs,
+    panic::{self, AssertUnwindSafe, Location},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, Once,
+    },
+};
+
+use crossterm::terminal;
+
+const RECOVERY_EXTENSION: &str = "crash";
+
+static PANIC_MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Whether a panic happens while [`catch`] runs, the panic hook leaves other panics to the default one
+static CATCHING: AtomicBool = AtomicBool::new(false);
+
+static HOOK: Once = Once::new();
+
+/// What happened when the application panicked, to be printed once the terminal is restored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashReport {
+    message: String,
+    recovery_files: Vec<Result<String, String>>,
+    log_tail: Vec<String>,
+}
+
+impl CrashReport {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            recovery_files: vec![],
+            log_tail: vec![],
+        }
+    }
+
+    /// Adds the file a board was saved to, or the reason it could not be saved
+    pub fn recovery_file(mut self, recovery_file: Result<String, String>) -> Self {
+        self.recovery_files.push(recovery_file);
+        self
+    }
+
+    pub fn log_tail(mut self, log_tail: Vec<String>) -> Self {
+        self.log_tail = log_tail;
+        self
+    }
+}
+
+impl Display for CrashReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "rustyban crashed: {}", self.message)?;
+        for recovery_file in &self.recovery_files {
+            match recovery_file {
+                Ok(file_name) => writeln!(f, "The board was saved to {}", file_name)?,
+                Err(e) => writeln!(f, "The board could not be saved: {}", e)?,
+            }
+        }
+        if !self.log_tail.is_empty() {
+            writeln!(f, "Last messages:")?;
+            for message in &self.log_tail {
+                writeln!(f, "  {}", message)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Installs the panic hook used while [`catch`] runs, once for the whole process
+///
+/// A panic then restores the terminal before anything else so the user is never left with a raw terminal, and
+/// the message is kept with its location instead of being printed over the user interface.
+pub fn install_panic_hook() {
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING.load(Ordering::SeqCst) {
+            true => on_panic(info.payload(), info.location()),
+            false => default_hook(info),
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error message instead of unwinding further
+///
+/// The message has the location of the panic once [`install_panic_hook`] was called.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    CATCHING.store(true, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.store(false, Ordering::SeqCst);
+
+    result.map_err(|payload| {
+        PANIC_MESSAGE
+            .lock()
+            .ok()
+            .and_then(|mut message| message.take())
+            .unwrap_or_else(|| payload_message(payload.as_ref()))
+    })
+}
+
+/// What the panic hook does while [`catch`] runs
+fn on_panic(payload: &(dyn Any + Send), location: Option<&Location>) {
+    if terminal::is_raw_mode_enabled().unwrap_or(true) {
+        ratatui::restore();
+    }
+    if let Ok(mut panic_message) = PANIC_MESSAGE.lock() {
+        *panic_message = Some(describe_panic(payload, location));
+    }
+}
+
+fn describe_panic(payload: &(dyn Any + Send), location: Option<&Location>) -> String {
+    match location {
+        Some(location) => format!("{} at {}", payload_message(payload), location),
+        None => payload_message(payload),
+    }
+}
+
+/// Where the board of a file is saved after a crash
+pub fn recovery_file_name(file_name: &str) -> String {
+    format!("{}.{}", file_name, RECOVERY_EXTENSION)
+}
+
+/// The recovery file of a board, when it is newer than the board file itself
+pub fn find_recovery_file(file_name: &str) -> Option<String> {
+    let recovery_file = recovery_file_name(file_name);
+    let recovered = fs::metadata(&recovery_file)
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+
+    match fs::metadata(file_name).and_then(|metadata| metadata.modified()) {
+        Ok(saved) if saved > recovered => None,
+        _ => Some(recovery_file),
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown error".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result, panic::Location, thread, time::Duration};
+
+    use super::{catch, describe_panic, find_recovery_file, recovery_file_name, CrashReport};
+
+    #[test]
+    fn panics_are_caught() -> Result<()> {
+        assert_eq!(Ok(42), catch(|| 42));
+
+        let error = catch(|| -> u32 { panic!("Out of {}", "cheese") }).unwrap_err();
+        assert_eq!("Out of cheese", error, "without the hook, the message has no location");
+
+        let message = describe_panic(&"Out of cheese", Some(Location::caller()));
+        assert!(message.starts_with("Out of cheese at src/app/crash.rs:"), "{}", message);
+        assert_eq!("unknown error", describe_panic(&42, None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn readable_report() -> Result<()> {
+        let report = CrashReport::new("Out of cheese".into())
+            .recovery_file(Ok("board.json.crash".into()))
+            .recovery_file(Err("disk full".into()))
+            .log_tail(vec!["[1] Opened board.json".into()]);
+
+        assert_eq!(
+            "rustyban crashed: Out of cheese\n\
+             The board was saved to board.json.crash\n\
+             The board could not be saved: disk full\n\
+             Last messages:\n  [1] Opened board.json\n",
+            report.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn recovery_file_newer_than_board() -> Result<()> {
+        let file_name = env::temp_dir()
+            .join("rustyban-recovery-lookup.json")
+            .display()
+            .to_string();
+        let recovery_file = recovery_file_name(&file_name);
+        assert_eq!(format!("{}.crash", file_name), recovery_file);
+        assert_eq!(None, find_recovery_file(&file_name));
+
+        fs::write(&recovery_file, "{}")?;
+        assert_eq!(
+            Some(recovery_file.clone()),
+            find_recovery_file(&file_name),
+            "no board file"
+        );
+
+        thread::sleep(Duration::from_millis(20));
+        fs::write(&file_name, "{}")?;
+        assert_eq!(None, find_recovery_file(&file_name), "board saved after the crash");
+
+        fs::remove_file(&file_name)?;
+        fs::remove_file(&recovery_file)
+    }
+}
@@ -0,0 +1,296 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Span, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+use tui_textarea::{Input, TextArea};
+
+use crate::{
+    app::widget_utils::centered_popup_area,
+    board::{CsvMapping, CSV_FIELDS},
+    utils::text,
+};
+
+/// Data rows shown in the preview of the cards
+const PREVIEW_ROWS: usize = 3;
+
+#[derive(Debug, Clone)]
+enum Step<'a> {
+    /// Typing the path of the CSV file
+    File { text_area: Box<TextArea<'a>> },
+    /// Choosing the CSV column giving each field of the cards, with a preview of the first ones
+    Mapping {
+        file_name: String,
+        rows: Vec<Vec<String>>,
+        mapping: CsvMapping,
+        selected: usize,
+    },
+}
+
+/// Imports the rows of a CSV file as cards, once told which CSV column is the title, the column, and so on
+#[derive(Debug, Clone)]
+pub struct CsvImportWizard<'a> {
+    step: Step<'a>,
+}
+
+impl PartialEq for CsvImportWizard<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mapping() == other.mapping()
+    }
+}
+
+impl Eq for CsvImportWizard<'_> {}
+
+impl Default for CsvImportWizard<'_> {
+    fn default() -> Self {
+        let mut wizard = Self {
+            step: Step::File {
+                text_area: Box::default(),
+            },
+        };
+        wizard.set_error(None);
+        wizard
+    }
+}
+
+impl<'a> CsvImportWizard<'a> {
+    pub fn push(&mut self, input: Input) {
+        if let Step::File { text_area } = &mut self.step {
+            text_area.input(input);
+        }
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        if let Step::File { text_area } = &mut self.step {
+            let pasted = text::sanitize_paste(pasted);
+            text_area.insert_str(pasted.lines().collect::<String>());
+        }
+    }
+
+    /// The path typed, None when it is empty or once the file is read
+    pub fn file_name(&self) -> Option<String> {
+        match &self.step {
+            Step::File { text_area } => Some(text_area.lines()[0].trim().to_string()).filter(|name| !name.is_empty()),
+            Step::Mapping { .. } => None,
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        if let Step::File { text_area } = &mut self.step {
+            let title = match error {
+                Some(error) => format!(" Import CSV file: {} ", error),
+                None => " Import CSV file: ".to_string(),
+            };
+
+            let block = Block::bordered().title(title).on_blue().border_set(border::DOUBLE);
+            let block = if error.is_some() { block.red() } else { block };
+            text_area.set_block(block);
+        }
+    }
+
+    /// Goes on to the mapping of the rows read from the file, guessed from the header row
+    pub fn load(&mut self, file_name: &str, rows: Vec<Vec<String>>) {
+        let mapping = rows.first().map(|header| CsvMapping::guess(header)).unwrap_or_default();
+        self.step = Step::Mapping {
+            file_name: file_name.into(),
+            rows,
+            mapping,
+            selected: 0,
+        };
+    }
+
+    pub fn is_mapping(&self) -> bool {
+        matches!(self.step, Step::Mapping { .. })
+    }
+
+    pub fn mapping(&self) -> Option<&CsvMapping> {
+        match &self.step {
+            Step::Mapping { mapping, .. } => Some(mapping),
+            Step::File { .. } => None,
+        }
+    }
+
+    /// The file, its rows and how they map to cards, once the file is read
+    pub fn import(&self) -> Option<(&str, &[Vec<String>], &CsvMapping)> {
+        match &self.step {
+            Step::Mapping {
+                file_name,
+                rows,
+                mapping,
+                ..
+            } => Some((file_name, rows, mapping)),
+            Step::File { .. } => None,
+        }
+    }
+
+    pub fn select_next_field(&mut self) {
+        if let Step::Mapping { selected, .. } = &mut self.step {
+            *selected = (*selected + 1).min(CSV_FIELDS.len() - 1);
+        }
+    }
+
+    pub fn select_prev_field(&mut self) {
+        if let Step::Mapping { selected, .. } = &mut self.step {
+            *selected = selected.saturating_sub(1);
+        }
+    }
+
+    /// Maps the selected field to the next CSV column, after the last one an optional field is left unmapped
+    pub fn next_column(&mut self) {
+        self.cycle_column(1);
+    }
+
+    pub fn prev_column(&mut self) {
+        self.cycle_column(-1);
+    }
+
+    fn cycle_column(&mut self, step: isize) {
+        if let Step::Mapping {
+            rows,
+            mapping,
+            selected,
+            ..
+        } = &mut self.step
+        {
+            // Choices are the CSV columns, and no column at all but for the title
+            let columns = rows.first().map_or(0, Vec::len) as isize;
+            let choices = if *selected == 0 { columns } else { columns + 1 };
+            if choices == 0 {
+                return;
+            }
+            let current = mapping.get(*selected).map_or(columns, |column| column as isize);
+            let next = (current + step).rem_euclid(choices);
+            mapping.set(*selected, (next < columns).then_some(next as usize));
+        }
+    }
+}
+
+impl Widget for &CsvImportWizard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (file_name, rows, mapping, selected) = match &self.step {
+            Step::File { text_area } => {
+                let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(3));
+                Clear.render(area, buf);
+                text_area.render(area, buf);
+                return;
+            }
+            Step::Mapping {
+                file_name,
+                rows,
+                mapping,
+                selected,
+            } => (file_name, rows, mapping, *selected),
+        };
+
+        let header = rows.first().cloned().unwrap_or_default();
+        let column_name = |column: Option<usize>| -> Span<'static> {
+            match column.and_then(|column| header.get(column)) {
+                Some(name) => name.clone().into(),
+                None => "not mapped".italic().dim(),
+            }
+        };
+
+        let mut lines: Vec<Line> = CSV_FIELDS
+            .iter()
+            .enumerate()
+            .map(|(field, name)| {
+                let line = Line::from(vec![format!(" {:12}", name).bold(), column_name(mapping.get(field))]);
+                if field == selected {
+                    line.reversed()
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        lines.push(Line::default());
+        lines.push(Line::from(" Preview".bold()));
+        let cell = |row: &Vec<String>, column: Option<usize>| {
+            column
+                .and_then(|column| row.get(column))
+                .map(|field| field.trim().to_string())
+                .unwrap_or_default()
+        };
+        for row in rows.iter().skip(1).take(PREVIEW_ROWS) {
+            let mut spans = vec![format!(" {}", cell(row, Some(mapping.title))).into()];
+            let column = cell(row, mapping.column);
+            if !column.is_empty() {
+                spans.push(format!("  → {}", column).cyan());
+            }
+            let due_date = cell(row, mapping.due_date);
+            if !due_date.is_empty() {
+                spans.push(format!("  due {}", due_date).yellow());
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let height = lines.len() as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(72), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let title = format!(" Import {} row(s) from {} ", rows.len().saturating_sub(1), file_name);
+        let block = Block::bordered()
+            .title(Title::from(title.bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Field  <h/l> CSV column  <Enter> Import  <Esc> Cancel ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use crate::board::{parse_csv, CsvMapping};
+
+    use super::CsvImportWizard;
+
+    #[test]
+    fn map_csv_columns() -> io::Result<()> {
+        let mut wizard = CsvImportWizard::default();
+        assert_eq!(None, wizard.file_name());
+        for c in "tasks.csv".chars() {
+            wizard.push(Input::from(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+        }
+        assert_eq!(Some("tasks.csv".into()), wizard.file_name());
+
+        wizard.load("tasks.csv", parse_csv("Title,Status\nBuy milk,Doing\n"));
+        assert!(wizard.is_mapping());
+        assert_eq!(
+            Some(&CsvMapping {
+                title: 0,
+                column: Some(1),
+                ..CsvMapping::default()
+            }),
+            wizard.mapping()
+        );
+
+        wizard.next_column();
+        assert_eq!(1, wizard.mapping().unwrap().title);
+        wizard.next_column();
+        assert_eq!(0, wizard.mapping().unwrap().title, "the title is always mapped");
+
+        wizard.select_next_field();
+        wizard.prev_column();
+        assert_eq!(Some(1), wizard.mapping().unwrap().description);
+        wizard.next_column();
+        assert_eq!(None, wizard.mapping().unwrap().description);
+        wizard.next_column();
+        assert_eq!(Some(0), wizard.mapping().unwrap().description);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,29 @@
+pub mod column_settings;
+pub mod conflict;
+pub mod csv_import;
 pub mod edit;
+pub mod export;
+pub mod feed;
+pub mod goto;
+pub mod health;
+pub mod help;
+pub mod history;
+pub mod idle;
+pub mod locked;
+pub mod move_card;
 pub mod normal;
+pub mod notes;
+pub mod quit;
+pub mod recovery;
+pub mod rename;
 pub mod save;
+pub mod search;
+pub mod search_picker;
+pub mod settings;
+pub mod split_column;
+pub mod standup;
+pub mod startup;
+pub mod sync;
+pub mod tags;
+pub mod title;
+pub mod window;
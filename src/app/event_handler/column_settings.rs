@@ -0,0 +1,28 @@
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, column_settings::ColumnSettings, App, AppOperations};
+
+pub fn handler<'a>(mut settings: ColumnSettings, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => match settings.get() {
+            Ok(options) => {
+                app.update_column_options(options);
+                State::Normal
+            }
+            Err(e) => {
+                settings.set_error(e);
+                State::ColumnSettings { settings }
+            }
+        },
+        Input { key: Key::Tab, .. } => {
+            settings.next_field();
+            State::ColumnSettings { settings }
+        }
+        input => {
+            settings.input(input);
+            State::ColumnSettings { settings }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, conflict_prompt::ConflictPrompt, save_to_file::Save, App};
+
+pub fn handler<'a>(prompt: ConflictPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('o') => {
+            app.overwrite();
+            State::Normal
+        }
+        KeyCode::Char('m') => {
+            app.merge_from_disk();
+            State::Normal
+        }
+        KeyCode::Char('s') => State::Save { save: Save::new() },
+        KeyCode::Esc => State::Normal,
+        _ => State::SaveConflict { prompt },
+    }
+}
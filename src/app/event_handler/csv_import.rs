@@ -0,0 +1,50 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
+
+use crate::{
+    app::{app_state::State, csv_import::CsvImportWizard, App},
+    board,
+};
+
+pub fn handler<'a>(mut wizard: CsvImportWizard<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if wizard.is_mapping() {
+        return mapping_handler(wizard, app, key_event);
+    }
+
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => return State::Normal,
+        Input { key: Key::Enter, .. } => match wizard.file_name() {
+            Some(file_name) => match board::read_csv(&file_name) {
+                Ok(rows) if rows.len() > 1 => wizard.load(&file_name, rows),
+                Ok(_) => wizard.set_error(Some("no row after the header")),
+                Err(e) => wizard.set_error(Some(&e.to_string())),
+            },
+            None => wizard.set_error(Some("file name cannot be empty")),
+        },
+        input => {
+            wizard.push(input);
+            wizard.set_error(None);
+        }
+    }
+
+    State::ImportCsv { wizard }
+}
+
+fn mapping_handler<'a>(mut wizard: CsvImportWizard<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Esc => return State::Normal,
+        KeyCode::Enter => {
+            if let Some((file_name, rows, mapping)) = wizard.import() {
+                app.import_csv(rows, mapping, file_name);
+            }
+            return State::Normal;
+        }
+        KeyCode::Char('j') | KeyCode::Down => wizard.select_next_field(),
+        KeyCode::Char('k') | KeyCode::Up => wizard.select_prev_field(),
+        KeyCode::Char('l') | KeyCode::Right => wizard.next_column(),
+        KeyCode::Char('h') | KeyCode::Left => wizard.prev_column(),
+        _ => {}
+    }
+
+    State::ImportCsv { wizard }
+}
@@ -1,9 +1,19 @@
 use crossterm::event::KeyEvent;
 use tui_textarea::{Input, Key};
 
-use crate::app::{app::App, app_state::State, card_editor::CardEditor};
+use crate::app::{app_state::State, application::App, card_editor::CardEditor, AppOperations};
 
 pub fn handler<'a>(mut editor: CardEditor, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if editor.is_showing_hints() {
+        if let Input { key: Key::Char(c), .. } = key_event.into() {
+            if let Some(url) = editor.hint_url(c) {
+                app.open_url(&url);
+            }
+        }
+        editor.hide_hints();
+        return State::Edit { editor };
+    }
+
     match key_event.into() {
         Input { key: Key::Esc, .. } => State::Normal,
         Input {
@@ -11,10 +21,42 @@ pub fn handler<'a>(mut editor: CardEditor, app: &mut App, key_event: KeyEvent) -
             ctrl: true,
             ..
         } => {
+            if !editor.errors().is_empty() {
+                app.log("The card cannot be saved until the fields marked with ⚠ are fixed".to_string());
+                return State::Edit { editor };
+            }
             let card = editor.get_card();
             app.update_card(card);
             State::Normal
         }
+        Input {
+            key: Key::Char('o'),
+            ctrl: true,
+            ..
+        } => {
+            editor.cycle_color();
+            State::Edit { editor }
+        }
+        Input {
+            key: Key::Char('l'),
+            ctrl: true,
+            ..
+        } => {
+            if !editor.show_link_hints() {
+                app.log("No link in the description".to_string());
+            }
+            State::Edit { editor }
+        }
+        Input {
+            key: Key::Char('t'),
+            ctrl: true,
+            ..
+        } => {
+            if !editor.expand_snippet(app.snippets()) {
+                app.log("No snippet before the cursor, snippets are set in the [snippets] settings".to_string());
+            }
+            State::Edit { editor }
+        }
         Input { key: Key::Tab, .. } => {
             editor.next_field();
             State::Edit { editor }
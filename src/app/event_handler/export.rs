@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, export_dialog::ExportDialog, App};
+
+pub fn handler<'a>(mut dialog: ExportDialog<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Tab, .. } => {
+            dialog.next_scope();
+            State::Export { dialog }
+        }
+        Input { key: Key::Enter, .. } => match (dialog.file_name(), dialog.scope()) {
+            (file_name, _) if Path::new(&file_name).exists() && !dialog.overwrites() => {
+                dialog.ask_overwrite();
+                State::Export { dialog }
+            }
+            (file_name, Some(scope)) if !file_name.is_empty() => {
+                app.export(&file_name, scope);
+                State::Normal
+            }
+            _ => State::Export { dialog },
+        },
+        input => {
+            dialog.push(input);
+            State::Export { dialog }
+        }
+    }
+}
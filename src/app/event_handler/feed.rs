@@ -0,0 +1,26 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, App};
+
+pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => app.feed_mut().select_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.feed_mut().select_prev(),
+        KeyCode::Enter => {
+            app.select_feed_card();
+            app.leave_feed();
+            return State::Normal;
+        }
+        KeyCode::Char('F') | KeyCode::Char('q') => {
+            app.hide_feed();
+            return State::Normal;
+        }
+        KeyCode::Esc => {
+            app.leave_feed();
+            return State::Normal;
+        }
+        _ => {}
+    }
+
+    State::Feed
+}
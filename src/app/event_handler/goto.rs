@@ -0,0 +1,37 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, App};
+
+/// Handles the key following `<g>`
+pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('g') => app.select_top_card(),
+        KeyCode::Char(c @ '1'..='9') => app.select_column(c as usize - '1' as usize),
+        _ => {}
+    }
+
+    State::Normal
+}
+
+/// Handles the digits of a card number following `<'>`, the part of the number typed so far is given
+///
+/// The selection moves as soon as no other digit can make a card number of the selected column, `<CR>` moves it
+/// before that.
+pub fn number_handler<'a>(number: usize, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char(c @ '0'..='9') => {
+            let number = number * 10 + (c as usize - '0' as usize);
+            if number * 10 > app.selected_column_size() {
+                app.select_card_number(number);
+                return State::Normal;
+            }
+            State::GotoNumber { number }
+        }
+        KeyCode::Enter if number > 0 => {
+            app.select_card_number(number);
+            State::Normal
+        }
+        KeyCode::Backspace => State::GotoNumber { number: number / 10 },
+        _ => State::Normal,
+    }
+}
@@ -0,0 +1,21 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, health_popup::HealthPopup, App};
+
+pub fn handler<'a>(mut popup: HealthPopup, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => popup.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => popup.select_prev(),
+        KeyCode::Enter => {
+            if let Some(finding) = popup.selected() {
+                let (column_index, card_index) = finding.position();
+                app.select_card(column_index, card_index);
+            }
+            return State::Normal;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => return State::Normal,
+        _ => {}
+    }
+
+    State::Health { popup }
+}
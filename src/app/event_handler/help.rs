@@ -0,0 +1,33 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, help::Help, help_search::HelpSearch};
+
+/// Handles the keys of the help, `j/k` scroll, `/` searches the keys by action and any other key closes it
+pub fn handler<'a>(mut help: Help, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => help.scroll_by(1),
+        KeyCode::Char('k') | KeyCode::Up => help.scroll_by(-1),
+        KeyCode::PageDown => help.scroll_by(10),
+        KeyCode::PageUp => help.scroll_by(-10),
+        KeyCode::Char('/') => {
+            return State::HelpSearch {
+                search: HelpSearch::default(),
+            }
+        }
+        _ => return State::Normal,
+    }
+
+    State::Help { help }
+}
+
+pub fn search_handler<'a>(mut search: HelpSearch<'a>, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Help { help: Help::default() },
+        Input { key: Key::Enter, .. } => State::Normal,
+        input => {
+            search.push(input);
+            State::HelpSearch { search }
+        }
+    }
+}
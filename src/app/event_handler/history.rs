@@ -0,0 +1,18 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, history_browser::HistoryBrowser, App};
+
+pub fn handler<'a>(mut browser: HistoryBrowser, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => browser.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => browser.select_prev(),
+        KeyCode::Enter => {
+            app.switch_history_branch(browser.selected());
+            return State::Normal;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => return State::Normal,
+        _ => {}
+    }
+
+    State::History { browser }
+}
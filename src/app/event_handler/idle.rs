@@ -0,0 +1,17 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, idle_prompt::IdlePrompt, App};
+
+pub fn handler<'a>(prompt: IdlePrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('k') => {
+            app.resolve_idle(true);
+            State::Normal
+        }
+        KeyCode::Char('d') => {
+            app.resolve_idle(false);
+            State::Normal
+        }
+        _ => State::Idle { prompt },
+    }
+}
@@ -0,0 +1,18 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, lock_prompt::LockPrompt, App};
+
+pub fn handler<'a>(prompt: LockPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('r') => {
+            app.open_read_only();
+            State::Normal
+        }
+        KeyCode::Char('t') => {
+            app.take_over_lock();
+            State::Normal
+        }
+        KeyCode::Char('q') | KeyCode::Esc => State::Quit,
+        _ => State::Locked { prompt },
+    }
+}
@@ -0,0 +1,23 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, App};
+
+pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('h') | KeyCode::Left => app.move_target_by(-1, 0),
+        KeyCode::Char('l') | KeyCode::Right => app.move_target_by(1, 0),
+        KeyCode::Char('j') | KeyCode::Down => app.move_target_by(0, 1),
+        KeyCode::Char('k') | KeyCode::Up => app.move_target_by(0, -1),
+        KeyCode::Enter => {
+            app.finish_move();
+            return State::Normal;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.cancel_move();
+            return State::Normal;
+        }
+        _ => {}
+    }
+
+    State::MoveCard
+}
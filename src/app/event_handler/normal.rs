@@ -1,47 +1,248 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 use crate::app::{
-    app::{App, InsertPosition},
     app_state::State,
+    application::App,
     card_editor::CardEditor,
+    column_settings::ColumnSettings,
+    conflict_prompt::ConflictPrompt,
+    csv_import::CsvImportWizard,
+    export_dialog::ExportDialog,
+    help::Help,
+    history_browser::HistoryBrowser,
+    inline_title::InlineTitle,
+    keymap::{self, Action},
+    notes_panel::NotesPanel,
+    rename_column::RenameColumn,
     save_to_file::Save,
+    search_panel::SearchPanel,
+    search_picker::SearchPicker,
+    settings_editor::SettingsEditor,
+    split_column::SplitColumn,
+    sync_prompt::SyncPrompt,
+    tag_manager::TagManager,
+    AppOperations, InsertPosition,
 };
 
 pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
-    match key_event.code {
+    match keymap::action(&key_event) {
+        Some(action) => perform(app, action),
+        None => State::Normal,
+    }
+}
+
+fn perform<'a>(app: &mut App, action: Action) -> State<'a> {
+    match action {
         // Card navigation
-        KeyCode::Char('h') | KeyCode::Left => navigate(app, Navigation::PrevColumn),
-        KeyCode::Char('j') | KeyCode::Down => navigate(app, Navigation::NextCard),
-        KeyCode::Char('k') | KeyCode::Up => navigate(app, Navigation::PrevCard),
-        KeyCode::Char('l') | KeyCode::Right => navigate(app, Navigation::NextColumn),
+        Action::SelectPrevColumn => navigate(app, Navigation::PrevColumn),
+        Action::SelectNextCard => navigate(app, Navigation::NextCard),
+        Action::SelectPrevCard => navigate(app, Navigation::PrevCard),
+        Action::SelectNextColumn => navigate(app, Navigation::NextColumn),
+        Action::Goto => State::Goto,
+        Action::GotoNumber => State::GotoNumber { number: 0 },
+        Action::SelectBottomCard => navigate(app, Navigation::BottomCard),
+        Action::SelectPrevColumnTop => navigate(app, Navigation::PrevColumnTop),
+        Action::SelectNextColumnTop => navigate(app, Navigation::NextColumnTop),
 
         // Card marking
-        KeyCode::Char('H') => card_marking(app, Operation::MarkUndone),
-        KeyCode::Char('J') => card_marking(app, Operation::DecreasePriority),
-        KeyCode::Char('K') => card_marking(app, Operation::IncreasePriority),
-        KeyCode::Char('L') => card_marking(app, Operation::MarkDone),
+        Action::MarkUndone => card_marking(app, Operation::MarkUndone),
+        Action::DecreasePriority => card_marking(app, Operation::DecreasePriority),
+        Action::IncreasePriority => card_marking(app, Operation::IncreasePriority),
+        Action::MarkDone => card_marking(app, Operation::MarkDone),
+        Action::MoveCard => match app.start_moving_card() {
+            true => State::MoveCard,
+            false => State::Normal,
+        },
 
         // Card edition
-        KeyCode::Char('i') => card_edition(app, Edition::InsertAtCurrentPosition),
-        KeyCode::Char('a') => card_edition(app, Edition::InsertAtNextPosition),
-        KeyCode::Char('I') => card_edition(app, Edition::InsertTop),
-        KeyCode::Char('A') => card_edition(app, Edition::InsertBottom),
-        KeyCode::Char('e') | KeyCode::Enter => card_edition(app, Edition::EditCurrent),
-        KeyCode::Char('x') | KeyCode::Delete => card_edition(app, Edition::RemoveCurrent),
+        Action::InsertAtCurrentPosition => card_edition(app, Edition::InsertAtCurrentPosition),
+        Action::InsertAtNextPosition => card_edition(app, Edition::InsertAtNextPosition),
+        Action::InsertTop => card_edition(app, Edition::InsertTop),
+        Action::InsertBottom => card_edition(app, Edition::InsertBottom),
+        Action::InsertScratch => card_edition(app, Edition::InsertScratch),
+        Action::EditCard => card_edition(app, Edition::EditCurrent),
+        Action::RemoveCard => card_edition(app, Edition::RemoveCurrent),
+        Action::EditTitle => match app.get_selected_card() {
+            Some(card) => State::EditTitle {
+                title: InlineTitle::new(card.short_description()),
+            },
+            None => {
+                app.log("No card selected".to_string());
+                State::Normal
+            }
+        },
+
+        // Column edition
+        Action::MoveColumnLeft => {
+            app.move_column_left();
+            State::Normal
+        }
+        Action::MoveColumnRight => {
+            app.move_column_right();
+            State::Normal
+        }
+        Action::RenameColumn => match app.selected_column_header() {
+            Some(header) => State::RenameColumn {
+                rename: RenameColumn::new(&header),
+            },
+            None => {
+                app.log("No column selected".to_string());
+                State::Normal
+            }
+        },
+        Action::SplitColumn => match app.column_split() {
+            Some((header, moved)) => State::SplitColumn {
+                split: SplitColumn::new(&header, moved),
+            },
+            None => State::Normal,
+        },
+        Action::ColumnSettings => match (app.selected_column_header(), app.selected_column_options()) {
+            (Some(header), Some(options)) => State::ColumnSettings {
+                settings: ColumnSettings::new(&header, &options),
+            },
+            _ => {
+                app.log("No column selected".to_string());
+                State::Normal
+            }
+        },
+        Action::CycleSortPolicy => {
+            app.cycle_sort_policy();
+            State::Normal
+        }
+        Action::ToggleTimer => {
+            app.toggle_timer();
+            State::Normal
+        }
+        Action::TogglePomodoro => {
+            app.toggle_pomodoro();
+            State::Normal
+        }
+
+        // History
+        Action::Undo => {
+            app.undo();
+            State::Normal
+        }
+        Action::Redo => {
+            app.redo();
+            State::Normal
+        }
+        Action::HistoryBranches => {
+            let branches = app.history_branches();
+            if branches.is_empty() {
+                app.log("No branch in the history, undone changes are kept as branches with history.tree".to_string());
+                return State::Normal;
+            }
+            State::History {
+                browser: HistoryBrowser::new(branches),
+            }
+        }
 
         // Other operations
-        KeyCode::Esc => {
+        Action::DisableSelection => {
             app.disable_selection();
             State::Normal
         }
-        KeyCode::Char('w') => {
+        Action::ToggleStar => {
+            app.toggle_star();
+            State::Normal
+        }
+        Action::ToggleStarredOnly => {
+            app.toggle_starred_only();
+            State::Normal
+        }
+        Action::ToggleFocusMode => {
+            app.toggle_focus_mode();
+            State::Normal
+        }
+        Action::ToggleSection => {
+            app.toggle_section();
+            State::Normal
+        }
+        Action::ToggleOldDoneCards => {
+            app.toggle_old_done_cards();
+            State::Normal
+        }
+        Action::ToggleCardNumbers => {
+            app.toggle_card_numbers();
+            State::Normal
+        }
+        Action::CycleDensity => {
+            app.cycle_density();
+            State::Normal
+        }
+        Action::Write if app.has_save_conflict() => State::SaveConflict {
+            prompt: ConflictPrompt::new(app.file_name().to_string()),
+        },
+        Action::Write => {
             app.write();
             State::Normal
         }
-        KeyCode::Char('W') => State::Save { save: Save::new() },
-        KeyCode::Char('q') => State::Quit,
-        KeyCode::Char('?') => State::Help,
-        _ => State::Normal,
+        Action::WriteToFile => State::Save { save: Save::new() },
+        Action::Feed => {
+            app.focus_feed();
+            State::Feed
+        }
+        Action::OpenBoard => State::OpenBoard {
+            path: Save::with_title(" Open board: "),
+        },
+        Action::ToggleAlternateBoard => {
+            app.toggle_alternate_board();
+            State::Normal
+        }
+        Action::Export => State::Export {
+            dialog: ExportDialog::new(app.export_scopes()),
+        },
+        Action::CopyBoard => {
+            app.copy_board_as_markdown();
+            State::Normal
+        }
+        Action::Sync => match app.plan_sync() {
+            Some(plan) => State::Sync {
+                prompt: SyncPrompt::new(plan),
+            },
+            None => State::Normal,
+        },
+        Action::ImportCsv => State::ImportCsv {
+            wizard: CsvImportWizard::default(),
+        },
+        Action::Reload => {
+            app.reload();
+            State::Normal
+        }
+        Action::ReloadBoard => {
+            app.reload_board();
+            State::Normal
+        }
+        Action::Quit => State::Quit,
+        Action::Help => State::Help { help: Help::default() },
+        Action::Stats => State::Stats,
+        Action::StandUp => State::StandUp,
+        Action::Search => State::Search {
+            search: Box::new(SearchPanel::new()),
+        },
+        Action::SavedSearches => State::SavedSearches {
+            picker: SearchPicker::new(app.saved_searches()),
+        },
+        Action::Tags => State::Tags {
+            manager: TagManager::new(app.board().tag_counts()),
+        },
+        Action::Settings => State::Settings {
+            editor: SettingsEditor::new(app.settings_entries()),
+        },
+        Action::Notes => {
+            app.show_notes();
+            State::Notes {
+                panel: NotesPanel::new(&app.notes()),
+            }
+        }
+        Action::ToggleNotes => {
+            app.toggle_notes();
+            State::Normal
+        }
+
+        // Acting on the panes, handled before the keys reach the focused board
+        Action::Window | Action::GrowLog | Action::ShrinkLog | Action::SendCard => State::Normal,
     }
 }
 
@@ -50,6 +251,9 @@ enum Navigation {
     NextColumn,
     PrevCard,
     NextCard,
+    BottomCard,
+    PrevColumnTop,
+    NextColumnTop,
 }
 
 fn navigate<'a>(app: &mut App, nav: Navigation) -> State<'a> {
@@ -58,6 +262,9 @@ fn navigate<'a>(app: &mut App, nav: Navigation) -> State<'a> {
         Navigation::NextColumn => app.select_next_column(),
         Navigation::PrevCard => app.select_prev_card(),
         Navigation::NextCard => app.select_next_card(),
+        Navigation::BottomCard => app.select_bottom_card(),
+        Navigation::PrevColumnTop => app.select_adjacent_column(false),
+        Navigation::NextColumnTop => app.select_adjacent_column(true),
     }
 
     State::Normal
@@ -88,11 +295,12 @@ enum Edition {
     InsertAtNextPosition,
     InsertTop,
     InsertBottom,
+    InsertScratch,
 }
 
 fn card_edition<'a>(app: &mut App, operation: Edition) -> State<'a> {
     let card = match operation {
-        Edition::EditCurrent => app.get_selected_card(),
+        Edition::EditCurrent => app.card_to_edit(),
         Edition::RemoveCurrent => {
             app.remove_card();
             None
@@ -101,11 +309,12 @@ fn card_edition<'a>(app: &mut App, operation: Edition) -> State<'a> {
         Edition::InsertAtNextPosition => app.insert_card(InsertPosition::Next),
         Edition::InsertTop => app.insert_card(InsertPosition::Top),
         Edition::InsertBottom => app.insert_card(InsertPosition::Bottom),
+        Edition::InsertScratch => app.insert_scratch_card(),
     };
 
     match card {
         Some(card) => State::Edit {
-            editor: CardEditor::new(card),
+            editor: CardEditor::new(card).title_soft_limit(app.title_soft_limit()),
         },
         None => State::Normal,
     }
@@ -117,7 +326,7 @@ mod tests {
 
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-    use crate::app::{app::App, app_state::State, event_handler::normal::handler};
+    use crate::app::{app_state::State, application::App, event_handler::normal::handler, AppOperations};
 
     fn build_event(c: char) -> KeyEvent {
         KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty())
@@ -175,11 +384,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn move_column_and_undo() -> Result<()> {
+        let mut app = App::new("res/test_board.json".to_string());
+        app.select_next_card();
+
+        let state = handler(&mut app, KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        assert_eq!(State::Normal, state);
+        assert_eq!("Doing", app.board().column(0).header());
+        assert_eq!("TODO", app.board().column(1).header());
+        assert_eq!("Buy milk", app.get_selected_card().unwrap().short_description());
+
+        handler(&mut app, build_event('u'));
+        assert_eq!("TODO", app.board().column(0).header());
+        assert_eq!("Buy milk", app.get_selected_card().unwrap().short_description());
+
+        handler(&mut app, build_event('U'));
+        assert_eq!("TODO", app.board().column(1).header());
+
+        Ok(())
+    }
+
     #[test]
     fn help() -> Result<()> {
         let mut app = App::new("res/test_board.json".to_string());
         let state = handler(&mut app, build_event('?'));
-        assert_eq!(State::Help, state);
+        assert!(matches!(state, State::Help { .. }));
 
         Ok(())
     }
@@ -0,0 +1,22 @@
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, application::App, notes_panel::NotesPanel};
+
+pub fn handler<'a>(mut panel: NotesPanel, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input {
+            key: Key::Char('s'),
+            ctrl: true,
+            ..
+        } => {
+            app.update_notes(&panel.notes());
+            State::Normal
+        }
+        input => {
+            panel.input(input);
+            State::Notes { panel }
+        }
+    }
+}
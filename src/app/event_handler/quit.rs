@@ -0,0 +1,32 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, quit_prompt::QuitPrompt, App};
+
+/// Quits right away, or first asks what to do when an open board has changes not written
+pub fn check<'a>(app: &App) -> State<'a> {
+    match app.boards_with_unwritten_changes() {
+        file_names if file_names.is_empty() => State::Quit,
+        file_names => State::QuitPrompt {
+            prompt: QuitPrompt::new(file_names),
+        },
+    }
+}
+
+/// Handles the answer to quitting with boards that have changes not written
+pub fn handler<'a>(prompt: QuitPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('w') => {
+            app.write_all();
+            match app.boards_with_unwritten_changes() {
+                file_names if file_names.is_empty() => State::Quit,
+                file_names => {
+                    app.log(format!("Not quitting, {} not written", file_names.join(", ")));
+                    State::Normal
+                }
+            }
+        }
+        KeyCode::Char('d') => State::Quit,
+        KeyCode::Esc => State::Normal,
+        _ => State::QuitPrompt { prompt },
+    }
+}
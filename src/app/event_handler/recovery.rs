@@ -0,0 +1,27 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, recovery_prompt::RecoveryPrompt, App};
+
+pub fn handler<'a>(mut prompt: RecoveryPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('r') => {
+            app.restore_recovery_file(prompt.recovery_file());
+            State::Normal
+        }
+        KeyCode::Char('d') => {
+            if prompt.is_showing_changes() {
+                prompt.hide_changes();
+            } else {
+                let changes = app.recovery_changes(prompt.recovery_file());
+                prompt.show_changes(changes.map_err(|e| e.to_string()));
+            }
+            State::Recovery { prompt }
+        }
+        KeyCode::Char('x') => {
+            app.discard_recovery_file(prompt.recovery_file());
+            State::Normal
+        }
+        KeyCode::Esc => State::Normal,
+        _ => State::Recovery { prompt },
+    }
+}
@@ -0,0 +1,25 @@
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, rename_column::RenameColumn, App, AppOperations};
+
+pub fn handler<'a>(mut rename: RenameColumn<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => match rename.get() {
+            Some(header) => {
+                app.rename_column(&header);
+                State::Normal
+            }
+            None => {
+                rename.set_error(Some("name cannot be empty"));
+                State::RenameColumn { rename }
+            }
+        },
+        input => {
+            rename.push(input);
+            rename.set_error(None);
+            State::RenameColumn { rename }
+        }
+    }
+}
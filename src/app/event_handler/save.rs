@@ -1,7 +1,7 @@
 use crossterm::event::KeyEvent;
 use tui_textarea::{Input, Key};
 
-use crate::app::{app_state::State, save_to_file::Save, App};
+use crate::app::{app_state::State, save_to_file::Save, App, AppOperations};
 
 pub fn handler<'a>(mut save: Save<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
     match key_event.into() {
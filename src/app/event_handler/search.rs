@@ -0,0 +1,62 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::{app_state::State, search_panel::SearchPanel, App, AppOperations};
+
+pub fn handler<'a>(mut search: Box<SearchPanel<'a>>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if let Some(name) = search.name() {
+        match key_event.code {
+            KeyCode::Enter => {
+                app.save_search(name, search.query());
+                search.stop_naming();
+            }
+            KeyCode::Esc => search.stop_naming(),
+            _ => search.push_name(key_event.into()),
+        }
+        return State::Search { search };
+    }
+
+    if key_event.code == KeyCode::Esc {
+        return State::Normal;
+    }
+    if key_event.code == KeyCode::Char('s') && key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        search.start_naming();
+        return State::Search { search };
+    }
+
+    if !search.is_browsing() {
+        match key_event.code {
+            KeyCode::Enter => {
+                app.remember_search(search.query());
+                search.browse(true);
+            }
+            KeyCode::Down => search.select_next(),
+            KeyCode::Up => search.select_prev(),
+            _ => search.push(key_event.into(), &app.board()),
+        }
+        return State::Search { search };
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => search.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => search.select_prev(),
+        KeyCode::Char('/') => search.browse(false),
+        KeyCode::Char('D') => {
+            let positions: Vec<(usize, usize)> = search
+                .results()
+                .iter()
+                .map(|result| (result.column_index, result.card_index))
+                .collect();
+            app.remove_cards(&positions);
+            return State::Normal;
+        }
+        KeyCode::Enter => {
+            if let Some(result) = search.selected() {
+                app.select_card(result.column_index, result.card_index);
+            }
+            return State::Normal;
+        }
+        _ => {}
+    }
+
+    State::Search { search }
+}
@@ -0,0 +1,31 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, search_panel::SearchPanel, search_picker::SearchPicker, App, AppOperations};
+
+pub fn handler<'a>(mut picker: SearchPicker, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => picker.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => picker.select_prev(),
+        KeyCode::Char('d') => {
+            if let Some(search) = picker.selected() {
+                app.remove_saved_search(&search.name.clone());
+                picker.refresh(app.saved_searches());
+            }
+        }
+        KeyCode::Enter => {
+            return match picker.selected() {
+                Some(search) => {
+                    app.remember_search(&search.query);
+                    State::Search {
+                        search: Box::new(SearchPanel::with_query(&search.query, &app.board())),
+                    }
+                }
+                None => State::Normal,
+            }
+        }
+        KeyCode::Esc => return State::Normal,
+        _ => {}
+    }
+
+    State::SavedSearches { picker }
+}
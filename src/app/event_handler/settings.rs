@@ -0,0 +1,40 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, settings_editor::SettingsEditor, App};
+
+pub fn handler<'a>(mut editor: SettingsEditor<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if editor.is_editing() {
+        return edit_handler(editor, app, key_event);
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => editor.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => editor.select_prev(),
+        KeyCode::Esc | KeyCode::Char('q') => return State::Normal,
+        KeyCode::Char('e') | KeyCode::Enter => editor.start_edit(),
+        KeyCode::Char('a') => editor.start_add(),
+        _ => {}
+    }
+
+    State::Settings { editor }
+}
+
+fn edit_handler<'a>(mut editor: SettingsEditor<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => editor.cancel(),
+        Input { key: Key::Enter, .. } => match editor.edited() {
+            Some((path, value)) => match app.change_setting(&path, &value) {
+                Ok(()) => editor.refresh(app.settings_entries(), &path),
+                Err(e) => editor.set_error(Some(&e)),
+            },
+            None => editor.set_error(Some("expected path = value")),
+        },
+        input => {
+            editor.push(input);
+            editor.set_error(None);
+        }
+    }
+
+    State::Settings { editor }
+}
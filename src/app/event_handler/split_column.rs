@@ -0,0 +1,25 @@
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, split_column::SplitColumn, App};
+
+pub fn handler<'a>(mut split: SplitColumn<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => match split.get() {
+            Some(header) => {
+                app.split_column(&header);
+                State::Normal
+            }
+            None => {
+                split.set_error(Some("name cannot be empty"));
+                State::SplitColumn { split }
+            }
+        },
+        input => {
+            split.push(input);
+            split.set_error(None);
+            State::SplitColumn { split }
+        }
+    }
+}
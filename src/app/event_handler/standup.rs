@@ -0,0 +1,12 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, App};
+
+/// Handles the keys of the stand-up summary, which closes after any of them
+pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if key_event.code == KeyCode::Char('y') {
+        app.copy_standup();
+    }
+
+    State::Normal
+}
@@ -0,0 +1,30 @@
+use crossterm::event::KeyEvent;
+
+use crate::app::{
+    app_state::State,
+    startup_wizard::{StartupWizard, WizardAction},
+    App,
+};
+
+pub fn handler<'a>(mut wizard: StartupWizard<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    let file_name = match wizard.handle(key_event) {
+        WizardAction::None => return State::Startup { wizard },
+        WizardAction::Empty => return State::Normal,
+        WizardAction::Open(file_name) => {
+            app.open_board(&file_name);
+            file_name
+        }
+        WizardAction::Create(template, file_name) => {
+            app.new_board(template, &file_name);
+            file_name
+        }
+    };
+
+    let session = wizard.session_mut();
+    session.add_recent_board(&file_name);
+    if let Err(e) = session.save() {
+        app.log(format!("Cannot save session because {}", e));
+    }
+
+    State::Normal
+}
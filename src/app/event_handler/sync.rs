@@ -0,0 +1,14 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{app_state::State, sync_prompt::SyncPrompt, App};
+
+pub fn handler<'a>(prompt: SyncPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Enter => {
+            app.apply_sync(prompt.plan());
+            State::Normal
+        }
+        KeyCode::Esc | KeyCode::Char('q') => State::Normal,
+        _ => State::Sync { prompt },
+    }
+}
@@ -0,0 +1,57 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, tag_manager::TagManager, App, AppOperations};
+
+pub fn handler<'a>(mut manager: TagManager<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    if manager.is_renaming() {
+        return rename_handler(manager, app, key_event);
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => manager.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => manager.select_prev(),
+        KeyCode::Esc if !manager.is_browsing() => manager.cancel(),
+        KeyCode::Esc | KeyCode::Char('q') => return State::Normal,
+        KeyCode::Enter | KeyCode::Char('m') if !manager.is_browsing() => {
+            if let (Some(tag), Some(into)) = (manager.merged_tag(), manager.selected()) {
+                if tag != into {
+                    app.merge_tags(tag, into);
+                }
+            }
+            manager.refresh(app.board().tag_counts());
+        }
+        KeyCode::Char('m') => manager.start_merge(),
+        KeyCode::Char('r') => manager.start_rename(),
+        KeyCode::Char('d') => {
+            if let Some(tag) = manager.selected() {
+                app.remove_tag(tag);
+                manager.refresh(app.board().tag_counts());
+            }
+        }
+        _ => {}
+    }
+
+    State::Tags { manager }
+}
+
+fn rename_handler<'a>(mut manager: TagManager<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => manager.cancel(),
+        Input { key: Key::Enter, .. } => match (manager.selected(), manager.new_name()) {
+            (Some(tag), Some(new_name)) => {
+                if tag != new_name {
+                    app.rename_tag(tag, &new_name);
+                }
+                manager.refresh(app.board().tag_counts());
+            }
+            _ => manager.set_error(Some("name cannot be empty or have spaces")),
+        },
+        input => {
+            manager.push(input);
+            manager.set_error(None);
+        }
+    }
+
+    State::Tags { manager }
+}
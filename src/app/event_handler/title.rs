@@ -0,0 +1,25 @@
+use crossterm::event::KeyEvent;
+use tui_textarea::{Input, Key};
+
+use crate::app::{app_state::State, inline_title::InlineTitle, App};
+
+pub fn handler<'a>(mut title: InlineTitle<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => match title.get() {
+            Some(new_title) => {
+                app.update_card_title(&new_title);
+                State::Normal
+            }
+            None => {
+                title.set_error(Some("Title cannot be empty"));
+                State::EditTitle { title }
+            }
+        },
+        input => {
+            title.push(input);
+            title.set_error(None);
+            State::EditTitle { title }
+        }
+    }
+}
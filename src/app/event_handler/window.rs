@@ -0,0 +1,95 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
+
+use crate::app::{
+    app_state::State,
+    close_split_prompt::CloseSplitPrompt,
+    keymap::{self, Action},
+    save_to_file::Save,
+    App,
+};
+
+/// Handles the keys acting on panes from the normal state, returns None for the other keys
+pub fn intercept<'a>(app: &mut App, key_event: KeyEvent) -> Option<State<'a>> {
+    let action = keymap::action(&key_event)?;
+    match action {
+        Action::Window => Some(State::Window),
+        Action::GrowLog | Action::ShrinkLog => {
+            app.resize_log(action == Action::GrowLog);
+            Some(State::Normal)
+        }
+        Action::SendCard => {
+            app.send_card_to_other_pane();
+            Some(State::Normal)
+        }
+        _ => None,
+    }
+}
+
+/// Handles the key following `<Ctrl-w>`
+pub fn handler<'a>(app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('v') => State::OpenSplit {
+            path: Save::with_title(" Open in split: "),
+        },
+        KeyCode::Char('w') | KeyCode::Char('h') | KeyCode::Char('l') | KeyCode::Left | KeyCode::Right => {
+            app.toggle_pane_focus();
+            State::Normal
+        }
+        KeyCode::Char('q') | KeyCode::Char('c') => match app.split_with_unwritten_changes() {
+            Some(file_name) => State::CloseSplit {
+                prompt: CloseSplitPrompt::new(file_name),
+            },
+            None => {
+                app.close_split();
+                State::Normal
+            }
+        },
+        _ => State::Normal,
+    }
+}
+
+/// Handles the answer to closing a split whose board has changes not written
+pub fn close_split_handler<'a>(prompt: CloseSplitPrompt, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.code {
+        KeyCode::Char('w') => {
+            app.write_and_close_split();
+            State::Normal
+        }
+        KeyCode::Char('d') => {
+            app.close_split();
+            State::Normal
+        }
+        KeyCode::Esc => State::Normal,
+        _ => State::CloseSplit { prompt },
+    }
+}
+
+pub fn open_split_handler<'a>(mut path: Save<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => {
+            app.open_split(path.get());
+            State::Normal
+        }
+        input => {
+            path.push(input);
+            State::OpenSplit { path }
+        }
+    }
+}
+
+/// Opens another board in the focused pane, the current one is kept to switch back to it with <B>
+pub fn open_board_handler<'a>(mut path: Save<'a>, app: &mut App, key_event: KeyEvent) -> State<'a> {
+    match key_event.into() {
+        Input { key: Key::Esc, .. } => State::Normal,
+        Input { key: Key::Enter, .. } => {
+            app.open_other_board(path.get());
+            State::Normal
+        }
+        input => {
+            path.push(input);
+            State::OpenBoard { path }
+        }
+    }
+}
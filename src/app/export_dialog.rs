@@ -0,0 +1,152 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Stylize,
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+use tui_textarea::{Input, TextArea};
+
+use super::widget_utils::centered_popup_area;
+use crate::{board::ExportScope, utils::text};
+
+/// Asks for the file to export part of the board to, and which part
+#[derive(Debug, Clone)]
+pub struct ExportDialog<'a> {
+    text_area: TextArea<'a>,
+    /// Scopes available with their label, the whole board first
+    scopes: Vec<(String, ExportScope)>,
+    selected: usize,
+    /// Existing file asked to be written over, exporting to it again confirms it
+    overwrite: Option<String>,
+}
+
+impl PartialEq for ExportDialog<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ExportDialog<'_> {}
+
+impl ExportDialog<'_> {
+    pub fn new(scopes: Vec<(String, ExportScope)>) -> Self {
+        let mut text_area = TextArea::default();
+        text_area.set_block(Block::bordered().title(" Export to: ").border_set(border::PLAIN));
+
+        Self {
+            text_area,
+            scopes,
+            selected: 0,
+            overwrite: None,
+        }
+    }
+
+    pub fn push(&mut self, input: Input) {
+        self.text_area.input(input);
+    }
+
+    pub fn paste(&mut self, text: &str) {
+        let text = text::sanitize_paste(text);
+        self.text_area.insert_str(text.lines().collect::<String>());
+    }
+
+    pub fn next_scope(&mut self) {
+        self.selected = (self.selected + 1) % self.scopes.len().max(1);
+    }
+
+    pub fn file_name(&self) -> String {
+        self.text_area.lines()[0].trim().to_string()
+    }
+
+    pub fn scope(&self) -> Option<&ExportScope> {
+        self.scopes.get(self.selected).map(|(_, scope)| scope)
+    }
+
+    /// Asks to confirm writing over the file typed in
+    pub fn ask_overwrite(&mut self) {
+        self.overwrite = Some(self.file_name());
+    }
+
+    /// Whether the file typed in was asked about, exporting to it now writes over it
+    pub fn overwrites(&self) -> bool {
+        self.overwrite
+            .as_ref()
+            .is_some_and(|file_name| *file_name == self.file_name())
+    }
+}
+
+impl Widget for &ExportDialog<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(64), Constraint::Length(7));
+        Clear.render(area, buf);
+
+        let title = Title::from(" Export ".bold());
+        let keys = Title::from(Line::from(vec![
+            " <Tab> ".bold(),
+            "Scope -".into(),
+            " <Enter> ".bold(),
+            "Export -".into(),
+            " <ESC> ".bold(),
+            "Cancel ".into(),
+        ]));
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(keys.alignment(Alignment::Center).position(Position::Bottom))
+            .on_blue()
+            .border_set(border::DOUBLE);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let [path_area, scope_area, overwrite_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Length(1), Constraint::Length(1)]).areas(inner_area);
+        self.text_area.render(path_area, buf);
+        let label = self.scopes.get(self.selected).map_or("", |(label, _)| label.as_str());
+        Paragraph::new(Line::from(vec![" Scope: ".bold(), label.to_string().into()])).render(scope_area, buf);
+        if self.overwrites() {
+            Paragraph::new(" The file exists, <Enter> again to write over it".bold()).render(overwrite_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use crate::board::ExportScope;
+
+    use super::ExportDialog;
+
+    #[test]
+    fn path_and_scope() -> io::Result<()> {
+        let mut dialog = ExportDialog::new(vec![
+            ("Whole board".to_string(), ExportScope::Board),
+            ("Column Done!".to_string(), ExportScope::Column(2)),
+        ]);
+        assert_eq!(Some(&ExportScope::Board), dialog.scope());
+
+        dialog.push(Input::from(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)));
+        dialog.paste(" done.md\n");
+        assert_eq!("a done.md", dialog.file_name());
+
+        dialog.next_scope();
+        assert_eq!(Some(&ExportScope::Column(2)), dialog.scope());
+        dialog.next_scope();
+        assert_eq!(Some(&ExportScope::Board), dialog.scope(), "back to the first one");
+
+        assert!(!dialog.overwrites());
+        dialog.ask_overwrite();
+        assert!(dialog.overwrites());
+        dialog.paste("x");
+        assert!(!dialog.overwrites(), "asked for another file");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{block::Title, Block, List, ListState, StatefulWidget},
+};
+
+use crate::board::Card;
+
+const MAX_EVENTS: usize = 100;
+
+/// A change made to the board, with the card it concerns when there is one
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEvent {
+    date: DateTime<Local>,
+    text: String,
+    card: Option<Card>,
+}
+
+impl FeedEvent {
+    pub fn new(date: DateTime<Local>, text: String, card: Option<Card>) -> Self {
+        Self { date, text, card }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn card(&self) -> Option<&Card> {
+        self.card.as_ref()
+    }
+}
+
+/// Recent changes to the board, newest first, to see what happened and jump to the cards concerned
+#[derive(Debug, Default)]
+pub struct Feed {
+    events: VecDeque<FeedEvent>,
+    selected: usize,
+}
+
+impl Feed {
+    /// Adds an event on top, the selection stays on the event it was on
+    pub fn push(&mut self, event: FeedEvent) {
+        self.events.push_front(event);
+        self.events.truncate(MAX_EVENTS);
+        if self.events.len() > 1 {
+            self.selected = (self.selected + 1).min(self.events.len() - 1);
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.events.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Goes back to the most recent event
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn selected(&self) -> Option<&FeedEvent> {
+        self.events.get(self.selected)
+    }
+
+    /// Renders the events with their time, the selected one is highlighted when the feed has the focus
+    pub fn render(&self, focused: bool, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered()
+            .title(Title::from(" Activity ".bold()).alignment(Alignment::Left))
+            .border_set(if focused { border::DOUBLE } else { border::THICK });
+
+        let items: Vec<Line> = self
+            .events
+            .iter()
+            .map(|event| {
+                Line::from(vec![
+                    format!(" {} ", event.date.format("%H:%M")).dim(),
+                    event.text.clone().into(),
+                ])
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(focused.then_some(self.selected));
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::Local;
+
+    use crate::board::Card;
+
+    use super::{Feed, FeedEvent, MAX_EVENTS};
+
+    #[test]
+    fn newest_events_first() -> Result<()> {
+        let now = Local::now();
+        let mut feed = Feed::default();
+        assert_eq!(None, feed.selected());
+
+        feed.push(FeedEvent::new(
+            now,
+            "Created 'Buy milk'".into(),
+            Some(Card::new("Buy milk", now)),
+        ));
+        feed.push(FeedEvent::new(now, "Sort column by name".into(), None));
+        assert_eq!(
+            Some("Created 'Buy milk'"),
+            feed.selected().map(FeedEvent::text),
+            "still selected"
+        );
+
+        feed.select_prev();
+        assert_eq!(Some("Sort column by name"), feed.selected().map(FeedEvent::text));
+        assert_eq!(None, feed.selected().and_then(FeedEvent::card));
+        feed.select_next();
+        feed.select_next();
+        assert_eq!(Some("Created 'Buy milk'"), feed.selected().map(FeedEvent::text));
+
+        for _ in 0..MAX_EVENTS {
+            feed.push(FeedEvent::new(now, "Remove card".into(), None));
+        }
+        feed.select_first();
+        assert_eq!(Some("Remove card"), feed.selected().map(FeedEvent::text));
+        assert_eq!(MAX_EVENTS, feed.events.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Paragraph, Widget, Wrap},
+};
+
+use crate::{
+    app::view::card_color,
+    board::{Board, Card},
+    utils::time,
+};
+
+const NEIGHBOR_WIDTH: u16 = 24;
+
+/// Renders a single column of the board in details, with slim previews of its neighbors
+pub struct FocusView<'a> {
+    board: &'a Board,
+    column_index: usize,
+    selected_card: Option<usize>,
+}
+
+impl<'a> FocusView<'a> {
+    pub fn new(board: &'a Board, column_index: usize, selected_card: Option<usize>) -> Self {
+        Self {
+            board,
+            column_index,
+            selected_card,
+        }
+    }
+
+    fn render_neighbor(&self, column_index: Option<usize>, area: Rect, buf: &mut Buffer) {
+        let Some(column_index) = column_index.filter(|index| *index < self.board.columns_count()) else {
+            return;
+        };
+
+        let column = self.board.column(column_index);
+        let lines: Vec<Line> = column
+            .cards_iter()
+            .map(|card| Line::from(format!("• {}", card.short_description())))
+            .collect();
+
+        let block = Block::bordered()
+            .title(Title::from(format!(" {} ", column.header()).dim()).alignment(Alignment::Center))
+            .border_set(border::PLAIN)
+            .dim();
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+
+    fn render_focused(&self, area: Rect, buf: &mut Buffer) {
+        let column = self.board.column(self.column_index);
+        let header = format!(" {} ", column.header());
+        let block = Block::bordered()
+            .title(Title::from(header.bold()).alignment(Alignment::Center))
+            .border_set(border::THICK);
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let cards: Vec<&Card> = column.cards_iter().collect();
+        let constraints: Vec<Constraint> = cards
+            .iter()
+            .map(|card| Constraint::Length(detailed_card_height(card)))
+            .collect();
+        let areas = Layout::vertical(constraints).split(inner_area);
+
+        for (card_index, (card, area)) in cards.iter().zip(areas.iter()).enumerate() {
+            render_detailed_card(card, self.selected_card == Some(card_index), *area, buf);
+        }
+    }
+}
+
+impl Widget for FocusView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [left, center, right] = Layout::horizontal([
+            Constraint::Length(NEIGHBOR_WIDTH),
+            Constraint::Min(0),
+            Constraint::Length(NEIGHBOR_WIDTH),
+        ])
+        .areas(area);
+
+        self.render_neighbor(self.column_index.checked_sub(1), left, buf);
+        self.render_focused(center, buf);
+        self.render_neighbor(Some(self.column_index + 1), right, buf);
+    }
+}
+
+fn detailed_card_height(card: &Card) -> u16 {
+    let description_lines = if card.long_description().is_empty() {
+        0
+    } else {
+        card.long_description().lines().count() + 1
+    };
+
+    (description_lines + 4) as u16
+}
+
+fn render_detailed_card(card: &Card, selected: bool, area: Rect, buf: &mut Buffer) {
+    let border = if selected { border::DOUBLE } else { border::ROUNDED };
+    let mut block = Block::bordered().border_set(border);
+    if let Some(color) = card.color() {
+        block = block.border_style(Style::new().fg(card_color(color)));
+    }
+
+    let mut lines = vec![Line::from(card.short_description().clone().bold())];
+    if !card.long_description().is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(card.long_description().lines().map(|line| Line::from(line.to_string())));
+    }
+    lines.push(Line::from(time::time_in_column(card.entered_column_date())).alignment(Alignment::Right));
+
+    Paragraph::new(Text::from(lines))
+        .wrap(Wrap { trim: false })
+        .block(block)
+        .render(area, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crossterm::event::KeyCode;
+
+    use crate::TestDriver;
+
+    #[test]
+    fn focus_on_selected_column() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("llz");
+        assert!(driver.screen_contains("Cook dinner"));
+        assert!(driver.screen_contains("This is a long explanation of what needs doing."));
+        assert!(driver.screen_contains("• Buy milk"));
+        assert!(driver.screen_contains("• Eat dinner"));
+
+        driver.press(KeyCode::Char('z').into());
+        assert!(!driver.screen_contains("This is a long explanation of what needs doing."));
+        assert!(!driver.screen_contains("• Buy milk"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, StatefulWidget, Widget,
+    },
+};
+
+use crate::{app::widget_utils::centered_popup_area, board::Finding};
+
+/// Lists what looks wrong on the board just opened, to jump to the cards concerned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthPopup {
+    findings: Vec<Finding>,
+    selected: usize,
+}
+
+impl HealthPopup {
+    pub fn new(findings: Vec<Finding>) -> Self {
+        Self { findings, selected: 0 }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.findings.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected(&self) -> Option<&Finding> {
+        self.findings.get(self.selected)
+    }
+}
+
+impl Widget for &HealthPopup {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = self.findings.len().clamp(1, 15) as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(70), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" Board health ".bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Select  <CR> Go to card  <Esc> Dismiss ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        let items: Vec<Line> = self
+            .findings
+            .iter()
+            .map(|finding| Line::from(format!(" {}", finding)))
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
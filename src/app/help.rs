@@ -10,49 +10,107 @@ use ratatui::{
     },
 };
 
-use crate::app::widget_utils::centered_popup_area;
+use crate::app::{keymap, widget_utils::centered_popup_area};
 
-pub struct Help;
+/// Keys whose action contains every word of the query, ignoring case, like "export" or "sort column"
+pub fn find_keys(query: &str) -> Vec<(String, &'static str)> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    keymap::help_entries()
+        .into_iter()
+        .filter(|(_, action)| {
+            let action = action.to_lowercase();
+            words.iter().all(|word| action.contains(word.as_str()))
+        })
+        .collect()
+}
+
+/// A line of the help, the key in bold followed by its action
+pub fn key_line(key: &str, action: &str) -> Line<'static> {
+    Line::from(vec![format!(" {} ", key).bold(), action.to_string().into()])
+}
+
+/// Keys of the normal mode with what they do, scrolled when they do not all fit the terminal
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Help {
+    scroll: usize,
+}
+
+impl Help {
+    /// Scrolls by a number of lines, up when negative
+    pub fn scroll_by(&mut self, lines: isize) {
+        let last = keymap::help_entries().len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(lines).min(last);
+    }
+}
 
-impl Widget for Help {
+impl Widget for &Help {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(20));
+        let entries = keymap::help_entries();
+        let area = centered_popup_area(
+            area,
+            Constraint::Length(72),
+            Constraint::Length(entries.len() as u16 + 2),
+        );
         Clear.render(area, buf);
 
+        let visible = area.height.saturating_sub(2) as usize;
+        let scroll = self.scroll.min(entries.len().saturating_sub(visible));
         let title = Title::from(" Help ".bold());
-        let status = Title::from(" Press any key to dismiss ");
-        let text = Text::from(vec![
-            Line::from(vec![" <h/j/k/l> ".bold(), "Select card".into()]),
-            Line::from(vec![" <←/↓/↑/→> ".bold(), "Select card".into()]),
-            Line::from(vec![" <e>  ".bold(), "Edit selected card".into()]),
-            Line::from(vec![" <CR> ".bold(), "Edit selected card".into()]),
-            Line::from(vec![" <i> ".bold(), "Insert card a current position".into()]),
-            Line::from(vec![" <I> ".bold(), "Insert card at the top of current column".into()]),
-            Line::from(vec![" <a> ".bold(), "Insert card a next position".into()]),
-            Line::from(vec![
-                " <A> ".bold(),
-                "Insert card at the bottom of current clumn".into(),
-            ]),
-            Line::from(vec![" <x>   ".bold(), "Delete current card".into()]),
-            Line::from(vec![" <DEL> ".bold(), "Delete current card".into()]),
-            Line::from(vec![" <K> ".bold(), "Increase priotity of selected card".into()]),
-            Line::from(vec![" <J> ".bold(), "Decrease priotity of selected card".into()]),
-            Line::from(vec![" <L> ".bold(), "Mark selected card done".into()]),
-            Line::from(vec![" <H> ".bold(), "Mark selected card undone".into()]),
-            Line::from(vec![" <w> ".bold(), "Write the board to file".into()]),
-            Line::from(vec![
-                " <W> ".bold(),
-                "Write the board to a new file (opens pop up)".into(),
-            ]),
-            Line::from(vec![" <q> ".bold(), "Quit the application".into()]),
-            Line::from(vec![" <?> ".bold(), "Toggle this help message".into()]),
-        ]);
+        let status = match visible < entries.len() {
+            true => Title::from(format!(
+                " <j/k> Scroll {}-{}/{}, any other key to dismiss ",
+                scroll + 1,
+                (scroll + visible).min(entries.len()),
+                entries.len()
+            )),
+            false => Title::from(" Press any key to dismiss "),
+        };
+        let search = Title::from(" </> Search ");
+        let text = Text::from(
+            entries
+                .iter()
+                .map(|(key, action)| key_line(key, action))
+                .collect::<Vec<_>>(),
+        );
 
         let block = Block::bordered()
             .title(title.alignment(Alignment::Center))
             .title(status.alignment(Alignment::Center).position(Position::Bottom))
+            .title(search.alignment(Alignment::Right).position(Position::Bottom))
             .on_dark_gray()
             .border_set(border::ROUNDED);
-        Paragraph::new(text).block(block).render(area, buf);
+        Paragraph::new(text)
+            .block(block)
+            .scroll((scroll as u16, 0))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::app::keymap;
+
+    use super::find_keys;
+
+    #[test]
+    fn find_keys_by_action() -> Result<()> {
+        assert_eq!(
+            vec![("<E>".to_string(), "Export the board, a column or search results")],
+            find_keys("export")
+        );
+        assert_eq!(
+            vec![("<o>".to_string(), "Cycle sort order of selected column")],
+            find_keys("Sort  COLUMN")
+        );
+        assert_eq!(
+            vec![("<R>".to_string(), "Rename selected column")],
+            find_keys("rename column")
+        );
+        assert!(find_keys("archive").is_empty());
+        assert_eq!(keymap::help_entries().len(), find_keys("").len());
+
+        Ok(())
     }
 }
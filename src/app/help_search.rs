@@ -0,0 +1,88 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+use tui_textarea::{Input, TextArea};
+
+use crate::{
+    app::{
+        help::{find_keys, key_line},
+        widget_utils::centered_popup_area,
+    },
+    utils::text,
+};
+
+/// Finds the keys of an action typed by name, like "export", from the help
+#[derive(Debug, Clone)]
+pub struct HelpSearch<'a> {
+    text_area: TextArea<'a>,
+}
+
+impl PartialEq for HelpSearch<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.query() == other.query()
+    }
+}
+
+impl Eq for HelpSearch<'_> {}
+
+impl Default for HelpSearch<'_> {
+    fn default() -> Self {
+        let mut text_area = TextArea::default();
+        text_area.set_block(
+            Block::bordered()
+                .title(" What key does: ")
+                .on_blue()
+                .border_set(border::DOUBLE),
+        );
+        Self { text_area }
+    }
+}
+
+impl HelpSearch<'_> {
+    pub fn push(&mut self, input: Input) {
+        self.text_area.input(input);
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        let pasted = text::sanitize_paste(pasted);
+        self.text_area.insert_str(pasted.lines().collect::<String>());
+    }
+
+    pub fn query(&self) -> &str {
+        &self.text_area.lines()[0]
+    }
+}
+
+impl Widget for &HelpSearch<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let keys = find_keys(self.query());
+        let height = keys.len().clamp(1, 20) as u16 + 5;
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let [query_area, keys_area] = Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+        self.text_area.render(query_area, buf);
+
+        let lines: Vec<Line> = match keys.is_empty() {
+            true => vec![Line::from(" No key found".italic())],
+            false => keys.into_iter().map(|(key, action)| key_line(&key, action)).collect(),
+        };
+        let block = Block::bordered()
+            .title(
+                Title::from(" <Esc> Back to the help ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .on_dark_gray()
+            .border_set(border::ROUNDED);
+        Paragraph::new(Text::from(lines)).block(block).render(keys_area, buf);
+    }
+}
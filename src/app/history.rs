@@ -0,0 +1,639 @@
+use std::fmt;
+
+use crate::{
+    app::{activity::Activity, command::Command},
+    board::Board,
+};
+
+const MAX_UNDO_HISTORY: usize = 100;
+const MAX_UNDO_BYTES: usize = 16 * 1024 * 1024;
+const MAX_CHECKPOINTS: usize = 10;
+const MAX_BRANCHES: usize = 10;
+
+/// Commands undone before other commands were executed, kept to be redone later instead of being lost
+#[derive(Debug)]
+struct Branch {
+    /// Number of commands applied, including the dropped ones, when the branch was left
+    fork: usize,
+    /// Commands of the branch, the next one to redo last, like the redo stack
+    commands: Vec<Box<dyn Command>>,
+    /// Branches forking from the commands of this one
+    branches: Vec<Branch>,
+}
+
+/// What switching to a branch of the history does, to choose one in a list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchSummary {
+    /// Commands undone to reach the point the branch starts from, 0 when it starts after undone commands
+    undone: usize,
+    /// Commands of the branch that are redone
+    redone: usize,
+    /// Description of the last command of the branch
+    last: String,
+}
+
+impl fmt::Display for BranchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "change" } else { "changes" };
+        write!(f, "{} {} ending with '{}'", self.redone, plural(self.redone), self.last)?;
+        match self.undone {
+            0 => write!(f, ", from here"),
+            undone => write!(f, ", from {} {} back", undone, plural(undone)),
+        }
+    }
+}
+
+/// Keeps track of executed commands so they can be undone and redone
+///
+/// The oldest commands are dropped when there are more than `max_commands` of them, or when they use more
+/// than `max_bytes` of memory altogether. The last command is always kept.
+///
+/// Checkpoints tie a revision of the board, like the content of its file, to a point of the history, so the
+/// board can be brought back to that revision by undoing or redoing commands.
+///
+/// As a tree, executing a command after undoing others keeps the undone commands as a branch, which can be
+/// switched to later. Otherwise they are lost.
+#[derive(Debug)]
+pub struct History {
+    undo_stack: Vec<Box<dyn Command>>,
+    redo_stack: Vec<Box<dyn Command>>,
+    max_commands: usize,
+    max_bytes: usize,
+    /// Number of commands dropped from the bottom of the undo stack, to keep checkpoints positions absolute
+    dropped: usize,
+    /// Revisions with the number of commands applied to reach them, oldest first
+    checkpoints: Vec<(u64, usize)>,
+    tree: bool,
+    /// Branches forking from the current line of commands, oldest first
+    branches: Vec<Branch>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo_stack: vec![],
+            redo_stack: vec![],
+            max_commands: MAX_UNDO_HISTORY,
+            max_bytes: MAX_UNDO_BYTES,
+            dropped: 0,
+            checkpoints: vec![],
+            tree: false,
+            branches: vec![],
+        }
+    }
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_commands(mut self, max_commands: usize) -> Self {
+        self.max_commands = max_commands.max(1);
+        self
+    }
+
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    pub fn tree(mut self, tree: bool) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Executes a command and records it, the commands that could be redone become a branch as a tree, or are
+    /// cleared otherwise
+    ///
+    /// A command that changed nothing is dropped, returns None, and the commands that could be redone still can.
+    pub fn execute(&mut self, mut command: Box<dyn Command>, board: &mut Board) -> Option<(usize, usize)> {
+        let position = command.execute(board);
+        if !command.changed() {
+            return None;
+        }
+
+        let undone = std::mem::take(&mut self.redo_stack);
+        if self.tree && !undone.is_empty() {
+            self.fork(undone);
+        }
+        self.undo_stack.push(command);
+        self.trim();
+        let applied = self.applied();
+        self.checkpoints.retain(|(_, position)| *position < applied);
+
+        Some(position)
+    }
+
+    /// Marks the board as being at a revision after the commands applied so far
+    pub fn checkpoint(&mut self, revision: u64) {
+        let applied = self.applied();
+        self.checkpoints.retain(|(_, position)| *position != applied);
+        self.checkpoints.push((revision, applied));
+        if self.checkpoints.len() > MAX_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
+    }
+
+    /// Undoes or redoes commands until the board is back at a revision, returns false when the revision is not
+    /// in the history anymore
+    pub fn rewind(&mut self, revision: u64, board: &mut Board) -> bool {
+        let Some(&(_, position)) = self.checkpoints.iter().rev().find(|(r, _)| *r == revision) else {
+            return false;
+        };
+        if position < self.dropped || position > self.applied() + self.redo_stack.len() {
+            return false;
+        }
+
+        self.go_to(position, board);
+        true
+    }
+
+    fn go_to(&mut self, position: usize, board: &mut Board) {
+        while self.applied() > position && self.undo(board).is_some() {}
+        while self.applied() < position && self.redo(board).is_some() {}
+    }
+
+    /// Keeps the commands that could be redone as a branch starting from the current point
+    fn fork(&mut self, undone: Vec<Box<dyn Command>>) {
+        let fork = self.applied();
+        // Branches starting from the undone commands go with them
+        let (later, branches) = std::mem::take(&mut self.branches)
+            .into_iter()
+            .partition(|branch| branch.fork > fork);
+        self.branches = branches;
+        self.branches.push(Branch {
+            fork,
+            commands: undone,
+            branches: later,
+        });
+        self.drop_oldest_branches();
+    }
+
+    fn drop_oldest_branches(&mut self) {
+        let excess = self.branches.len().saturating_sub(MAX_BRANCHES);
+        self.branches.drain(..excess);
+    }
+
+    /// Branches that can be switched to, oldest first
+    pub fn branches(&self) -> Vec<BranchSummary> {
+        let applied = self.applied();
+        self.branches
+            .iter()
+            .map(|branch| BranchSummary {
+                undone: applied.saturating_sub(branch.fork),
+                redone: branch.commands.len(),
+                last: branch.commands.first().map(|c| c.description()).unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Columns changed by switching to a branch, by the commands undone or redone to reach where it starts and
+    /// by its own commands, or `None` when there is no such branch
+    pub fn branch_columns(&self, index: usize) -> Option<Vec<usize>> {
+        let branch = self.branches.get(index)?;
+        let applied = self.applied();
+        let undone = applied.saturating_sub(branch.fork).min(self.undo_stack.len());
+        let redone = branch.fork.saturating_sub(applied).min(self.redo_stack.len());
+        let mut columns: Vec<usize> = self.undo_stack[self.undo_stack.len() - undone..]
+            .iter()
+            .chain(&self.redo_stack[self.redo_stack.len() - redone..])
+            .chain(&branch.commands)
+            .flat_map(|command| command.columns())
+            .collect();
+        columns.sort_unstable();
+        columns.dedup();
+
+        Some(columns)
+    }
+
+    /// Goes back to where a branch starts and redoes all its commands, the commands left become a branch in turn
+    ///
+    /// Returns the position of the last command redone, or `None` when there is no such branch.
+    pub fn switch_branch(&mut self, index: usize, board: &mut Board) -> Option<(usize, usize)> {
+        if index >= self.branches.len() {
+            return None;
+        }
+
+        let fork = self.branches[index].fork;
+        self.go_to(fork, board);
+        let branch = self.branches.remove(index);
+        let left = std::mem::replace(&mut self.redo_stack, branch.commands);
+        if !left.is_empty() {
+            self.fork(left);
+        }
+        self.branches.extend(branch.branches);
+        self.drop_oldest_branches();
+        self.checkpoints.retain(|(_, position)| *position <= fork);
+
+        let mut position = None;
+        while let Some(redone) = self.redo(board) {
+            position = Some(redone);
+        }
+        position
+    }
+
+    /// Number of commands applied to the board since the history started, including the dropped ones
+    fn applied(&self) -> usize {
+        self.dropped + self.undo_stack.len()
+    }
+
+    /// Approximate memory used by the commands that can be undone, in bytes
+    pub fn size(&self) -> usize {
+        self.undo_stack.iter().map(|command| command.size()).sum()
+    }
+
+    fn trim(&mut self) {
+        let mut size = self.size();
+        while self.undo_stack.len() > 1 && (self.undo_stack.len() > self.max_commands || size > self.max_bytes) {
+            size -= self.undo_stack.remove(0).size();
+            self.dropped += 1;
+        }
+        let dropped = self.dropped;
+        self.branches.retain(|branch| branch.fork >= dropped);
+    }
+
+    pub fn undo(&mut self, board: &mut Board) -> Option<(usize, usize)> {
+        let mut command = self.undo_stack.pop()?;
+        let position = command.undo(board);
+        self.redo_stack.push(command);
+        Some(position)
+    }
+
+    pub fn redo(&mut self, board: &mut Board) -> Option<(usize, usize)> {
+        let mut command = self.redo_stack.pop()?;
+        let position = command.execute(board);
+        self.undo_stack.push(command);
+        Some(position)
+    }
+
+    /// Undoes the last command that changed a column, even when commands changed other columns since
+    ///
+    /// The command is only undone when none of the later commands changed the other columns it changed, as
+    /// the positions it kept would not match anymore. Undoing out of order loses the checkpoints and the branches.
+    pub fn undo_in_column(&mut self, column_index: usize, board: &mut Board) -> Option<(usize, usize)> {
+        let index = isolated_command(&self.undo_stack, column_index)?;
+        if index + 1 == self.undo_stack.len() {
+            return self.undo(board);
+        }
+
+        let mut command = self.undo_stack.remove(index);
+        let position = command.undo(board);
+        self.redo_stack.push(command);
+        self.checkpoints.clear();
+        self.branches.clear();
+        Some(position)
+    }
+
+    /// Redoes the last command undone in a column, under the same conditions as `undo_in_column`
+    pub fn redo_in_column(&mut self, column_index: usize, board: &mut Board) -> Option<(usize, usize)> {
+        let index = isolated_command(&self.redo_stack, column_index)?;
+        if index + 1 == self.redo_stack.len() {
+            return self.redo(board);
+        }
+
+        let mut command = self.redo_stack.remove(index);
+        let position = command.execute(board);
+        self.undo_stack.push(command);
+        self.checkpoints.clear();
+        self.branches.clear();
+        Some(position)
+    }
+
+    /// Description of the command that was undone last
+    pub fn last_undo_description(&self) -> Option<String> {
+        self.redo_stack.last().map(|command| command.description())
+    }
+
+    /// What the command that was undone last did
+    pub fn last_undo_activity(&self) -> Option<Activity> {
+        self.redo_stack.last().and_then(|command| command.activity())
+    }
+
+    /// What the command that was executed or redone last did
+    pub fn last_redo_activity(&self) -> Option<Activity> {
+        self.undo_stack.last().and_then(|command| command.activity())
+    }
+
+    /// Description of the command that was executed or redone last
+    pub fn last_redo_description(&self) -> Option<String> {
+        self.undo_stack.last().map(|command| command.description())
+    }
+}
+
+/// Index in the stack of the last command that changed the column, if no later command changed its columns
+fn isolated_command(stack: &[Box<dyn Command>], column_index: usize) -> Option<usize> {
+    let index = stack
+        .iter()
+        .rposition(|command| command.columns().contains(&column_index))?;
+    let columns = stack[index].columns();
+    stack[index + 1..]
+        .iter()
+        .all(|later| later.columns().iter().all(|column| !columns.contains(column)))
+        .then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::Local;
+
+    use crate::{
+        app::command::{
+            ChangePriorityCommand, ColumnDirection, InsertCardCommand, InsertColumnCommand, MoveColumnCommand,
+            Priority, RemoveCardCommand, UpdateCardCommand, UpdateNotesCommand,
+        },
+        board::{Board, Card},
+    };
+
+    use super::{History, MAX_BRANCHES, MAX_UNDO_HISTORY};
+
+    #[test]
+    fn rewind_to_checkpoints() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new().max_commands(3);
+        let insert = || Box::new(InsertCardCommand::new(1, 0, Card::new("New card", Local::now())));
+
+        history.checkpoint(1);
+        history.execute(insert(), &mut board);
+        history.checkpoint(2);
+        history.execute(insert(), &mut board);
+        history.checkpoint(3);
+        assert_eq!(3, board.column(1).size());
+
+        assert!(history.rewind(1, &mut board));
+        assert_eq!(1, board.column(1).size());
+        assert!(history.rewind(3, &mut board), "redoes the commands");
+        assert_eq!(3, board.column(1).size());
+        assert!(!history.rewind(4, &mut board));
+
+        history.rewind(2, &mut board);
+        history.execute(insert(), &mut board);
+        assert!(!history.rewind(3, &mut board), "cannot be redone anymore");
+
+        history.execute(insert(), &mut board);
+        history.execute(insert(), &mut board);
+        assert!(!history.rewind(1, &mut board), "commands were dropped");
+        assert!(history.rewind(2, &mut board));
+        assert_eq!(2, board.column(1).size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_and_redo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new();
+
+        assert_eq!(None, history.undo(&mut board));
+        assert_eq!(None, history.redo(&mut board));
+
+        let command = InsertCardCommand::new(1, 1, Card::new("New card", Local::now()));
+        assert_eq!(Some((1, 1)), history.execute(Box::new(command), &mut board));
+        assert_eq!(2, board.column(1).size());
+        assert_eq!(Some("Insert card".into()), history.last_redo_description());
+
+        assert_eq!(Some((1, 0)), history.undo(&mut board));
+        assert_eq!(1, board.column(1).size());
+        assert_eq!(Some("Insert card".into()), history.last_undo_description());
+
+        assert_eq!(Some((1, 1)), history.redo(&mut board));
+        assert_eq!("New card", board.card(1, 1).short_description());
+        assert_eq!(None, history.redo(&mut board));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_op_not_recorded() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new();
+
+        let command = InsertCardCommand::new(1, 1, Card::new("New card", Local::now()));
+        history.execute(Box::new(command), &mut board);
+        history.undo(&mut board);
+
+        let command = ChangePriorityCommand::new(0, 0, Priority::Increase);
+        assert_eq!(None, history.execute(Box::new(command), &mut board));
+        let command = MoveColumnCommand::new(2, 0, ColumnDirection::Right);
+        assert_eq!(None, history.execute(Box::new(command), &mut board));
+        assert_eq!(None, history.undo(&mut board));
+        assert_eq!(Some((1, 1)), history.redo(&mut board), "the undone change is kept");
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_and_redo_in_column() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new();
+        history.checkpoint(1);
+
+        history.execute(Box::new(RemoveCardCommand::new(0, 0)), &mut board);
+        history.execute(Box::new(RemoveCardCommand::new(2, 0)), &mut board);
+        history.execute(Box::new(UpdateNotesCommand::new(2, 0, "Notes")), &mut board);
+
+        assert_eq!(Some((0, 0)), history.undo_in_column(0, &mut board));
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+        assert_eq!(1, board.column(2).size(), "later change in another column kept");
+        assert_eq!("Notes", board.notes());
+        assert_eq!(Some("Remove card".into()), history.last_undo_description());
+        assert_eq!(
+            None,
+            history.undo_in_column(0, &mut board),
+            "nothing else in the column"
+        );
+        assert_eq!(None, history.undo_in_column(1, &mut board));
+        assert!(!history.rewind(1, &mut board), "checkpoints are lost");
+
+        assert_eq!(Some((0, 0)), history.redo_in_column(0, &mut board));
+        assert_eq!("Buy eggs", board.card(0, 0).short_description());
+        assert_eq!(None, history.redo_in_column(0, &mut board));
+
+        history.execute(
+            Box::new(MoveColumnCommand::new(0, 0, ColumnDirection::Right)),
+            &mut board,
+        );
+        history.execute(Box::new(RemoveCardCommand::new(1, 0)), &mut board);
+        assert_eq!(
+            None,
+            history.undo_in_column(0, &mut board),
+            "the moved card was changed in the other column"
+        );
+
+        history.execute(Box::new(RemoveCardCommand::new(2, 0)), &mut board);
+        let size = board.column(2).size();
+        history.execute(Box::new(InsertColumnCommand::new(1, "Ready")), &mut board);
+        assert_eq!(
+            Some((0, 0)),
+            history.undo_in_column(2, &mut board),
+            "the insertion moved the column, it is undone first"
+        );
+        assert_eq!(3, board.columns_count());
+        assert_eq!(size, board.column(2).size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn executing_clears_redo() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new();
+
+        let command = MoveColumnCommand::new(0, 0, ColumnDirection::Right);
+        history.execute(Box::new(command), &mut board);
+        history.undo(&mut board);
+        assert!(history.last_undo_description().is_some());
+
+        let command = MoveColumnCommand::new(2, 0, ColumnDirection::Left);
+        history.execute(Box::new(command), &mut board);
+        assert_eq!(None, history.last_undo_description());
+        assert_eq!(None, history.redo(&mut board));
+
+        Ok(())
+    }
+
+    #[test]
+    fn switch_branches() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new().tree(true);
+        let insert = |title| Box::new(InsertCardCommand::new(1, 0, Card::new(title, Local::now())));
+        let titles = |board: &Board| {
+            board
+                .column(1)
+                .cards_iter()
+                .map(|card| card.short_description().clone())
+                .collect::<Vec<_>>()
+        };
+
+        history.execute(insert("A"), &mut board);
+        history.execute(insert("B"), &mut board);
+        history.undo(&mut board);
+        history.execute(insert("C"), &mut board);
+        assert_eq!(None, history.redo(&mut board));
+        assert_eq!(
+            "1 change ending with 'Insert card', from 1 change back",
+            history.branches()[0].to_string()
+        );
+
+        assert_eq!(Some((1, 0)), history.switch_branch(0, &mut board));
+        assert_eq!(vec!["B", "A", "Cook dinner"], titles(&board));
+        assert_eq!(1, history.branches().len(), "the other branch is kept");
+
+        history.undo(&mut board);
+        history.undo(&mut board);
+        history.execute(insert("D"), &mut board);
+        assert_eq!(
+            1,
+            history.branches().len(),
+            "branches follow the commands they start from"
+        );
+        assert_eq!(
+            "2 changes ending with 'Insert card', from 1 change back",
+            history.branches()[0].to_string()
+        );
+
+        history.switch_branch(0, &mut board);
+        assert_eq!(vec!["B", "A", "Cook dinner"], titles(&board));
+        assert_eq!(2, history.branches().len());
+        history.switch_branch(1, &mut board);
+        assert_eq!(vec!["C", "A", "Cook dinner"], titles(&board));
+        assert_eq!(None, history.switch_branch(2, &mut board));
+
+        Ok(())
+    }
+
+    #[test]
+    fn switching_keeps_branches_bounded() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new().tree(true);
+        let insert = |title: &str| Box::new(InsertCardCommand::new(1, 0, Card::new(title, Local::now())));
+
+        history.execute(insert("A"), &mut board);
+        history.execute(insert("B"), &mut board);
+        for n in 0..MAX_BRANCHES {
+            history.undo(&mut board);
+            history.execute(insert(&format!("Branch {}", n)), &mut board);
+        }
+        history.undo(&mut board);
+        history.undo(&mut board);
+        history.execute(insert("C"), &mut board);
+        assert_eq!(1, history.branches().len());
+        assert_eq!(Some(vec![1]), history.branch_columns(0));
+        assert_eq!(None, history.branch_columns(1));
+
+        history.switch_branch(0, &mut board);
+        assert_eq!(
+            MAX_BRANCHES,
+            history.branches().len(),
+            "the branches of the branch count too"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn history_is_bounded() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new();
+
+        for _ in 0..MAX_UNDO_HISTORY + 10 {
+            let command = MoveColumnCommand::new(0, 0, ColumnDirection::Right);
+            history.execute(Box::new(command), &mut board);
+        }
+
+        let mut undone = 0;
+        while history.undo(&mut board).is_some() {
+            undone += 1;
+        }
+        assert_eq!(MAX_UNDO_HISTORY, undone);
+
+        Ok(())
+    }
+
+    #[test]
+    fn configured_number_of_commands() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new().max_commands(3);
+
+        for _ in 0..5 {
+            let command = MoveColumnCommand::new(0, 0, ColumnDirection::Right);
+            history.execute(Box::new(command), &mut board);
+        }
+
+        let mut undone = 0;
+        while history.undo(&mut board).is_some() {
+            undone += 1;
+        }
+        assert_eq!(3, undone);
+
+        Ok(())
+    }
+
+    #[test]
+    fn history_is_bounded_by_size() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = History::new().max_bytes(45_000);
+
+        let mut card = board.card(0, 0).clone();
+        card.update_long_description(&"a".repeat(10_000));
+        for _ in 0..4 {
+            let command = UpdateCardCommand::new(0, 0, card.clone());
+            history.execute(Box::new(command), &mut board);
+        }
+        assert!(history.size() <= 45_000);
+
+        let mut undone = 0;
+        while history.undo(&mut board).is_some() {
+            undone += 1;
+        }
+        assert_eq!(2, undone, "each command keeps both versions of the card, 20 kB");
+
+        let mut history = History::new().max_bytes(0);
+        history.execute(Box::new(UpdateCardCommand::new(0, 0, card)), &mut board);
+        assert!(history.undo(&mut board).is_some(), "the last command is always kept");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, StatefulWidget, Widget,
+    },
+};
+
+use crate::app::{widget_utils::centered_popup_area, BranchSummary};
+
+/// Lists the branches of the undo history, to switch to changes that were undone before making others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryBrowser {
+    branches: Vec<BranchSummary>,
+    selected: usize,
+}
+
+impl HistoryBrowser {
+    /// Starts with the most recent branch selected
+    pub fn new(branches: Vec<BranchSummary>) -> Self {
+        let selected = branches.len().saturating_sub(1);
+        Self { branches, selected }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.branches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Index of the selected branch in the history
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+}
+
+impl Widget for &HistoryBrowser {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = self.branches.len().clamp(1, 15) as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(70), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" History branches ".bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Select  <CR> Switch to branch  <Esc> Dismiss ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        let items: Vec<Line> = self
+            .branches
+            .iter()
+            .map(|branch| Line::from(format!(" {}", branch)))
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
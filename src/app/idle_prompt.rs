@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::{app::widget_utils::centered_popup_area, utils::time};
+
+/// Asks what to do with the idle period of the timer when the user comes back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlePrompt {
+    idle: Duration,
+}
+
+impl IdlePrompt {
+    pub fn new(idle: Duration) -> Self {
+        Self { idle }
+    }
+}
+
+impl Widget for &IdlePrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(8));
+        Clear.render(area, buf);
+
+        let text = Text::from(vec![
+            Line::from(format!(
+                " No key was pressed for {}, the timer was paused.",
+                time::pretty_duration(self.idle)
+            )),
+            Line::from(""),
+            Line::from(vec![" <k> ".bold(), "Keep the idle time on the card".into()]),
+            Line::from(vec![" <d> ".bold(), "Discard the idle time".into()]),
+        ]);
+
+        let block = Block::bordered()
+            .title(Title::from(" Welcome back ".bold()).alignment(Alignment::Center))
+            .on_blue()
+            .border_set(border::DOUBLE);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,112 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    symbols::border,
+    widgets::{Block, Clear, Widget},
+};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use crate::utils::text;
+
+/// Single line editor of the title of the selected card, drawn over the card itself
+#[derive(Debug, Clone)]
+pub struct InlineTitle<'a> {
+    text_area: TextArea<'a>,
+}
+
+impl PartialEq for InlineTitle<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for InlineTitle<'_> {}
+
+impl InlineTitle<'_> {
+    pub fn new(title: &str) -> Self {
+        let mut text_area = TextArea::new(vec![title.to_string()]);
+        text_area.move_cursor(CursorMove::End);
+
+        let mut inline_title = Self { text_area };
+        inline_title.set_error(None);
+        inline_title
+    }
+
+    pub fn push(&mut self, input: Input) {
+        self.text_area.input(input);
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        let pasted = text::sanitize_paste(pasted);
+        self.text_area.insert_str(pasted.lines().collect::<String>());
+    }
+
+    /// The new title, or None when it would be empty
+    pub fn get(&self) -> Option<String> {
+        let title = self.text_area.lines()[0].trim();
+        if title.is_empty() {
+            None
+        } else {
+            Some(title.to_string())
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        let block = match error {
+            Some(error) => Block::bordered().title(format!(" {} ", error)).red(),
+            None => Block::bordered().title(" Title "),
+        };
+        self.text_area.set_block(block.border_set(border::THICK));
+    }
+}
+
+/// Drawn at the top of `area`, the area of the card being renamed
+impl Widget for &InlineTitle<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = Rect {
+            height: area.height.min(3),
+            ..area
+        };
+        Clear.render(area, buf);
+        self.text_area.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    use crate::TestDriver;
+
+    #[test]
+    fn rename_card_in_place() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("jc");
+        assert!(driver.screen_contains("Title"));
+        driver.press_keys(" and honey");
+        driver.press(KeyEvent::from(KeyCode::Enter));
+        assert_eq!("Buy milk and honey", driver.board().card(0, 0).short_description());
+        assert_eq!(
+            "Buy milk and honey",
+            driver.selected_card().unwrap().short_description()
+        );
+
+        driver.press_keys("c");
+        for _ in 0.."Buy milk and honey".len() {
+            driver.press(KeyEvent::from(KeyCode::Backspace));
+        }
+        driver.press(KeyEvent::from(KeyCode::Enter));
+        assert!(driver.screen_contains("Title cannot be empty"));
+        driver.press(KeyEvent::from(KeyCode::Esc));
+        assert_eq!("Buy milk and honey", driver.board().card(0, 0).short_description());
+
+        driver.press_keys("u");
+        assert_eq!("Buy milk", driver.board().card(0, 0).short_description());
+
+        Ok(())
+    }
+}
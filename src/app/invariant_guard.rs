@@ -0,0 +1,139 @@
+use crate::board::Board;
+
+/// Checks that the board is consistent after each change, to catch a corrupted state before it is written
+///
+/// The checks always run in debug builds and in release builds only when enabled in the settings, a broken
+/// invariant is logged and the application keeps running.
+#[derive(Debug)]
+pub struct BoardInvariantGuard {
+    enabled: bool,
+}
+
+impl BoardInvariantGuard {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: enabled || cfg!(debug_assertions),
+        }
+    }
+
+    /// Diagnostic dump of the broken invariants after `change`, None when the board is consistent
+    pub fn check(&self, board: &Board, selection: Option<(usize, usize)>, change: &str) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let violations = violations(board, selection);
+        if violations.is_empty() {
+            return None;
+        }
+
+        let mut dump = format!("Board inconsistent after '{}': {}", change, violations.join(", "));
+        dump.push_str(&format!(" - selection: {:?}", selection));
+        for (column_index, column) in board.columns_iter().enumerate() {
+            dump.push_str(&format!(
+                " - column {} '{}': {} cards",
+                column_index,
+                column.header(),
+                column.size()
+            ));
+        }
+        Some(dump)
+    }
+}
+
+/// Invariants the board and the selection break, described for the dump
+fn violations(board: &Board, selection: Option<(usize, usize)>) -> Vec<String> {
+    let mut violations = vec![];
+
+    if board.columns_count() == 0 {
+        violations.push("no column".to_string());
+    }
+
+    for (column_index, column) in board.columns_iter().enumerate() {
+        if column.header().trim().is_empty() {
+            violations.push(format!("empty header for column {}", column_index));
+        }
+        let ranks: Vec<f64> = column.cards_iter().map(|card| card.rank()).collect();
+        if !ranks.windows(2).all(|pair| pair[0] < pair[1]) {
+            violations.push(format!("ranks not increasing in column {}", column_index));
+        }
+    }
+
+    let timed: Vec<(usize, usize)> = board
+        .cards_with_positions()
+        .filter(|(_, _, card)| card.is_timed())
+        .map(|(column_index, card_index, _)| (column_index, card_index))
+        .collect();
+    if timed.len() > 1 {
+        violations.push(format!("{} timed cards at {:?}", timed.len(), timed));
+    }
+
+    if let Some((column_index, card_index)) = selection {
+        if column_index >= board.columns_count() {
+            violations.push(format!("selected column {} out of range", column_index));
+        } else {
+            let size = board.column(column_index).size();
+            if card_index >= size.max(1) {
+                violations.push(format!(
+                    "selected card {} out of range in column {}",
+                    card_index, column_index
+                ));
+            }
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::{Board, BoardBuilder};
+
+    use super::{violations, BoardInvariantGuard};
+
+    #[test]
+    fn consistent_board() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        board.set_timed_card(Some((0, 1)));
+
+        assert!(violations(&board, Some((0, 1))).is_empty());
+        assert!(BoardInvariantGuard::new(true)
+            .check(&board, Some((0, 1)), "Test")
+            .is_none());
+
+        let board = BoardBuilder::new().column("Empty", |c| c).build();
+        assert!(violations(&board, Some((0, 0))).is_empty(), "empty column");
+
+        Ok(())
+    }
+
+    #[test]
+    fn broken_invariants() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        board.set_timed_card(Some((0, 1)));
+        let mut card = board.card(2, 0).clone();
+        card.set_timed(true);
+        board.update_card(2, 0, card);
+        board.rename_column(1, " ");
+
+        assert_eq!(
+            vec![
+                "empty header for column 1",
+                "2 timed cards at [(0, 1), (2, 0)]",
+                "selected card 5 out of range in column 0",
+            ],
+            violations(&board, Some((0, 5)))
+        );
+
+        let dump = BoardInvariantGuard::new(true)
+            .check(&board, Some((3, 0)), "Rename column")
+            .expect("Board is inconsistent");
+        assert!(dump.starts_with("Board inconsistent after 'Rename column': "));
+        assert!(dump.contains("selected column 3 out of range"));
+        assert!(dump.contains("column 1 ' ': 1 cards"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,540 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, BufRead, BufReader, ErrorKind, Write},
+};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::command::{
+        ArchiveCardsCommand, ChangePriorityCommand, ColumnDirection, Command, CompositeCommand, InsertCardCommand,
+        InsertColumnCommand, Mark, MarkCardCommand, MoveCardCommand, MoveCardsCommand, MoveColumnCommand, Priority,
+        RemoveCardCommand, RenameColumnCommand, RollOverCardsCommand, SortColumnCommand, UpdateCardCommand,
+        UpdateCardTemplateCommand, UpdateColumnCommand, UpdateNotesCommand,
+    },
+    board::{self, Board, Card, CardTemplate, ColumnOptions, SortPolicy},
+};
+
+const JOURNAL_EXTENSION: &str = "journal";
+
+/// A change made to the board since it was last written, as recorded in the journal
+///
+/// Commands are recorded with what is needed to execute them again. Undoing may revert a command executed
+/// before the board was last written, so undo and redo are recorded as the board they lead to.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum JournalEntry {
+    InsertCard {
+        column_index: usize,
+        card_index: usize,
+        card: Card,
+    },
+    RemoveCard {
+        column_index: usize,
+        card_index: usize,
+    },
+    ChangePriority {
+        column_index: usize,
+        card_index: usize,
+        priority: Priority,
+    },
+    MarkCard {
+        column_index: usize,
+        card_index: usize,
+        mark: Mark,
+        date: DateTime<Local>,
+    },
+    MoveColumn {
+        column_index: usize,
+        card_index: usize,
+        direction: ColumnDirection,
+    },
+    RenameColumn {
+        column_index: usize,
+        card_index: usize,
+        header: String,
+    },
+    InsertColumn {
+        column_index: usize,
+        header: String,
+    },
+    MoveCard {
+        column_index: usize,
+        card_index: usize,
+        new_column_index: usize,
+        slot: usize,
+        date: DateTime<Local>,
+    },
+    MoveCards {
+        column_index: usize,
+        card_index: usize,
+        new_column_index: usize,
+        date: DateTime<Local>,
+    },
+    SortColumn {
+        column_index: usize,
+        card_index: usize,
+        policy: SortPolicy,
+    },
+    UpdateCard {
+        column_index: usize,
+        card_index: usize,
+        card: Card,
+    },
+    UpdateCardTemplate {
+        column_index: usize,
+        card_index: usize,
+        card_template: CardTemplate,
+    },
+    UpdateColumn {
+        column_index: usize,
+        card_index: usize,
+        options: ColumnOptions,
+    },
+    UpdateNotes {
+        column_index: usize,
+        card_index: usize,
+        notes: String,
+    },
+    /// Several changes made as one
+    Composite {
+        description: String,
+        entries: Vec<JournalEntry>,
+    },
+    /// Cards of the last column archived
+    Archive {
+        after_days: u32,
+        date: DateTime<Local>,
+    },
+    /// Unfinished cards of a daily planning column moved back
+    Rollover {
+        column_index: usize,
+        new_column_index: usize,
+        before: DateTime<Local>,
+        date: DateTime<Local>,
+    },
+    /// The whole board after a change that no command describes, like an undo or a merge
+    Board {
+        board: Board,
+    },
+}
+
+impl JournalEntry {
+    /// The command recorded by the entry, none for the changes of the whole board
+    fn command(self) -> Option<Box<dyn Command>> {
+        let command: Box<dyn Command> = match self {
+            Self::InsertCard {
+                column_index,
+                card_index,
+                card,
+            } => Box::new(InsertCardCommand::new(column_index, card_index, card)),
+            Self::RemoveCard {
+                column_index,
+                card_index,
+            } => Box::new(RemoveCardCommand::new(column_index, card_index)),
+            Self::ChangePriority {
+                column_index,
+                card_index,
+                priority,
+            } => Box::new(ChangePriorityCommand::new(column_index, card_index, priority)),
+            Self::MarkCard {
+                column_index,
+                card_index,
+                mark,
+                date,
+            } => Box::new(MarkCardCommand::new(column_index, card_index, mark).at(date)),
+            Self::MoveColumn {
+                column_index,
+                card_index,
+                direction,
+            } => Box::new(MoveColumnCommand::new(column_index, card_index, direction)),
+            Self::RenameColumn {
+                column_index,
+                card_index,
+                header,
+            } => Box::new(RenameColumnCommand::new(column_index, card_index, &header)),
+            Self::InsertColumn { column_index, header } => Box::new(InsertColumnCommand::new(column_index, &header)),
+            Self::MoveCard {
+                column_index,
+                card_index,
+                new_column_index,
+                slot,
+                date,
+            } => Box::new(MoveCardCommand::new(column_index, card_index, new_column_index, slot).at(date)),
+            Self::MoveCards {
+                column_index,
+                card_index,
+                new_column_index,
+                date,
+            } => Box::new(MoveCardsCommand::new(column_index, card_index, new_column_index).at(date)),
+            Self::SortColumn {
+                column_index,
+                card_index,
+                policy,
+            } => Box::new(SortColumnCommand::new(column_index, card_index, policy)),
+            Self::UpdateCard {
+                column_index,
+                card_index,
+                card,
+            } => Box::new(UpdateCardCommand::new(column_index, card_index, card)),
+            Self::UpdateCardTemplate {
+                column_index,
+                card_index,
+                card_template,
+            } => Box::new(UpdateCardTemplateCommand::new(column_index, card_index, card_template)),
+            Self::UpdateColumn {
+                column_index,
+                card_index,
+                options,
+            } => Box::new(UpdateColumnCommand::new(column_index, card_index, options)),
+            Self::UpdateNotes {
+                column_index,
+                card_index,
+                notes,
+            } => Box::new(UpdateNotesCommand::new(column_index, card_index, &notes)),
+            Self::Composite { description, entries } => {
+                let commands = entries.into_iter().filter_map(JournalEntry::command).collect();
+                Box::new(CompositeCommand::new(&description, commands))
+            }
+            Self::Archive { after_days, date } => Box::new(ArchiveCardsCommand::new(after_days, date)),
+            Self::Rollover {
+                column_index,
+                new_column_index,
+                before,
+                date,
+            } => Box::new(RollOverCardsCommand::new(column_index, new_column_index, before, date)),
+            Self::Board { .. } => return None,
+        };
+
+        Some(command)
+    }
+
+    /// Whether the positions of the entry exist on the board, a journal not matching the board would panic
+    fn fits(&self, board: &Board) -> bool {
+        let (column_index, card_index, inserted) = match self {
+            Self::InsertCard {
+                column_index,
+                card_index,
+                ..
+            } => (*column_index, *card_index, true),
+            Self::InsertColumn { column_index, .. } => return *column_index <= board.columns_count(),
+            Self::MoveCard {
+                column_index,
+                card_index,
+                new_column_index,
+                ..
+            } => {
+                if *new_column_index >= board.columns_count() {
+                    return false;
+                }
+                (*column_index, *card_index, false)
+            }
+            Self::MoveCards {
+                column_index,
+                card_index,
+                new_column_index,
+                ..
+            } => {
+                if *new_column_index >= board.columns_count() {
+                    return false;
+                }
+                (*column_index, *card_index, true)
+            }
+            Self::RemoveCard {
+                column_index,
+                card_index,
+            }
+            | Self::ChangePriority {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::MarkCard {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::MoveColumn {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::RenameColumn {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::SortColumn {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::UpdateCard {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::UpdateCardTemplate {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::UpdateColumn {
+                column_index,
+                card_index,
+                ..
+            }
+            | Self::UpdateNotes {
+                column_index,
+                card_index,
+                ..
+            } => (*column_index, *card_index, false),
+            Self::Composite { entries, .. } => return entries.iter().all(|entry| entry.fits(board)),
+            Self::Rollover {
+                column_index,
+                new_column_index,
+                ..
+            } => return (*column_index).max(*new_column_index) < board.columns_count(),
+            Self::Archive { .. } | Self::Board { .. } => return true,
+        };
+        if column_index >= board.columns_count() {
+            return false;
+        }
+
+        let size = board.column(column_index).size();
+        match inserted {
+            true => card_index <= size,
+            false => card_index < size.max(1),
+        }
+    }
+}
+
+/// A line of the journal, the change stamped with the revision of the board file it was made on
+#[derive(Deserialize, Serialize)]
+struct JournalLine<E> {
+    base: Option<u64>,
+    #[serde(flatten)]
+    entry: E,
+}
+
+/// Revision of what is stored for a board, a hash which stays the same from one build to the next
+pub fn file_revision(file_name: &str) -> Option<u64> {
+    let content = board::stored_content(file_name).ok()?;
+    Some(content.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    }))
+}
+
+/// Where the changes made to a board since it was last written are journaled
+fn journal_file_name(board_file_name: &str) -> String {
+    format!("{}.{}", board_file_name, JOURNAL_EXTENSION)
+}
+
+/// Append-only record of the changes made to a board since it was last written
+///
+/// Each change is written, one JSON object per line, before being applied to the board, so that the board
+/// can be rebuilt after a crash by replaying the journal on top of the board file.
+#[derive(Debug)]
+pub struct Journal {
+    file_name: String,
+}
+
+impl Journal {
+    /// The journal of a board file, which is only created with the first change
+    pub fn new(board_file_name: &str) -> Self {
+        Self {
+            file_name: journal_file_name(board_file_name),
+        }
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Number of changes in the journal, a missing journal has none
+    pub fn len(&self) -> io::Result<usize> {
+        Ok(self.entries()?.len())
+    }
+
+    /// Changes of the journal, in the order they were made, a line cut short by a crash ends the journal
+    pub fn entries(&self) -> io::Result<Vec<JournalEntry>> {
+        Ok(self.lines()?.into_iter().map(|line| line.entry).collect())
+    }
+
+    /// Whether all the changes were made on the board file at a revision, see [`file_revision`]
+    ///
+    /// Changes made on another revision, like when the board was written but the journal not cleared yet,
+    /// must not be replayed.
+    pub fn matches(&self, revision: Option<u64>) -> io::Result<bool> {
+        Ok(self
+            .lines()?
+            .iter()
+            .all(|line| line.base.is_some() && line.base == revision))
+    }
+
+    fn lines(&self) -> io::Result<Vec<JournalLine<JournalEntry>>> {
+        let file = match fs::File::open(&self.file_name) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut lines = vec![];
+        for line in BufReader::new(file).lines() {
+            match serde_json::from_str(&line?) {
+                Ok(line) => lines.push(line),
+                Err(_) => break,
+            }
+        }
+        Ok(lines)
+    }
+
+    /// Writes a change made on the board file at a revision at the end of the journal, and makes sure it reached
+    /// the disk
+    pub fn append(&self, base: Option<u64>, entry: &JournalEntry) -> io::Result<()> {
+        let mut line = serde_json::to_string(&JournalLine { base, entry })?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.file_name)?;
+        file.write_all(line.as_bytes())?;
+        file.sync_data()
+    }
+
+    /// Forgets the changes, once the board they apply to is written
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.file_name) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Applies the entries of a journal to the board it was recorded on, returns the number of entries applied
+pub fn replay(board: &mut Board, entries: Vec<JournalEntry>) -> Result<usize, String> {
+    let count = entries.len();
+    for (index, entry) in entries.into_iter().enumerate() {
+        if !entry.fits(board) {
+            return Err(format!(
+                "change {} of {} does not match the board, {} changes replayed",
+                index + 1,
+                count,
+                index
+            ));
+        }
+
+        match entry {
+            JournalEntry::Board { board: replaced } => *board = replaced,
+            entry => {
+                if let Some(mut command) = entry.command() {
+                    command.execute(board);
+                }
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use chrono::Local;
+
+    use crate::{
+        app::command::{Mark, Priority},
+        board::{Board, Card},
+    };
+
+    use super::{replay, Journal, JournalEntry};
+
+    fn journal_for(name: &str) -> Journal {
+        let board_file = env::temp_dir().join(name).display().to_string();
+        let journal = Journal::new(&board_file);
+        journal.clear().expect("Cannot clear journal");
+        journal
+    }
+
+    #[test]
+    fn append_and_read() -> Result<()> {
+        let journal = journal_for("rustyban-journal-append.json");
+        assert_eq!(0, journal.len()?);
+
+        let entries = vec![
+            JournalEntry::InsertCard {
+                column_index: 0,
+                card_index: 1,
+                card: Card::new("Buy bread", Local::now()),
+            },
+            JournalEntry::Archive {
+                after_days: 7,
+                date: Local::now(),
+            },
+        ];
+        for entry in &entries {
+            journal.append(Some(42), entry)?;
+        }
+        let read_entries = journal.entries()?;
+        assert_eq!(serde_json::to_value(&entries)?, serde_json::to_value(&read_entries)?);
+        assert!(journal.matches(Some(42))?);
+        assert!(!journal.matches(Some(7))?);
+        assert!(!journal.matches(None)?);
+
+        let mut content = fs::read_to_string(journal.file_name())?;
+        assert!(content
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with(r#"{"base":42,"op":"archive""#));
+        content.push_str(r#"{"op":"remove_ca"#);
+        fs::write(journal.file_name(), content)?;
+        assert_eq!(2, journal.len()?, "the entry cut short by a crash is ignored");
+
+        journal.clear()?;
+        assert_eq!(0, journal.len()?);
+        journal.clear()
+    }
+
+    #[test]
+    fn replay_on_last_save() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut removed = board.clone();
+        removed.remove_card(0, 2);
+        let entries = vec![
+            JournalEntry::ChangePriority {
+                column_index: 0,
+                card_index: 1,
+                priority: Priority::Increase,
+            },
+            JournalEntry::MarkCard {
+                column_index: 0,
+                card_index: 0,
+                mark: Mark::Done,
+                date: Local::now(),
+            },
+            JournalEntry::RemoveCard {
+                column_index: 0,
+                card_index: 0,
+            },
+            JournalEntry::Board { board: removed },
+        ];
+
+        assert_eq!(Ok(4), replay(&mut board, entries.clone()));
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+        assert_eq!(2, board.column(0).size());
+
+        let mut board = Board::open("res/test_board.json")?;
+        assert_eq!(Ok(3), replay(&mut board, entries[..3].to_vec()));
+        assert_eq!("Buy eggs", board.card(1, 0).short_description());
+        assert_eq!("Buy bread", board.card(0, 0).short_description());
+
+        let entries = vec![JournalEntry::RemoveCard {
+            column_index: 7,
+            card_index: 0,
+        }];
+        assert!(replay(&mut board, entries).unwrap_err().contains("does not match"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// What a key of the normal mode does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    SelectPrevColumn,
+    SelectNextCard,
+    SelectPrevCard,
+    SelectNextColumn,
+    Goto,
+    GotoNumber,
+    SelectBottomCard,
+    SelectPrevColumnTop,
+    SelectNextColumnTop,
+    EditCard,
+    EditTitle,
+    InsertAtCurrentPosition,
+    InsertTop,
+    InsertAtNextPosition,
+    InsertBottom,
+    InsertScratch,
+    RemoveCard,
+    IncreasePriority,
+    DecreasePriority,
+    MarkDone,
+    MarkUndone,
+    MoveCard,
+    MoveColumnLeft,
+    MoveColumnRight,
+    RenameColumn,
+    ColumnSettings,
+    SplitColumn,
+    CycleSortPolicy,
+    ToggleTimer,
+    TogglePomodoro,
+    Window,
+    GrowLog,
+    ShrinkLog,
+    SendCard,
+    Undo,
+    Redo,
+    HistoryBranches,
+    ToggleFocusMode,
+    ToggleSection,
+    ToggleOldDoneCards,
+    ToggleStar,
+    ToggleStarredOnly,
+    CycleDensity,
+    ToggleCardNumbers,
+    DisableSelection,
+    Write,
+    WriteToFile,
+    OpenBoard,
+    ToggleAlternateBoard,
+    Export,
+    CopyBoard,
+    ImportCsv,
+    Reload,
+    ReloadBoard,
+    Sync,
+    Stats,
+    StandUp,
+    Feed,
+    Search,
+    SavedSearches,
+    Tags,
+    Notes,
+    ToggleNotes,
+    Settings,
+    Quit,
+    Help,
+}
+
+impl Action {
+    /// What the action does, as listed in the help
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::SelectPrevColumn => "Select card in previous column",
+            Self::SelectNextCard => "Select next card",
+            Self::SelectPrevCard => "Select previous card",
+            Self::SelectNextColumn => "Select card in next column",
+            Self::Goto => "Select first card of current column (gg) or of a column (g1..g9)",
+            Self::GotoNumber => "Select a card of current column by its number ('1..'99)",
+            Self::SelectBottomCard => "Select last card of current column",
+            Self::SelectPrevColumnTop => "Select first card of previous column",
+            Self::SelectNextColumnTop => "Select first card of next column",
+            Self::EditCard => "Edit selected card",
+            Self::EditTitle => "Edit the title of selected card in place",
+            Self::InsertAtCurrentPosition => "Insert card at current position",
+            Self::InsertTop => "Insert card at the top of current column",
+            Self::InsertAtNextPosition => "Insert card at next position",
+            Self::InsertBottom => "Insert card at the bottom of current column",
+            Self::InsertScratch => "Insert a scratch card, not written to file",
+            Self::RemoveCard => "Delete current card",
+            Self::IncreasePriority => "Increase priority of selected card",
+            Self::DecreasePriority => "Decrease priority of selected card",
+            Self::MarkDone => "Mark selected card done",
+            Self::MarkUndone => "Mark selected card undone",
+            Self::MoveCard => "Move selected card, a ghost shows where it lands",
+            Self::MoveColumnLeft => "Move selected column left",
+            Self::MoveColumnRight => "Move selected column right",
+            Self::RenameColumn => "Rename selected column",
+            Self::ColumnSettings => "Edit settings of selected column, like its WIP limit or color",
+            Self::SplitColumn => "Split selected column beyond its maximum",
+            Self::CycleSortPolicy => "Cycle sort order of selected column",
+            Self::ToggleTimer => "Start or stop the timer on selected card",
+            Self::TogglePomodoro => "Start or stop a pomodoro on selected card",
+            Self::Window => "Then v opens a board in a split, w switches focus, q closes the split",
+            Self::GrowLog => "Grow the log pane",
+            Self::ShrinkLog => "Shrink the log pane",
+            Self::SendCard => "Send selected card to the other board",
+            Self::Undo => "Undo last change",
+            Self::Redo => "Redo last undone change",
+            Self::HistoryBranches => "List the branches of the undo history to switch to one",
+            Self::ToggleFocusMode => "Toggle focus mode on selected column",
+            Self::ToggleSection => "Collapse or expand the section of selected column",
+            Self::ToggleOldDoneCards => "Hide or show cards done long ago",
+            Self::ToggleStar => "Star or unstar selected card",
+            Self::ToggleStarredOnly => "Show only starred cards, or all cards again",
+            Self::CycleDensity => "Cycle card density (compact, normal, detailed)",
+            Self::ToggleCardNumbers => "Show or hide the numbers of the cards",
+            Self::DisableSelection => "Clear the selection",
+            Self::Write => "Write the board to file",
+            Self::WriteToFile => "Write the board to a new file (opens pop up)",
+            Self::OpenBoard => "Open another board, keeping this one to switch back",
+            Self::ToggleAlternateBoard => "Switch between the last two boards opened",
+            Self::Export => "Export the board, a column or search results",
+            Self::CopyBoard => "Copy the board to the clipboard as markdown",
+            Self::ImportCsv => "Import cards from a CSV file",
+            Self::Reload => "Reload the board from its file",
+            Self::ReloadBoard => "Reload, keeping the selected card and logging the changes",
+            Self::Sync => "Sync the board with its remote, after a review",
+            Self::Stats => "Show board statistics",
+            Self::StandUp => "Show what moved since the last stand-up",
+            Self::Feed => "Show the feed of recent changes, <CR> goes to the card",
+            Self::Search => "Search cards, <Ctrl-s> saves the search with the board",
+            Self::SavedSearches => "Run a saved search",
+            Self::Tags => "Rename, merge or delete tags",
+            Self::Notes => "Edit the notes of the board, in a panel next to it",
+            Self::ToggleNotes => "Show or hide the notes of the board",
+            Self::Settings => "View and change the settings",
+            Self::Quit => "Quit the application",
+            Self::Help => "Toggle this help message",
+        }
+    }
+}
+
+/// A key of the normal mode and the action it triggers
+#[derive(Debug, Clone, Copy)]
+pub struct Binding {
+    code: KeyCode,
+    control: bool,
+    action: Action,
+}
+
+const fn key(c: char, action: Action) -> Binding {
+    code(KeyCode::Char(c), action)
+}
+
+const fn code(code: KeyCode, action: Action) -> Binding {
+    Binding {
+        code,
+        control: false,
+        action,
+    }
+}
+
+const fn ctrl(code: KeyCode, action: Action) -> Binding {
+    Binding {
+        code,
+        control: true,
+        action,
+    }
+}
+
+/// Keys of the normal mode, the key handlers and the help both read them from here
+///
+/// The help lists the actions in the order of their first key, with all their keys.
+pub const BINDINGS: &[Binding] = &[
+    key('h', Action::SelectPrevColumn),
+    code(KeyCode::Left, Action::SelectPrevColumn),
+    key('j', Action::SelectNextCard),
+    code(KeyCode::Down, Action::SelectNextCard),
+    key('k', Action::SelectPrevCard),
+    code(KeyCode::Up, Action::SelectPrevCard),
+    key('l', Action::SelectNextColumn),
+    code(KeyCode::Right, Action::SelectNextColumn),
+    key('g', Action::Goto),
+    key('\'', Action::GotoNumber),
+    key('G', Action::SelectBottomCard),
+    key('[', Action::SelectPrevColumnTop),
+    key(']', Action::SelectNextColumnTop),
+    key('e', Action::EditCard),
+    code(KeyCode::Enter, Action::EditCard),
+    key('c', Action::EditTitle),
+    key('i', Action::InsertAtCurrentPosition),
+    key('I', Action::InsertTop),
+    key('a', Action::InsertAtNextPosition),
+    key('A', Action::InsertBottom),
+    key('N', Action::InsertScratch),
+    key('x', Action::RemoveCard),
+    code(KeyCode::Delete, Action::RemoveCard),
+    key('K', Action::IncreasePriority),
+    key('J', Action::DecreasePriority),
+    key('L', Action::MarkDone),
+    key('H', Action::MarkUndone),
+    key('d', Action::MoveCard),
+    ctrl(KeyCode::Char('h'), Action::MoveColumnLeft),
+    ctrl(KeyCode::Left, Action::MoveColumnLeft),
+    ctrl(KeyCode::Char('l'), Action::MoveColumnRight),
+    ctrl(KeyCode::Right, Action::MoveColumnRight),
+    key('R', Action::RenameColumn),
+    key('C', Action::ColumnSettings),
+    key('P', Action::SplitColumn),
+    key('o', Action::CycleSortPolicy),
+    key('t', Action::ToggleTimer),
+    key('p', Action::TogglePomodoro),
+    ctrl(KeyCode::Char('w'), Action::Window),
+    ctrl(KeyCode::Up, Action::GrowLog),
+    ctrl(KeyCode::Down, Action::ShrinkLog),
+    key('>', Action::SendCard),
+    key('u', Action::Undo),
+    key('U', Action::Redo),
+    key('V', Action::HistoryBranches),
+    key('z', Action::ToggleFocusMode),
+    key('Z', Action::ToggleSection),
+    key('D', Action::ToggleOldDoneCards),
+    key('*', Action::ToggleStar),
+    key('m', Action::ToggleStarredOnly),
+    key('v', Action::CycleDensity),
+    key('#', Action::ToggleCardNumbers),
+    code(KeyCode::Esc, Action::DisableSelection),
+    key('w', Action::Write),
+    key('W', Action::WriteToFile),
+    key('b', Action::OpenBoard),
+    key('B', Action::ToggleAlternateBoard),
+    key('E', Action::Export),
+    key('y', Action::CopyBoard),
+    key('M', Action::ImportCsv),
+    key('r', Action::Reload),
+    ctrl(KeyCode::Char('r'), Action::ReloadBoard),
+    key('Y', Action::Sync),
+    key('s', Action::Stats),
+    key('S', Action::StandUp),
+    key('F', Action::Feed),
+    key('/', Action::Search),
+    key('f', Action::SavedSearches),
+    key('T', Action::Tags),
+    key('n', Action::Notes),
+    ctrl(KeyCode::Char('n'), Action::ToggleNotes),
+    key('O', Action::Settings),
+    key('q', Action::Quit),
+    key('?', Action::Help),
+];
+
+/// Action of a key of the normal mode, if it has one
+pub fn action(key_event: &KeyEvent) -> Option<Action> {
+    let control = key_event.modifiers.contains(KeyModifiers::CONTROL);
+    BINDINGS
+        .iter()
+        .find(|binding| binding.code == key_event.code && binding.control == control)
+        .map(|binding| binding.action)
+}
+
+/// Name of a key as shown in the help
+fn key_name(binding: &Binding) -> String {
+    let name = match binding.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Enter => "CR".to_string(),
+        KeyCode::Delete => "DEL".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        other => format!("{:?}", other),
+    };
+    match binding.control {
+        true => format!("Ctrl-{}", name),
+        false => name,
+    }
+}
+
+/// Keys of the normal mode with what they do, one entry per action with all its keys, like `<x/DEL>`
+pub fn help_entries() -> Vec<(String, &'static str)> {
+    let mut actions: Vec<Action> = vec![];
+    for binding in BINDINGS {
+        if !actions.contains(&binding.action) {
+            actions.push(binding.action);
+        }
+    }
+
+    actions
+        .into_iter()
+        .map(|action| {
+            let keys: Vec<String> = BINDINGS
+                .iter()
+                .filter(|binding| binding.action == action)
+                .map(key_name)
+                .collect();
+            (format!("<{}>", keys.join("/")), action.description())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{action, help_entries, Action, BINDINGS};
+
+    #[test]
+    fn keys_bound_once() -> Result<()> {
+        for (i, binding) in BINDINGS.iter().enumerate() {
+            assert!(
+                BINDINGS[i + 1..]
+                    .iter()
+                    .all(|other| (other.code, other.control) != (binding.code, binding.control)),
+                "{:?} bound twice",
+                binding
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn actions_of_keys() -> Result<()> {
+        assert_eq!(Some(Action::RenameColumn), action(&KeyCode::Char('R').into()));
+        assert_eq!(
+            Some(Action::MoveColumnLeft),
+            action(&KeyEvent::new(KeyCode::Char('h'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            Some(Action::GrowLog),
+            action(&KeyEvent::new(KeyCode::Up, KeyModifiers::CONTROL))
+        );
+        assert_eq!(None, action(&KeyCode::Char('Q').into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn help_lists_every_action_once() -> Result<()> {
+        let entries = help_entries();
+        assert!(entries.contains(&("<x/DEL>".to_string(), "Delete current card")));
+        assert!(entries.contains(&("<R>".to_string(), "Rename selected column")));
+        assert!(entries.contains(&("<Ctrl-h/Ctrl-←>".to_string(), "Move selected column left")));
+        assert_eq!(
+            1,
+            entries
+                .iter()
+                .filter(|(_, description)| *description == Action::EditCard.description())
+                .count()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::app::widget_utils::centered_popup_area;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockPrompt {
+    owner: String,
+}
+
+impl LockPrompt {
+    pub fn new(owner: String) -> Self {
+        Self { owner }
+    }
+}
+
+impl Widget for &LockPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(9));
+        Clear.render(area, buf);
+
+        let text = Text::from(vec![
+            Line::from(format!(" This board is already opened by {}.", self.owner)),
+            Line::from(" Saving from both instances would lose changes."),
+            Line::from(""),
+            Line::from(vec![" <r> ".bold(), "Open read-only".into()]),
+            Line::from(vec![" <t> ".bold(), "Take over the lock".into()]),
+            Line::from(vec![" <q> ".bold(), "Quit".into()]),
+        ]);
+
+        let block = Block::bordered()
+            .title(Title::from(" Board locked ".bold()).alignment(Alignment::Center))
+            .on_red()
+            .border_set(border::DOUBLE);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
@@ -7,10 +9,12 @@ use ratatui::{
     widgets::{block::Title, Block, Paragraph, Widget},
 };
 
+const MAX_MESSAGES: usize = 50;
+
 #[derive(Debug)]
 pub struct Logger {
     counter: u32,
-    message: String,
+    messages: VecDeque<String>,
 }
 
 impl Default for Logger {
@@ -23,17 +27,26 @@ impl Logger {
     pub fn new() -> Self {
         Self {
             counter: 0,
-            message: String::new(),
+            messages: VecDeque::new(),
         }
     }
 
     pub fn log(&mut self, msg: String) {
         self.counter += 1;
-        self.message = format!("[{}] {}", self.counter, msg)
+        self.messages.push_back(format!("[{}] {}", self.counter, msg));
+        if self.messages.len() > MAX_MESSAGES {
+            self.messages.pop_front();
+        }
     }
 
+    /// The last message logged
     pub fn show(&self) -> &str {
-        &self.message
+        self.messages.back().map_or("", String::as_str)
+    }
+
+    /// The last messages logged, oldest first
+    pub fn last(&self, count: usize) -> impl Iterator<Item = &String> {
+        self.messages.iter().skip(self.messages.len().saturating_sub(count))
     }
 }
 
@@ -44,9 +57,12 @@ impl Widget for &Logger {
             .title(title.alignment(Alignment::Left))
             .border_set(border::THICK);
 
-        let message = Line::from(vec![" ".into(), self.show().into()]);
+        let lines: Vec<Line> = self
+            .last(block.inner(area).height as usize)
+            .map(|message| Line::from(vec![" ".into(), message.as_str().into()]))
+            .collect();
 
-        Paragraph::new(message).block(block).render(area, buf);
+        Paragraph::new(lines).block(block).render(area, buf);
     }
 }
 
@@ -67,6 +83,14 @@ mod tests {
         logger.log("One more time for the road".into());
         assert_eq!("[3] One more time for the road", logger.show());
 
+        let last: Vec<&String> = logger.last(2).collect();
+        assert_eq!(vec!["[2] Hello again", "[3] One more time for the road"], last);
+
+        for i in 0..MAX_MESSAGES {
+            logger.log(format!("Message {}", i));
+        }
+        assert_eq!(MAX_MESSAGES, logger.last(MAX_MESSAGES * 2).count());
+
         Ok(())
     }
 }
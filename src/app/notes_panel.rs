@@ -0,0 +1,111 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget, Wrap,
+    },
+};
+use tui_textarea::Input;
+
+use crate::{app::text_widget::TextWidget, utils::text};
+
+/// Free text attached to the board, for what does not belong on any single card, edited in the panel next to it
+#[derive(Debug, Clone)]
+pub struct NotesPanel {
+    widget: TextWidget,
+}
+
+impl PartialEq for NotesPanel {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotesPanel {}
+
+impl NotesPanel {
+    pub fn new(notes: &str) -> Self {
+        Self {
+            widget: TextWidget::new("Notes".into(), notes.to_string(), Constraint::Min(1), true),
+        }
+    }
+
+    pub fn input(&mut self, input: Input) {
+        self.widget.input(input);
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        self.widget.paste(&text::sanitize_paste(pasted));
+    }
+
+    pub fn notes(&self) -> String {
+        self.widget.lines().join("\n")
+    }
+}
+
+impl Widget for &NotesPanel {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" Board notes ".bold()).alignment(Alignment::Left))
+            .title(
+                Title::from(Line::from(vec![
+                    " <Ctrl-s> ".bold(),
+                    "Save -".into(),
+                    " <ESC> ".bold(),
+                    "Discard ".into(),
+                ]))
+                .alignment(Alignment::Center)
+                .position(Position::Bottom),
+            )
+            .border_set(border::DOUBLE);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        self.widget.render(inner_area, buf);
+    }
+}
+
+/// Draws the notes of the board next to it, while the board has the focus
+pub fn render_notes(notes: &str, area: Rect, buf: &mut Buffer) {
+    Clear.render(area, buf);
+
+    let block = Block::bordered()
+        .title(Title::from(" Board notes ".bold()).alignment(Alignment::Left))
+        .title(
+            Title::from(Line::from(vec![" <n> ".bold(), "Edit ".into()]))
+                .alignment(Alignment::Center)
+                .position(Position::Bottom),
+        )
+        .border_set(border::THICK);
+    Paragraph::new(notes)
+        .wrap(Wrap { trim: false })
+        .block(block)
+        .render(area, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    use super::NotesPanel;
+
+    #[test]
+    fn edit_notes() -> Result<()> {
+        let mut panel = NotesPanel::new("Sprint goal");
+
+        panel.input(KeyEvent::from(KeyCode::Char(':')).into());
+        panel.paste(" eat\r\n- buy \u{1b}[1mfood\u{1b}[0m");
+
+        assert_eq!("Sprint goal: eat\n- buy food", panel.notes());
+
+        Ok(())
+    }
+}
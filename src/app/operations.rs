@@ -0,0 +1,181 @@
+use crate::board::{Board, Card, CardTemplate, ColumnOptions};
+
+/// Where a new card is inserted, relative to the selected card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertPosition {
+    /// At the position of the selected card, which moves down
+    Current,
+    /// Right after the selected card
+    Next,
+    /// At the top of the selected column
+    Top,
+    /// At the bottom of the selected column
+    Bottom,
+}
+
+/// Operations on a board, as triggered by the keys of the application
+///
+/// This is the stable way to drive an [`App`](crate::App) without the terminal user interface, for scripts,
+/// external tools or tests. Changes to this trait follow semantic versioning: methods are only removed or changed
+/// in a major version, and are deprecated first.
+///
+/// Most operations apply to the selected card or column and do nothing but log a message when nothing is
+/// selected. Operations modifying the board go through the history, so they can be undone.
+///
+/// # Examples
+///
+/// ```
+/// use rustyban::{App, AppOperations, InsertPosition};
+///
+/// // A new board, with no file, has the TODO, Doing and Done! columns
+/// let mut app = App::new(String::new());
+/// app.select_next_card();
+/// app.insert_card(InsertPosition::Top);
+/// app.mark_card_done();
+/// assert_eq!(1, app.board().column(1).size());
+///
+/// app.undo();
+/// assert_eq!(1, app.board().column(0).size());
+/// assert!(app.board().column(1).is_empty());
+/// ```
+pub trait AppOperations {
+    /// Selects the card at the same position in the next column, enables the selection if needed
+    fn select_next_column(&mut self);
+
+    /// Selects the card at the same position in the previous column, enables the selection if needed
+    fn select_prev_column(&mut self);
+
+    /// Selects the next card of the column, enables the selection if needed
+    fn select_next_card(&mut self);
+
+    /// Selects the previous card of the column, enables the selection if needed
+    fn select_prev_card(&mut self);
+
+    /// Selects nothing, the selected position is kept for when the selection is enabled again
+    fn disable_selection(&mut self);
+
+    /// A copy of the current state of the board
+    fn board(&self) -> Board;
+
+    /// A copy of the selected card, none when no card is selected or shown
+    fn get_selected_card(&self) -> Option<Card>;
+
+    /// Replaces the selected card
+    fn update_card(&mut self, card: Card);
+
+    /// Inserts a new card, filled from the template of the column, and selects it, returns the new card
+    fn insert_card(&mut self, position: InsertPosition) -> Option<Card>;
+
+    /// Removes the selected card
+    fn remove_card(&mut self);
+
+    /// Moves the selected card up in its column
+    fn increase_priority(&mut self);
+
+    /// Moves the selected card down in its column
+    fn decrease_priority(&mut self);
+
+    /// Moves the selected card to the top of the next column
+    fn mark_card_done(&mut self);
+
+    /// Moves the selected card to the top of the previous column
+    fn mark_card_undone(&mut self);
+
+    /// Swaps the selected column with the one on its left
+    fn move_column_left(&mut self);
+
+    /// Swaps the selected column with the one on its right
+    fn move_column_right(&mut self);
+
+    /// Header of the selected column, none when no column is selected
+    fn selected_column_header(&self) -> Option<String>;
+
+    /// Renames the selected column
+    fn rename_column(&mut self, header: &str);
+
+    /// Settings of the selected column, like its WIP limit or the template of the cards inserted in it
+    fn selected_column_options(&self) -> Option<ColumnOptions>;
+
+    /// Changes the settings of the selected column as a single change that can be undone
+    fn update_column_options(&mut self, options: ColumnOptions);
+
+    /// Template of the cards inserted in the selected column
+    ///
+    /// Kept with [`AppOperations::update_card_template`] for code written before the other column settings.
+    #[deprecated(note = "use `selected_column_options`, which has the template with the other settings")]
+    fn selected_column_card_template(&self) -> Option<CardTemplate> {
+        self.selected_column_options().map(|options| options.card_template)
+    }
+
+    /// Changes the template of the cards inserted in the selected column, keeping its other settings
+    #[deprecated(note = "use `update_column_options`, which changes the template with the other settings")]
+    fn update_card_template(&mut self, card_template: CardTemplate) {
+        if let Some(options) = self.selected_column_options() {
+            self.update_column_options(ColumnOptions {
+                card_template,
+                ..options
+            });
+        }
+    }
+
+    /// Switches the selected column to the next sort policy, new cards are then inserted according to it
+    fn cycle_sort_policy(&mut self);
+
+    /// Reverts the last change
+    fn undo(&mut self);
+
+    /// Applies the last change undone again
+    fn redo(&mut self);
+
+    /// Writes the board to the file it was read from
+    fn write(&mut self);
+
+    /// Writes the board to another file, which becomes the file of the board
+    fn write_to_file(&mut self, file_name: String);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::{app::App, board::CardTemplate};
+
+    use super::{AppOperations, InsertPosition};
+
+    #[test]
+    fn drive_a_board() -> Result<()> {
+        let mut app = App::new("res/test_board.json".into());
+        app.select_next_card();
+        app.mark_card_done();
+        assert_eq!("Buy milk", app.board().card(1, 0).short_description());
+
+        app.insert_card(InsertPosition::Bottom);
+        assert_eq!("TODO", app.get_selected_card().unwrap().short_description());
+
+        app.undo();
+        app.undo();
+        assert_eq!("Buy milk", app.board().card(0, 0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn card_template_forwarded_to_column_options() -> Result<()> {
+        let mut app = App::new("res/test_board.json".into());
+        app.select_next_card();
+        let template = CardTemplate {
+            title_prefix: "Bug: ".to_string(),
+            ..CardTemplate::default()
+        };
+
+        app.update_card_template(template.clone());
+        assert_eq!(Some(template.clone()), app.selected_column_card_template());
+        assert_eq!(
+            Some(template),
+            app.selected_column_options().map(|options| options.card_template)
+        );
+
+        Ok(())
+    }
+}
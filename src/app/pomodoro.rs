@@ -0,0 +1,97 @@
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Work,
+    Break,
+}
+
+/// Alternates work and break periods, the card timer counts the work periods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pomodoro {
+    phase: Phase,
+    phase_end: Instant,
+    work: Duration,
+    rest: Duration,
+}
+
+impl Pomodoro {
+    /// Starts with a work period, none when the periods are too long to be timed
+    pub fn start(now: Instant, work: Duration, rest: Duration) -> Option<Self> {
+        now.checked_add(rest)?;
+        Some(Self {
+            phase: Phase::Work,
+            phase_end: now.checked_add(work)?,
+            work,
+            rest,
+        })
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.phase_end.saturating_duration_since(now)
+    }
+
+    /// Switches to the next phase once the current one is over, returns the new phase
+    pub fn advance(&mut self, now: Instant) -> Option<Phase> {
+        if now < self.phase_end {
+            return None;
+        }
+
+        let (phase, duration) = match self.phase {
+            Phase::Work => (Phase::Break, self.rest),
+            Phase::Break => (Phase::Work, self.work),
+        };
+        self.phase = phase;
+        self.phase_end = now.checked_add(duration)?;
+        Some(phase)
+    }
+}
+
+/// A period of some minutes, none when it cannot be counted
+pub fn minutes(count: u32) -> Option<Duration> {
+    u64::from(count).checked_mul(60).map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Result,
+        time::{Duration, Instant},
+    };
+
+    use super::{minutes, Phase, Pomodoro};
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn alternate_work_and_break() -> Result<()> {
+        let start = Instant::now();
+        let mut pomodoro = Pomodoro::start(start, 25 * MINUTE, 5 * MINUTE).unwrap();
+
+        assert_eq!(15 * MINUTE, pomodoro.remaining(start + 10 * MINUTE));
+        assert_eq!(None, pomodoro.advance(start + 10 * MINUTE));
+
+        assert_eq!(Some(Phase::Break), pomodoro.advance(start + 25 * MINUTE));
+        assert_eq!(5 * MINUTE, pomodoro.remaining(start + 25 * MINUTE));
+        assert_eq!(Some(Phase::Work), pomodoro.advance(start + 31 * MINUTE));
+        assert_eq!(Phase::Work, pomodoro.phase());
+        assert_eq!(25 * MINUTE, pomodoro.remaining(start + 31 * MINUTE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_long_periods() -> Result<()> {
+        let start = Instant::now();
+
+        assert_eq!(Some(25 * MINUTE), minutes(25));
+        assert_eq!(None, Pomodoro::start(start, Duration::MAX, 5 * MINUTE));
+        assert_eq!(None, Pomodoro::start(start, 25 * MINUTE, Duration::MAX));
+
+        Ok(())
+    }
+}
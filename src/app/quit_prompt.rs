@@ -0,0 +1,52 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::app::widget_utils::centered_popup_area;
+
+/// Asks what to do with the changes not written of the open boards, shown or not, before quitting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuitPrompt {
+    file_names: Vec<String>,
+}
+
+impl QuitPrompt {
+    pub fn new(file_names: Vec<String>) -> Self {
+        Self { file_names }
+    }
+}
+
+impl Widget for &QuitPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = 8 + self.file_names.len() as u16;
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let mut lines = vec![Line::from(" These boards have changes not written:")];
+        lines.extend(
+            self.file_names
+                .iter()
+                .map(|file_name| Line::from(format!("   {}", file_name))),
+        );
+        lines.extend([
+            Line::from(""),
+            Line::from(vec![" <w> ".bold(), "Write the boards and quit".into()]),
+            Line::from(vec![" <d> ".bold(), "Discard the changes and quit".into()]),
+            Line::from(vec![" <Esc> ".bold(), "Keep working on them".into()]),
+        ]);
+
+        let block = Block::bordered()
+            .title(Title::from(" Quit ".bold()).alignment(Alignment::Center))
+            .on_red()
+            .border_set(border::DOUBLE);
+        Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
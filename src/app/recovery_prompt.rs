@@ -0,0 +1,101 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{block::Title, Block, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::{app::widget_utils::centered_popup_area, board::BoardChange};
+
+const MAX_SHOWN_CHANGES: usize = 15;
+
+/// Asks what to do with the board saved after a crash, when it is newer than the board file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryPrompt {
+    recovery_file: String,
+    changes: Option<Result<Vec<BoardChange>, String>>,
+}
+
+impl RecoveryPrompt {
+    pub fn new(recovery_file: String) -> Self {
+        Self {
+            recovery_file,
+            changes: None,
+        }
+    }
+
+    pub fn recovery_file(&self) -> &str {
+        &self.recovery_file
+    }
+
+    pub fn is_showing_changes(&self) -> bool {
+        self.changes.is_some()
+    }
+
+    /// Shows the changes the recovery file would bring, or the reason they cannot be computed
+    pub fn show_changes(&mut self, changes: Result<Vec<BoardChange>, String>) {
+        self.changes = Some(changes);
+    }
+
+    pub fn hide_changes(&mut self) {
+        self.changes = None;
+    }
+
+    fn changes_lines(&self) -> Vec<Line<'_>> {
+        let mut lines = vec![Line::from("")];
+        match &self.changes {
+            None => return vec![],
+            Some(Err(e)) => lines.push(Line::from(format!(" Cannot compare the boards: {}", e)).red()),
+            Some(Ok(changes)) if changes.is_empty() => lines.push(Line::from(" No difference with the saved board")),
+            Some(Ok(changes)) => {
+                lines.extend(
+                    changes
+                        .iter()
+                        .take(MAX_SHOWN_CHANGES)
+                        .map(|change| Line::from(format!(" {}", change))),
+                );
+                if changes.len() > MAX_SHOWN_CHANGES {
+                    lines.push(Line::from(format!(" and {} more", changes.len() - MAX_SHOWN_CHANGES)).dim());
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+impl Widget for &RecoveryPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let changes = self.changes_lines();
+        let height = 10 + changes.len() as u16;
+        let area = centered_popup_area(area, Constraint::Length(70), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let diff_action = if self.is_showing_changes() {
+            "Hide the changes"
+        } else {
+            "Show the changes from the saved board"
+        };
+        let mut lines = vec![
+            Line::from(" The application crashed while this board was opened."),
+            Line::from(format!(" A more recent version was saved to {}.", self.recovery_file)),
+            Line::from(""),
+            Line::from(vec![" <r> ".bold(), "Restore it".into()]),
+            Line::from(vec![" <d> ".bold(), diff_action.into()]),
+            Line::from(vec![" <x> ".bold(), "Discard it".into()]),
+            Line::from(vec![" <Esc> ".bold(), "Decide later, keep the saved board".into()]),
+        ];
+        lines.extend(changes);
+
+        let block = Block::bordered()
+            .title(Title::from(" Recover board ".bold()).alignment(Alignment::Center))
+            .on_blue()
+            .border_set(border::DOUBLE);
+        Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,100 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    widgets::{Block, Clear, Widget},
+};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use super::widget_utils::centered_popup_area;
+use crate::utils::text;
+
+#[derive(Debug, Clone)]
+pub struct RenameColumn<'a> {
+    text_area: TextArea<'a>,
+}
+
+impl PartialEq for RenameColumn<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RenameColumn<'_> {}
+
+impl RenameColumn<'_> {
+    pub fn new(header: &str) -> Self {
+        let mut text_area = TextArea::new(vec![header.to_string()]);
+        text_area.move_cursor(CursorMove::End);
+
+        let mut rename = Self { text_area };
+        rename.set_error(None);
+        rename
+    }
+
+    pub fn push(&mut self, input: Input) {
+        self.text_area.input(input);
+    }
+
+    pub fn paste(&mut self, text: &str) {
+        let text = text::sanitize_paste(text);
+        self.text_area.insert_str(text.lines().collect::<String>());
+    }
+
+    /// The new header, or None when it would be empty
+    pub fn get(&self) -> Option<String> {
+        let header = self.text_area.lines()[0].trim();
+        if header.is_empty() {
+            None
+        } else {
+            Some(header.to_string())
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        let title = match error {
+            Some(error) => format!(" Rename column: {} ", error),
+            None => " Rename column: ".to_string(),
+        };
+
+        let block = Block::bordered().title(title).on_blue().border_set(border::DOUBLE);
+        let block = if error.is_some() { block.red() } else { block };
+        self.text_area.set_block(block);
+    }
+}
+
+impl Widget for &RenameColumn<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(48), Constraint::Length(3));
+        Clear.render(area, buf);
+        self.text_area.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use super::RenameColumn;
+
+    #[test]
+    fn edit_header() -> io::Result<()> {
+        let mut rename = RenameColumn::new("Doing");
+        assert_eq!(Some("Doing".into()), rename.get());
+
+        rename.push(Input::from(KeyEvent::new(KeyCode::Char('!'), KeyModifiers::NONE)));
+        assert_eq!(Some("Doing!".into()), rename.get());
+
+        for _ in 0..6 {
+            rename.push(Input::from(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE)));
+        }
+        rename.paste("   ");
+        assert_eq!(None, rename.get());
+
+        Ok(())
+    }
+}
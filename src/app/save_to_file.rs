@@ -8,6 +8,7 @@ use ratatui::{
 use tui_textarea::{Input, TextArea};
 
 use super::widget_utils::centered_popup_area;
+use crate::utils::text;
 
 #[derive(Debug, Clone)]
 pub struct Save<'a> {
@@ -30,8 +31,12 @@ impl Default for Save<'_> {
 
 impl Save<'_> {
     pub fn new() -> Self {
+        Self::with_title(" Enter path: ")
+    }
+
+    pub fn with_title(title: &str) -> Self {
         let block = Block::bordered()
-            .title(" Enter path: ")
+            .title(title.to_string())
             .on_blue()
             .border_set(border::DOUBLE);
         let mut text_area = TextArea::default();
@@ -44,6 +49,11 @@ impl Save<'_> {
         self.text_area.input(input);
     }
 
+    pub fn paste(&mut self, text: &str) {
+        let text = text::sanitize_paste(text);
+        self.text_area.insert_str(text.lines().collect::<String>());
+    }
+
     pub fn get(&self) -> String {
         self.text_area.lines()[0].clone()
     }
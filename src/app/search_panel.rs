@@ -0,0 +1,257 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, StatefulWidget, Widget,
+    },
+};
+use tui_textarea::{Input, TextArea};
+
+use crate::{
+    app::widget_utils::centered_popup_area,
+    board::{Board, SearchMatch},
+    utils::text,
+};
+
+/// Lists the cards matching a query, to jump to one of them
+///
+/// The query is typed first, then Enter moves to the list of results, browsed with j/k. The query can be
+/// saved with the board under a name, typed in place of the query.
+#[derive(Debug, Clone)]
+pub struct SearchPanel<'a> {
+    text_area: TextArea<'a>,
+    results: Vec<SearchMatch>,
+    selected: usize,
+    browsing: bool,
+    name_area: Option<TextArea<'a>>,
+}
+
+impl PartialEq for SearchPanel<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchPanel<'_> {}
+
+impl SearchPanel<'_> {
+    pub fn new() -> Self {
+        let mut text_area = TextArea::default();
+        text_area.set_block(Block::bordered().title(" Search: ").border_set(border::PLAIN));
+
+        Self {
+            text_area,
+            results: vec![],
+            selected: 0,
+            browsing: false,
+            name_area: None,
+        }
+    }
+
+    /// Runs a query again, like a saved one, starting with its results
+    pub fn with_query(query: &str, board: &Board) -> Self {
+        let mut panel = Self::new();
+        panel.text_area.insert_str(query);
+        panel.update(board);
+        panel.browse(true);
+        panel
+    }
+
+    pub fn push(&mut self, input: Input, board: &Board) {
+        self.text_area.input(input);
+        self.update(board);
+    }
+
+    pub fn paste(&mut self, text: &str, board: &Board) {
+        let text: String = text::sanitize_paste(text).lines().collect();
+        match self.name_area.as_mut() {
+            Some(name_area) => {
+                name_area.insert_str(text);
+            }
+            None => {
+                self.text_area.insert_str(text);
+                self.update(board);
+            }
+        }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.text_area.lines()[0]
+    }
+
+    fn update(&mut self, board: &Board) {
+        self.results = SearchMatch::find(board, self.query());
+        self.selected = 0;
+    }
+
+    pub fn results(&self) -> &[SearchMatch] {
+        &self.results
+    }
+
+    pub fn is_browsing(&self) -> bool {
+        self.browsing
+    }
+
+    /// Moves from the query to the results, only when there are some
+    pub fn browse(&mut self, browsing: bool) {
+        self.browsing = browsing && !self.results.is_empty();
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.results.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected(&self) -> Option<&SearchMatch> {
+        self.results.get(self.selected)
+    }
+
+    /// Asks for a name to save the query under, only when there is a query
+    pub fn start_naming(&mut self) {
+        if self.query().trim().is_empty() {
+            return;
+        }
+
+        let mut name_area = TextArea::default();
+        name_area.set_block(Block::bordered().title(" Save search as: ").border_set(border::PLAIN));
+        self.name_area = Some(name_area);
+    }
+
+    pub fn stop_naming(&mut self) {
+        self.name_area = None;
+    }
+
+    pub fn is_naming(&self) -> bool {
+        self.name_area.is_some()
+    }
+
+    pub fn push_name(&mut self, input: Input) {
+        if let Some(name_area) = self.name_area.as_mut() {
+            name_area.input(input);
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name_area.as_ref().map(|name_area| name_area.lines()[0].as_str())
+    }
+}
+
+impl Widget for &SearchPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Percentage(80), Constraint::Percentage(60));
+        Clear.render(area, buf);
+
+        let [query_area, results_area] = Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        match &self.name_area {
+            Some(name_area) => name_area.render(query_area, buf),
+            None => self.text_area.render(query_area, buf),
+        }
+
+        let header_width = self
+            .results
+            .iter()
+            .map(|result| result.column_header.chars().count())
+            .max()
+            .unwrap_or(0);
+        let items: Vec<Line> = self
+            .results
+            .iter()
+            .map(|result| {
+                Line::from(vec![
+                    format!(" {:width$} │ ", result.column_header, width = header_width).dim(),
+                    result.short_description.clone().bold(),
+                    format!("  {}", result.snippet).italic(),
+                ])
+            })
+            .collect();
+
+        let status = if self.is_naming() {
+            " <CR> Save  <Esc> Cancel "
+        } else if self.browsing {
+            " <j/k> Select  <CR> Jump to card  <D> Delete all  </> Edit query  <Ctrl-s> Save  <Esc> Close "
+        } else {
+            " <CR> Browse results  <Ctrl-s> Save  <Esc> Close "
+        };
+        let block = Block::bordered()
+            .title(Title::from(format!(" {} results ", self.results.len()).bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(status)
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        let highlight = if self.browsing {
+            Style::new().reversed()
+        } else {
+            Style::new()
+        };
+        let list = List::new(items).block(block).highlight_style(highlight);
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, results_area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::Board;
+
+    use super::SearchPanel;
+
+    #[test]
+    fn browse_results() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let mut panel = SearchPanel::new();
+
+        panel.browse(true);
+        assert!(!panel.is_browsing(), "nothing to browse");
+
+        panel.paste("buy", &board);
+        assert_eq!(3, panel.results().len());
+        panel.browse(true);
+        assert!(panel.is_browsing());
+
+        panel.select_prev();
+        assert_eq!("Buy milk", panel.selected().unwrap().short_description);
+        panel.select_next();
+        panel.select_next();
+        panel.select_next();
+        assert_eq!("Buy bread", panel.selected().unwrap().short_description);
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_the_query() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let mut panel = SearchPanel::new();
+
+        panel.start_naming();
+        assert!(!panel.is_naming(), "nothing to save");
+
+        panel.paste("dinner", &board);
+        panel.start_naming();
+        panel.paste("Meals", &board);
+        assert_eq!(Some("Meals"), panel.name());
+        assert_eq!("dinner", panel.query());
+        panel.stop_naming();
+        assert_eq!(None, panel.name());
+
+        let panel = SearchPanel::with_query("buy", &board);
+        assert!(panel.is_browsing());
+        assert_eq!(3, panel.results().len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+
+use crate::{app::widget_utils::centered_popup_area, board::SavedSearch};
+
+/// Lists the searches saved with the board, to run one of them again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPicker {
+    searches: Vec<SavedSearch>,
+    selected: usize,
+}
+
+impl SearchPicker {
+    pub fn new(searches: Vec<SavedSearch>) -> Self {
+        Self { searches, selected: 0 }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.searches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected(&self) -> Option<&SavedSearch> {
+        self.searches.get(self.selected)
+    }
+
+    /// Takes the searches left after one was removed, keeping the selection in the list
+    pub fn refresh(&mut self, searches: Vec<SavedSearch>) {
+        self.searches = searches;
+        self.selected = self.selected.min(self.searches.len().saturating_sub(1));
+    }
+}
+
+impl Widget for &SearchPicker {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = self.searches.len().clamp(1, 15) as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" Saved searches ".bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Select  <CR> Search  <d> Delete  <Esc> Close ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        if self.searches.is_empty() {
+            Paragraph::new(Line::from(" No saved search, save one from the search panel with <Ctrl-s>").italic())
+                .block(block)
+                .render(area, buf);
+            return;
+        }
+
+        let name_width = self
+            .searches
+            .iter()
+            .map(|search| search.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let items: Vec<Line> = self
+            .searches
+            .iter()
+            .map(|search| {
+                Line::from(vec![
+                    format!(" {:width$}", search.name, width = name_width).bold(),
+                    format!("  {}", search.query).dim(),
+                ])
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
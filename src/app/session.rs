@@ -0,0 +1,206 @@
+use std::{
+    env, fs,
+    io::Result,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+const MAX_RECENT_BOARDS: usize = 10;
+const MIN_LOG_HEIGHT: u16 = 3;
+const MAX_LOG_HEIGHT: u16 = 20;
+
+/// State kept between runs of the application, stored in `~/.local/state/rustyban/session.json`
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Session {
+    #[serde(default)]
+    recent_boards: Vec<String>,
+
+    #[serde(default)]
+    layout: PaneLayout,
+
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl Session {
+    /// Loads the session of the user, starting a new one when none can be read
+    pub fn load() -> Self {
+        session_path().map_or_else(Self::default, Self::load_from)
+    }
+
+    /// Loads a session from a file, saved back there
+    fn load_from(path: PathBuf) -> Self {
+        let mut session: Session = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        session.path = Some(path);
+        session
+    }
+
+    /// Writes the session back where it was loaded from, a session not loaded from a file is not saved
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)
+    }
+
+    pub fn recent_boards(&self) -> &[String] {
+        &self.recent_boards
+    }
+
+    /// Moves a board at the top of the recent boards
+    pub fn add_recent_board(&mut self, file_name: &str) {
+        let file_name = fs::canonicalize(file_name)
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| file_name.to_string());
+
+        self.recent_boards.retain(|board| *board != file_name);
+        self.recent_boards.insert(0, file_name);
+        self.recent_boards.truncate(MAX_RECENT_BOARDS);
+    }
+
+    pub fn layout(&self) -> PaneLayout {
+        self.layout
+    }
+
+    pub fn set_layout(&mut self, layout: PaneLayout) {
+        self.layout = layout;
+    }
+}
+
+/// Sizes of the panes around the board, as resized by the user
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PaneLayout {
+    /// Height of the log pane, borders included
+    log_height: u16,
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        Self {
+            log_height: MIN_LOG_HEIGHT,
+        }
+    }
+}
+
+impl PaneLayout {
+    pub fn log_height(&self) -> u16 {
+        self.log_height
+    }
+
+    /// Grows the log pane by one line, returns false when it is already at its largest
+    pub fn grow_log(&mut self) -> bool {
+        self.resize_log(self.log_height + 1)
+    }
+
+    /// Shrinks the log pane by one line, returns false when it is already at its smallest
+    pub fn shrink_log(&mut self) -> bool {
+        self.resize_log(self.log_height.saturating_sub(1))
+    }
+
+    fn resize_log(&mut self, log_height: u16) -> bool {
+        let log_height = log_height.clamp(MIN_LOG_HEIGHT, MAX_LOG_HEIGHT);
+        let resized = log_height != self.log_height;
+        self.log_height = log_height;
+        resized
+    }
+}
+
+fn session_path() -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("state")))?;
+
+    Some(state_home.join("rustyban").join("session.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use super::{PaneLayout, Session, MAX_LOG_HEIGHT, MAX_RECENT_BOARDS, MIN_LOG_HEIGHT};
+
+    #[test]
+    fn recent_boards() -> Result<()> {
+        let mut session = Session::default();
+
+        session.add_recent_board("first.json");
+        session.add_recent_board("second.json");
+        session.add_recent_board("first.json");
+        assert_eq!(
+            vec!["first.json".to_string(), "second.json".to_string()],
+            session.recent_boards()
+        );
+
+        for i in 0..MAX_RECENT_BOARDS * 2 {
+            session.add_recent_board(&format!("board_{}.json", i));
+        }
+        assert_eq!(MAX_RECENT_BOARDS, session.recent_boards().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn saved_and_loaded_again() -> Result<()> {
+        let path = env::temp_dir().join("rustyban-session").join("session.json");
+        let _ = fs::remove_file(&path);
+
+        let mut session = Session::load_from(path.clone());
+        assert!(session.recent_boards().is_empty());
+        session.add_recent_board("board.json");
+        session.save()?;
+        assert_eq!(
+            session.recent_boards(),
+            Session::load_from(path.clone()).recent_boards()
+        );
+
+        assert!(Session::default().save().is_ok(), "not saved anywhere");
+        fs::remove_file(path)
+    }
+
+    #[test]
+    fn existing_files_are_recorded_with_absolute_path() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-recent.json");
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut session = Session::default();
+
+        session.add_recent_board(&file_name.display().to_string());
+        assert!(session.recent_boards()[0].ends_with("/rustyban-recent.json"));
+        assert!(session.recent_boards()[0].starts_with('/'));
+
+        fs::remove_file(file_name)
+    }
+
+    #[test]
+    fn log_pane_resizing() -> Result<()> {
+        let mut layout = PaneLayout::default();
+
+        assert!(!layout.shrink_log());
+        assert_eq!(MIN_LOG_HEIGHT, layout.log_height());
+
+        assert!(layout.grow_log());
+        assert_eq!(MIN_LOG_HEIGHT + 1, layout.log_height());
+
+        while layout.grow_log() {}
+        assert_eq!(MAX_LOG_HEIGHT, layout.log_height());
+
+        let session: Session = serde_json::from_str(r#"{ "recent_boards": [] }"#)?;
+        assert_eq!(PaneLayout::default(), session.layout());
+
+        let mut session = Session::default();
+        session.set_layout(layout);
+        let content = serde_json::to_string(&session)?;
+        let session: Session = serde_json::from_str(&content)?;
+        assert_eq!(MAX_LOG_HEIGHT, session.layout().log_height());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, StatefulWidget, Widget,
+    },
+};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use crate::{app::widget_utils::centered_popup_area, utils::text};
+
+#[derive(Debug, Clone)]
+enum Mode<'a> {
+    Browse,
+    /// Editing the value of the selected setting
+    Edit {
+        text_area: TextArea<'a>,
+    },
+    /// Typing a setting missing from the list, like a WIP limit, as `path = value`
+    Add {
+        text_area: TextArea<'a>,
+    },
+}
+
+/// Lists the effective settings, to change their values and write them to the configuration file
+#[derive(Debug, Clone)]
+pub struct SettingsEditor<'a> {
+    entries: Vec<(String, String)>,
+    selected: usize,
+    mode: Mode<'a>,
+}
+
+impl PartialEq for SettingsEditor<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries && self.selected == other.selected
+    }
+}
+
+impl Eq for SettingsEditor<'_> {}
+
+impl<'a> SettingsEditor<'a> {
+    pub fn new(entries: Vec<(String, String)>) -> Self {
+        Self {
+            entries,
+            selected: 0,
+            mode: Mode::Browse,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Path of the selected setting, like `view.density`
+    pub fn selected(&self) -> Option<&str> {
+        self.entries.get(self.selected).map(|(path, _)| path.as_str())
+    }
+
+    /// Takes the settings after a change, selecting the changed one, and goes back to browsing
+    pub fn refresh(&mut self, entries: Vec<(String, String)>, path: &str) {
+        self.entries = entries;
+        self.selected = match self.entries.iter().position(|(entry, _)| entry == path) {
+            Some(index) => index,
+            None => self.selected.min(self.entries.len().saturating_sub(1)),
+        };
+        self.mode = Mode::Browse;
+    }
+
+    /// Starts editing the value of the selected setting
+    pub fn start_edit(&mut self) {
+        if let Some((_, value)) = self.entries.get(self.selected) {
+            let mut text_area = TextArea::new(vec![value.clone()]);
+            text_area.move_cursor(CursorMove::End);
+            self.mode = Mode::Edit { text_area };
+            self.set_error(None);
+        }
+    }
+
+    /// Starts typing a setting which is not in the list
+    pub fn start_add(&mut self) {
+        self.mode = Mode::Add {
+            text_area: TextArea::default(),
+        };
+        self.set_error(None);
+    }
+
+    /// Goes back to browsing the settings
+    pub fn cancel(&mut self) {
+        self.mode = Mode::Browse;
+    }
+
+    pub fn is_editing(&self) -> bool {
+        !matches!(self.mode, Mode::Browse)
+    }
+
+    fn text_area_mut(&mut self) -> Option<&mut TextArea<'a>> {
+        match &mut self.mode {
+            Mode::Edit { text_area } | Mode::Add { text_area } => Some(text_area),
+            Mode::Browse => None,
+        }
+    }
+
+    pub fn push(&mut self, input: Input) {
+        if let Some(text_area) = self.text_area_mut() {
+            text_area.input(input);
+        }
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        if let Some(text_area) = self.text_area_mut() {
+            let pasted = text::sanitize_paste(pasted);
+            text_area.insert_str(pasted.lines().collect::<String>());
+        }
+    }
+
+    /// Path and new value of the setting being edited, None when a new setting is not typed as `path = value`
+    pub fn edited(&self) -> Option<(String, String)> {
+        match &self.mode {
+            Mode::Browse => None,
+            Mode::Edit { text_area } => {
+                let path = self.selected()?.to_string();
+                Some((path, text_area.lines()[0].trim().to_string()))
+            }
+            Mode::Add { text_area } => {
+                let (path, value) = text_area.lines()[0].split_once('=')?;
+                let path = path.trim();
+                (!path.is_empty()).then(|| (path.to_string(), value.trim().to_string()))
+            }
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        let title = match (&self.mode, self.selected()) {
+            (Mode::Edit { .. }, Some(path)) => format!(" {} ", path),
+            _ => " New setting (path = value) ".to_string(),
+        };
+        if let Some(text_area) = self.text_area_mut() {
+            let title = match error {
+                Some(error) => format!("{}: {} ", title.trim_end(), error),
+                None => title,
+            };
+
+            let block = Block::bordered().title(title).on_blue().border_set(border::DOUBLE);
+            let block = if error.is_some() { block.red() } else { block };
+            text_area.set_block(block);
+        }
+    }
+}
+
+impl Widget for &SettingsEditor<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = self.entries.len().clamp(1, 20) as u16 + 2;
+        let popup_area = centered_popup_area(area, Constraint::Length(72), Constraint::Length(height));
+        Clear.render(popup_area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" Settings ".bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Select  <e> Edit  <a> Add  <Esc> Close ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        let width = self
+            .entries
+            .iter()
+            .map(|(path, _)| path.chars().count())
+            .max()
+            .unwrap_or(0);
+        let items: Vec<Line> = self
+            .entries
+            .iter()
+            .map(|(path, value)| {
+                let value = match value.as_str() {
+                    "" => "not set".italic().dim(),
+                    value => value.to_string().into(),
+                };
+                Line::from(vec![format!(" {:width$}  ", path, width = width).bold(), value])
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, popup_area, buf, &mut state);
+
+        if let Mode::Edit { text_area } | Mode::Add { text_area } = &self.mode {
+            let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(3));
+            Clear.render(area, buf);
+            text_area.render(area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use super::SettingsEditor;
+
+    fn type_text(editor: &mut SettingsEditor, text: &str) {
+        for c in text.chars() {
+            editor.push(Input::from(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+        }
+    }
+
+    #[test]
+    fn edit_and_add() -> io::Result<()> {
+        let mut editor = SettingsEditor::new(vec![
+            ("user.name".into(), "".into()),
+            ("view.density".into(), "\"normal\"".into()),
+        ]);
+        editor.select_next();
+        editor.select_next();
+        assert_eq!(Some("view.density"), editor.selected());
+
+        editor.start_edit();
+        assert_eq!(Some(("view.density".into(), "\"normal\"".into())), editor.edited());
+        editor.cancel();
+        assert!(!editor.is_editing());
+
+        editor.start_add();
+        type_text(&mut editor, "wip_limits.Doing");
+        assert_eq!(None, editor.edited(), "the value is missing");
+        type_text(&mut editor, " = 3");
+        assert_eq!(Some(("wip_limits.Doing".into(), "3".into())), editor.edited());
+
+        editor.refresh(
+            vec![
+                ("user.name".into(), "".into()),
+                ("view.density".into(), "\"normal\"".into()),
+                ("wip_limits.Doing".into(), "3".into()),
+            ],
+            "wip_limits.Doing",
+        );
+        assert!(!editor.is_editing());
+        assert_eq!(Some("wip_limits.Doing"), editor.selected());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Widget,
+    },
+};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use super::widget_utils::centered_popup_area;
+use crate::utils::text;
+
+/// Asks for the header of the column receiving the cards beyond the maximum of the selected column
+#[derive(Debug, Clone)]
+pub struct SplitColumn<'a> {
+    text_area: TextArea<'a>,
+    header: String,
+    moved: usize,
+}
+
+impl PartialEq for SplitColumn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.moved == other.moved
+    }
+}
+
+impl Eq for SplitColumn<'_> {}
+
+impl SplitColumn<'_> {
+    /// Proposes a new column named after the split one, like "TODO (overflow)"
+    pub fn new(header: &str, moved: usize) -> Self {
+        let mut text_area = TextArea::new(vec![format!("{} (overflow)", header)]);
+        text_area.move_cursor(CursorMove::End);
+
+        let mut split = Self {
+            text_area,
+            header: header.into(),
+            moved,
+        };
+        split.set_error(None);
+        split
+    }
+
+    pub fn push(&mut self, input: Input) {
+        self.text_area.input(input);
+    }
+
+    pub fn paste(&mut self, text: &str) {
+        let text = text::sanitize_paste(text);
+        self.text_area.insert_str(text.lines().collect::<String>());
+    }
+
+    /// The header of the new column, or None when it would be empty
+    pub fn get(&self) -> Option<String> {
+        let header = self.text_area.lines()[0].trim();
+        if header.is_empty() {
+            None
+        } else {
+            Some(header.to_string())
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        let title = format!(" Move the last {} card(s) of {} to: ", self.moved, self.header);
+        let title = match error {
+            Some(error) => format!("{}{} ", title, error),
+            None => title,
+        };
+
+        let block = Block::bordered()
+            .title(title)
+            .title(
+                Title::from(" <Enter> Split  <Esc> Cancel ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .on_blue()
+            .border_set(border::DOUBLE);
+        let block = if error.is_some() { block.red() } else { block };
+        self.text_area.set_block(block);
+    }
+}
+
+impl Widget for &SplitColumn<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(3));
+        Clear.render(area, buf);
+        self.text_area.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use super::SplitColumn;
+
+    #[test]
+    fn propose_header() -> io::Result<()> {
+        let mut split = SplitColumn::new("TODO", 5);
+        assert_eq!(Some("TODO (overflow)".into()), split.get());
+
+        for _ in 0.."TODO (overflow)".len() {
+            split.push(Input::from(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE)));
+        }
+        assert_eq!(None, split.get());
+
+        split.paste("Later");
+        assert_eq!(Some("Later".into()), split.get());
+
+        Ok(())
+    }
+}
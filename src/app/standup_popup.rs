@@ -0,0 +1,89 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+
+use crate::{app::widget_utils::centered_popup_area, board::StandUp};
+
+/// What moved on the board since the last business day, to go through at a stand-up meeting
+pub struct StandUpPopup {
+    standup: StandUp,
+}
+
+impl StandUpPopup {
+    pub fn new(standup: StandUp) -> Self {
+        Self { standup }
+    }
+
+    fn lines(&self) -> Vec<Line<'_>> {
+        let since = self.standup.since.format("%A %Y-%m-%d").to_string();
+        if self.standup.is_empty() {
+            return vec![Line::from(format!(" Nothing moved since {}", since)).italic()];
+        }
+
+        let mut lines = vec![Line::from(vec![" Since ".into(), since.bold()])];
+        for (header, cards) in &self.standup.columns {
+            lines.push(Line::default());
+            lines.push(Line::from(format!(" {} ({})", header, cards.len())).bold());
+            for card in cards {
+                let mut line = Line::from(format!(" - {}", card.short_description));
+                if let Some(by) = &card.by {
+                    line.push_span(format!(" ({})", by).dim());
+                }
+                lines.push(line);
+            }
+        }
+        lines
+    }
+}
+
+impl Widget for &StandUpPopup {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lines();
+        let height = lines.len() as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let title = Title::from(" Stand-up ".bold());
+        let status = Title::from(" <y> Copy to clipboard  Any other key to dismiss ");
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(status.alignment(Alignment::Center).position(Position::Bottom))
+            .on_dark_gray()
+            .border_set(border::ROUNDED);
+
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::TestDriver;
+
+    #[test]
+    fn show_standup() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("S");
+        assert!(driver.screen_contains("Stand-up"));
+        assert!(driver.screen_contains("Nothing moved since"));
+
+        driver.press_keys("q").press_keys("jL").press_keys("S");
+        assert!(driver.screen_contains("Doing (1)"));
+        assert!(driver.screen_contains("- Buy milk"));
+
+        driver.press_keys("q");
+        assert!(!driver.screen_contains("Stand-up"));
+
+        Ok(())
+    }
+}
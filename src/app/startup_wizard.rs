@@ -0,0 +1,455 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Stylize,
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+use tui_textarea::{Input, TextArea};
+
+use crate::{
+    app::{session::Session, widget_utils::centered_popup_area},
+    board::Template,
+    utils::text,
+};
+
+const MAX_RECENT_BOARDS_SHOWN: usize = 5;
+
+/// What the user chose in the startup wizard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WizardAction {
+    None,
+    Open(String),
+    Create(Template, String),
+    Empty,
+}
+
+#[derive(Debug, Clone)]
+enum Step<'a> {
+    Menu,
+    Template,
+    Path {
+        template: Template,
+        input: Box<TextArea<'a>>,
+    },
+    Browse {
+        directory: PathBuf,
+        entries: Vec<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MenuEntry {
+    Recent(String),
+    NewBoard,
+    OpenExisting,
+    EmptyBoard,
+}
+
+/// Screen shown when starting without a file, to open a recent board or create a new one
+#[derive(Debug, Clone)]
+pub struct StartupWizard<'a> {
+    session: Session,
+    /// Where browsing for an existing board starts
+    start_directory: PathBuf,
+    step: Step<'a>,
+    selected: usize,
+    error: Option<String>,
+}
+
+impl PartialEq for StartupWizard<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for StartupWizard<'_> {}
+
+impl StartupWizard<'_> {
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            start_directory: PathBuf::from("."),
+            step: Step::Menu,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    pub fn session_mut(&mut self) -> &mut Session {
+        &mut self.session
+    }
+
+    pub fn handle(&mut self, key_event: KeyEvent) -> WizardAction {
+        self.error = None;
+
+        if let Step::Path { template, input } = &mut self.step {
+            let template = *template;
+            return match key_event.code {
+                KeyCode::Esc => {
+                    self.go_to(Step::Template);
+                    WizardAction::None
+                }
+                KeyCode::Enter => {
+                    let path = input.lines()[0].trim().to_string();
+                    self.create(template, path)
+                }
+                _ => {
+                    input.input(Input::from(key_event));
+                    WizardAction::None
+                }
+            };
+        }
+
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.items_count().saturating_sub(1));
+                WizardAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                WizardAction::None
+            }
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => self.choose(),
+            KeyCode::Backspace | KeyCode::Char('h') | KeyCode::Left => {
+                if let Step::Browse { directory, .. } = &self.step {
+                    if let Some(parent) = directory.parent() {
+                        let parent = parent.to_path_buf();
+                        self.browse(parent);
+                    }
+                }
+                WizardAction::None
+            }
+            KeyCode::Esc => match self.step {
+                Step::Menu => WizardAction::Empty,
+                _ => {
+                    self.go_to(Step::Menu);
+                    WizardAction::None
+                }
+            },
+            _ => WizardAction::None,
+        }
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        if let Step::Path { input, .. } = &mut self.step {
+            let pasted = text::sanitize_paste(pasted);
+            input.insert_str(pasted.lines().collect::<String>());
+        }
+    }
+
+    fn menu_entries(&self) -> Vec<MenuEntry> {
+        let mut entries: Vec<MenuEntry> = self
+            .session
+            .recent_boards()
+            .iter()
+            .take(MAX_RECENT_BOARDS_SHOWN)
+            .map(|board| MenuEntry::Recent(board.clone()))
+            .collect();
+        entries.extend([MenuEntry::NewBoard, MenuEntry::OpenExisting, MenuEntry::EmptyBoard]);
+        entries
+    }
+
+    fn items_count(&self) -> usize {
+        match &self.step {
+            Step::Menu => self.menu_entries().len(),
+            Step::Template => Template::all().len(),
+            Step::Path { .. } => 0,
+            Step::Browse { entries, .. } => entries.len(),
+        }
+    }
+
+    fn go_to(&mut self, step: Step<'static>) {
+        self.step = step;
+        self.selected = 0;
+    }
+
+    fn choose(&mut self) -> WizardAction {
+        match &self.step {
+            Step::Menu => match self.menu_entries().get(self.selected) {
+                Some(MenuEntry::Recent(board)) => WizardAction::Open(board.clone()),
+                Some(MenuEntry::NewBoard) => {
+                    self.go_to(Step::Template);
+                    WizardAction::None
+                }
+                Some(MenuEntry::OpenExisting) => {
+                    self.browse(self.start_directory.clone());
+                    WizardAction::None
+                }
+                Some(MenuEntry::EmptyBoard) | None => WizardAction::Empty,
+            },
+            Step::Template => {
+                let template = Template::all()[self.selected];
+                let mut input = Box::new(TextArea::new(vec![format!("{}.json", template.name())]));
+                input.move_cursor(tui_textarea::CursorMove::End);
+                self.go_to(Step::Path { template, input });
+                WizardAction::None
+            }
+            Step::Path { .. } => WizardAction::None,
+            Step::Browse { entries, .. } => match entries.get(self.selected).cloned() {
+                Some(entry) if entry.is_dir() => {
+                    self.browse(entry);
+                    WizardAction::None
+                }
+                Some(entry) => WizardAction::Open(entry.display().to_string()),
+                None => WizardAction::None,
+            },
+        }
+    }
+
+    fn create(&mut self, template: Template, path: String) -> WizardAction {
+        if path.is_empty() {
+            self.error = Some("Path cannot be empty".into());
+            WizardAction::None
+        } else if Path::new(&path).exists() {
+            self.error = Some(format!("{} already exists", path));
+            WizardAction::None
+        } else {
+            WizardAction::Create(template, path)
+        }
+    }
+
+    fn browse(&mut self, directory: PathBuf) {
+        match list_boards(&directory) {
+            Ok(entries) => self.go_to(Step::Browse { directory, entries }),
+            Err(e) => self.error = Some(format!("Cannot read {}: {}", directory.display(), e)),
+        }
+    }
+
+    fn lines(&self) -> (String, Vec<String>) {
+        match &self.step {
+            Step::Menu => (
+                " Welcome to Rustyban ".into(),
+                self.menu_entries()
+                    .iter()
+                    .map(|entry| match entry {
+                        MenuEntry::Recent(board) => format!("Open {}", board),
+                        MenuEntry::NewBoard => "New board...".into(),
+                        MenuEntry::OpenExisting => "Open existing file...".into(),
+                        MenuEntry::EmptyBoard => "Continue with an unsaved empty board".into(),
+                    })
+                    .collect(),
+            ),
+            Step::Template => (
+                " Choose a template ".into(),
+                Template::all()
+                    .iter()
+                    .map(|template| format!("{:<10} {}", template.name(), template.headers().join(" | ")))
+                    .collect(),
+            ),
+            Step::Path { .. } => (" Path of the new board ".into(), vec![]),
+            Step::Browse { directory, entries } => (
+                format!(" {} ", directory.display()),
+                entries
+                    .iter()
+                    .map(|entry| {
+                        let name = entry.file_name().map_or("..".into(), |name| name.to_string_lossy());
+                        if entry.is_dir() {
+                            format!("{}/", name)
+                        } else {
+                            name.to_string()
+                        }
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Lists the sub-directories and board files (`.json` or `.md`) of a directory
+fn list_boards(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut directories = vec![];
+    let mut files = vec![];
+
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+
+        if hidden {
+            continue;
+        } else if path.is_dir() {
+            directories.push(path);
+        } else if path.extension().is_some_and(|ext| ext == "json" || ext == "md") {
+            files.push(path);
+        }
+    }
+
+    directories.sort();
+    files.sort();
+    directories.extend(files);
+    Ok(directories)
+}
+
+impl Widget for &StartupWizard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let area = centered_popup_area(area, Constraint::Length(72), Constraint::Length(16));
+
+        let (title, items) = self.lines();
+        let block = Block::bordered()
+            .title(Title::from(title.bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <j/k> Move - <Enter> Choose - <Esc> Back ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let [content_area, error_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner_area);
+
+        if let Step::Path { input, .. } = &self.step {
+            let [input_area, _] = Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(content_area);
+            let mut input = input.clone();
+            input.set_block(Block::bordered().border_set(border::PLAIN));
+            input.render(input_area, buf);
+        } else {
+            let height = content_area.height as usize;
+            let offset = (self.selected + 1).saturating_sub(height);
+            let lines: Vec<Line> = items
+                .iter()
+                .enumerate()
+                .skip(offset)
+                .map(|(index, item)| {
+                    if index == self.selected {
+                        Line::from(format!("> {}", item).bold().reversed())
+                    } else {
+                        Line::from(format!("  {}", item))
+                    }
+                })
+                .collect();
+            Paragraph::new(lines).render(content_area, buf);
+        }
+
+        if let Some(error) = &self.error {
+            Line::from(error.clone().red().bold()).render(error_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    use crate::{app::session::Session, board::Template};
+
+    use super::{StartupWizard, WizardAction};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        code.into()
+    }
+
+    #[test]
+    fn open_recent_board() -> Result<()> {
+        let mut session = Session::default();
+        session.add_recent_board("other.json");
+        session.add_recent_board("board.json");
+        let mut wizard = StartupWizard::new(session);
+
+        assert_eq!(WizardAction::None, wizard.handle(key(KeyCode::Char('j'))));
+        assert_eq!(
+            WizardAction::Open("other.json".into()),
+            wizard.handle(key(KeyCode::Enter))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_board_from_template() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-startup-create");
+        fs::create_dir_all(&directory)?;
+        let existing = directory.join("board.json");
+        fs::copy("res/test_board.json", &existing)?;
+        let mut wizard = StartupWizard::new(Session::default());
+
+        wizard.handle(key(KeyCode::Enter));
+        wizard.handle(key(KeyCode::Down));
+        wizard.handle(key(KeyCode::Enter));
+
+        for _ in 0.."scrum.json".len() {
+            wizard.handle(key(KeyCode::Backspace));
+        }
+        assert_eq!(WizardAction::None, wizard.handle(key(KeyCode::Enter)));
+        assert_eq!(Some("Path cannot be empty".into()), wizard.error);
+
+        wizard.paste(&existing.display().to_string());
+        assert_eq!(WizardAction::None, wizard.handle(key(KeyCode::Enter)));
+        assert_eq!(Some(format!("{} already exists", existing.display())), wizard.error);
+
+        for _ in 0.."board.json".len() {
+            wizard.handle(key(KeyCode::Backspace));
+        }
+        wizard.paste("sprint.json");
+        assert_eq!(
+            WizardAction::Create(Template::Scrum, directory.join("sprint.json").display().to_string()),
+            wizard.handle(key(KeyCode::Enter))
+        );
+
+        fs::remove_dir_all(directory)
+    }
+
+    #[test]
+    fn browse_files() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-startup-browse");
+        fs::create_dir_all(directory.join("boards"))?;
+        fs::copy("res/test_board.json", directory.join("boards").join("test_board.json"))?;
+        fs::write(directory.join("Cargo.toml"), "")?;
+        let mut wizard = StartupWizard::new(Session::default());
+        wizard.start_directory = directory.clone();
+
+        wizard.handle(key(KeyCode::Down));
+        wizard.handle(key(KeyCode::Enter));
+        let (_, entries) = wizard.lines();
+        let boards = entries
+            .iter()
+            .position(|entry| entry == "boards/")
+            .expect("boards directory is listed");
+        assert!(!entries.iter().any(|entry| entry == "Cargo.toml"));
+
+        for _ in 0..boards {
+            wizard.handle(key(KeyCode::Char('j')));
+        }
+        wizard.handle(key(KeyCode::Enter));
+        let (title, entries) = wizard.lines();
+        assert!(title.contains("boards"));
+        let board = entries.iter().position(|entry| entry == "test_board.json").unwrap();
+
+        for _ in 0..board {
+            wizard.handle(key(KeyCode::Char('j')));
+        }
+        assert_eq!(
+            WizardAction::Open(directory.join("boards").join("test_board.json").display().to_string()),
+            wizard.handle(key(KeyCode::Enter))
+        );
+
+        fs::remove_dir_all(directory)
+    }
+
+    #[test]
+    fn escape_gives_empty_board() -> Result<()> {
+        let mut wizard = StartupWizard::new(Session::default());
+
+        wizard.handle(key(KeyCode::Enter));
+        assert_eq!(WizardAction::None, wizard.handle(key(KeyCode::Esc)));
+        assert_eq!(WizardAction::Empty, wizard.handle(key(KeyCode::Esc)));
+
+        Ok(())
+    }
+}
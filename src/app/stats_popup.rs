@@ -0,0 +1,258 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+
+use crate::{
+    app::widget_utils::centered_popup_area,
+    board::{BoardStatistics, TimeDistribution},
+};
+
+/// Weeks charted in the trend of the columns
+pub const TREND_WEEKS: usize = 12;
+const TREND_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+pub struct StatsPopup {
+    statistics: BoardStatistics,
+    trend: Vec<(String, Vec<usize>)>,
+}
+
+impl StatsPopup {
+    pub fn new(statistics: BoardStatistics) -> Self {
+        Self {
+            statistics,
+            trend: vec![],
+        }
+    }
+
+    /// Cards per column at the end of each week, charted when there are at least two weeks
+    pub fn trend(mut self, trend: Vec<(String, Vec<usize>)>) -> Self {
+        self.trend = trend;
+        self
+    }
+
+    fn lines(&self) -> Vec<Line<'_>> {
+        let throughput = &self.statistics.throughput;
+        let mut lines = vec![
+            Line::from(vec![
+                " Total: ".bold(),
+                format!("{} cards", self.statistics.cards_count()).into(),
+            ]),
+            Line::from(vec![
+                " Completed: ".bold(),
+                format!(
+                    "{} in the last 7 days, {} in the last 30 days",
+                    throughput.last_7_days, throughput.last_30_days
+                )
+                .into(),
+            ]),
+        ];
+
+        for (label, distribution) in [
+            (" Cycle time: ", &self.statistics.cycle_times),
+            (" Lead time: ", &self.statistics.lead_times),
+        ] {
+            if let Some(line) = distribution_line(label, distribution) {
+                lines.push(line);
+            }
+        }
+
+        for column in &self.statistics.columns {
+            lines.push(Line::from(""));
+
+            let mut line = Line::from(vec![
+                format!(" {}: ", column.header).bold(),
+                format!("{} cards", column.cards_count).into(),
+            ]);
+            if let Some(age) = column.oldest_card_age_in_days {
+                line.push_span(format!(", oldest {} days", age));
+            }
+            lines.push(line);
+
+            if let Some(threshold) = column.stale_threshold_in_days {
+                lines.push(Line::from(format!(
+                    "   {} stale (more than {} days)",
+                    column.stale_cards.len(),
+                    threshold
+                )));
+            }
+
+            for stale in &column.stale_cards {
+                lines.push(Line::from(vec![
+                    "   ⚠ ".yellow().bold(),
+                    format!("{} ({} days)", stale.short_description, stale.age_in_days).into(),
+                ]));
+            }
+
+            if column.checklist.total > 0 {
+                lines.push(Line::from(format!(
+                    "   Checklists: {} items done, {} remaining",
+                    column.checklist.done,
+                    column.checklist.remaining()
+                )));
+            }
+        }
+
+        if !self.statistics.ready_cards.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(" Checklist complete, not done yet:".bold()));
+            for ready in &self.statistics.ready_cards {
+                lines.push(Line::from(vec![
+                    "   ✔ ".green().bold(),
+                    format!(
+                        "{} in {} ({} items)",
+                        ready.short_description,
+                        self.statistics.columns[ready.column_index].header,
+                        ready.checklist_items
+                    )
+                    .into(),
+                ]));
+            }
+        }
+
+        let weeks = self.trend.first().map_or(0, |(_, counts)| counts.len());
+        if weeks > 1 {
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(" Trend over {} weeks:", weeks).bold()));
+            let header_width = self
+                .trend
+                .iter()
+                .map(|(header, _)| header.chars().count())
+                .max()
+                .unwrap_or(0);
+            for (header, counts) in &self.trend {
+                lines.push(trend_line(header, header_width, counts));
+            }
+        }
+
+        lines
+    }
+}
+
+/// A bar per week, scaled to the highest count of the column, followed by the last count and its change
+fn trend_line<'a>(header: &str, header_width: usize, counts: &[usize]) -> Line<'a> {
+    let highest = counts.iter().max().copied().unwrap_or(0).max(1);
+    let bars: String = counts
+        .iter()
+        .map(|count| TREND_BARS[count * (TREND_BARS.len() - 1) / highest])
+        .collect();
+    let last = counts.last().copied().unwrap_or(0);
+    let first = counts.first().copied().unwrap_or(0);
+
+    Line::from(vec![
+        format!("   {:<width$} ", header, width = header_width).into(),
+        bars.cyan(),
+        format!(" {} ({:+})", last, last as i64 - first as i64).into(),
+    ])
+}
+
+fn distribution_line<'a>(label: &'a str, distribution: &TimeDistribution) -> Option<Line<'a>> {
+    let (average, median, percentile_85) = (
+        distribution.average_in_hours?,
+        distribution.median_in_hours?,
+        distribution.percentile_85_in_hours?,
+    );
+
+    Some(Line::from(vec![
+        label.bold(),
+        format!(
+            "{}h average, {}h median, {}h 85th percentile",
+            average, median, percentile_85
+        )
+        .into(),
+    ]))
+}
+
+impl Widget for &StatsPopup {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lines();
+        let height = lines.len() as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(60), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let title = Title::from(" Statistics ".bold());
+        let status = Title::from(" Press any key to dismiss ");
+        let block = Block::bordered()
+            .title(title.alignment(Alignment::Center))
+            .title(status.alignment(Alignment::Center).position(Position::Bottom))
+            .on_dark_gray()
+            .border_set(border::ROUNDED);
+
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Result};
+
+    use chrono::Local;
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use crate::{
+        board::{Board, BoardStatistics},
+        TestDriver,
+    };
+
+    use super::StatsPopup;
+
+    #[test]
+    fn show_statistics() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("s");
+        assert!(driver.screen_contains("Statistics"));
+        assert!(driver.screen_contains("Total: 6 cards"));
+        assert!(driver.screen_contains("TODO: 3 cards"));
+        assert!(driver.screen_contains("Completed: 0 in the last 7 days"));
+        assert!(!driver.screen_contains("Cycle time:"));
+
+        driver.press_keys("sllL").press_keys("s");
+        assert!(driver.screen_contains("Cycle time: 0h average"));
+        assert!(driver.screen_contains("Lead time: "));
+
+        driver.press_keys("s");
+        assert!(!driver.screen_contains("Statistics"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trend_of_the_columns() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let statistics = BoardStatistics::compute(&board, &HashMap::new(), Local::now());
+        let popup = StatsPopup::new(statistics).trend(vec![
+            ("TODO".to_string(), vec![8, 6, 3]),
+            ("Doing".to_string(), vec![0, 1, 1]),
+            ("Done!".to_string(), vec![0, 3, 7]),
+        ]);
+
+        let area = Rect::new(0, 0, 80, 40);
+        let mut buffer = Buffer::empty(area);
+        (&popup).render(area, &mut buffer);
+        let screen: Vec<String> = (0..40)
+            .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let line = |text: &str| {
+            screen
+                .iter()
+                .find(|line| line.contains(text))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        assert!(line("Trend over 3 weeks:").contains("Trend over 3 weeks:"));
+        assert!(line("   TODO ").contains("TODO  █▆▃ 3 (-5)"));
+        assert!(line("   Doing ").contains("Doing ▁██ 1 (+1)"));
+        assert!(line("   Done! ").contains("Done! ▁▄█ 7 (+7)"));
+
+        Ok(())
+    }
+}
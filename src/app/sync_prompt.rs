@@ -0,0 +1,85 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, Paragraph, Widget,
+    },
+};
+
+use crate::{
+    app::widget_utils::centered_popup_area,
+    board::{BoardChange, SyncPlan},
+};
+
+/// Changes listed per side, the others are counted
+const MAX_LISTED_CHANGES: usize = 12;
+
+/// Shows what syncing the board with its remote would change on each side, before applying it
+#[derive(Debug, Clone)]
+pub struct SyncPrompt {
+    plan: SyncPlan,
+}
+
+impl PartialEq for SyncPrompt {
+    fn eq(&self, other: &Self) -> bool {
+        self.plan.pulled == other.plan.pulled && self.plan.pushed == other.plan.pushed
+    }
+}
+
+impl Eq for SyncPrompt {}
+
+impl SyncPrompt {
+    pub fn new(plan: SyncPlan) -> Self {
+        Self { plan }
+    }
+
+    pub fn plan(&self) -> &SyncPlan {
+        &self.plan
+    }
+}
+
+/// Lines of the changes of one side, under a title
+fn change_lines(title: String, changes: &[BoardChange]) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(format!(" {}", title).bold())];
+    if changes.is_empty() {
+        lines.push(Line::from("   nothing".italic().dim()));
+    }
+    lines.extend(
+        changes
+            .iter()
+            .take(MAX_LISTED_CHANGES)
+            .map(|change| Line::from(format!("   {}", change))),
+    );
+    if changes.len() > MAX_LISTED_CHANGES {
+        lines.push(Line::from(
+            format!("   and {} more", changes.len() - MAX_LISTED_CHANGES).italic(),
+        ));
+    }
+    lines
+}
+
+impl Widget for &SyncPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut lines = change_lines(format!("Pull from {}", self.plan.remote), &self.plan.pulled);
+        lines.push(Line::default());
+        lines.extend(change_lines(format!("Push to {}", self.plan.remote), &self.plan.pushed));
+
+        let height = lines.len() as u16 + 2;
+        let area = centered_popup_area(area, Constraint::Length(72), Constraint::Length(height));
+        Clear.render(area, buf);
+
+        let block = Block::bordered()
+            .title(Title::from(" Sync plan ".bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(" <Enter> Sync  <Esc> Cancel ")
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
@@ -0,0 +1,242 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::Line,
+    widgets::{
+        block::{Position, Title},
+        Block, Clear, List, ListState, Paragraph, StatefulWidget, Widget,
+    },
+};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use crate::{app::widget_utils::centered_popup_area, utils::text};
+
+#[derive(Debug, Clone)]
+enum Mode<'a> {
+    Browse,
+    Rename { text_area: Box<TextArea<'a>> },
+    Merge { tag: String },
+}
+
+/// Lists the tags of the board with the number of cards using them, to rename, merge or remove them
+#[derive(Debug, Clone)]
+pub struct TagManager<'a> {
+    tags: Vec<(String, usize)>,
+    selected: usize,
+    mode: Mode<'a>,
+}
+
+impl PartialEq for TagManager<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tags == other.tags && self.selected == other.selected
+    }
+}
+
+impl Eq for TagManager<'_> {}
+
+impl TagManager<'_> {
+    pub fn new(tags: Vec<(String, usize)>) -> Self {
+        Self {
+            tags,
+            selected: 0,
+            mode: Mode::Browse,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.tags.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected(&self) -> Option<&str> {
+        self.tags.get(self.selected).map(|(tag, _)| tag.as_str())
+    }
+
+    /// Takes the tags left after a change, keeping the selection in the list, and goes back to browsing
+    pub fn refresh(&mut self, tags: Vec<(String, usize)>) {
+        self.tags = tags;
+        self.selected = self.selected.min(self.tags.len().saturating_sub(1));
+        self.mode = Mode::Browse;
+    }
+
+    /// Starts editing the name of the selected tag
+    pub fn start_rename(&mut self) {
+        if let Some(tag) = self.selected() {
+            let mut text_area = TextArea::new(vec![tag.to_string()]);
+            text_area.move_cursor(CursorMove::End);
+            self.mode = Mode::Rename {
+                text_area: Box::new(text_area),
+            };
+            self.set_error(None);
+        }
+    }
+
+    /// Marks the selected tag to be merged into the tag selected next
+    pub fn start_merge(&mut self) {
+        if let Some(tag) = self.selected() {
+            self.mode = Mode::Merge { tag: tag.to_string() };
+        }
+    }
+
+    /// Goes back to browsing the tags
+    pub fn cancel(&mut self) {
+        self.mode = Mode::Browse;
+    }
+
+    pub fn is_browsing(&self) -> bool {
+        matches!(self.mode, Mode::Browse)
+    }
+
+    pub fn is_renaming(&self) -> bool {
+        matches!(self.mode, Mode::Rename { .. })
+    }
+
+    /// The tag to merge into the selected one, when merging
+    pub fn merged_tag(&self) -> Option<&str> {
+        match &self.mode {
+            Mode::Merge { tag } => Some(tag),
+            _ => None,
+        }
+    }
+
+    pub fn push(&mut self, input: Input) {
+        if let Mode::Rename { text_area } = &mut self.mode {
+            text_area.input(input);
+        }
+    }
+
+    pub fn paste(&mut self, pasted: &str) {
+        if let Mode::Rename { text_area } = &mut self.mode {
+            let pasted = text::sanitize_paste(pasted);
+            text_area.insert_str(pasted.lines().collect::<String>());
+        }
+    }
+
+    /// The new name of the tag being renamed, without its leading `#`, or None when it is empty or has spaces
+    pub fn new_name(&self) -> Option<String> {
+        let Mode::Rename { text_area } = &self.mode else {
+            return None;
+        };
+        let name = text_area.lines()[0].trim().trim_start_matches('#');
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            None
+        } else {
+            Some(name.to_string())
+        }
+    }
+
+    pub fn set_error(&mut self, error: Option<&str>) {
+        if let Mode::Rename { text_area } = &mut self.mode {
+            let title = match error {
+                Some(error) => format!(" Rename tag: {} ", error),
+                None => " Rename tag: ".to_string(),
+            };
+
+            let block = Block::bordered().title(title).on_blue().border_set(border::DOUBLE);
+            let block = if error.is_some() { block.red() } else { block };
+            text_area.set_block(block);
+        }
+    }
+}
+
+impl Widget for &TagManager<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let height = self.tags.len().clamp(1, 15) as u16 + 2;
+        let popup_area = centered_popup_area(area, Constraint::Length(48), Constraint::Length(height));
+        Clear.render(popup_area, buf);
+
+        let (title, keys) = match &self.mode {
+            Mode::Merge { tag } => (
+                format!(" Merge #{} into ", tag),
+                " <j/k> Select  <CR> Merge  <Esc> Cancel ",
+            ),
+            _ => (
+                " Tags ".to_string(),
+                " <j/k> Select  <r> Rename  <m> Merge  <d> Delete  <Esc> Close ",
+            ),
+        };
+        let block = Block::bordered()
+            .title(Title::from(title.bold()).alignment(Alignment::Center))
+            .title(
+                Title::from(keys)
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .border_set(border::ROUNDED);
+
+        if self.tags.is_empty() {
+            Paragraph::new(Line::from(" No card is tagged").italic())
+                .block(block)
+                .render(popup_area, buf);
+            return;
+        }
+
+        let merged = self.merged_tag();
+        let items: Vec<Line> = self
+            .tags
+            .iter()
+            .map(|(tag, count)| {
+                let line = Line::from(vec![format!(" #{}", tag).bold(), format!("  {}", count).dim()]);
+                if Some(tag.as_str()) == merged {
+                    line.italic().dim()
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        let list = List::new(items).block(block).highlight_style(Style::new().reversed());
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, popup_area, buf, &mut state);
+
+        if let Mode::Rename { text_area } = &self.mode {
+            let area = centered_popup_area(area, Constraint::Length(40), Constraint::Length(3));
+            Clear.render(area, buf);
+            text_area.render(area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tui_textarea::Input;
+
+    use super::TagManager;
+
+    #[test]
+    fn rename_and_merge() -> io::Result<()> {
+        let mut manager = TagManager::new(vec![("home".into(), 1), ("shopping".into(), 2)]);
+        manager.select_next();
+        manager.select_next();
+        assert_eq!(Some("shopping"), manager.selected());
+
+        manager.start_rename();
+        assert_eq!(Some("shopping".into()), manager.new_name());
+        manager.push(Input::from(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)));
+        manager.push(Input::from(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)));
+        assert_eq!(None, manager.new_name(), "tags have no spaces");
+        manager.cancel();
+        assert!(manager.is_browsing());
+
+        manager.start_merge();
+        manager.select_prev();
+        assert_eq!(Some("shopping"), manager.merged_tag());
+        assert_eq!(Some("home"), manager.selected());
+
+        manager.refresh(vec![("home".into(), 3)]);
+        assert!(manager.is_browsing());
+        assert_eq!(Some("home"), manager.selected());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,836 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+use crate::app::{App, AppOperations, AppState};
+use crate::board::{Board, Card};
+
+const DEFAULT_WIDTH: u16 = 120;
+const DEFAULT_HEIGHT: u16 = 40;
+
+/// Drives the whole application without a real terminal, for end-to-end tests
+///
+/// Key events go through the same state machine and event handlers as when running in a terminal, and
+/// rendering happens on a ratatui `TestBackend` so the screen content can be inspected.
+///
+/// # Examples
+///
+/// ```
+/// use crossterm::event::KeyCode;
+/// use rustyban::TestDriver;
+///
+/// let mut driver = TestDriver::new("res/test_board.json");
+/// driver.press_keys("jj").press(KeyCode::Char('L').into());
+///
+/// assert_eq!("Buy eggs", driver.board().card(1, 0).short_description());
+/// assert!(driver.screen_contains("Buy eggs"));
+/// ```
+#[derive(Debug)]
+pub struct TestDriver<'a> {
+    app: App,
+    state: AppState<'a>,
+    terminal: Terminal<TestBackend>,
+}
+
+impl TestDriver<'_> {
+    pub fn new(file_name: &str) -> Self {
+        Self::with_size(file_name, DEFAULT_WIDTH, DEFAULT_HEIGHT)
+    }
+
+    pub fn with_size(file_name: &str, width: u16, height: u16) -> Self {
+        let terminal = Terminal::new(TestBackend::new(width, height)).expect("Cannot create test terminal");
+
+        Self {
+            app: App::new(file_name.into()),
+            state: AppState::new(),
+            terminal,
+        }
+    }
+
+    /// Sends a single key event, as if pressed by the user
+    pub fn press(&mut self, key_event: KeyEvent) -> &mut Self {
+        self.state.handle_events(&mut self.app, key_event);
+        self
+    }
+
+    /// Sends text as pasted in the terminal with bracketed paste enabled
+    pub fn paste(&mut self, text: &str) -> &mut Self {
+        self.state.handle_paste(&self.app, text);
+        self
+    }
+
+    /// Sends one key event per character, upper case characters are sent with the shift modifier
+    pub fn press_keys(&mut self, keys: &str) -> &mut Self {
+        for c in keys.chars() {
+            let modifiers = if c.is_uppercase() {
+                KeyModifiers::SHIFT
+            } else {
+                KeyModifiers::NONE
+            };
+            self.press(KeyEvent::new(KeyCode::Char(c), modifiers));
+        }
+        self
+    }
+
+    /// Simulates time passing without any user input
+    pub fn tick(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            self.app.tick();
+        }
+        self
+    }
+
+    pub fn should_continue(&self) -> bool {
+        self.state.should_continue()
+    }
+
+    pub fn board(&self) -> Board {
+        self.app.board()
+    }
+
+    pub fn selected_card(&self) -> Option<Card> {
+        self.app.get_selected_card()
+    }
+
+    /// Changes the size of the terminal, as if the user resized it
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.terminal.backend_mut().resize(width, height);
+        self
+    }
+
+    /// Renders the application and returns the resulting buffer
+    pub fn render(&mut self) -> &Buffer {
+        let (app, state) = (&self.app, &self.state);
+        self.terminal
+            .draw(|frame| state.render(app, frame))
+            .expect("Cannot draw on test terminal");
+        self.terminal.backend().buffer()
+    }
+
+    /// Renders the application and returns the screen content, one string per line
+    pub fn screen(&mut self) -> Vec<String> {
+        let buffer = self.render();
+        let area = buffer.area;
+
+        (area.top()..area.bottom())
+            .map(|y| {
+                (area.left()..area.right())
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    pub fn screen_contains(&mut self, text: &str) -> bool {
+        self.screen().iter().any(|line| line.contains(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result, path::Path};
+
+    use chrono::Local;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::board::{metrics_file_name, Board, Card, CardColor, ColumnOptions};
+
+    use super::TestDriver;
+
+    #[test]
+    fn render_board() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        assert!(driver.screen_contains("TODO"));
+        assert!(driver.screen_contains("Buy milk"));
+        assert!(driver.screen_contains("Wash dishes"));
+        assert!(driver.screen_contains("Logs"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn edit_card_end_to_end() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("je");
+        assert!(driver.screen_contains("Edit card"));
+
+        driver
+            .press(KeyCode::End.into())
+            .press_keys(" and cream")
+            .press(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+        assert!(!driver.screen_contains("Edit card"));
+        assert!(driver.screen_contains("Buy milk and cream"));
+        assert_eq!("Buy milk and cream", driver.board().card(0, 0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn block_saving_invalid_card() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        driver.press_keys("je");
+        for _ in 0..8 {
+            driver.press(KeyCode::Backspace.into());
+        }
+        driver.press(ctrl_s);
+        assert!(driver.screen_contains("Edit card"));
+        assert!(driver.screen_contains("⚠ Cannot be empty"));
+        assert_eq!("Buy milk", driver.board().card(0, 0).short_description());
+
+        driver.press_keys("Buy oat milk").press(ctrl_s);
+        assert!(!driver.screen_contains("Edit card"));
+        assert_eq!("Buy oat milk", driver.board().card(0, 0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn feed_of_changes() -> Result<()> {
+        let mut driver = TestDriver::with_size("res/test_board.json", 140, 30);
+
+        driver.press_keys("jJL");
+        assert!(!driver.screen_contains(" Activity "));
+        driver.press_keys("F");
+        assert!(driver.screen_contains(" Activity "));
+        assert!(driver.screen_contains("Changed the priority of 'Buy milk'"));
+        assert!(driver.screen_contains("Moved 'Buy milk' to Doing"));
+
+        driver.press(KeyCode::Esc.into()).press_keys("uk");
+        assert!(driver.screen_contains("Undid: "));
+        assert_ne!("Buy milk", driver.selected_card().unwrap().short_description());
+
+        driver.press_keys("Fjj").press(KeyCode::Enter.into());
+        assert_eq!("Buy milk", driver.selected_card().unwrap().short_description());
+        assert!(driver.screen_contains(" Activity "), "still shown");
+
+        driver.press_keys("FF");
+        assert!(!driver.screen_contains(" Activity "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_change_without_visible_cards() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("jm");
+        assert_eq!(None, driver.selected_card(), "no starred card");
+        driver.press_keys("xLKJ");
+
+        let board = driver.board();
+        assert_eq!(3, board.column(0).size());
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+        assert_eq!(1, board.column(1).size());
+        assert!(!driver.screen_contains("Card deleted"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_toast_disappears() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        let occurrences = |driver: &mut TestDriver| {
+            let screen = driver.screen();
+            screen.iter().filter(|line| line.contains("Undid: Remove card")).count()
+        };
+
+        driver.press_keys("jxu");
+        assert_eq!(2, occurrences(&mut driver), "toast and log pane");
+
+        driver.tick(20);
+        assert_eq!(1, occurrences(&mut driver), "log pane only");
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_column() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("llR");
+        assert!(driver.screen_contains("Rename column"));
+
+        for _ in 0.."Doing".len() {
+            driver.press(KeyCode::Backspace.into());
+        }
+        driver.press(KeyCode::Enter.into());
+        assert!(driver.screen_contains("name cannot be empty"));
+
+        driver.press_keys("In progress").press(KeyCode::Enter.into());
+        assert!(!driver.screen_contains("Rename column"));
+        assert_eq!("In progress", driver.board().column(1).header());
+
+        driver.press_keys("u");
+        assert_eq!("Doing", driver.board().column(1).header());
+
+        Ok(())
+    }
+
+    #[test]
+    fn priority_badges() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        let screen = driver.screen();
+        assert!(screen.iter().any(|line| line.contains("╭ 1 ─")));
+        assert!(screen.iter().any(|line| line.contains("╭ 3 ─")));
+        assert!(!screen.iter().any(|line| line.contains("╭ 4 ─")));
+        assert!(
+            !screen.iter().any(|line| line.contains("┄┄┄")),
+            "no separator with 3 cards"
+        );
+
+        driver.press_keys("jA").press(KeyCode::Esc.into());
+        assert!(driver.screen_contains("┄┄┄"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_card_with_ghost() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let titles = |driver: &TestDriver, column_index: usize| -> Vec<String> {
+            let board = driver.board();
+            board
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().to_string())
+                .collect()
+        };
+
+        driver.press_keys("jdlj");
+        assert!(driver.screen_contains("┆"), "ghost after Cook dinner");
+        assert_eq!(vec!["Cook dinner"], titles(&driver, 1));
+
+        driver.press(KeyCode::Enter.into());
+        assert!(!driver.screen_contains("┆"));
+        assert_eq!(vec!["Cook dinner", "Buy milk"], titles(&driver, 1));
+        assert_eq!("Buy milk", driver.selected_card().unwrap().short_description());
+
+        driver.press_keys("dh").press(KeyCode::Esc.into());
+        assert!(!driver.screen_contains("┆"));
+        assert_eq!(vec!["Cook dinner", "Buy milk"], titles(&driver, 1));
+
+        driver.press_keys("u");
+        assert_eq!(vec!["Buy milk", "Buy eggs", "Buy bread"], titles(&driver, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn column_card_template() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("llC");
+        assert!(driver.screen_contains("Doing settings"));
+
+        driver
+            .paste("[BUG] ")
+            .press(KeyCode::Tab.into())
+            .paste("bug")
+            .press(KeyCode::Enter.into());
+        assert!(!driver.screen_contains("Doing settings"));
+
+        driver.press_keys("A");
+        assert!(driver.screen_contains("Edit card"));
+        driver
+            .press_keys("crash")
+            .press(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+        let card = driver.board().card(1, 1).clone();
+        assert_eq!("[BUG] crash", card.short_description());
+        assert_eq!(vec!["bug".to_string()], card.tags());
+
+        Ok(())
+    }
+
+    #[test]
+    fn undo_column_settings() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("lC");
+        for _ in 0..3 {
+            driver.press(KeyCode::Tab.into());
+        }
+        driver
+            .paste("2")
+            .press(KeyCode::Tab.into())
+            .press_keys("l")
+            .press(KeyCode::Tab.into())
+            .press(KeyCode::Tab.into())
+            .press_keys("l")
+            .press(KeyCode::Enter.into());
+        assert!(driver.screen_contains("TODO 3/2"));
+        assert!(driver.screen_contains("🔒"));
+
+        let options = driver.board().column(0).options();
+        assert_eq!(Some(2), options.wip_limit);
+        assert_eq!(Some(CardColor::Red), options.color);
+        assert!(options.read_only);
+
+        driver.press_keys("u");
+        assert_eq!(ColumnOptions::default(), driver.board().column(0).options());
+        assert!(driver.screen_contains(" TODO "));
+        assert!(!driver.screen_contains("3/2"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_boards() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-split-boards");
+        fs::create_dir_all(&directory)?;
+        let main = directory.join("main.json").display().to_string();
+        let other = directory.join("other.md").display().to_string();
+        fs::copy("res/test_board.json", &main)?;
+        fs::copy("res/test_board.md", &other)?;
+        let mut driver = TestDriver::new(&main);
+        let ctrl_w = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+
+        driver
+            .press(ctrl_w)
+            .press_keys("v")
+            .paste(&other)
+            .press(KeyCode::Enter.into());
+        assert!(driver.screen_contains(&format!(" {} ", other)));
+        assert!(driver.screen_contains(&format!(" {} ", main)));
+
+        driver.press_keys("jj>");
+        assert!(driver.screen_contains(&format!("Sent 'Buy eggs' to {}", main)));
+        assert_eq!("Buy eggs", driver.board().card(0, 0).short_description());
+        assert_eq!(4, driver.board().column(0).size());
+        assert_eq!(4, Board::open(&main)?.column(0).size(), "both boards written");
+        assert_eq!(2, Board::open(&other)?.column(0).size());
+
+        driver.press_keys("x").press(ctrl_w).press_keys("q");
+        assert!(
+            driver.screen_contains(" Close split "),
+            "changes not written in the split"
+        );
+        driver.press(KeyCode::Esc.into());
+        assert!(driver.screen_contains(&format!(" {} ", other)));
+
+        driver.press(ctrl_w).press_keys("qd");
+        let headers = |line: &String| line.contains(&main) && line.contains(&other);
+        assert!(!driver.screen().iter().any(headers), "single pane left");
+        assert_eq!(2, Board::open(&other)?.column(0).size(), "changes discarded");
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn switch_to_alternate_board() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let open = |driver: &mut TestDriver, file_name: &str| {
+            driver.press_keys("b").paste(file_name).press(KeyCode::Enter.into());
+        };
+
+        driver.press_keys("B");
+        assert!(driver.screen_contains("No other board opened"));
+
+        driver.press_keys("jj");
+        open(&mut driver, "res/test_board.md");
+        assert!(driver.screen_contains("Opened res/test_board.md, <B> goes back to res/test_board.json"));
+        driver.press_keys("jjjK");
+        assert_eq!("Buy bread", driver.board().card(0, 1).short_description());
+
+        driver.press_keys("B");
+        assert!(driver.screen_contains("Switched to res/test_board.json"));
+        assert_eq!("Buy eggs", driver.selected_card().unwrap().short_description());
+        assert_eq!("Buy eggs", driver.board().card(0, 1).short_description());
+
+        open(&mut driver, "res/test_board.json");
+        assert!(driver.screen_contains("res/test_board.json is already open"));
+        open(&mut driver, "res/roadmap.json");
+        assert!(driver.screen_contains("res/test_board.md has changes not written"));
+
+        open(&mut driver, "res/test_board.md");
+        assert_eq!(
+            "Buy bread",
+            driver.selected_card().unwrap().short_description(),
+            "as it was left"
+        );
+        assert_eq!("Buy bread", driver.board().card(0, 1).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_log_pane() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+
+        driver.press_keys("uU");
+        assert!(driver.screen_contains("Nothing to redo"));
+        assert!(!driver.screen_contains("Nothing to undo"), "only the last message fits");
+
+        driver.press(ctrl(KeyCode::Up));
+        assert!(driver.screen_contains("Nothing to undo"));
+        assert!(driver.screen_contains("Nothing to redo"));
+
+        driver.press(ctrl(KeyCode::Down)).press(ctrl(KeyCode::Down));
+        assert!(driver.screen_contains("The log pane cannot be resized further"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_to_column_ends() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let selected = |driver: &TestDriver| driver.selected_card().unwrap().short_description().clone();
+
+        driver.press_keys("G");
+        assert_eq!("Buy bread", selected(&driver));
+        driver.press_keys("gg");
+        assert_eq!("Buy milk", selected(&driver));
+
+        driver.press_keys("g3");
+        assert_eq!("Eat dinner", selected(&driver));
+        driver.press_keys("G[");
+        assert_eq!("Cook dinner", selected(&driver));
+        driver.press_keys("]]");
+        assert_eq!("Eat dinner", selected(&driver));
+
+        driver.press_keys("g9");
+        assert_eq!("Eat dinner", selected(&driver));
+        assert!(driver.screen_contains("No column 9"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_to_card_number() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let selected = |driver: &TestDriver| driver.selected_card().unwrap().short_description().clone();
+
+        assert!(!driver.screen_contains("#3"));
+        driver.press_keys("#");
+        assert!(driver.screen_contains("#3"));
+
+        driver.press_keys("j'3");
+        assert_eq!("Buy bread", selected(&driver));
+        driver.press_keys("'4");
+        assert_eq!("Buy bread", selected(&driver));
+        assert!(driver.screen_contains("No card 4 in TODO"));
+
+        driver.press_keys("#");
+        assert!(!driver.screen_contains("#3"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn preview_selected_card() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("j");
+        assert!(
+            !driver.screen_contains("This is a long explanation"),
+            "not right after moving"
+        );
+        driver.tick(2);
+        assert!(driver.screen_contains("│This is a long explanation"));
+
+        driver.press_keys("j");
+        assert!(
+            !driver.screen_contains("This is a long explanation"),
+            "dismissed when moving"
+        );
+        driver.tick(2);
+        assert!(driver.screen_contains("│This is a long explanation"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_empty_selected_column() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board_with_empty_column.json");
+
+        driver.press_keys("jl").tick(2);
+        assert!(driver.screen_contains("Doing"));
+        assert_eq!(None, driver.selected_card());
+
+        driver.press_keys("z");
+        assert!(driver.screen_contains("Doing"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn saved_searches() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("f");
+        assert!(driver.screen_contains("No saved search"));
+        driver.press(KeyCode::Esc.into());
+
+        driver
+            .press_keys("/dinner")
+            .press(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+            .press_keys("Meals")
+            .press(KeyCode::Enter.into())
+            .press(KeyCode::Esc.into());
+        assert_eq!("dinner", driver.board().saved_searches()[0].query);
+        assert!(driver.screen_contains("Saved search 'Meals'"));
+
+        driver.press_keys("f");
+        assert!(driver.screen_contains(" Meals  dinner"));
+        driver.press(KeyCode::Enter.into());
+        assert!(driver.screen_contains(" 2 results "));
+        driver.press(KeyCode::Enter.into());
+        assert_eq!("Cook dinner", driver.selected_card().unwrap().short_description());
+
+        driver.press_keys("fd");
+        assert!(driver.board().saved_searches().is_empty());
+        assert!(driver.screen_contains("No saved search"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn terminal_too_small() -> Result<()> {
+        let mut driver = TestDriver::with_size("res/test_board.json", 60, 20);
+        assert!(driver.screen_contains("need 80x24, got 60x20"));
+        assert!(!driver.screen_contains("Buy milk"));
+
+        driver.press_keys("jx").resize(100, 30);
+        assert!(!driver.screen_contains("Terminal too small"));
+        assert!(
+            driver.screen_contains("Buy milk"),
+            "keys are ignored while the board is hidden"
+        );
+        assert_eq!(None, driver.selected_card());
+
+        driver.resize(60, 20).render();
+        driver.press_keys("q");
+        assert!(!driver.should_continue());
+
+        Ok(())
+    }
+
+    #[test]
+    fn board_notes() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+        driver.press_keys("nSprint goal");
+        assert!(driver.screen_contains(" Board notes "));
+        driver.press(KeyCode::Esc.into());
+        assert_eq!("", driver.board().notes(), "discarded");
+
+        driver.press_keys("nSprint goal").press(ctrl_s);
+        assert_eq!("Sprint goal", driver.board().notes());
+        assert!(
+            driver.screen_contains(" Board notes "),
+            "the panel stays next to the board"
+        );
+        assert!(driver.screen_contains("Sprint goal"));
+
+        driver.press_keys("j");
+        assert_eq!("Sprint goal", driver.board().notes(), "the board has the focus back");
+        driver.press(ctrl_n);
+        assert!(!driver.screen_contains(" Board notes "));
+        driver.press(ctrl_n);
+        assert!(driver.screen_contains("Sprint goal"));
+
+        driver.press_keys("u");
+        assert_eq!("", driver.board().notes(), "undone");
+
+        Ok(())
+    }
+
+    #[test]
+    fn save_conflict() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-conflict.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let mut driver = TestDriver::new(&file_name);
+        let change_on_disk = |title: &str| -> Result<()> {
+            let mut board = Board::open(&file_name)?;
+            board.insert_card(1, 0, Card::new(title, Local::now()));
+            board.to_file(&file_name)
+        };
+
+        driver.press_keys("jL");
+        change_on_disk("Set the table")?;
+        driver.press_keys("w");
+        assert!(driver.screen_contains(" File changed "));
+        driver.press(KeyCode::Esc.into());
+        assert_eq!(
+            "Set the table",
+            Board::open(&file_name)?.card(1, 0).short_description(),
+            "not written"
+        );
+
+        driver.press_keys("wm");
+        let titles = |board: &Board| -> Vec<String> {
+            board
+                .column(1)
+                .cards_iter()
+                .map(|card| card.short_description().clone())
+                .collect()
+        };
+        assert_eq!(
+            vec!["Buy milk", "Set the table", "Cook dinner"],
+            titles(&driver.board())
+        );
+        driver.press_keys("w");
+        assert_eq!(titles(&driver.board()), titles(&Board::open(&file_name)?));
+
+        change_on_disk("Light candles")?;
+        driver.press_keys("wo");
+        assert_eq!(
+            titles(&driver.board()),
+            titles(&Board::open(&file_name)?),
+            "overwritten"
+        );
+
+        fs::remove_file(metrics_file_name(&file_name))?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn export_column_and_search_results() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-export.json").display().to_string();
+        let _ = fs::remove_file(&file_name);
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("lllE").paste(&file_name);
+        assert!(driver.screen_contains(" Scope: Whole board"));
+        driver.press(KeyCode::Tab.into());
+        assert!(driver.screen_contains(" Scope: Column Done!"));
+        driver.press(KeyCode::Enter.into());
+        assert!(driver.screen_contains(&format!("Exported 2 cards to {}", file_name)));
+        let exported = Board::open(&file_name)?;
+        assert_eq!(1, exported.columns_count());
+        assert_eq!("Done!", exported.column(0).header());
+
+        driver
+            .press_keys("/buy")
+            .press(KeyCode::Enter.into())
+            .press(KeyCode::Esc.into());
+        driver
+            .press_keys("E")
+            .paste(&file_name)
+            .press(KeyCode::Tab.into())
+            .press(KeyCode::Tab.into());
+        assert!(driver.screen_contains("Results of the search \"buy\", 3 cards"));
+        driver.press(KeyCode::Enter.into());
+        assert!(driver.screen_contains("The file exists, <Enter> again to write over it"));
+        assert_eq!(2, Board::open(&file_name)?.column(0).size(), "not written over yet");
+        driver.press(KeyCode::Enter.into());
+        assert_eq!(3, Board::open(&file_name)?.column(0).size());
+
+        driver
+            .press_keys("E")
+            .paste("res/../res/test_board.json")
+            .press(KeyCode::Enter.into())
+            .press(KeyCode::Enter.into());
+        assert!(driver.screen_contains("it is the file of an open board"));
+
+        driver
+            .press_keys("/nothing to find")
+            .press(KeyCode::Enter.into())
+            .press(KeyCode::Esc.into());
+        let empty_file = format!("{}.empty", file_name);
+        driver
+            .press_keys("E")
+            .paste(&empty_file)
+            .press(KeyCode::Tab.into())
+            .press(KeyCode::Tab.into())
+            .press(KeyCode::Enter.into());
+        assert!(driver.screen_contains("Nothing to export"));
+        assert!(!Path::new(&empty_file).exists());
+        assert_eq!(
+            "res/test_board.json",
+            driver.app.file_name(),
+            "the board keeps its file"
+        );
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn search_and_jump() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("/dinner");
+        assert!(driver.screen_contains(" 2 results "));
+        assert!(driver.screen_contains("Doing │ Cook dinner"));
+
+        driver.press(KeyCode::Enter.into()).press_keys("j");
+        driver.press(KeyCode::Enter.into());
+        assert!(!driver.screen_contains(" 2 results "));
+        assert_eq!("Eat dinner", driver.selected_card().unwrap().short_description());
+
+        driver.press_keys("/nothing");
+        assert!(driver.screen_contains(" 0 results "));
+        driver.press(KeyCode::Esc.into());
+        assert!(!driver.screen_contains(" 0 results "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_keys_in_help() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("?/export");
+        assert!(driver.screen_contains("What key does"));
+        assert!(driver.screen_contains("<E> Export the board"));
+        assert!(!driver.screen_contains("<w> Write the board"));
+
+        driver.press_keys("ed");
+        assert!(driver.screen_contains("No key found"));
+
+        driver.press(KeyCode::Esc.into());
+        assert!(driver.screen_contains("to dismiss"));
+        driver.press(KeyCode::Esc.into());
+        assert!(!driver.screen_contains("to dismiss"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn scroll_help() -> Result<()> {
+        let mut driver = TestDriver::with_size("res/test_board.json", 80, 24);
+
+        driver.press_keys("?");
+        assert!(driver.screen_contains("<h/←> Select card in previous column"));
+        assert!(driver.screen_contains("<j/k> Scroll 1-22/"));
+        assert!(!driver.screen_contains("<?> Toggle this help message"));
+
+        driver.press_keys("jj");
+        assert!(!driver.screen_contains("<h/←> Select card in previous column"));
+        (0..10).for_each(|_| {
+            driver.press(KeyCode::PageDown.into());
+        });
+        assert!(driver.screen_contains("<?> Toggle this help message"));
+
+        driver.press_keys("x");
+        assert!(!driver.screen_contains("to dismiss"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn help_and_quit() -> Result<()> {
+        let mut driver = TestDriver::new("res/test_board.json");
+
+        driver.press_keys("?");
+        assert!(driver.screen_contains("to dismiss"));
+
+        driver.press_keys("x");
+        assert!(!driver.screen_contains("to dismiss"));
+        assert!(driver.should_continue());
+
+        driver.press_keys("q");
+        assert!(!driver.should_continue());
+
+        Ok(())
+    }
+}
@@ -41,6 +41,29 @@ impl TextWidget {
         self.text_area.input(input);
     }
 
+    pub fn paste(&mut self, text: &str) {
+        self.text_area.insert_str(text);
+    }
+
+    /// Characters before the cursor back to the previous whitespace
+    pub fn word_before_cursor(&self) -> String {
+        let (row, column) = self.text_area.cursor();
+        let before: Vec<char> = self.text_area.lines()[row].chars().take(column).collect();
+        let start = before
+            .iter()
+            .rposition(|c| c.is_whitespace())
+            .map_or(0, |index| index + 1);
+        before[start..].iter().collect()
+    }
+
+    /// Replaces the `chars` characters before the cursor with `text`
+    pub fn replace_before_cursor(&mut self, chars: usize, text: &str) {
+        for _ in 0..chars {
+            self.text_area.delete_char();
+        }
+        self.text_area.insert_str(text);
+    }
+
     pub fn lines(&self) -> Vec<String> {
         self.text_area.lines().to_vec()
     }
@@ -0,0 +1,135 @@
+use std::time::{Duration, Instant};
+
+/// Counts the time spent on the card flagged as timed on the board
+///
+/// The time is counted until no key has been pressed for a while: the idle period is then set aside
+/// until the user decides, on return, whether to keep or discard it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timer {
+    accumulated: Duration,
+    running_since: Instant,
+    idle_since: Option<Instant>,
+    pending_idle: Option<Duration>,
+}
+
+impl Timer {
+    pub fn start(now: Instant) -> Self {
+        Self {
+            accumulated: Duration::ZERO,
+            running_since: now,
+            idle_since: None,
+            pending_idle: None,
+        }
+    }
+
+    /// Time counted and not yet added to the card, idle periods excluded
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        match self.idle_since {
+            Some(_) => self.accumulated,
+            None => self.accumulated + now.saturating_duration_since(self.running_since),
+        }
+    }
+
+    /// Takes the time counted so far, to add it to the card, and keeps counting
+    pub fn take(&mut self, now: Instant) -> Duration {
+        let elapsed = self.elapsed(now);
+        self.accumulated = Duration::ZERO;
+        self.running_since = now;
+        elapsed
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle_since.is_some()
+    }
+
+    /// Stops counting from the last key press, the idle period starts then
+    pub fn pause_idle(&mut self, last_input: Instant) {
+        if self.is_idle() {
+            return;
+        }
+
+        let since = last_input.max(self.running_since);
+        self.accumulated += since - self.running_since;
+        self.idle_since = Some(since);
+    }
+
+    /// Counts again after an idle period, returns the idle period waiting for a decision
+    pub fn wake_up(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(idle_since) = self.idle_since.take() {
+            self.running_since = now;
+            let idle = now.saturating_duration_since(idle_since);
+            self.pending_idle = Some(self.pending_idle.unwrap_or_default() + idle);
+        }
+        self.pending_idle
+    }
+
+    /// Adds the idle period to the counted time or forgets it
+    pub fn resolve_idle(&mut self, keep: bool) -> Option<Duration> {
+        let idle = self.pending_idle.take()?;
+        if keep {
+            self.accumulated += idle;
+        }
+        Some(idle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Result,
+        time::{Duration, Instant},
+    };
+
+    use super::Timer;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn count_and_take() -> Result<()> {
+        let start = Instant::now();
+        let mut timer = Timer::start(start);
+
+        assert_eq!(5 * MINUTE, timer.elapsed(start + 5 * MINUTE));
+        assert_eq!(5 * MINUTE, timer.take(start + 5 * MINUTE));
+        assert_eq!(2 * MINUTE, timer.elapsed(start + 7 * MINUTE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn keep_idle_period() -> Result<()> {
+        let start = Instant::now();
+        let mut timer = Timer::start(start);
+
+        timer.pause_idle(start + 5 * MINUTE);
+        assert!(timer.is_idle());
+        assert_eq!(5 * MINUTE, timer.elapsed(start + 20 * MINUTE));
+
+        assert_eq!(Some(15 * MINUTE), timer.wake_up(start + 20 * MINUTE));
+        assert!(!timer.is_idle());
+        assert_eq!(6 * MINUTE, timer.elapsed(start + 21 * MINUTE));
+
+        assert_eq!(Some(15 * MINUTE), timer.resolve_idle(true));
+        assert_eq!(21 * MINUTE, timer.elapsed(start + 21 * MINUTE));
+        assert_eq!(None, timer.wake_up(start + 22 * MINUTE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn discard_idle_period() -> Result<()> {
+        let start = Instant::now();
+        let mut timer = Timer::start(start);
+
+        timer.pause_idle(start + 5 * MINUTE);
+        timer.pause_idle(start + 8 * MINUTE);
+        timer.wake_up(start + 20 * MINUTE);
+        assert_eq!(5 * MINUTE, timer.take(start + 20 * MINUTE));
+
+        assert_eq!(Some(15 * MINUTE), timer.resolve_idle(false));
+        assert_eq!(MINUTE, timer.elapsed(start + 21 * MINUTE));
+        assert_eq!(None, timer.resolve_idle(false));
+
+        Ok(())
+    }
+}
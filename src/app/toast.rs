@@ -0,0 +1,88 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    symbols::border,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+const TOAST_TICKS: u8 = 12;
+const FADING_TICKS: u8 = 4;
+
+/// A short notification shown in a corner of the screen, disappearing after a few ticks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    message: String,
+    ticks_left: u8,
+}
+
+impl Toast {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            ticks_left: TOAST_TICKS,
+        }
+    }
+
+    /// A toast staying for a given number of ticks, at least long enough to fade out
+    pub fn lasting(message: String, ticks: u8) -> Self {
+        Self {
+            message,
+            ticks_left: ticks.max(FADING_TICKS + 1),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns false once the toast should not be displayed anymore
+    pub fn tick(&mut self) -> bool {
+        self.ticks_left = self.ticks_left.saturating_sub(1);
+        self.ticks_left > 0
+    }
+
+    fn is_fading(&self) -> bool {
+        self.ticks_left <= FADING_TICKS
+    }
+}
+
+impl Widget for &Toast {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = (self.message.chars().count() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let area = Rect::new(area.right().saturating_sub(width + 1), area.top() + 1, width, height);
+        Clear.render(area, buf);
+
+        let block = Block::bordered().border_set(border::ROUNDED);
+        let paragraph = Paragraph::new(format!(" {} ", self.message)).block(block);
+        if self.is_fading() {
+            paragraph.dim().render(area, buf);
+        } else {
+            paragraph.on_blue().bold().render(area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::{Toast, TOAST_TICKS};
+
+    #[test]
+    fn toast_expires() -> Result<()> {
+        let mut toast = Toast::new("Undid: Insert card".into());
+        assert_eq!("Undid: Insert card", toast.message());
+        assert!(!toast.is_fading());
+
+        for _ in 0..TOAST_TICKS - 1 {
+            assert!(toast.tick());
+        }
+        assert!(toast.is_fading());
+        assert!(!toast.tick());
+        assert!(!toast.tick());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    app::activity::Activity,
+    board::{Board, BoardBuilder, CardColor},
+};
+
+const DONE_MARK: &str = "✔ ";
+
+/// A step of the tutorial, done as soon as the user performs its action
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    title: &'static str,
+    explanation: &'static str,
+    is_done_by: fn(&Activity) -> bool,
+}
+
+const STEPS: [Step; 7] = [
+    Step {
+        title: "Select a card with h/j/k/l",
+        explanation: "The arrow keys work too. <Esc> clears the selection.",
+        is_done_by: |activity| *activity == Activity::CardSelected,
+    },
+    Step {
+        title: "Insert a card with i or a",
+        explanation: "<i> inserts at the selected position, <a> right after it, <I> and <A> at the top and bottom.",
+        is_done_by: |activity| *activity == Activity::CardCreated,
+    },
+    Step {
+        title: "Edit a card with e",
+        explanation: "<Tab> moves between the fields, <Ctrl-s> saves and <Esc> cancels.",
+        is_done_by: |activity| *activity == Activity::CardEdited,
+    },
+    Step {
+        title: "Change a priority with K/J",
+        explanation: "Cards at the top of a column are the most important ones.",
+        is_done_by: |activity| *activity == Activity::PriorityChanged,
+    },
+    Step {
+        title: "Move a card with L/H",
+        explanation: "<L> moves the card to the next column, <H> back to the previous one.",
+        is_done_by: |activity| matches!(activity, Activity::CardMoved | Activity::CardCompleted),
+    },
+    Step {
+        title: "Undo a change with u",
+        explanation: "<U> redoes what was undone.",
+        is_done_by: |activity| *activity == Activity::ChangeUndone,
+    },
+    Step {
+        title: "Save the board with w",
+        explanation: "<W> writes it to another file. Press <?> any time to see all the keys.",
+        is_done_by: |activity| matches!(activity, Activity::BoardSaved(_)),
+    },
+];
+
+/// Follows the progress of the user through the tutorial board
+#[derive(Debug, Clone, Default)]
+pub struct Tutorial {
+    done: [bool; STEPS.len()],
+}
+
+impl Tutorial {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The board teaching the keys, one card per step
+    pub fn board() -> Board {
+        BoardBuilder::new()
+            .column("Tutorial", |column| {
+                STEPS.iter().fold(column, |column, step| {
+                    column.card_with(step.title, |card| card.long_description(step.explanation))
+                })
+            })
+            .column("Doing", |column| column)
+            .column("Done!", |column| column)
+            .build()
+    }
+
+    /// Marks done the first pending step performed by the activity, returns its title
+    pub fn observe(&mut self, activity: &Activity) -> Option<&'static str> {
+        let index = (0..STEPS.len()).find(|index| !self.done[*index] && (STEPS[*index].is_done_by)(activity))?;
+        self.done[index] = true;
+        Some(STEPS[index].title)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.done.iter().all(|done| *done)
+    }
+
+    /// Shows a step as done on the card teaching it, wherever it is on the board
+    pub fn mark_step_done(board: &mut Board, title: &str) {
+        let Some((column_index, card_index, card)) = board
+            .cards_with_positions()
+            .find(|(_, _, card)| card.short_description() == title)
+        else {
+            return;
+        };
+
+        let mut card = card.clone();
+        card.update_short_description(&format!("{}{}", DONE_MARK, title));
+        card.update_color(Some(CardColor::Green));
+        board.update_card(column_index, card_index, card);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::app::activity::Activity;
+
+    use super::{Tutorial, STEPS};
+
+    #[test]
+    fn steps_done_by_activities() -> Result<()> {
+        let mut board = Tutorial::board();
+        let mut tutorial = Tutorial::new();
+        assert_eq!(STEPS.len(), board.column(0).size());
+
+        assert_eq!(None, tutorial.observe(&Activity::TimeTracked(Default::default())));
+        let title = tutorial.observe(&Activity::CardEdited).unwrap();
+        assert_eq!(None, tutorial.observe(&Activity::CardEdited), "already done");
+
+        Tutorial::mark_step_done(&mut board, title);
+        assert_eq!("✔ Edit a card with e", board.card(0, 2).short_description());
+
+        for activity in [
+            Activity::CardSelected,
+            Activity::CardCreated,
+            Activity::PriorityChanged,
+            Activity::CardCompleted,
+            Activity::ChangeUndone,
+        ] {
+            assert!(tutorial.observe(&activity).is_some());
+        }
+        assert!(!tutorial.is_complete());
+        assert!(tutorial
+            .observe(&Activity::BoardSaved("tutorial.json".into()))
+            .is_some());
+        assert!(tutorial.is_complete());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+mod board_view;
+mod card_preview;
+mod card_renderer;
+mod card_view;
+mod column_view;
+mod plain_text;
+mod theme;
+mod too_small;
+
+pub use board_view::BoardView;
+pub use card_preview::CardPreview;
+pub use card_renderer::{card_renderer, CardRenderer, NormalRenderer};
+pub use card_view::{card_color, CardView};
+pub use column_view::ColumnView;
+pub use plain_text::{print_board, DEFAULT_PRINT_WIDTH};
+pub use theme::Theme;
+pub use too_small::TooSmall;
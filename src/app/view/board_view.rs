@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{block::Title, Block, Widget},
+};
+
+use crate::{
+    app::view::{card_color, card_renderer, ColumnView, Theme},
+    board::{Board, CardColor, Section},
+    config::Density,
+    utils::text,
+};
+
+const COLLAPSED_SECTION_WIDTH: u16 = 16;
+
+/// Renders all the columns of a board side by side, under the headers of their sections if they have any
+pub struct BoardView<'a> {
+    board: &'a Board,
+    theme: &'a Theme,
+    selection: Option<(usize, usize)>,
+    flashed_card: Option<(usize, usize)>,
+    committed_cards: usize,
+    numbered: bool,
+    tag_colors: Option<&'a HashMap<String, CardColor>>,
+    hide_done_before: Option<DateTime<Local>>,
+    starred_only: bool,
+    density: Density,
+    collapsed_sections: Option<&'a HashSet<String>>,
+    selected_column: Option<usize>,
+    ghost: Option<(usize, usize, &'a str)>,
+}
+
+impl<'a> BoardView<'a> {
+    pub fn new(board: &'a Board, theme: &'a Theme) -> Self {
+        Self {
+            board,
+            theme,
+            selection: None,
+            flashed_card: None,
+            committed_cards: 0,
+            numbered: false,
+            tag_colors: None,
+            hide_done_before: None,
+            starred_only: false,
+            density: Density::default(),
+            collapsed_sections: None,
+            selected_column: None,
+            ghost: None,
+        }
+    }
+
+    /// Position (column index, card index) of the selected card
+    pub fn selection(mut self, selection: Option<(usize, usize)>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Position (column index, card index) of a card to highlight for a moment
+    pub fn flashed_card(mut self, flashed_card: Option<(usize, usize)>) -> Self {
+        self.flashed_card = flashed_card;
+        self
+    }
+
+    pub fn committed_cards(mut self, committed_cards: usize) -> Self {
+        self.committed_cards = committed_cards;
+        self
+    }
+
+    /// Hides the cards of the last column completed before a date
+    pub fn hide_done_before(mut self, hide_done_before: Option<DateTime<Local>>) -> Self {
+        self.hide_done_before = hide_done_before;
+        self
+    }
+
+    /// Hides the cards that are not starred
+    pub fn starred_only(mut self, starred_only: bool) -> Self {
+        self.starred_only = starred_only;
+        self
+    }
+
+    /// Shows the number of each card in its column
+    pub fn numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    /// Colors of the column headers by tag, for the columns where most cards have one of the tags and no color of
+    /// their own
+    pub fn tag_colors(mut self, tag_colors: &'a HashMap<String, CardColor>) -> Self {
+        self.tag_colors = Some(tag_colors);
+        self
+    }
+
+    /// How much of each card is shown
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Sections shown as a narrow strip listing their columns, instead of their columns and cards
+    pub fn collapsed_sections(mut self, collapsed_sections: &'a HashSet<String>) -> Self {
+        self.collapsed_sections = Some(collapsed_sections);
+        self
+    }
+
+    /// Column of the selection, a collapsed section is highlighted when the column is one of its own
+    pub fn selected_column(mut self, selected_column: Option<usize>) -> Self {
+        self.selected_column = selected_column;
+        self
+    }
+
+    /// Column, slot and title of a card being moved, a ghost of it shows where it lands
+    pub fn ghost(mut self, ghost: Option<(usize, usize, &'a str)>) -> Self {
+        self.ghost = ghost;
+        self
+    }
+
+    fn is_collapsed(&self, section: &Section) -> bool {
+        section.name.as_ref().is_some_and(|name| {
+            self.collapsed_sections
+                .is_some_and(|collapsed| collapsed.contains(name))
+        })
+    }
+
+    fn is_column_collapsed(&self, column_index: usize) -> bool {
+        self.board
+            .sections()
+            .iter()
+            .any(|section| section.contains(column_index) && self.is_collapsed(section))
+    }
+
+    fn column_view(&self, column_index: usize) -> ColumnView<'_> {
+        let is_last = column_index + 1 == self.board.columns_count();
+        let column = self.board.column(column_index);
+        let tag_color = self
+            .tag_colors
+            .zip(column.dominant_tag())
+            .and_then(|(tag_colors, tag)| tag_colors.get(tag).copied());
+        let header_color = column.color().or(tag_color).map(card_color);
+        ColumnView::new(column, self.theme)
+            .committed_cards(self.committed_cards)
+            .numbered(self.numbered)
+            .header_color(header_color)
+            .hidden_before(self.hide_done_before.filter(|_| is_last))
+            .starred_only(self.starred_only)
+            .renderer(card_renderer(self.density))
+    }
+}
+
+impl BoardView<'_> {
+    /// The row of the section headers, only when a column is in a section, and the area of the columns
+    fn split_sections_row(&self, area: Rect) -> (Option<Rect>, Rect) {
+        if self.board.columns_iter().all(|column| column.section().is_none()) {
+            return (None, area);
+        }
+
+        let [sections_row, columns_area] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+        (Some(sections_row), columns_area)
+    }
+
+    /// Area of each section, collapsed sections being narrower than a single column
+    fn section_areas(&self, area: Rect) -> Vec<(Section, Rect)> {
+        let sections = self.board.sections();
+        let constraints: Vec<Constraint> = sections
+            .iter()
+            .map(|section| match self.is_collapsed(section) {
+                true => Constraint::Length(COLLAPSED_SECTION_WIDTH),
+                false => Constraint::Fill(section.columns.len() as u16),
+            })
+            .collect();
+        let areas = Layout::horizontal(constraints).split(area);
+
+        sections.into_iter().zip(areas.iter().copied()).collect()
+    }
+
+    /// Area of each column, the columns of a collapsed section share the area of the section
+    fn column_areas(&self, area: Rect) -> Vec<Rect> {
+        let (_, area) = self.split_sections_row(area);
+        let mut column_areas = vec![];
+        for (section, area) in self.section_areas(area) {
+            if self.is_collapsed(&section) {
+                column_areas.extend(section.columns.map(|_| area));
+                continue;
+            }
+
+            let count = section.columns.len() as u32;
+            let areas = Layout::horizontal((0..count).map(|_| Constraint::Ratio(1, count))).split(area);
+            column_areas.extend(areas.iter().copied());
+        }
+        column_areas
+    }
+
+    fn render_section_header(&self, section: &Section, area: Rect, buf: &mut Buffer) {
+        let Some(name) = &section.name else {
+            return;
+        };
+
+        Line::from(Span::styled("─".repeat(area.width as usize), self.theme.separator)).render(area, buf);
+        let name = text::wrap_truncated(name, area.width.saturating_sub(2) as usize, 1).concat();
+        Line::from(Span::styled(format!(" {} ", name), self.theme.column_header))
+            .alignment(Alignment::Center)
+            .render(area, buf);
+    }
+
+    /// A collapsed section, with the number of cards of each of its columns
+    fn render_collapsed_section(&self, section: &Section, area: Rect, buf: &mut Buffer) {
+        let selected = self
+            .selected_column
+            .is_some_and(|column_index| section.contains(column_index));
+        let cards: usize = section
+            .columns
+            .clone()
+            .map(|index| self.board.column(index).size())
+            .sum();
+        let block = Block::bordered()
+            .title(Title::from(format!(" {} ", cards)).alignment(Alignment::Center))
+            .border_set(if selected { border::DOUBLE } else { border::THICK })
+            .border_style(if selected {
+                self.theme.selected_card
+            } else {
+                self.theme.separator
+            });
+
+        let inner_area = block.inner(area);
+        let width = inner_area.width as usize;
+        for (row, column_index) in section.columns.clone().take(inner_area.height as usize).enumerate() {
+            let column = self.board.column(column_index);
+            let line = format!("{} {}", column.size(), column.header());
+            let row_area = Rect::new(inner_area.x, inner_area.y + row as u16, inner_area.width, 1);
+            Line::from(text::wrap_truncated(&line, width, 1).concat()).render(row_area, buf);
+        }
+        block.render(area, buf);
+    }
+
+    /// Where the selected card is drawn when the board is rendered in `area`, if it is visible
+    pub fn selected_card_area(&self, area: Rect) -> Option<Rect> {
+        let (column_index, card_index) = self.selection?;
+        let column_area = *self.column_areas(area).get(column_index)?;
+
+        self.column_view(column_index)
+            .selected_card(Some(card_index))
+            .card_area(column_area, card_index)
+    }
+}
+
+impl Widget for BoardView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (sections_row, columns_area) = self.split_sections_row(area);
+        for (section, area) in self.section_areas(columns_area) {
+            if let Some(row) = sections_row {
+                self.render_section_header(&section, Rect::new(area.x, row.y, area.width, 1), buf);
+            }
+            if self.is_collapsed(&section) {
+                self.render_collapsed_section(&section, area, buf);
+            }
+        }
+
+        for (column_index, area) in self.column_areas(area).iter().enumerate() {
+            if self.is_column_collapsed(column_index) {
+                continue;
+            }
+
+            let selected_card = self
+                .selection
+                .filter(|(selected_column, _)| *selected_column == column_index)
+                .map(|(_, card_index)| card_index);
+            let flashed_card = self
+                .flashed_card
+                .filter(|(flashed_column, _)| *flashed_column == column_index)
+                .map(|(_, card_index)| card_index);
+            let ghost = self
+                .ghost
+                .filter(|(ghost_column, _, _)| *ghost_column == column_index)
+                .map(|(_, slot, title)| (slot, title));
+
+            self.column_view(column_index)
+                .selected_card(selected_card)
+                .flashed_card(flashed_card)
+                .ghost(ghost)
+                .render(*area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        io::Result,
+    };
+
+    use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+    use crate::{
+        app::view::Theme,
+        board::{BoardBuilder, CardColor},
+    };
+
+    use super::BoardView;
+
+    fn row(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn sections_and_collapsed_sections() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Backlog", |c| c.section("Later").card("Idea").card("Other idea"))
+            .column("Ready", |c| c.section("Later").card("Plan"))
+            .column("Doing", |c| c.section("Active").card("Work"))
+            .column("Done", |c| c.section("Active"))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 80, 20);
+
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme).render(area, &mut buffer);
+        assert!(row(&buffer, 0).contains(" Later "));
+        assert!(row(&buffer, 0).contains(" Active "));
+        assert!(
+            row(&buffer, 1).contains(" Backlog "),
+            "column headers under the sections"
+        );
+
+        let collapsed = HashSet::from(["Later".to_string()]);
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme)
+            .collapsed_sections(&collapsed)
+            .render(area, &mut buffer);
+        assert!(row(&buffer, 1).contains(" 3 "), "cards of the collapsed section");
+        assert!(row(&buffer, 2).contains("2 Backlog"));
+        assert!(row(&buffer, 3).contains("1 Ready"));
+        assert!(!row(&buffer, 1).contains(" Backlog "));
+        assert!(row(&buffer, 1).contains(" Doing "));
+
+        let board = BoardBuilder::new().column("TODO", |c| c.card("Task")).build();
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme).render(area, &mut buffer);
+        assert!(row(&buffer, 0).contains(" TODO "), "no row without sections");
+
+        Ok(())
+    }
+
+    #[test]
+    fn tint_headers_by_dominant_tag() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Bugs", |c| {
+                c.card_with("Crash", |card| card.tag("bug"))
+                    .card_with("Freeze", |card| card.tag("bug"))
+            })
+            .column("Features", |c| c.card_with("Export", |card| card.tag("feature")))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 40, 10);
+        let header_color = |buffer: &Buffer, header: &str| {
+            let row = row(buffer, 0);
+            let x = row[..row.find(header).unwrap()].chars().count() as u16;
+            buffer[(x, 0)].fg
+        };
+
+        let tag_colors = HashMap::from([("bug".to_string(), CardColor::Red)]);
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme)
+            .tag_colors(&tag_colors)
+            .render(area, &mut buffer);
+        assert_eq!(Color::Red, header_color(&buffer, "Bugs"));
+        assert_ne!(Color::Red, header_color(&buffer, "Features"), "no color for the tag");
+
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme).render(area, &mut buffer);
+        assert_ne!(Color::Red, header_color(&buffer, "Bugs"));
+
+        let board = BoardBuilder::new()
+            .column("Bugs", |c| {
+                c.color(CardColor::Blue).card_with("Crash", |card| card.tag("bug"))
+            })
+            .build();
+        let mut buffer = Buffer::empty(area);
+        BoardView::new(&board, &theme)
+            .tag_colors(&tag_colors)
+            .render(area, &mut buffer);
+        assert_eq!(
+            Color::Blue,
+            header_color(&buffer, "Bugs"),
+            "the color of the column first"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    symbols::border,
+    text::{Line, Text},
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+use crate::{
+    board::Card,
+    utils::{text, time},
+};
+
+const PREVIEW_WIDTH: u16 = 40;
+const MAX_DESCRIPTION_LINES: usize = 8;
+
+/// Details of the selected card floating next to it, so they can be read without opening the editor
+pub struct CardPreview<'a> {
+    card: &'a Card,
+    card_area: Rect,
+}
+
+impl<'a> CardPreview<'a> {
+    /// Preview of a card drawn in `card_area`
+    pub fn new(card: &'a Card, card_area: Rect) -> Self {
+        Self { card, card_area }
+    }
+
+    /// Whether the card has more to show than what its view in the column already shows
+    pub fn has_details(card: &Card) -> bool {
+        !card.long_description().is_empty()
+            || !card.tags().is_empty()
+            || card.due_date().is_some()
+            || card.estimate().is_some()
+            || !card.time_spent().is_zero()
+            || card.pomodoros() > 0
+    }
+
+    fn lines(&self, width: usize) -> Vec<Line<'a>> {
+        let card = self.card;
+        let mut lines = vec![];
+
+        let description: Vec<String> = card
+            .long_description()
+            .lines()
+            .flat_map(|line| text::wrap(line, width))
+            .collect();
+        let hidden = description.len().saturating_sub(MAX_DESCRIPTION_LINES);
+        lines.extend(description.into_iter().take(MAX_DESCRIPTION_LINES).map(Line::from));
+        if hidden > 0 {
+            lines.push(Line::from(format!("+{} lines", hidden)).dim());
+        }
+
+        let mut metadata = vec![];
+        if !card.tags().is_empty() {
+            let tags: Vec<String> = card.tags().iter().map(|tag| format!("#{}", tag)).collect();
+            metadata.push(Line::from(tags.join(" ")).cyan());
+        }
+        if let Some(due_date) = card.due_date() {
+            metadata.push(Line::from(vec![
+                "Due: ".bold(),
+                time::formatter().date(&due_date).into(),
+            ]));
+        }
+        if let Some(estimate) = card.estimate() {
+            metadata.push(Line::from(vec!["Estimate: ".bold(), estimate.to_string().into()]));
+        }
+        if !card.time_spent().is_zero() {
+            metadata.push(Line::from(vec![
+                "Time spent: ".bold(),
+                time::pretty_duration(card.time_spent()).into(),
+            ]));
+        }
+        if card.pomodoros() > 0 {
+            metadata.push(Line::from(vec![
+                "Pomodoros: ".bold(),
+                card.pomodoros().to_string().into(),
+            ]));
+        }
+        if !lines.is_empty() && !metadata.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.extend(metadata);
+
+        lines
+    }
+}
+
+impl Widget for CardPreview<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = PREVIEW_WIDTH.min(area.width);
+        let lines = self.lines(width.saturating_sub(2) as usize);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let area = preview_area(self.card_area, area, width, height);
+        Clear.render(area, buf);
+
+        let block = Block::bordered().border_set(border::ROUNDED).on_dark_gray();
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
+
+/// Next to the card, on its right when there is room, else on its left, else over the card below its title
+fn preview_area(card_area: Rect, screen: Rect, width: u16, height: u16) -> Rect {
+    let (x, y) = if card_area.right() + width <= screen.right() {
+        (card_area.right(), card_area.top())
+    } else if card_area.left() >= screen.left() + width {
+        (card_area.left() - width, card_area.top())
+    } else {
+        (
+            card_area.left().min(screen.right().saturating_sub(width)),
+            card_area.top() + 2,
+        )
+    };
+    let y = y.min(screen.bottom().saturating_sub(height)).max(screen.top());
+
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use crate::board::BoardBuilder;
+
+    use super::{preview_area, CardPreview};
+
+    #[test]
+    fn placed_next_to_the_card() -> Result<()> {
+        let screen = Rect::new(0, 0, 120, 40);
+
+        let card_area = Rect::new(10, 5, 30, 4);
+        assert_eq!(Rect::new(40, 5, 40, 6), preview_area(card_area, screen, 40, 6));
+
+        let card_area = Rect::new(90, 5, 30, 4);
+        assert_eq!(Rect::new(50, 5, 40, 6), preview_area(card_area, screen, 40, 6));
+
+        let card_area = Rect::new(90, 36, 30, 4);
+        assert_eq!(
+            Rect::new(50, 34, 40, 6),
+            preview_area(card_area, screen, 40, 6),
+            "kept on screen"
+        );
+
+        let screen = Rect::new(0, 0, 50, 40);
+        let card_area = Rect::new(20, 5, 30, 4);
+        assert_eq!(
+            Rect::new(10, 7, 40, 6),
+            preview_area(card_area, screen, 40, 6),
+            "over the card"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn description_and_metadata() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card("Plain").card_with("Detailed", |card| {
+                    card.long_description("First line\nSecond line").tag("home").estimate(3)
+                })
+            })
+            .build();
+        assert!(!CardPreview::has_details(board.card(0, 0)));
+        assert!(CardPreview::has_details(board.card(0, 1)));
+
+        let area = Rect::new(0, 0, 80, 20);
+        let mut buffer = Buffer::empty(area);
+        CardPreview::new(board.card(0, 1), Rect::new(0, 0, 20, 4)).render(area, &mut buffer);
+        let screen: Vec<String> = (0..20)
+            .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+
+        assert!(screen[1].contains("│First line"));
+        assert!(screen[2].contains("│Second line"));
+        assert!(screen[4].contains("│#home"));
+        assert!(screen[5].contains("│Estimate: 3"));
+        assert!(screen[6].contains("╰"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use ratatui::{
+    layout::Alignment,
+    style::Stylize,
+    text::{Line, Span},
+};
+
+use crate::{
+    board::Card,
+    config::Density,
+    utils::{text, time},
+};
+
+const MAX_TITLE_LINES: usize = 3;
+
+/// Draws the content of a card within its borders, each board density has its own renderer
+pub trait CardRenderer {
+    /// Height needed to show the card within `width` columns, borders included
+    fn height(&self, card: &Card, width: u16) -> u16;
+
+    /// Lines shown inside the borders of the card, at most `height` of them
+    fn lines(&self, card: &Card, width: usize, height: usize) -> Vec<Line<'static>>;
+}
+
+/// Renderer of the cards for a board density
+pub fn card_renderer(density: Density) -> &'static dyn CardRenderer {
+    match density {
+        Density::Compact => &CompactRenderer,
+        Density::Normal => &NormalRenderer,
+        Density::Detailed => &DetailedRenderer,
+    }
+}
+
+/// The title on a single line, to fit as many cards as possible
+struct CompactRenderer;
+
+impl CardRenderer for CompactRenderer {
+    fn height(&self, _card: &Card, _width: u16) -> u16 {
+        3
+    }
+
+    fn lines(&self, card: &Card, width: usize, _height: usize) -> Vec<Line<'static>> {
+        text::wrap_truncated(card.short_description(), width, 1)
+            .into_iter()
+            .map(Line::from)
+            .collect()
+    }
+}
+
+/// The title, up to a few lines, and how long the card has been in its column
+pub struct NormalRenderer;
+
+impl CardRenderer for NormalRenderer {
+    fn height(&self, card: &Card, width: u16) -> u16 {
+        (title_lines(card, width) + 3) as u16
+    }
+
+    fn lines(&self, card: &Card, width: usize, height: usize) -> Vec<Line<'static>> {
+        let mut lines = title(card, width, height.saturating_sub(1));
+        if height > lines.len() {
+            lines.push(age_footer(card, width));
+        }
+        lines
+    }
+}
+
+/// The title followed by the tags, the due date and the checklist progress of the card
+struct DetailedRenderer;
+
+impl DetailedRenderer {
+    fn details(card: &Card, width: usize) -> Vec<Line<'static>> {
+        let mut details = vec![];
+        if !card.tags().is_empty() {
+            let tags: Vec<String> = card.tags().iter().map(|tag| format!("#{}", tag)).collect();
+            let tags = text::wrap_truncated(&tags.join(" "), width, 1).concat();
+            details.push(Line::from(tags).cyan());
+        }
+        if let Some(due_date) = card.due_date() {
+            details.push(Line::from(vec![
+                "Due: ".bold(),
+                time::formatter().date(&due_date).into(),
+            ]));
+        }
+        let checklist = card.checklist();
+        if checklist.total > 0 {
+            details.push(Line::from(vec![
+                "Done: ".bold(),
+                format!("{}/{}", checklist.done, checklist.total).into(),
+            ]));
+        }
+        details.push(age_footer(card, width));
+        details
+    }
+}
+
+impl CardRenderer for DetailedRenderer {
+    fn height(&self, card: &Card, width: u16) -> u16 {
+        let details = Self::details(card, width.saturating_sub(2) as usize).len();
+        (title_lines(card, width) + details + 2) as u16
+    }
+
+    fn lines(&self, card: &Card, width: usize, height: usize) -> Vec<Line<'static>> {
+        let details = Self::details(card, width);
+        let mut lines = title(card, width, height.saturating_sub(details.len()));
+        let room = height.saturating_sub(lines.len());
+        lines.extend(details.into_iter().take(room));
+        lines
+    }
+}
+
+/// Number of lines of the title of a card in `width` columns, borders included, up to a few lines
+fn title_lines(card: &Card, width: u16) -> usize {
+    text::wrap(card.short_description(), width.saturating_sub(2) as usize)
+        .len()
+        .clamp(1, MAX_TITLE_LINES)
+}
+
+/// The title of a card wrapped on up to `max_lines` lines, at least one
+fn title(card: &Card, width: usize, max_lines: usize) -> Vec<Line<'static>> {
+    text::wrap_truncated(card.short_description(), width, max_lines.clamp(1, MAX_TITLE_LINES))
+        .into_iter()
+        .map(Line::from)
+        .collect()
+}
+
+fn age_footer(card: &Card, width: usize) -> Line<'static> {
+    let age = time::time_in_column(card.entered_column_date());
+    footer(hidden_lines(card), age, width)
+}
+
+/// Number of lines of the long description, which is only shown when editing the card
+fn hidden_lines(card: &Card) -> usize {
+    card.long_description().lines().count()
+}
+
+/// Hidden lines indicator on the left, age on the right, the indicator is dropped when both do not fit
+fn footer(hidden_lines: usize, age: String, width: usize) -> Line<'static> {
+    let indicator = match hidden_lines {
+        0 => String::new(),
+        1 => "+1 line".to_string(),
+        count => format!("+{} lines", count),
+    };
+    let padding = width.saturating_sub(indicator.chars().count() + age.chars().count());
+    if indicator.is_empty() || padding == 0 {
+        return Line::from(age).alignment(Alignment::Right);
+    }
+
+    Line::from(vec![indicator.dim(), Span::raw(" ".repeat(padding)), Span::raw(age)])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::NaiveDate;
+
+    use crate::{board::BoardBuilder, config::Density};
+
+    use super::{card_renderer, footer};
+
+    fn text(line: &ratatui::text::Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.to_string())
+            .collect::<String>()
+    }
+
+    #[test]
+    fn footer_layout() -> Result<()> {
+        assert_eq!("+2 lines     2d", text(&footer(2, "2d".into(), 15)));
+        assert_eq!("2d", text(&footer(0, "2d".into(), 15)));
+        assert_eq!("2d", text(&footer(2, "2d".into(), 10)), "the indicator needs a space");
+
+        Ok(())
+    }
+
+    #[test]
+    fn densities() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Buy milk, eggs and bread", |card| {
+                    card.tag("shopping")
+                        .due(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+                        .long_description("- [x] milk\n- [ ] eggs")
+                })
+            })
+            .build();
+        let card = board.card(0, 0);
+
+        let compact = card_renderer(Density::Compact);
+        assert_eq!(3, compact.height(card, 20));
+        let lines: Vec<String> = compact.lines(card, 18, 1).iter().map(text).collect();
+        assert_eq!(vec!["Buy milk, eggs an…"], lines);
+
+        let normal = card_renderer(Density::Normal);
+        assert_eq!(5, normal.height(card, 20));
+        let lines: Vec<String> = normal.lines(card, 18, 3).iter().map(text).collect();
+        assert_eq!(3, lines.len());
+        assert!(lines[2].contains("in column for"));
+
+        let detailed = card_renderer(Density::Detailed);
+        assert_eq!(8, detailed.height(card, 20));
+        let lines: Vec<String> = detailed.lines(card, 18, 6).iter().map(text).collect();
+        assert_eq!("#shopping", lines[2]);
+        assert_eq!("Due: 2024-05-01", lines[3]);
+        assert_eq!("Done: 1/2", lines[4]);
+        assert!(lines[5].contains("in column for"));
+
+        Ok(())
+    }
+}
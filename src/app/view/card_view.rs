@@ -0,0 +1,279 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    symbols::border,
+    text::{Span, Text},
+    widgets::{block::Title, Block, Paragraph, Widget},
+};
+
+use crate::{
+    app::view::{CardRenderer, NormalRenderer, Theme},
+    board::{Card, CardColor},
+};
+
+/// Dashed border of the scratch cards, which are not written to the board file
+const SCRATCH_BORDER: border::Set = border::Set {
+    top_left: "╭",
+    top_right: "╮",
+    bottom_left: "╰",
+    bottom_right: "╯",
+    vertical_left: "┆",
+    vertical_right: "┆",
+    horizontal_top: "┄",
+    horizontal_bottom: "┄",
+};
+
+/// Renders a card as shown in its column
+pub struct CardView<'a> {
+    card: &'a Card,
+    theme: &'a Theme,
+    selected: bool,
+    flashed: bool,
+    rank: Option<usize>,
+    number: Option<usize>,
+    renderer: &'a dyn CardRenderer,
+}
+
+impl<'a> CardView<'a> {
+    pub fn new(card: &'a Card, theme: &'a Theme) -> Self {
+        Self {
+            card,
+            theme,
+            selected: false,
+            flashed: false,
+            rank: None,
+            number: None,
+            renderer: &NormalRenderer,
+        }
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Highlights the whole card, to draw the eye to it after it moved
+    pub fn flashed(mut self, flashed: bool) -> Self {
+        self.flashed = flashed;
+        self
+    }
+
+    /// Shows the rank of the card in its column as a badge, for the top priority cards
+    pub fn rank(mut self, rank: Option<usize>) -> Self {
+        self.rank = rank;
+        self
+    }
+
+    /// Shows the number of the card in its column on the right of its border, to jump to it with `'`
+    pub fn number(mut self, number: Option<usize>) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// Draws the content of the card, its title and age by default
+    pub fn renderer(mut self, renderer: &'a dyn CardRenderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+}
+
+impl Widget for CardView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut block = if self.selected {
+            Block::bordered()
+                .border_set(border::DOUBLE)
+                .border_style(self.theme.selected_card)
+        } else {
+            Block::bordered().border_set(border::ROUNDED)
+        };
+        if self.card.is_scratch() {
+            block = block.border_set(SCRATCH_BORDER);
+        }
+        if let Some(color) = self.card.color() {
+            block = block.border_style(Style::new().fg(card_color(color)));
+        }
+        if let Some(rank) = self.rank {
+            block = block.title(Span::styled(format!(" {} ", rank), self.theme.rank_badge));
+        }
+        if self.card.is_starred() {
+            block = block.title(Title::from(Span::styled(" ★ ", self.theme.star)).alignment(Alignment::Right));
+        }
+        if let Some(number) = self.number {
+            block = block.title(Title::from(format!(" #{} ", number)).alignment(Alignment::Right));
+        }
+        if self.flashed {
+            block = block.style(self.theme.flashed_card);
+        }
+
+        let inner_area = block.inner(area);
+        let lines = self
+            .renderer
+            .lines(self.card, inner_area.width as usize, inner_area.height as usize);
+
+        Paragraph::new(Text::from(lines)).block(block).render(area, buf);
+    }
+}
+
+/// Terminal color of a card color, which takes precedence over the theme
+pub fn card_color(color: CardColor) -> Color {
+    match color {
+        CardColor::Red => Color::Red,
+        CardColor::Yellow => Color::Yellow,
+        CardColor::Green => Color::Green,
+        CardColor::Cyan => Color::Cyan,
+        CardColor::Blue => Color::Blue,
+        CardColor::Magenta => Color::Magenta,
+        CardColor::Gray => Color::Gray,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, TimeDelta};
+    use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+    use crate::{
+        app::view::{CardRenderer, NormalRenderer, Theme},
+        board::{BoardBuilder, CardColor},
+    };
+
+    use super::CardView;
+
+    #[test]
+    fn colored_border() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Blocked", |card| card.color(CardColor::Red)).card("Normal")
+            })
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 20, 4);
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(board.card(0, 0), &theme)
+            .selected(true)
+            .render(area, &mut buffer);
+        assert_eq!(Color::Red, buffer[(0, 0)].fg);
+        assert_eq!("╔", buffer[(0, 0)].symbol());
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(board.card(0, 1), &theme).render(area, &mut buffer);
+        assert_eq!(Color::Reset, buffer[(0, 0)].fg);
+
+        Ok(())
+    }
+
+    #[test]
+    fn numbered_card() -> Result<()> {
+        let board = BoardBuilder::new().column("TODO", |c| c.card("Call back")).build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 20, 4);
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(board.card(0, 0), &theme)
+            .number(Some(12))
+            .render(area, &mut buffer);
+        let top: String = (0..20).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(top.ends_with(" #12 ╮"), "{}", top);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dashed_border_of_scratch_cards() -> Result<()> {
+        let board = BoardBuilder::new().column("TODO", |c| c.card("Call back")).build();
+        let mut card = board.card(0, 0).clone();
+        card.set_scratch(true);
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 20, 4);
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(&card, &theme).selected(true).render(area, &mut buffer);
+        assert_eq!("┄", buffer[(1, 0)].symbol());
+        assert_eq!("┆", buffer[(0, 1)].symbol());
+
+        Ok(())
+    }
+
+    #[test]
+    fn star_on_starred_cards() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card_with("Call back", |card| card.starred()))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 20, 4);
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(board.card(0, 0), &theme).render(area, &mut buffer);
+        let top: String = (0..20).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(top.ends_with(" ★ ╮"), "{}", top);
+        assert_eq!(Some(Color::Yellow), buffer[(17, 0)].style().fg);
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_in_column() -> Result<()> {
+        let now = Local::now();
+        let board = BoardBuilder::new()
+            .column("Doing", |c| {
+                c.card_with("Long lived", |card| card.created(now - TimeDelta::days(30)))
+            })
+            .build();
+        let mut card = board.card(0, 0).clone();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 4);
+        let line = |buffer: &Buffer| (0..30).map(|x| buffer[(x, 2)].symbol()).collect::<String>();
+
+        let mut buffer = Buffer::empty(area);
+        CardView::new(&card, &theme).render(area, &mut buffer);
+        assert!(line(&buffer).contains("in column for 30d"));
+
+        card.enter_column("Doing", now - TimeDelta::days(3));
+        let mut buffer = Buffer::empty(area);
+        CardView::new(&card, &theme).render(area, &mut buffer);
+        assert!(line(&buffer).contains("in column for 3d"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_titles_are_wrapped() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card("Short")
+                    .card("Buy milk, eggs and bread for the whole week")
+                    .card_with("Plan", |card| card.long_description("First\nSecond\nThird"))
+            })
+            .build();
+        let theme = Theme::default();
+        let column = board.column(0);
+
+        assert_eq!(4, NormalRenderer.height(column.get_card(0), 20));
+        assert_eq!(6, NormalRenderer.height(column.get_card(1), 20), "3 title lines");
+        assert_eq!(
+            6,
+            NormalRenderer.height(column.get_card(1), 10),
+            "at most 3 title lines"
+        );
+
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buffer = Buffer::empty(area);
+        CardView::new(column.get_card(1), &theme).render(area, &mut buffer);
+        let line = |buffer: &Buffer, y| (0..20).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+        assert_eq!("│Buy milk, eggs and│", line(&buffer, 1));
+        assert_eq!("│bread for the…    │", line(&buffer, 2));
+        assert!(line(&buffer, 3).contains("in column for"));
+
+        let area = Rect::new(0, 0, 30, 4);
+        let mut buffer = Buffer::empty(area);
+        CardView::new(column.get_card(2), &theme).render(area, &mut buffer);
+        let line = (0..30).map(|x| buffer[(x, 2)].symbol()).collect::<String>();
+        assert!(line.starts_with("│+3 lines "), "{}", line);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,592 @@
+use std::cmp::min;
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Color,
+    symbols::border,
+    text::{Line, Span},
+    widgets::{block::Title, Block, Widget},
+};
+
+use crate::{
+    app::view::{CardRenderer, CardView, NormalRenderer, Theme},
+    board::Column,
+};
+
+/// Height below which a card shows nothing but its borders, it is counted as hidden instead
+const MIN_CARD_HEIGHT: u16 = 3;
+
+/// Height of the ghost showing where a card being moved lands, enough for its title
+const GHOST_HEIGHT: u16 = 3;
+
+/// Dashed borders of the ghost, to tell it from the cards
+const GHOST_BORDER: border::Set = border::Set {
+    top_left: "┌",
+    top_right: "┐",
+    bottom_left: "└",
+    bottom_right: "┘",
+    vertical_left: "┆",
+    vertical_right: "┆",
+    horizontal_top: "┄",
+    horizontal_bottom: "┄",
+};
+
+/// Number of cards laid out from the first visible one, as many as the column could hold at their smallest
+///
+/// Taller cards do not all fit, the layout leaves out the ones past the bottom of the column.
+fn card_slots(inner_area: Rect) -> usize {
+    (inner_area.height / MIN_CARD_HEIGHT).max(1) as usize
+}
+
+/// Area of the ghost at the height `y` of the area when it fits, `y` then moves past it
+fn ghost_area(area: Rect, y: &mut u16) -> Option<Rect> {
+    if *y + GHOST_HEIGHT > area.bottom() {
+        return None;
+    }
+
+    let ghost = Rect {
+        y: *y,
+        height: GHOST_HEIGHT,
+        ..area
+    };
+    *y += GHOST_HEIGHT;
+    Some(ghost)
+}
+
+/// Renders a column and the cards that fit in it, scrolled to keep the selected card visible
+pub struct ColumnView<'a> {
+    column: &'a Column,
+    theme: &'a Theme,
+    selected_card: Option<usize>,
+    flashed_card: Option<usize>,
+    committed_cards: usize,
+    numbered: bool,
+    header_color: Option<Color>,
+    hidden_before: Option<DateTime<Local>>,
+    starred_only: bool,
+    renderer: &'a dyn CardRenderer,
+    ghost: Option<(usize, &'a str)>,
+}
+
+impl<'a> ColumnView<'a> {
+    pub fn new(column: &'a Column, theme: &'a Theme) -> Self {
+        Self {
+            column,
+            theme,
+            selected_card: None,
+            flashed_card: None,
+            committed_cards: 0,
+            numbered: false,
+            header_color: None,
+            hidden_before: None,
+            starred_only: false,
+            renderer: &NormalRenderer,
+            ghost: None,
+        }
+    }
+
+    pub fn selected_card(mut self, selected_card: Option<usize>) -> Self {
+        self.selected_card = selected_card;
+        self
+    }
+
+    pub fn flashed_card(mut self, flashed_card: Option<usize>) -> Self {
+        self.flashed_card = flashed_card;
+        self
+    }
+
+    /// The first `committed_cards` cards get a rank badge and are separated from the others
+    pub fn committed_cards(mut self, committed_cards: usize) -> Self {
+        self.committed_cards = committed_cards;
+        self
+    }
+
+    /// Shows the number of each card in the column, starting from 1
+    pub fn numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    /// Tints the header, like with the color of the tag of most cards
+    pub fn header_color(mut self, header_color: Option<Color>) -> Self {
+        self.header_color = header_color;
+        self
+    }
+
+    /// Cards completed before the date are not shown, they are only counted in the header
+    pub fn hidden_before(mut self, hidden_before: Option<DateTime<Local>>) -> Self {
+        self.hidden_before = hidden_before;
+        self
+    }
+
+    /// Cards that are not starred are not shown, they are only counted in the header
+    pub fn starred_only(mut self, starred_only: bool) -> Self {
+        self.starred_only = starred_only;
+        self
+    }
+
+    /// Draws the content of the cards, which also decides how tall they are
+    pub fn renderer(mut self, renderer: &'a dyn CardRenderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// Slot and title of a card being moved to the column, a ghost of it is drawn before the card at the slot,
+    /// or after the last one
+    pub fn ghost(mut self, ghost: Option<(usize, &'a str)>) -> Self {
+        self.ghost = ghost;
+        self
+    }
+
+    /// Indexes of the cards that are not hidden
+    fn shown_cards(&self) -> Vec<usize> {
+        (0..self.column.size())
+            .filter(|card_index| {
+                let card = self.column.get_card(*card_index);
+                self.hidden_before.is_none_or(|date| !card.completed_before(date))
+                    && (!self.starred_only || card.is_starred())
+            })
+            .collect()
+    }
+
+    /// Position among the shown cards of what must stay visible, the ghost when there is one or the selected card
+    ///
+    /// The ghost is at the position of the first shown card it goes before, or after the shown cards.
+    fn anchor(&self, shown: &[usize]) -> Option<usize> {
+        match self.ghost {
+            Some((slot, _)) => Some(
+                shown
+                    .iter()
+                    .position(|card_index| *card_index >= slot)
+                    .unwrap_or(shown.len()),
+            ),
+            None => self
+                .selected_card
+                .and_then(|selected| shown.iter().position(|card_index| *card_index == selected)),
+        }
+    }
+
+    /// Position of the first visible card among the shown cards
+    fn scroll_offset(&self, shown: &[usize], slots: usize) -> usize {
+        self.anchor(shown)
+            .map_or(0, |position| (position + 1).saturating_sub(slots))
+    }
+}
+
+/// Where the parts of a column are drawn within its borders
+struct ColumnLayout {
+    /// Visible cards with their index
+    cards: Vec<(usize, Rect)>,
+
+    /// Line between the committed cards and the others
+    separator: Option<Rect>,
+
+    /// Number of cards scrolled out above the visible ones, with the line telling so
+    above: Option<(usize, Rect)>,
+
+    /// Number of cards after the visible ones, past the card slots or not fitting the column
+    below: Option<(usize, Rect)>,
+
+    /// Ghost of the card being moved
+    ghost: Option<Rect>,
+}
+
+impl ColumnView<'_> {
+    /// Lays out the visible cards, the separator after the committed cards and the lines telling how many cards
+    /// are hidden above and below
+    ///
+    /// The column scrolls further when the selected card, or the ghost, is among the card slots but does not fit
+    /// the column.
+    fn layout(&self, inner_area: Rect) -> ColumnLayout {
+        let shown = self.shown_cards();
+        let anchor = self.anchor(&shown);
+        let mut offset = min(self.scroll_offset(&shown, card_slots(inner_area)), shown.len());
+        loop {
+            let layout = self.layout_from(&shown, offset, inner_area);
+            let fits = match self.ghost {
+                Some(_) => layout.ghost.is_some(),
+                None => layout
+                    .cards
+                    .iter()
+                    .any(|(card_index, _)| Some(*card_index) == self.selected_card),
+            };
+            match anchor {
+                Some(position) if !fits && offset < position => offset += 1,
+                _ => return layout,
+            }
+        }
+    }
+
+    /// Lays out the shown cards from the one at `offset`
+    fn layout_from(&self, shown: &[usize], offset: usize, inner_area: Rect) -> ColumnLayout {
+        let visible = &shown[offset..min(offset + card_slots(inner_area), shown.len())];
+        let after = shown.len() - offset - visible.len();
+        let ghost_at = self
+            .anchor(shown)
+            .filter(|_| self.ghost.is_some())
+            .and_then(|position| position.checked_sub(offset))
+            .filter(|position| *position <= visible.len());
+
+        let mut area = inner_area;
+        let above = (offset > 0 && area.height > 0).then(|| {
+            let [line, rest] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+            area = rest;
+            (offset, line)
+        });
+
+        let (mut cards, mut separator, mut ghost) = self.card_areas(visible, ghost_at, area);
+        let mut below = None;
+        if after + visible.len() > cards.len() && area.height > 0 {
+            // Making room for the line takes some space from the cards, fewer of them may fit
+            let [rest, line] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+            (cards, separator, ghost) = self.card_areas(visible, ghost_at, rest);
+            below = Some((after + visible.len() - cards.len(), line));
+        }
+
+        ColumnLayout {
+            cards,
+            separator,
+            above,
+            below,
+            ghost,
+        }
+    }
+
+    /// Areas of the cards fitting in the area with their index, the area of the separator after the committed
+    /// cards and the area of the ghost, drawn before the visible card at `ghost_at` or after the last one
+    ///
+    /// Cards are stacked from the top, the last one is cut with an ellipsis when the column is too short for it,
+    /// the ones after it are left out.
+    fn card_areas(
+        &self,
+        visible: &[usize],
+        ghost_at: Option<usize>,
+        area: Rect,
+    ) -> (Vec<(usize, Rect)>, Option<Rect>, Option<Rect>) {
+        let separator_position = visible
+            .iter()
+            .position(|card_index| *card_index >= self.committed_cards)
+            .filter(|position| *position > 0);
+
+        let mut cards = vec![];
+        let mut separator = None;
+        let mut ghost = None;
+        let mut y = area.y;
+        for (position, card_index) in visible.iter().copied().enumerate() {
+            if Some(position) == separator_position && y < area.bottom() {
+                separator = Some(Rect { y, height: 1, ..area });
+                y += 1;
+            }
+            if Some(position) == ghost_at {
+                ghost = ghost_area(area, &mut y);
+            }
+
+            let wanted = self.renderer.height(self.column.get_card(card_index), area.width);
+            let height = min(wanted, area.bottom() - y);
+            if height < MIN_CARD_HEIGHT {
+                break;
+            }
+            cards.push((card_index, Rect { y, height, ..area }));
+            y += height;
+        }
+        if ghost_at == Some(visible.len()) && cards.len() == visible.len() {
+            ghost = ghost_area(area, &mut y);
+        }
+
+        (cards, separator, ghost)
+    }
+
+    fn hidden_cards_line(&self, arrow: &str, count: usize) -> Line<'static> {
+        Line::from(Span::styled(format!("{} {} more", arrow, count), self.theme.separator)).alignment(Alignment::Center)
+    }
+
+    /// Where the card is drawn when the column is rendered in `area`, if it is visible
+    pub fn card_area(&self, area: Rect, card_index: usize) -> Option<Rect> {
+        let inner_area = Block::bordered().inner(area);
+        self.layout(inner_area)
+            .cards
+            .into_iter()
+            .find(|(index, _)| *index == card_index)
+            .map(|(_, area)| area)
+    }
+}
+
+impl Widget for ColumnView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let column = self.column;
+        let lock = if column.is_read_only() { "🔒 " } else { "" };
+        let header = if column.sort_policy().is_manual() {
+            format!(" {}{} ", lock, column.header())
+        } else {
+            format!(" {}{} (by {}) ", lock, column.header(), column.sort_policy().name())
+        };
+        let header_style = match self.header_color {
+            Some(color) => self.theme.column_header.fg(color),
+            None => self.theme.column_header,
+        };
+        let mut title = Line::from(Span::styled(header, header_style));
+        if let Some(limit) = column.wip_limit() {
+            let style = if column.size() > limit {
+                self.theme.overdue_badge
+            } else {
+                self.theme.separator
+            };
+            title.push_span(Span::styled(format!("{}/{} ", column.size(), limit), style));
+        }
+        let overdue = column.overdue_count(Local::now().date_naive());
+        if overdue > 0 {
+            title.push_span(Span::styled(format!("⚠{} ", overdue), self.theme.overdue_badge));
+        }
+        let hidden = column.size() - self.shown_cards().len();
+        if hidden > 0 {
+            title.push_span(Span::styled(format!("+{} hidden ", hidden), self.theme.separator));
+        }
+        let title = Title::from(title).alignment(Alignment::Center);
+
+        let block = Block::bordered().title(title).border_set(border::THICK);
+        let layout = self.layout(block.inner(area));
+
+        if let Some(separator_area) = layout.separator {
+            Line::from(Span::styled(
+                "┄".repeat(separator_area.width as usize),
+                self.theme.separator,
+            ))
+            .render(separator_area, buf);
+        }
+
+        if let Some((count, line_area)) = layout.above {
+            self.hidden_cards_line("▲", count).render(line_area, buf);
+        }
+        if let Some((count, line_area)) = layout.below {
+            self.hidden_cards_line("▼", count).render(line_area, buf);
+        }
+
+        if let (Some((_, title)), Some(ghost_area)) = (self.ghost, layout.ghost) {
+            let ghost = Block::bordered()
+                .border_set(GHOST_BORDER)
+                .border_style(self.theme.separator);
+            Line::from(Span::styled(title, self.theme.separator)).render(ghost.inner(ghost_area), buf);
+            ghost.render(ghost_area, buf);
+        }
+
+        for (card_index, area) in layout.cards {
+            let rank = (card_index < self.committed_cards).then_some(card_index + 1);
+            CardView::new(column.get_card(card_index), self.theme)
+                .selected(self.selected_card == Some(card_index))
+                .flashed(self.flashed_card == Some(card_index))
+                .rank(rank)
+                .number(self.numbered.then_some(card_index + 1))
+                .renderer(self.renderer)
+                .render(area, buf);
+        }
+
+        block.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, TimeDelta};
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use crate::{
+        app::view::{card_renderer, Theme},
+        board::{Board, BoardBuilder},
+        config::Density,
+    };
+
+    use super::ColumnView;
+
+    fn screen(buffer: &Buffer) -> String {
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn overdue_badge() -> Result<()> {
+        let yesterday = Local::now().date_naive().pred_opt().unwrap();
+        let board: Board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Late", |card| card.due(yesterday))
+                    .card_with("Later", |card| card.due(yesterday))
+                    .card("Whenever")
+            })
+            .column("Done", |c| c.card("Whenever"))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 10);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(screen(&buffer).contains(" TODO ⚠2 "));
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(1), &theme).render(area, &mut buffer);
+        assert!(!screen(&buffer).contains("⚠"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn wip_limit_badge() -> Result<()> {
+        let board: Board = BoardBuilder::new()
+            .column("Doing", |c| c.wip_limit(1).card("Cook").card("Clean"))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 10);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(screen(&buffer).contains(" Doing 2/1 "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_on_read_only_columns() -> Result<()> {
+        let board: Board = BoardBuilder::new()
+            .column("Inbox", |c| c.read_only().card("Imported"))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 10);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(screen(&buffer).contains("🔒"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn scroll_to_selected_card() -> Result<()> {
+        let board: Board = BoardBuilder::new()
+            .column("TODO", |c| (1..=12).fold(c, |c, i| c.card(&format!("Card #{:02}", i))))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 40);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(screen(&buffer).contains("Card #01"));
+        assert!(screen(&buffer).contains("Card #09"));
+        assert!(!screen(&buffer).contains("Card #10"));
+        assert!(!screen(&buffer).contains("▲"));
+        assert!(screen(&buffer).contains("▼ 3 more"));
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .selected_card(Some(10))
+            .committed_cards(3)
+            .render(area, &mut buffer);
+        assert!(!screen(&buffer).contains("Card #02"));
+        assert!(screen(&buffer).contains("Card #03"));
+        assert!(screen(&buffer).contains("Card #11"));
+        assert!(screen(&buffer).contains("╔"));
+        assert!(
+            screen(&buffer).contains("┄"),
+            "the last committed card is still visible"
+        );
+        assert!(screen(&buffer).contains("▲ 2 more"));
+        assert!(screen(&buffer).contains("▼ 1 more"));
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .renderer(card_renderer(Density::Compact))
+            .render(area, &mut buffer);
+        assert!(
+            screen(&buffer).contains("Card #12"),
+            "as many compact cards as fit the column"
+        );
+        assert!(!screen(&buffer).contains("more"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_cards_not_fitting() -> Result<()> {
+        let board: Board = BoardBuilder::new()
+            .column("TODO", |c| (1..=5).fold(c, |c, i| c.card(&format!("Card #{:02}", i))))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 12);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(screen(&buffer).contains("Card #02"));
+        assert!(!screen(&buffer).contains("Card #03"));
+        assert!(screen(&buffer).contains("▼ 3 more"));
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .selected_card(Some(3))
+            .render(area, &mut buffer);
+        assert!(screen(&buffer).contains("Card #04"), "scrolled to the selected card");
+        assert!(screen(&buffer).contains("▲"));
+
+        let area = Rect::new(0, 0, 30, 40);
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme).render(area, &mut buffer);
+        assert!(!screen(&buffer).contains("more"), "all cards fit");
+
+        Ok(())
+    }
+
+    #[test]
+    fn ghost_of_moved_card() -> Result<()> {
+        let board: Board = BoardBuilder::new()
+            .column("TODO", |c| (1..=12).fold(c, |c, i| c.card(&format!("Card #{:02}", i))))
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 40);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .ghost(Some((1, "Moved card")))
+            .render(area, &mut buffer);
+        let content = screen(&buffer);
+        assert!(content.contains("┆Moved card"));
+        assert!(content.find("Card #01") < content.find("Moved card"));
+        assert!(content.find("Moved card") < content.find("Card #02"));
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .ghost(Some((12, "Moved card")))
+            .render(area, &mut buffer);
+        assert!(
+            screen(&buffer).contains("Moved card"),
+            "scrolled to the ghost after the last card"
+        );
+        assert!(screen(&buffer).contains("Card #12"));
+        assert!(screen(&buffer).contains("▲"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn hide_cards_done_long_ago() -> Result<()> {
+        let now = Local::now();
+        let long_ago = now - TimeDelta::days(30);
+        let board: Board = BoardBuilder::new()
+            .column("Done", |c| {
+                c.card_with("Done long ago", |card| card.completed(long_ago))
+                    .card_with("Done today", |card| card.completed(now))
+                    .card_with("Also long ago", |card| card.completed(long_ago))
+            })
+            .build();
+        let theme = Theme::default();
+        let area = Rect::new(0, 0, 30, 40);
+
+        let mut buffer = Buffer::empty(area);
+        ColumnView::new(board.column(0), &theme)
+            .hidden_before(Some(now - TimeDelta::days(14)))
+            .render(area, &mut buffer);
+        assert!(screen(&buffer).contains(" Done +2 hidden "));
+        assert!(screen(&buffer).contains("Done today"));
+        assert!(!screen(&buffer).contains("long ago"));
+
+        Ok(())
+    }
+}
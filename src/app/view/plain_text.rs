@@ -0,0 +1,86 @@
+use ratatui::text::Line;
+
+use crate::{app::view::card_renderer, board::Board, config::Density, utils::text};
+
+/// Width of the printed board when none is given, that of a classic terminal
+pub const DEFAULT_PRINT_WIDTH: usize = 80;
+
+/// Spaces between two printed columns
+const COLUMN_GAP: usize = 2;
+
+/// The board as plain text columns side by side, without colors, to read outside of the application
+///
+/// Each column gets an equal share of `width` characters and shows its cards one per line, cut like by the
+/// compact density.
+pub fn print_board(board: &Board, width: usize) -> String {
+    let count = board.columns_count().max(1);
+    let column_width = (width.saturating_sub(COLUMN_GAP * (count - 1)) / count).max(1);
+    let renderer = card_renderer(Density::Compact);
+
+    let columns: Vec<Vec<String>> = board
+        .columns_iter()
+        .map(|column| {
+            let header = format!("{} ({})", column.header(), column.size());
+            let mut lines = text::wrap_truncated(&header, column_width, 1);
+            lines.push("-".repeat(column_width));
+            lines.extend(
+                column
+                    .cards_iter()
+                    .map(|card| renderer.lines(card, column_width, 1).iter().map(plain).collect()),
+            );
+            lines
+        })
+        .collect();
+
+    let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+    let mut output = String::new();
+    for row in 0..height {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|lines| {
+                format!(
+                    "{:width$}",
+                    lines.get(row).map_or("", String::as_str),
+                    width = column_width
+                )
+            })
+            .collect();
+        output.push_str(cells.join(&" ".repeat(COLUMN_GAP)).trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Text of a line without its styles
+fn plain(line: &Line) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::BoardBuilder;
+
+    use super::print_board;
+
+    #[test]
+    fn print_columns_side_by_side() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk").card("Write a very long shopping list"))
+            .column("Doing", |c| c.card("Cook"))
+            .column("Done", |c| c)
+            .build();
+
+        assert_eq!(
+            "TODO (2)      Doing (1)     Done (0)\n\
+             ------------  ------------  ------------\n\
+             Buy milk      Cook\n\
+             Write a ver…\n",
+            print_board(&board, 40)
+        );
+
+        Ok(())
+    }
+}
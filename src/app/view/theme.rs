@@ -0,0 +1,27 @@
+use ratatui::style::{Color, Modifier, Style};
+
+/// Styles used to render the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub column_header: Style,
+    pub selected_card: Style,
+    pub flashed_card: Style,
+    pub rank_badge: Style,
+    pub overdue_badge: Style,
+    pub star: Style,
+    pub separator: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            column_header: Style::new().add_modifier(Modifier::BOLD),
+            selected_card: Style::new(),
+            flashed_card: Style::new().add_modifier(Modifier::REVERSED),
+            rank_badge: Style::new().add_modifier(Modifier::BOLD),
+            overdue_badge: Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            star: Style::new().fg(Color::Yellow),
+            separator: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+}
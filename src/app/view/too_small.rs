@@ -0,0 +1,64 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Stylize,
+    text::{Line, Text},
+    widgets::{Paragraph, Widget, Wrap},
+};
+
+/// Smallest terminal in which the board, the logs and the popups are drawn without overlapping
+pub const MIN_WIDTH: u16 = 80;
+pub const MIN_HEIGHT: u16 = 24;
+
+/// Shown instead of the application while the terminal is too small for it
+pub struct TooSmall;
+
+impl TooSmall {
+    pub fn fits(area: Rect) -> bool {
+        area.width >= MIN_WIDTH && area.height >= MIN_HEIGHT
+    }
+}
+
+impl Widget for TooSmall {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let text = Text::from(vec![
+            Line::from("Terminal too small".bold()),
+            Line::from(format!(
+                "need {}x{}, got {}x{}",
+                MIN_WIDTH, MIN_HEIGHT, area.width, area.height
+            )),
+            Line::from("Resize it to see the board, or press <q> to quit".dim()),
+        ])
+        .centered();
+
+        let [area] = Layout::vertical([Constraint::Length(text.height() as u16)])
+            .flex(Flex::Center)
+            .areas(area);
+        Paragraph::new(text).wrap(Wrap { trim: true }).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use super::TooSmall;
+
+    #[test]
+    fn minimum_size() -> Result<()> {
+        assert!(TooSmall::fits(Rect::new(0, 0, 80, 24)));
+        assert!(!TooSmall::fits(Rect::new(0, 0, 79, 40)));
+        assert!(!TooSmall::fits(Rect::new(0, 0, 120, 23)));
+
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buffer = Buffer::empty(area);
+        TooSmall.render(area, &mut buffer);
+        let screen: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(screen.contains("Terminal too small"));
+        assert!(screen.contains("need 80x24, got 40x10"));
+
+        Ok(())
+    }
+}
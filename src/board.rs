@@ -1,7 +1,53 @@
-mod board;
+mod advice;
+#[cfg(test)]
+mod arbitrary;
+mod builder;
+mod burn_up;
 mod card;
+mod card_template;
+mod checklist;
+mod color;
 mod column;
+mod column_files;
+mod csv_import;
+mod diff;
+mod file_service;
+mod flow;
+mod health;
+mod kanban;
+mod metrics;
+mod obsidian;
+mod search;
+mod section;
+mod sort;
+mod standup;
+mod stats;
+mod stats_log;
+mod sync;
+mod template;
 
-pub use board::Board;
-pub use card::Card;
-use column::Column;
+pub use advice::{suggestions, Suggestion};
+#[cfg(test)]
+pub(crate) use arbitrary::arbitrary_board;
+pub use builder::{BoardBuilder, CardBuilder, ColumnBuilder};
+pub use burn_up::{week_start, BurnUp};
+pub use card::{Card, ColumnEntry};
+pub use card_template::CardTemplate;
+pub use checklist::Checklist;
+pub use color::CardColor;
+pub use column::{Column, ColumnOptions};
+pub use csv_import::{csv_cards, parse_csv, read_csv, CsvMapping, CSV_FIELDS};
+pub use diff::{find_card, merge, BoardChange};
+pub use file_service::{file_service, ColumnFiles, FileService, JsonFile, ObsidianFile};
+pub use flow::CumulativeFlow;
+pub use health::{health_check, Finding};
+pub use kanban::{stored_content, Board, ExportScope, JsonLayout, SaveOptions};
+pub use metrics::{metrics_file_name, ColumnCount, DailySnapshot, MetricsHistory};
+pub use search::{SavedSearch, SearchMatch};
+pub use section::Section;
+pub use sort::SortPolicy;
+pub use standup::{StandUp, StandUpCard};
+pub use stats::{BoardStatistics, ColumnStatistics, ReadyCard, StaleCard, Throughput, TimeDistribution};
+pub use stats_log::{append_stats_row, stats_log_file_name, StatsRow};
+pub use sync::{sync_base_file_name, FileSyncAdapter, SyncAdapter, SyncPlan};
+pub use template::Template;
@@ -0,0 +1,220 @@
+use std::{collections::HashMap, fmt};
+
+use chrono::{DateTime, Duration, Local};
+
+use crate::board::{Board, BoardStatistics, MetricsHistory};
+
+/// Days over which the growth of the first column is compared to the completed cards
+const GROWTH_DAYS: i64 = 7;
+
+/// Something to look at to keep the work flowing through the columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Suggestion {
+    /// A column holding more cards than its work in progress limit, for a number of days
+    WipExceeded {
+        header: String,
+        limit: usize,
+        cards: usize,
+        days: i64,
+    },
+    /// The first column getting cards faster than the board completes them
+    Growing {
+        header: String,
+        added: i64,
+        completed: usize,
+    },
+    /// Cards in progress for longer than most completed cards took from their creation
+    Aging { header: String, cards: usize, days: i64 },
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Suggestion::WipExceeded {
+                header,
+                limit,
+                cards,
+                days,
+            } => write!(
+                f,
+                "{} exceeded its WIP limit of {} for {} day(s), {} cards in it",
+                header, limit, days, cards
+            ),
+            Suggestion::Growing {
+                header,
+                added,
+                completed,
+            } => write!(
+                f,
+                "{} growing faster than completion: {} more cards in {} days, {} completed",
+                header, added, GROWTH_DAYS, completed
+            ),
+            Suggestion::Aging { header, cards, days } => write!(
+                f,
+                "{} has {} card(s) older than {} days, when 85% of the completed ones took less",
+                header, cards, days
+            ),
+        }
+    }
+}
+
+/// Looks at the work in progress limits, the growth of the backlog and the age of the cards in progress
+///
+/// `wip_limits` gives a maximum number of cards per column header. The days a limit has been exceeded and
+/// the growth of the first column are read from the daily snapshots of the metrics history.
+pub fn suggestions(
+    board: &Board,
+    statistics: &BoardStatistics,
+    metrics: &MetricsHistory,
+    wip_limits: &HashMap<String, usize>,
+    now: DateTime<Local>,
+) -> Vec<Suggestion> {
+    let today = now.date_naive();
+    let mut suggestions = vec![];
+
+    for column in &statistics.columns {
+        let Some(&limit) = wip_limits.get(&column.header) else {
+            continue;
+        };
+        if column.cards_count <= limit {
+            continue;
+        }
+
+        // Going back over the snapshots until the first one within the limit
+        let since = metrics
+            .snapshots()
+            .iter()
+            .rev()
+            .filter(|snapshot| snapshot.date < today)
+            .take_while(|snapshot| {
+                snapshot
+                    .columns
+                    .iter()
+                    .any(|count| count.header == column.header && count.cards > limit)
+            })
+            .last()
+            .map_or(today, |snapshot| snapshot.date);
+        suggestions.push(Suggestion::WipExceeded {
+            header: column.header.clone(),
+            limit,
+            cards: column.cards_count,
+            days: (today - since).num_days() + 1,
+        });
+    }
+
+    if let Some(first) = statistics.columns.first() {
+        let week_ago = metrics
+            .snapshots()
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.date <= today - Duration::days(GROWTH_DAYS))
+            .and_then(|snapshot| snapshot.columns.iter().find(|count| count.header == first.header));
+        if let Some(week_ago) = week_ago {
+            let added = first.cards_count as i64 - week_ago.cards as i64;
+            let completed = statistics.throughput.last_7_days;
+            if added > completed as i64 {
+                suggestions.push(Suggestion::Growing {
+                    header: first.header.clone(),
+                    added,
+                    completed,
+                });
+            }
+        }
+    }
+
+    if let Some(hours) = statistics.lead_times.percentile_85_in_hours {
+        let in_progress = board
+            .columns_iter()
+            .skip(1)
+            .take(board.columns_count().saturating_sub(2));
+        for column in in_progress {
+            let cards = column
+                .cards_iter()
+                .filter(|card| (now - *card.creation_date()).num_hours() > hours)
+                .count();
+            if cards > 0 {
+                suggestions.push(Suggestion::Aging {
+                    header: column.header().to_string(),
+                    cards,
+                    days: hours / 24,
+                });
+            }
+        }
+    }
+
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Result};
+
+    use chrono::{Duration, Local};
+
+    use crate::board::{BoardBuilder, BoardStatistics, MetricsHistory};
+
+    use super::{suggestions, Suggestion};
+
+    #[test]
+    fn wip_growth_and_aging() -> Result<()> {
+        let now = Local::now();
+        let today = now.date_naive();
+        let board = |todo: usize, doing: usize| {
+            BoardBuilder::new()
+                .column("TODO", |c| (0..todo).fold(c, |c, _| c.card("Task")))
+                .column("Doing", |c| (0..doing).fold(c, |c, _| c.card("Task")))
+                .column("Done", |c| c)
+                .build()
+        };
+
+        let mut metrics = MetricsHistory::default();
+        metrics.record(&board(2, 1), today - Duration::days(8));
+        metrics.record(&board(3, 3), today - Duration::days(2));
+        metrics.record(&board(4, 4), today - Duration::days(1));
+
+        let board = board(5, 4);
+        let statistics = BoardStatistics::compute(&board, &HashMap::new(), now);
+        let limits = HashMap::from([("Doing".to_string(), 2), ("TODO".to_string(), 10)]);
+        let found = suggestions(&board, &statistics, &metrics, &limits, now);
+        assert_eq!(
+            vec![
+                Suggestion::WipExceeded {
+                    header: "Doing".into(),
+                    limit: 2,
+                    cards: 4,
+                    days: 3
+                },
+                Suggestion::Growing {
+                    header: "TODO".into(),
+                    added: 3,
+                    completed: 0
+                },
+            ],
+            found
+        );
+        assert_eq!(
+            "Doing exceeded its WIP limit of 2 for 3 day(s), 4 cards in it",
+            found[0].to_string()
+        );
+
+        let later = now + Duration::days(30);
+        let completed = BoardBuilder::new()
+            .column("TODO", |c| c)
+            .column("Doing", |c| c.card("Task"))
+            .column("Done", |c| {
+                c.card_with("Shipped", |card| card.created(now - Duration::days(2)).completed(now))
+            })
+            .build();
+        let statistics = BoardStatistics::compute(&completed, &HashMap::new(), later);
+        let found = suggestions(
+            &completed,
+            &statistics,
+            &MetricsHistory::default(),
+            &HashMap::new(),
+            later,
+        );
+        assert!(matches!(found[..], [Suggestion::Aging { cards: 1, .. }]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use chrono::{Local, TimeDelta};
+
+use crate::{
+    board::{Board, BoardBuilder, CardBuilder, CardColor},
+    utils::random::Random,
+};
+
+const ARBITRARY_MAX_COLUMNS: usize = 5;
+const ARBITRARY_MAX_CARDS: usize = 6;
+
+/// A board generated from a seed, with one to a few columns, some of them empty, and cards with various fields
+///
+/// The same seed always gives the same board, up to the creation dates which are relative to now, so a case found
+/// by a property test can be replayed.
+pub fn arbitrary_board(seed: u64) -> Board {
+    let mut random = Random::new(seed);
+    let columns_count = 1 + random.below(ARBITRARY_MAX_COLUMNS);
+
+    (0..columns_count)
+        .fold(BoardBuilder::new(), |builder, column_index| {
+            let cards_count = random.below(ARBITRARY_MAX_CARDS + 1);
+            builder.column(&format!("Column {}", column_index), |column| {
+                (0..cards_count).fold(column, |column, card_index| {
+                    let title = format!("Card {}.{}", column_index, card_index);
+                    column.card_with(&title, |card| arbitrary_card(card, &mut random))
+                })
+            })
+        })
+        .build()
+}
+
+fn arbitrary_card(mut card: CardBuilder, random: &mut Random) -> CardBuilder {
+    let now = Local::now();
+    card = card.created(now - TimeDelta::hours(random.below(24 * 30) as i64));
+    if random.one_in(3) {
+        card = card.long_description("- [x] first\n- [ ] second");
+    }
+    if random.one_in(3) {
+        let tag = *random.pick(&["bug", "feature", "chore"]);
+        card = card.tag(tag);
+    }
+    if random.one_in(4) {
+        card = card.due(now.date_naive() + TimeDelta::days(random.below(20) as i64 - 10));
+    }
+    if random.one_in(4) {
+        card = card.estimate(random.below(8) as u32 + 1);
+    }
+    if random.one_in(5) {
+        card = card.color(*random.pick(&[CardColor::Red, CardColor::Green, CardColor::Blue]));
+    }
+    if random.one_in(5) {
+        card = card.completed(now - TimeDelta::days(random.below(30) as i64));
+    }
+    card
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::arbitrary_board;
+
+    #[test]
+    fn arbitrary_boards() -> Result<()> {
+        let boards: Vec<_> = (0..50).map(arbitrary_board).collect();
+
+        assert!(boards.iter().all(|board| (1..=5).contains(&board.columns_count())));
+        assert!(boards.iter().any(|board| board.columns_count() == 1));
+        assert!(boards
+            .iter()
+            .any(|board| board.columns_iter().any(|column| column.is_empty())));
+        assert_eq!(
+            boards[7].cards_with_positions().count(),
+            arbitrary_board(7).cards_with_positions().count()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::board::{Board, Card, CardColor, Column, ColumnOptions};
+
+/// Builds a board programmatically, column by column
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use rustyban::board::BoardBuilder;
+///
+/// let board = BoardBuilder::new()
+///     .column("TODO", |c| {
+///         c.card("Buy milk")
+///             .card_with("Pay rent", |card| card.due(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()))
+///     })
+///     .column("Done!", |c| c.card("Eat dinner"))
+///     .build();
+///
+/// assert_eq!(2, board.columns_count());
+/// assert_eq!("Pay rent", board.card(0, 1).short_description());
+/// assert_eq!(NaiveDate::from_ymd_opt(2025, 1, 1), board.card(0, 1).due_date());
+/// ```
+#[derive(Debug, Default)]
+pub struct BoardBuilder {
+    columns: Vec<Column>,
+}
+
+impl BoardBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn column<F>(mut self, header: &str, build: F) -> Self
+    where
+        F: FnOnce(ColumnBuilder) -> ColumnBuilder,
+    {
+        let builder = build(ColumnBuilder::new(header));
+        let mut column = Column::new(&builder.header, builder.cards);
+        column.set_section(builder.section.as_deref());
+        column.set_read_only(builder.read_only);
+        column.set_options(ColumnOptions {
+            wip_limit: builder.wip_limit,
+            color: builder.color,
+            ..column.options()
+        });
+        self.columns.push(column);
+        self
+    }
+
+    /// Builds the board, a builder without any column gives the default board
+    pub fn build(self) -> Board {
+        if self.columns.is_empty() {
+            Board::new()
+        } else {
+            Board::from_columns(self.columns)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ColumnBuilder {
+    header: String,
+    cards: Vec<Card>,
+    section: Option<String>,
+    read_only: bool,
+    wip_limit: Option<usize>,
+    color: Option<CardColor>,
+}
+
+impl ColumnBuilder {
+    fn new(header: &str) -> Self {
+        Self {
+            header: header.into(),
+            cards: vec![],
+            section: None,
+            read_only: false,
+            wip_limit: None,
+            color: None,
+        }
+    }
+
+    /// Makes the cards of the column view only
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    pub fn wip_limit(mut self, wip_limit: usize) -> Self {
+        self.wip_limit = Some(wip_limit);
+        self
+    }
+
+    /// Colors the header of the column
+    pub fn color(mut self, color: CardColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Groups the column with its neighbours of the same section
+    pub fn section(mut self, section: &str) -> Self {
+        self.section = Some(section.into());
+        self
+    }
+
+    pub fn card(self, short_description: &str) -> Self {
+        self.card_with(short_description, |card| card)
+    }
+
+    pub fn card_with<F>(mut self, short_description: &str, build: F) -> Self
+    where
+        F: FnOnce(CardBuilder) -> CardBuilder,
+    {
+        let builder = build(CardBuilder::new(short_description));
+        self.cards.push(builder.build());
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct CardBuilder {
+    short_description: String,
+    long_description: String,
+    creation_date: DateTime<Local>,
+    tags: Vec<String>,
+    due_date: Option<NaiveDate>,
+    estimate: Option<u32>,
+    color: Option<CardColor>,
+    completion_date: Option<DateTime<Local>>,
+    starred: bool,
+}
+
+impl CardBuilder {
+    fn new(short_description: &str) -> Self {
+        Self {
+            short_description: short_description.into(),
+            long_description: String::new(),
+            creation_date: Local::now(),
+            tags: vec![],
+            due_date: None,
+            estimate: None,
+            color: None,
+            completion_date: None,
+            starred: false,
+        }
+    }
+
+    pub fn long_description(mut self, long_description: &str) -> Self {
+        self.long_description = long_description.into();
+        self
+    }
+
+    pub fn created(mut self, creation_date: DateTime<Local>) -> Self {
+        self.creation_date = creation_date;
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn due(mut self, due_date: NaiveDate) -> Self {
+        self.due_date = Some(due_date);
+        self
+    }
+
+    pub fn estimate(mut self, estimate: u32) -> Self {
+        self.estimate = Some(estimate);
+        self
+    }
+
+    pub fn color(mut self, color: CardColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn completed(mut self, completion_date: DateTime<Local>) -> Self {
+        self.completion_date = Some(completion_date);
+        self
+    }
+
+    pub fn starred(mut self) -> Self {
+        self.starred = true;
+        self
+    }
+
+    fn build(self) -> Card {
+        let mut card = Card::new(&self.short_description, self.creation_date);
+        card.update_long_description(&self.long_description);
+        card.update_tags(self.tags);
+        card.update_due_date(self.due_date);
+        card.update_estimate(self.estimate);
+        card.update_color(self.color);
+        card.update_completion_date(self.completion_date);
+        card.set_starred(self.starred);
+        card
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, NaiveDate};
+
+    use super::BoardBuilder;
+
+    #[test]
+    fn build_board() -> Result<()> {
+        let creation_date = Local::now() - chrono::Duration::days(3);
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card("Buy milk").card_with("Buy eggs", |card| {
+                    card.long_description("A dozen")
+                        .tag("shopping")
+                        .tag("food")
+                        .due(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+                        .created(creation_date)
+                })
+            })
+            .column("Doing", |c| c)
+            .column("Done!", |c| c.card("Eat dinner"))
+            .build();
+
+        assert_eq!(3, board.columns_count());
+        assert_eq!("TODO", board.column(0).header());
+        assert_eq!(2, board.column(0).size());
+        assert!(board.column(1).is_empty());
+        assert_eq!("Eat dinner", board.card(2, 0).short_description());
+
+        let card = board.card(0, 1);
+        assert_eq!("Buy eggs", card.short_description());
+        assert_eq!("A dozen", card.long_description());
+        assert_eq!(vec!["shopping".to_string(), "food".to_string()], card.tags());
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 24), card.due_date());
+        assert_eq!(&creation_date, card.creation_date());
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_builder_gives_default_board() -> Result<()> {
+        let board = BoardBuilder::new().build();
+
+        assert_eq!(3, board.columns_count());
+        assert_eq!("TODO", board.column(0).header());
+
+        Ok(())
+    }
+}
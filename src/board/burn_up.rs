@@ -0,0 +1,111 @@
+use chrono::{DateTime, Datelike, Days, Local, NaiveTime};
+
+use crate::{
+    board::{
+        stats::{completed_cards, completed_since},
+        Board,
+    },
+    utils::time,
+};
+
+/// Cards completed since the start of the week, against the weekly target of the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnUp {
+    pub done: usize,
+    pub target: usize,
+}
+
+impl BurnUp {
+    /// Counts the cards of the last column and of the archive completed this week, `None` without a target
+    pub fn compute(board: &Board, now: DateTime<Local>) -> Option<Self> {
+        let target = board.weekly_target()?;
+        let done = completed_since(&completed_cards(board), week_start(now));
+        Some(Self { done, target })
+    }
+
+    pub fn is_reached(&self) -> bool {
+        self.done >= self.target
+    }
+
+    /// Share of the target completed, from 0 to `width` cells, to draw a gauge
+    pub fn filled(&self, width: usize) -> usize {
+        (self.done * width / self.target.max(1)).min(width)
+    }
+}
+
+/// Monday at midnight of the week of `now`, in local time, or the first time of that Monday when midnight is skipped
+pub fn week_start(now: DateTime<Local>) -> DateTime<Local> {
+    let monday = now.date_naive() - Days::new(now.weekday().num_days_from_monday() as u64);
+    time::first_valid_time(&Local, monday.and_time(NaiveTime::MIN))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Duration, Local, NaiveDate, TimeZone};
+
+    use crate::board::BoardBuilder;
+
+    use super::{week_start, BurnUp};
+
+    #[test]
+    fn weeks_start_on_monday() -> Result<()> {
+        let monday = Local.with_ymd_and_hms(2024, 12, 23, 0, 0, 0).unwrap();
+
+        assert_eq!(monday, week_start(monday));
+        assert_eq!(
+            monday,
+            week_start(Local.with_ymd_and_hms(2024, 12, 25, 15, 30, 0).unwrap())
+        );
+        assert_eq!(
+            monday,
+            week_start(Local.with_ymd_and_hms(2024, 12, 29, 23, 59, 59).unwrap())
+        );
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+            week_start(Local.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap()).date_naive(),
+            "across years"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn done_this_week() -> Result<()> {
+        let now = Local.with_ymd_and_hms(2024, 12, 25, 15, 30, 0).unwrap();
+        let monday = Local.with_ymd_and_hms(2024, 12, 23, 0, 0, 0).unwrap();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Done", |c| {
+                c.card_with("Wash dishes", |card| card.completed(monday))
+                    .card_with("Cook dinner", |card| card.completed(now - Duration::hours(1)))
+                    .card_with("Eat dinner", |card| card.completed(monday - Duration::seconds(1)))
+            })
+            .build();
+        assert_eq!(None, BurnUp::compute(&board, now), "no target");
+
+        board.set_weekly_target(Some(4));
+        let burn_up = BurnUp::compute(&board, now).unwrap();
+        assert_eq!(
+            BurnUp { done: 2, target: 4 },
+            burn_up,
+            "not the card completed on Sunday"
+        );
+        assert!(!burn_up.is_reached());
+        assert_eq!(5, burn_up.filled(10));
+
+        let next_monday = monday + Duration::weeks(1);
+        assert_eq!(0, BurnUp::compute(&board, next_monday).unwrap().done);
+
+        board.set_weekly_target(Some(2));
+        let burn_up = BurnUp::compute(&board, now).unwrap();
+        assert!(burn_up.is_reached());
+        assert_eq!(10, BurnUp { done: 3, target: 2 }.filled(10));
+
+        board.set_weekly_target(Some(0));
+        assert_eq!(None, board.weekly_target());
+
+        Ok(())
+    }
+}
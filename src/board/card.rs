@@ -1,16 +1,20 @@
-use std::borrow::Borrow;
-
-use chrono::{DateTime, Local};
-use ratatui::{
-    buffer::Buffer,
-    layout::{Alignment, Rect},
-    symbols::border,
-    text::{Line, Text},
-    widgets::{Block, Paragraph, Widget},
-};
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate, TimeDelta};
 use serde::{Deserialize, Serialize};
 
-use crate::utils::time;
+use crate::board::{column::is_false, CardColor, Checklist};
+
+/// A card moving into a column, the first column of a card is not recorded as it was created there
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ColumnEntry {
+    pub header: String,
+    pub date: DateTime<Local>,
+
+    /// Who moved the card, when the board is shared
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
 pub struct Card {
@@ -20,8 +24,46 @@ pub struct Card {
 
     creation_date: DateTime<Local>,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    due_date: Option<NaiveDate>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    estimate: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    completion_date: Option<DateTime<Local>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<CardColor>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    column_entries: Vec<ColumnEntry>,
+
+    /// Time tracked on the card, in seconds
+    #[serde(default, skip_serializing_if = "is_zero")]
+    time_spent: u64,
+
+    /// Pomodoros completed on the card
+    #[serde(default, skip_serializing_if = "is_zero_u32")]
+    pomodoros: u32,
+
+    /// Temporary card for the working session, left out when the board is written to a file
+    #[serde(default, skip_serializing_if = "is_false")]
+    scratch: bool,
+
+    /// Marked to keep an eye on it, starred cards can be shown alone
+    #[serde(default, skip_serializing_if = "is_false")]
+    starred: bool,
+
+    /// Position of the card in its column as a sort key, kept by the column so it does not depend on the other cards
+    #[serde(default)]
+    rank: f64,
+
     #[serde(skip)]
-    is_selected: bool,
+    is_timed: bool,
 }
 
 impl Card {
@@ -30,7 +72,18 @@ impl Card {
             short_description: short_description.into(),
             long_description: "".into(),
             creation_date,
-            is_selected: false,
+            tags: vec![],
+            due_date: None,
+            estimate: None,
+            completion_date: None,
+            color: None,
+            column_entries: vec![],
+            time_spent: 0,
+            pomodoros: 0,
+            scratch: false,
+            starred: false,
+            rank: 0.0,
+            is_timed: false,
         }
     }
 
@@ -46,8 +99,94 @@ impl Card {
         &self.creation_date
     }
 
-    pub fn is_selected(&self) -> bool {
-        self.is_selected
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn due_date(&self) -> Option<NaiveDate> {
+        self.due_date
+    }
+
+    /// Estimated effort, in whatever unit the team uses
+    pub fn estimate(&self) -> Option<u32> {
+        self.estimate
+    }
+
+    pub fn checklist(&self) -> Checklist {
+        Checklist::parse(&self.long_description)
+    }
+
+    /// When the card reached the last column of the board
+    pub fn completion_date(&self) -> Option<DateTime<Local>> {
+        self.completion_date
+    }
+
+    /// Columns the card moved into, oldest first
+    pub fn column_entries(&self) -> &[ColumnEntry] {
+        &self.column_entries
+    }
+
+    /// When the card entered the column it is in, its creation date if it never moved
+    pub fn entered_column_date(&self) -> DateTime<Local> {
+        self.column_entries
+            .last()
+            .map_or(self.creation_date, |entry| entry.date)
+    }
+
+    /// Whether the due date has passed and the card is not completed
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.completion_date.is_none() && self.due_date.is_some_and(|due_date| due_date < today)
+    }
+
+    /// Whether the card was completed before a date, cards never completed were not
+    pub fn completed_before(&self, date: DateTime<Local>) -> bool {
+        self.completion_date
+            .is_some_and(|completion_date| completion_date < date)
+    }
+
+    /// Time from the creation of the card to its completion
+    pub fn lead_time(&self) -> Option<TimeDelta> {
+        Some(self.completion_date? - self.creation_date)
+    }
+
+    /// Time from the card leaving its first column, when work started, to its completion
+    ///
+    /// A card which never moved has no known start of work, its lead time is no cycle time.
+    pub fn cycle_time(&self) -> Option<TimeDelta> {
+        let started = self.column_entries.first()?.date;
+        Some(self.completion_date? - started)
+    }
+
+    pub fn color(&self) -> Option<CardColor> {
+        self.color
+    }
+
+    /// Time tracked on the card with the timer
+    pub fn time_spent(&self) -> Duration {
+        Duration::from_secs(self.time_spent)
+    }
+
+    /// Pomodoros completed with the timer on the card
+    pub fn pomodoros(&self) -> u32 {
+        self.pomodoros
+    }
+
+    /// Whether the running timer tracks time on this card
+    /// Cards are ordered by increasing rank in their column
+    pub fn is_scratch(&self) -> bool {
+        self.scratch
+    }
+
+    pub fn is_starred(&self) -> bool {
+        self.starred
+    }
+
+    pub fn rank(&self) -> f64 {
+        self.rank
+    }
+
+    pub fn is_timed(&self) -> bool {
+        self.is_timed
     }
 
     pub fn update_short_description(&mut self, short_description: &str) {
@@ -58,59 +197,108 @@ impl Card {
         self.long_description = long_description.into();
     }
 
-    pub fn select(&mut self) {
-        self.is_selected = true;
+    pub fn update_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
     }
 
-    pub fn deselect(&mut self) {
-        self.is_selected = false;
+    pub fn update_due_date(&mut self, due_date: Option<NaiveDate>) {
+        self.due_date = due_date;
     }
-}
 
-impl Widget for &Card {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let border = if self.is_selected {
-            border::DOUBLE
-        } else {
-            border::ROUNDED
-        };
+    pub fn update_completion_date(&mut self, completion_date: Option<DateTime<Local>>) {
+        self.completion_date = completion_date;
+    }
 
-        let block = Block::bordered().border_set(border);
-        let now = Local::now();
+    pub fn update_estimate(&mut self, estimate: Option<u32>) {
+        self.estimate = estimate;
+    }
 
-        let text = Text::from(vec![
-            Line::from(self.short_description.borrow()),
-            Line::from(time::pretty_diff(self.creation_date, now)).alignment(Alignment::Right),
-        ]);
+    pub fn enter_column(&mut self, header: &str, date: DateTime<Local>) {
+        self.enter_column_by(header, date, None);
+    }
+
+    /// Records a move into a column made by someone, so shared boards show who did what
+    pub fn enter_column_by(&mut self, header: &str, date: DateTime<Local>, by: Option<&str>) {
+        self.column_entries.push(ColumnEntry {
+            header: header.into(),
+            date,
+            by: by.map(String::from),
+        });
+    }
 
-        Paragraph::new(text).block(block).render(area, buf);
+    /// Forgets the last column entry, when the move is undone
+    pub fn cancel_column_entry(&mut self) {
+        self.column_entries.pop();
+    }
+
+    pub fn update_color(&mut self, color: Option<CardColor>) {
+        self.color = color;
+    }
+
+    pub fn add_time_spent(&mut self, duration: Duration) {
+        self.time_spent += duration.as_secs();
+    }
+
+    pub fn add_pomodoro(&mut self) {
+        self.pomodoros += 1;
+    }
+
+    pub fn set_scratch(&mut self, scratch: bool) {
+        self.scratch = scratch;
+    }
+
+    pub fn set_starred(&mut self, starred: bool) {
+        self.starred = starred;
+    }
+
+    pub fn set_timed(&mut self, is_timed: bool) {
+        self.is_timed = is_timed;
+    }
+
+    pub(crate) fn set_rank(&mut self, rank: f64) {
+        self.rank = rank;
     }
 }
 
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+fn is_zero_u32(value: &u32) -> bool {
+    *value == 0
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Result;
 
-    use chrono::Local;
+    use chrono::{Local, TimeDelta};
 
     use super::Card;
 
     #[test]
-    fn selection() -> Result<()> {
-        let mut card = Card::new("test", Local::now());
-        assert!(!card.is_selected());
-
-        card.deselect();
-        assert!(!card.is_selected());
+    fn cycle_and_lead_times() -> Result<()> {
+        let created = Local::now() - TimeDelta::days(10);
+        let mut card = Card::new("test", created);
+        assert_eq!(created, card.entered_column_date());
+        assert_eq!(None, card.lead_time());
 
-        card.select();
-        assert!(card.is_selected());
+        let mut done_at_once = card.clone();
+        done_at_once.update_completion_date(Some(created + TimeDelta::days(2)));
+        assert_eq!(Some(TimeDelta::days(2)), done_at_once.lead_time());
+        assert_eq!(None, done_at_once.cycle_time(), "work never started");
 
-        card.select();
-        assert!(card.is_selected());
+        card.enter_column("Doing", created + TimeDelta::days(4));
+        card.enter_column("Review", created + TimeDelta::days(6));
+        card.enter_column("Oops", created + TimeDelta::days(7));
+        card.cancel_column_entry();
+        card.enter_column("Done!", created + TimeDelta::days(9));
+        card.update_completion_date(Some(created + TimeDelta::days(9)));
 
-        card.deselect();
-        assert!(!card.is_selected());
+        assert_eq!(3, card.column_entries().len());
+        assert_eq!(created + TimeDelta::days(9), card.entered_column_date());
+        assert_eq!(Some(TimeDelta::days(9)), card.lead_time());
+        assert_eq!(Some(TimeDelta::days(5)), card.cycle_time());
 
         Ok(())
     }
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::board::Card;
+
+const DEFAULT_SHORT_DESCRIPTION: &str = "TODO";
+
+/// Default values of the cards inserted in a column
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CardTemplate {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub title_prefix: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<u32>,
+}
+
+impl CardTemplate {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Creates a card filled with the default values, the title prefix replaces the usual placeholder
+    pub fn new_card(&self, creation_date: DateTime<Local>) -> Card {
+        let short_description = if self.title_prefix.is_empty() {
+            DEFAULT_SHORT_DESCRIPTION
+        } else {
+            &self.title_prefix
+        };
+
+        let mut card = Card::new(short_description, creation_date);
+        card.update_tags(self.tags.clone());
+        card.update_estimate(self.estimate);
+        card
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::Local;
+
+    use super::CardTemplate;
+
+    #[test]
+    fn new_card_from_template() -> Result<()> {
+        let template = CardTemplate::default();
+        assert!(template.is_empty());
+        assert_eq!("TODO", template.new_card(Local::now()).short_description());
+
+        let template = CardTemplate {
+            title_prefix: "[BUG] ".into(),
+            tags: vec!["bug".into()],
+            estimate: Some(3),
+        };
+        let card = template.new_card(Local::now());
+        assert_eq!("[BUG] ", card.short_description());
+        assert_eq!(vec!["bug".to_string()], card.tags());
+        assert_eq!(Some(3), card.estimate());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+/// Progress of the checklist found in the long description of a card
+///
+/// Checklist items are written as markdown task list items, `- [ ] to do` or `- [x] done`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Checklist {
+    pub done: usize,
+    pub total: usize,
+}
+
+impl Checklist {
+    pub fn parse(text: &str) -> Self {
+        text.lines()
+            .filter_map(|line| {
+                let line = line.trim_start();
+                let item = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))?;
+                match item.get(..3)? {
+                    "[ ]" => Some(false),
+                    "[x]" | "[X]" => Some(true),
+                    _ => None,
+                }
+            })
+            .fold(Self::default(), |checklist, done| Self {
+                done: checklist.done + done as usize,
+                total: checklist.total + 1,
+            })
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.total - self.done
+    }
+
+    /// Whether there is a checklist and all of its items are done
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.done == self.total
+    }
+}
+
+impl std::ops::Add for Checklist {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            done: self.done + other.done,
+            total: self.total + other.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::Checklist;
+
+    #[test]
+    fn parse_checklist() -> Result<()> {
+        let checklist =
+            Checklist::parse("Shopping list:\n- [x] Milk\n  - [ ] Eggs\n* [X] Bread\n- [] Not an item\n- Butter");
+
+        assert_eq!(Checklist { done: 2, total: 3 }, checklist);
+        assert_eq!(1, checklist.remaining());
+        assert!(!checklist.is_complete());
+        assert!(Checklist::parse("- [x] Milk").is_complete());
+        assert!(!Checklist::parse("No checklist").is_complete());
+
+        Ok(())
+    }
+}
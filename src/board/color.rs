@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Color set on a card to make it stand out, for blockers or urgent work
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardColor {
+    Red,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Magenta,
+    Gray,
+}
+
+impl CardColor {
+    pub fn all() -> [CardColor; 7] {
+        [
+            CardColor::Red,
+            CardColor::Yellow,
+            CardColor::Green,
+            CardColor::Cyan,
+            CardColor::Blue,
+            CardColor::Magenta,
+            CardColor::Gray,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardColor::Red => "red",
+            CardColor::Yellow => "yellow",
+            CardColor::Green => "green",
+            CardColor::Cyan => "cyan",
+            CardColor::Blue => "blue",
+            CardColor::Magenta => "magenta",
+            CardColor::Gray => "gray",
+        }
+    }
+
+    /// The color after the given one, cycling through all colors and no color at all
+    pub fn cycle(color: Option<CardColor>) -> Option<CardColor> {
+        let all = Self::all();
+        match color {
+            None => Some(all[0]),
+            Some(color) => all.iter().skip_while(|other| **other != color).nth(1).copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::CardColor;
+
+    #[test]
+    fn cycle_colors() -> Result<()> {
+        let mut color = None;
+        for expected in CardColor::all() {
+            color = CardColor::cycle(color);
+            assert_eq!(Some(expected), color);
+        }
+        assert_eq!(None, CardColor::cycle(color));
+
+        Ok(())
+    }
+}
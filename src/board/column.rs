@@ -1,34 +1,198 @@
-use std::cmp::min;
-
-use ratatui::{
-    buffer::Buffer,
-    layout::{Alignment, Constraint, Layout, Rect},
-    style::Stylize,
-    symbols::border,
-    widgets::{block::Title, Block, Widget},
-};
+use std::{cmp::min, slice};
+
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
-use crate::board::Card;
+use crate::board::{Card, CardColor, CardTemplate, SortPolicy};
+
+/// Gap between the ranks of consecutive cards when a column is numbered from scratch
+const RANK_STEP: f64 = 1.0;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Column {
     header: String,
     cards: Vec<Card>,
+
+    #[serde(default, skip_serializing_if = "SortPolicy::is_manual")]
+    sort: SortPolicy,
+
+    #[serde(default, skip_serializing_if = "CardTemplate::is_empty")]
+    card_template: CardTemplate,
+
+    /// Name of the section grouping the column with its neighbours, like "Active" or "Later"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    section: Option<String>,
+
+    /// Whether the cards of the column can only be viewed, like for an inbox filled by an importer
+    #[serde(default, skip_serializing_if = "is_false")]
+    read_only: bool,
+
+    /// Maximum number of cards, instead of the one set for the header in the configuration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wip_limit: Option<usize>,
+
+    /// Color of the header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<CardColor>,
+}
+
+/// Settings of a column edited together, all but its header and section
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ColumnOptions {
+    pub wip_limit: Option<usize>,
+    pub color: Option<CardColor>,
+    pub sort: SortPolicy,
+    pub card_template: CardTemplate,
+    pub read_only: bool,
 }
 
 impl Column {
     pub fn new(header: &str, cards: Vec<Card>) -> Self {
-        Column {
+        let mut column = Column {
             header: header.into(),
             cards,
-        }
+            sort: SortPolicy::default(),
+            card_template: CardTemplate::default(),
+            section: None,
+            read_only: false,
+            wip_limit: None,
+            color: None,
+        };
+        column.normalize_ranks();
+        column
     }
 
     pub fn header(&self) -> &str {
         &self.header
     }
 
+    pub fn rename(&mut self, header: &str) -> String {
+        std::mem::replace(&mut self.header, header.into())
+    }
+
+    pub fn section(&self) -> Option<&str> {
+        self.section.as_deref()
+    }
+
+    pub fn set_section(&mut self, section: Option<&str>) {
+        self.section = section.map(String::from);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    pub fn wip_limit(&self) -> Option<usize> {
+        self.wip_limit
+    }
+
+    pub fn color(&self) -> Option<CardColor> {
+        self.color
+    }
+
+    pub fn options(&self) -> ColumnOptions {
+        ColumnOptions {
+            wip_limit: self.wip_limit,
+            color: self.color,
+            sort: self.sort,
+            card_template: self.card_template.clone(),
+            read_only: self.read_only,
+        }
+    }
+
+    /// Replaces the settings and returns the previous ones, with the cards when a new sort policy reordered them
+    pub fn set_options(&mut self, options: ColumnOptions) -> (ColumnOptions, Option<Vec<Card>>) {
+        let previous = self.options();
+        let cards = (options.sort != self.sort).then(|| self.sort(options.sort).1);
+        self.wip_limit = options.wip_limit;
+        self.color = options.color;
+        self.card_template = options.card_template;
+        self.read_only = options.read_only;
+        (previous, cards)
+    }
+
+    /// Puts back the settings and cards returned by `set_options`
+    pub fn restore_options(&mut self, options: ColumnOptions, cards: Option<Vec<Card>>) {
+        if let Some(cards) = cards {
+            self.cards = cards;
+        }
+        self.sort = options.sort;
+        self.wip_limit = options.wip_limit;
+        self.color = options.color;
+        self.card_template = options.card_template;
+        self.read_only = options.read_only;
+    }
+
+    /// Default values of the cards inserted in this column
+    pub fn card_template(&self) -> &CardTemplate {
+        &self.card_template
+    }
+
+    /// Replaces the card template and returns the previous one
+    pub fn set_card_template(&mut self, card_template: CardTemplate) -> CardTemplate {
+        std::mem::replace(&mut self.card_template, card_template)
+    }
+
+    pub fn sort_policy(&self) -> SortPolicy {
+        self.sort
+    }
+
+    /// Sorts the cards with a new policy, returns the previous policy and cards so they can be restored
+    pub fn sort(&mut self, policy: SortPolicy) -> (SortPolicy, Vec<Card>) {
+        let previous = (self.sort, self.cards.clone());
+        self.sort = policy;
+        self.cards.sort_by(|a, b| policy.compare(a, b));
+        self.renumber_ranks();
+        previous
+    }
+
+    pub fn restore(&mut self, policy: SortPolicy, cards: Vec<Card>) {
+        self.sort = policy;
+        self.cards = cards;
+    }
+
+    /// Where a card should be inserted, the requested index is only honored when the column is sorted manually
+    ///
+    /// With an active sort, the card goes after all the cards with the same or a lower sort key.
+    pub fn insertion_index(&self, card: &Card, requested: usize) -> usize {
+        if self.sort.is_manual() {
+            return min(requested, self.cards.len());
+        }
+
+        self.cards
+            .iter()
+            .position(|other| self.sort.compare(card, other).is_lt())
+            .unwrap_or(self.cards.len())
+    }
+
+    /// Number of cards past their due date, for the badge of the column
+    pub fn overdue_count(&self, today: NaiveDate) -> usize {
+        self.cards.iter().filter(|card| card.is_overdue(today)).count()
+    }
+
+    /// The tag of at least half of the cards and of more cards than any other tag, telling what kind of work
+    /// fills the column
+    pub fn dominant_tag(&self) -> Option<&str> {
+        let mut counts: Vec<(&str, usize)> = vec![];
+        for tag in self.cards.iter().flat_map(|card| card.tags()) {
+            match counts.iter_mut().find(|(other, _)| other == tag) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((tag, 1)),
+            }
+        }
+
+        counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+        match counts[..] {
+            [(_, first), (_, second), ..] if first == second => None,
+            [(tag, count), ..] if count * 2 >= self.cards.len() => Some(tag),
+            _ => None,
+        }
+    }
+
     pub fn size(&self) -> usize {
         self.cards.len()
     }
@@ -41,8 +205,29 @@ impl Column {
         &self.cards[i]
     }
 
+    /// Cards of the column, from the top
+    pub fn cards_iter(&self) -> slice::Iter<'_, Card> {
+        self.cards.iter()
+    }
+
     pub fn insert_card(&mut self, card: Card, index: usize) {
         self.cards.insert(index, card);
+        self.rank_card(index);
+    }
+
+    /// Puts back a card removed from the column, it keeps its rank when the rank still fits between its neighbours
+    pub fn restore_card(&mut self, card: Card, index: usize) {
+        let fits = index.checked_sub(1).is_none_or(|i| self.cards[i].rank() < card.rank())
+            && self.cards.get(index).is_none_or(|after| card.rank() < after.rank());
+        self.cards.insert(index, card);
+        if !fits {
+            self.rank_card(index);
+        }
+    }
+
+    /// Takes the cards of the column from an index to the end
+    pub fn split_off(&mut self, index: usize) -> Vec<Card> {
+        self.cards.split_off(min(index, self.cards.len()))
     }
 
     pub fn remove_card(&mut self, index: usize) -> usize {
@@ -59,72 +244,126 @@ impl Column {
         }
     }
 
-    pub fn select_card(&mut self, card_index: usize) {
-        if !self.is_empty() {
-            self.cards[card_index].select();
-        }
-    }
-
-    pub fn deselect_card(&mut self, card_index: usize) {
+    pub fn set_timed(&mut self, card_index: usize, is_timed: bool) {
         if !self.is_empty() {
-            self.cards[card_index].deselect();
+            self.cards[card_index].set_timed(is_timed);
         }
     }
 
-    pub fn update_card(&mut self, card_index: usize, card: Card) {
+    /// Replaces a card, which keeps the rank of the card it replaces as it stays in place
+    pub fn update_card(&mut self, card_index: usize, mut card: Card) {
         if !self.is_empty() {
+            card.set_rank(self.cards[card_index].rank());
             self.cards[card_index] = card;
         }
     }
 
+    /// Moves a card up, the cards of a sorted column stay in the order of the sort
     pub fn increase_priority(&mut self, card_index: usize) -> usize {
-        if card_index > 0 && card_index < self.cards.len() {
+        if self.sort.is_manual() && card_index > 0 && card_index < self.cards.len() {
             let new_index = card_index - 1;
-            self.cards.swap(card_index, new_index);
+            self.swap_cards(card_index, new_index);
             return new_index;
         }
 
         card_index
     }
 
+    /// Moves a card down, the cards of a sorted column stay in the order of the sort
     pub fn decrease_priority(&mut self, card_index: usize) -> usize {
-        if card_index < self.cards.len() - 1 {
+        if self.sort.is_manual() && card_index < self.cards.len() - 1 {
             let new_index = card_index + 1;
-            self.cards.swap(card_index, card_index + 1);
+            self.swap_cards(card_index, new_index);
             return new_index;
         }
 
         card_index
     }
-}
 
-impl Widget for &Column {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let header = format!(" {} ", self.header);
-        let title = Title::from(header.bold()).alignment(Alignment::Center);
+    /// Swaps two cards, their ranks stay where they are so they keep increasing down the column
+    fn swap_cards(&mut self, a: usize, b: usize) {
+        let (rank_a, rank_b) = (self.cards[a].rank(), self.cards[b].rank());
+        self.cards.swap(a, b);
+        self.cards[a].set_rank(rank_a);
+        self.cards[b].set_rank(rank_b);
+    }
 
-        let block = Block::bordered().title(title).border_set(border::THICK);
+    /// Gives the card at `index` a rank between the ranks of its neighbours, the column is numbered again when
+    /// there is no room left between them
+    fn rank_card(&mut self, index: usize) {
+        let before = index.checked_sub(1).map(|i| self.cards[i].rank());
+        let after = self.cards.get(index + 1).map(Card::rank);
+        let rank = match (before, after) {
+            (None, None) => RANK_STEP,
+            (Some(before), None) => before + RANK_STEP,
+            (None, Some(after)) => after - RANK_STEP,
+            (Some(before), Some(after)) => (before + after) / 2.0,
+        };
+
+        if before.is_some_and(|before| rank <= before) || after.is_some_and(|after| rank >= after) {
+            self.renumber_ranks();
+        } else {
+            self.cards[index].set_rank(rank);
+        }
+    }
 
-        let inner_area = block.inner(area);
-        let areas = Layout::vertical([Constraint::Max(4); 8]).split(inner_area);
-        self.cards.iter().enumerate().for_each(|(i, card)| {
-            card.render(areas[i], buf);
-        });
+    /// Drops the scratch cards, which are not written to the board file
+    pub(crate) fn remove_scratch_cards(&mut self) {
+        self.cards.retain(|card| !card.is_scratch());
+    }
+
+    /// Numbers the column again when the ranks of its cards do not increase, like for cards written without rank
+    pub(crate) fn normalize_ranks(&mut self) {
+        if !self.cards.windows(2).all(|pair| pair[0].rank() < pair[1].rank()) {
+            self.renumber_ranks();
+        }
+    }
 
-        block.render(area, buf);
+    fn renumber_ranks(&mut self) {
+        for (index, card) in self.cards.iter_mut().enumerate() {
+            card.set_rank((index + 1) as f64 * RANK_STEP);
+        }
     }
 }
 
+pub(super) fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Result;
 
-    use chrono::Local;
+    use chrono::{Duration, Local, NaiveDate};
 
-    use crate::board::card::Card;
+    use crate::board::{card::Card, BoardBuilder, SortPolicy};
 
     use super::Column;
 
+    #[test]
+    fn dominant_tag() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Bugs", |c| {
+                c.card_with("Crash", |card| card.tag("bug").tag("ui"))
+                    .card_with("Freeze", |card| card.tag("bug"))
+                    .card("Typo")
+            })
+            .column("Mixed", |c| {
+                c.card_with("Crash", |card| card.tag("bug"))
+                    .card_with("Button", |card| card.tag("ui"))
+            })
+            .column("Few", |c| {
+                c.card_with("Crash", |card| card.tag("bug")).card("Typo").card("Idea")
+            })
+            .build();
+
+        assert_eq!(Some("bug"), board.column(0).dominant_tag());
+        assert_eq!(None, board.column(1).dominant_tag(), "tie");
+        assert_eq!(None, board.column(2).dominant_tag(), "less than half of the cards");
+
+        Ok(())
+    }
+
     #[test]
     fn insert_and_remove_cards() -> Result<()> {
         let now = Local::now();
@@ -202,4 +441,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn count_overdue_cards() -> Result<()> {
+        let now = Local::now();
+        let due = |day: u32| {
+            let mut card = Card::new("card", now);
+            card.update_due_date(NaiveDate::from_ymd_opt(2024, 12, day));
+            card
+        };
+        let mut done = due(1);
+        done.update_completion_date(Some(now));
+        let column = Column::new(
+            "test",
+            vec![due(1), due(10), due(20), done, Card::new("no due date", now)],
+        );
+
+        let today = NaiveDate::from_ymd_opt(2024, 12, 10).unwrap();
+        assert_eq!(1, column.overdue_count(today), "due today is not overdue yet");
+        assert_eq!(2, column.overdue_count(today.succ_opt().unwrap()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_in_sorted_column() -> Result<()> {
+        let now = Local::now();
+        let due = |description: &str, day: u32| {
+            let mut card = Card::new(description, now);
+            card.update_due_date(NaiveDate::from_ymd_opt(2024, 12, day));
+            card
+        };
+        let mut column = Column::new(
+            "test",
+            vec![due("card 20", 20), Card::new("no due date", now), due("card 10", 10)],
+        );
+
+        assert_eq!(1, column.insertion_index(&due("card 15", 15), 1));
+        assert_eq!(3, column.insertion_index(&due("card 15", 15), 7));
+
+        let (previous, cards) = column.sort(SortPolicy::DueDate);
+        assert_eq!(SortPolicy::Manual, previous);
+        assert_eq!("card 10", column.get_card(0).short_description());
+        assert_eq!("card 20", column.get_card(1).short_description());
+        assert_eq!("no due date", column.get_card(2).short_description());
+
+        assert_eq!(1, column.insertion_index(&due("card 15", 15), 0));
+        assert_eq!(2, column.insertion_index(&due("other card 20", 20), 0));
+        assert_eq!(3, column.insertion_index(&Card::new("other", now), 0));
+
+        column.sort(SortPolicy::CreationDate);
+        assert_eq!(
+            0,
+            column.insertion_index(&Card::new("older", now - Duration::days(1)), 3)
+        );
+
+        column.restore(previous, cards);
+        assert_eq!(SortPolicy::Manual, column.sort_policy());
+        assert_eq!("card 20", column.get_card(0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ranks_follow_the_order_of_the_cards() -> Result<()> {
+        let now = Local::now();
+        let ranks = |column: &Column| -> Vec<f64> { column.cards_iter().map(Card::rank).collect() };
+
+        let mut column = Column::new("test", vec![Card::new("card 1", now), Card::new("card 2", now)]);
+        assert_eq!(vec![1.0, 2.0], ranks(&column), "numbered when created");
+
+        column.insert_card(Card::new("card 0", now), 0);
+        column.insert_card(Card::new("card 1.5", now), 2);
+        column.insert_card(Card::new("card 3", now), 4);
+        assert_eq!(vec![0.0, 1.0, 1.5, 2.0, 3.0], ranks(&column));
+
+        column.increase_priority(3);
+        assert_eq!("card 2", column.get_card(2).short_description());
+        assert_eq!(vec![0.0, 1.0, 1.5, 2.0, 3.0], ranks(&column), "ranks stay in place");
+
+        let mut card = Card::new("card 2 edited", now);
+        card.set_rank(42.0);
+        column.update_card(2, card);
+        assert_eq!(1.5, column.get_card(2).rank());
+
+        for _ in 0..60 {
+            column.insert_card(Card::new("squeezed", now), 1);
+        }
+        assert!(
+            ranks(&column).windows(2).all(|pair| pair[0] < pair[1]),
+            "renumbered when out of room"
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,454 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::Path,
+};
+
+use serde_json::{Map, Value};
+
+use crate::board::Board;
+
+const BOARD_FILE: &str = "board.md";
+const ARCHIVE_FILE: &str = "archive.md";
+const FRONT_MATTER: &str = "---";
+const CARD_INDENT: &str = "  ";
+/// Files written for the board, one per line, only those are read, replaced or removed
+const MANIFEST_FILE: &str = ".rustyban-files";
+/// Where the files are written before being moved in place, so that a failed write leaves the board as it was
+const STAGING_DIRECTORY: &str = ".rustyban-staging";
+
+/// Whether a board is kept as a directory of markdown files, for paths ending with a slash or naming a directory
+pub fn is_directory(file_name: &str) -> bool {
+    file_name.ends_with('/') || Path::new(file_name).is_dir()
+}
+
+/// Reads a board kept as a directory with one markdown file per column
+///
+/// Column files are named after their position and header, like `01-todo.md`, and read in the order of their
+/// position. The other markdown files are `board.md`, with the notes of the board, and `archive.md`. Only the
+/// files listed in the manifest written with them are read, a directory without it is not a board.
+pub fn read(directory: &str) -> Result<Board> {
+    let mut column_files: Vec<(usize, String)> = manifest(directory)?
+        .ok_or_else(|| not_a_board(directory))?
+        .into_iter()
+        .filter_map(|name| Some((column_position(&name)?, name)))
+        .collect();
+    column_files.sort();
+    if column_files.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "no column file found in board directory",
+        ));
+    }
+
+    let path = |name: &str| Path::new(directory).join(name);
+    let mut board = match read_optional(&path(BOARD_FILE))? {
+        Some(content) => {
+            let (mut fields, notes) = parse_front_matter(&content).map_err(|e| invalid(BOARD_FILE, e))?;
+            fields.insert("notes".into(), Value::String(notes.trim().to_string()));
+            fields
+        }
+        None => Map::new(),
+    };
+
+    let mut columns = vec![];
+    for (_, name) in &column_files {
+        let content = fs::read_to_string(path(name))?;
+        let (mut column, cards) = parse_front_matter(&content).map_err(|e| invalid(name, e))?;
+        column.insert("cards".into(), parse_cards(&cards).map_err(|e| invalid(name, e))?);
+        columns.push(Value::Object(column));
+    }
+    board.insert("columns".into(), Value::Array(columns));
+
+    if let Some(content) = read_optional(&path(ARCHIVE_FILE))? {
+        board.insert(
+            "archive".into(),
+            parse_cards(&content).map_err(|e| invalid(ARCHIVE_FILE, e))?,
+        );
+    }
+
+    serde_json::from_value(Value::Object(board)).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+/// Writes a board as a directory of markdown files, removing the files of columns which are gone
+///
+/// The files are first written to a staging directory, then moved in place with the manifest last. Only files
+/// listed in the previous manifest are removed, and a directory holding other files but no manifest is refused.
+pub fn write(directory: &str, board: &Board) -> Result<()> {
+    let previous = match manifest(directory)? {
+        Some(previous) => previous,
+        None if is_empty(directory)? => vec![],
+        None => return Err(not_a_board(directory)),
+    };
+    let files = files(board)?;
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+
+    fs::create_dir_all(directory)?;
+    let staging = Path::new(directory).join(STAGING_DIRECTORY);
+    let staged = stage(&staging, &files);
+    if let Err(e) = staged {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+
+    for name in names.iter().chain([MANIFEST_FILE].iter()) {
+        fs::rename(staging.join(name), Path::new(directory).join(name))?;
+    }
+    for name in previous.iter().filter(|name| !names.contains(&name.as_str())) {
+        match fs::remove_file(Path::new(directory).join(name)) {
+            Err(e) if e.kind() != ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+    fs::remove_dir_all(staging)
+}
+
+/// Writes the files and their manifest to the staging directory
+fn stage(staging: &Path, files: &[(String, String)]) -> Result<()> {
+    if staging.exists() {
+        fs::remove_dir_all(staging)?;
+    }
+    fs::create_dir(staging)?;
+    for (name, content) in files {
+        fs::write(staging.join(name), content)?;
+    }
+    let manifest: String = files.iter().map(|(name, _)| format!("{}\n", name)).collect();
+    fs::write(staging.join(MANIFEST_FILE), manifest)
+}
+
+/// Names of the files written for the board, None when the directory has no manifest
+fn manifest(directory: &str) -> Result<Option<Vec<String>>> {
+    let content = read_optional(&Path::new(directory).join(MANIFEST_FILE))?;
+    Ok(content.map(|content| {
+        content
+            .lines()
+            .map(str::trim)
+            // Names are kept to the directory itself
+            .filter(|name| !name.is_empty() && !name.contains(['/', '\\']) && !name.starts_with('.'))
+            .map(str::to_string)
+            .collect()
+    }))
+}
+
+/// Whether the directory is missing or holds nothing but a staging directory left by a failed write
+fn is_empty(directory: &str) -> Result<bool> {
+    match fs::read_dir(directory) {
+        Ok(entries) => {
+            for entry in entries {
+                if entry?.file_name() != STAGING_DIRECTORY {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(true),
+        Err(e) => Err(e),
+    }
+}
+
+fn not_a_board(directory: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "{} is not a board directory, it has no {} file",
+            directory, MANIFEST_FILE
+        ),
+    )
+}
+
+/// The files of the board directory one after the other, manifest first, to notice changes made to any of them
+pub fn stored_content(directory: &str) -> Result<Vec<u8>> {
+    let names = manifest(directory)?.ok_or_else(|| not_a_board(directory))?;
+
+    let mut content = fs::read(Path::new(directory).join(MANIFEST_FILE))?;
+    for name in names {
+        if let Some(file) = read_optional(&Path::new(directory).join(name))? {
+            content.extend(file.into_bytes());
+        }
+    }
+    Ok(content)
+}
+
+/// The files of the directory of a board, by name
+pub fn files(board: &Board) -> Result<Vec<(String, String)>> {
+    let Value::Object(mut fields) = serde_json::to_value(board)? else {
+        return Err(Error::new(ErrorKind::InvalidData, "board is not an object"));
+    };
+
+    let columns = fields.remove("columns").unwrap_or_default();
+    let archive = fields.remove("archive");
+    let notes = match fields.remove("notes") {
+        Some(Value::String(notes)) => notes,
+        _ => String::new(),
+    };
+
+    let mut files = vec![];
+    let mut content = front_matter(&fields);
+    if !notes.is_empty() {
+        content.push('\n');
+        content.push_str(&notes);
+        content.push('\n');
+    }
+    files.push((BOARD_FILE.to_string(), content));
+
+    for (index, column) in columns.as_array().into_iter().flatten().enumerate() {
+        let mut column = column.as_object().cloned().unwrap_or_default();
+        let cards = column.remove("cards").unwrap_or_default();
+        let header = column.get("header").and_then(Value::as_str).unwrap_or_default();
+        let name = format!("{:02}-{}.md", index + 1, slug(header));
+
+        let mut content = front_matter(&column);
+        content.push_str(&cards_markdown(&cards));
+        files.push((name, content));
+    }
+
+    if let Some(archive) = archive {
+        files.push((ARCHIVE_FILE.to_string(), cards_markdown(&archive)));
+    }
+
+    Ok(files)
+}
+
+/// Fields written as YAML, each value on its line as JSON, which YAML reads as well
+fn front_matter(fields: &Map<String, Value>) -> String {
+    let mut content = format!("{}\n", FRONT_MATTER);
+    for (key, value) in fields {
+        content.push_str(&format!("{}: {}\n", key, value));
+    }
+    content.push_str(FRONT_MATTER);
+    content.push('\n');
+    content
+}
+
+/// Cards as list items: the short description, the other fields as front matter, then the long description
+fn cards_markdown(cards: &Value) -> String {
+    let mut content = String::new();
+    for card in cards.as_array().into_iter().flatten() {
+        let mut fields = card.as_object().cloned().unwrap_or_default();
+        let short_description = take_text(&mut fields, "short_description");
+        let long_description = take_text(&mut fields, "long_description");
+
+        // Texts the list item cannot hold as they are stay with the other fields
+        if short_description.contains('\n') {
+            fields.insert("short_description".into(), Value::String(short_description.clone()));
+        }
+        if long_description.ends_with('\n') {
+            fields.insert("long_description".into(), Value::String(long_description.clone()));
+        }
+
+        content.push_str(&format!(
+            "\n- {}\n",
+            short_description.lines().next().unwrap_or_default()
+        ));
+        for line in front_matter(&fields).lines() {
+            content.push_str(&format!("{}{}\n", CARD_INDENT, line));
+        }
+        if !long_description.ends_with('\n') {
+            for line in long_description.lines() {
+                match line.is_empty() {
+                    true => content.push('\n'),
+                    false => content.push_str(&format!("{}{}\n", CARD_INDENT, line)),
+                }
+            }
+        }
+    }
+    content
+}
+
+fn take_text(fields: &mut Map<String, Value>, key: &str) -> String {
+    match fields.remove(key) {
+        Some(Value::String(text)) => text,
+        _ => String::new(),
+    }
+}
+
+/// Reads the fields of the front matter, and the rest of the content
+fn parse_front_matter(content: &str) -> std::result::Result<(Map<String, Value>, String), String> {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim) != Some(FRONT_MATTER) {
+        return Err("missing front matter".into());
+    }
+
+    let mut fields = Map::new();
+    for line in lines.by_ref() {
+        if line.trim() == FRONT_MATTER {
+            let rest: Vec<&str> = lines.collect();
+            return Ok((fields, rest.join("\n")));
+        }
+        let (key, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("expected key: value in \"{}\"", line))?;
+        let value = serde_json::from_str(value.trim()).map_err(|e| format!("{}: {}", key.trim(), e))?;
+        fields.insert(key.trim().to_string(), value);
+    }
+
+    Err("unclosed front matter".into())
+}
+
+fn parse_cards(content: &str) -> std::result::Result<Value, String> {
+    let mut cards: Vec<Vec<&str>> = vec![];
+    for line in content.lines() {
+        if let Some(item) = line.strip_prefix("- ") {
+            cards.push(vec![item]);
+        } else if let Some(card) = cards.last_mut() {
+            card.push(line.strip_prefix(CARD_INDENT).unwrap_or(line.trim_start()));
+        } else if !line.trim().is_empty() {
+            return Err(format!("text found before any card: \"{}\"", line));
+        }
+    }
+
+    let cards = cards
+        .into_iter()
+        .map(|lines| {
+            let (mut fields, long_description) = parse_front_matter(&lines[1..].join("\n"))?;
+            fields
+                .entry("short_description")
+                .or_insert_with(|| Value::String(lines[0].trim().to_string()));
+            fields
+                .entry("long_description")
+                .or_insert_with(|| Value::String(long_description.trim_end().to_string()));
+            Ok(Value::Object(fields))
+        })
+        .collect::<std::result::Result<Vec<Value>, String>>()?;
+
+    Ok(Value::Array(cards))
+}
+
+/// Position of the column of a file named like `01-todo.md`, None for the other files
+fn column_position(name: &str) -> Option<usize> {
+    let stem = name.strip_suffix(".md")?;
+    let (position, _) = stem.split_once('-')?;
+    position.parse().ok()
+}
+
+/// The header of a column in a file name: lowercase letters and digits, separated by dashes
+fn slug(header: &str) -> String {
+    let words: Vec<String> = header
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    match words.is_empty() {
+        true => "column".into(),
+        false => words.join("-"),
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn invalid(name: &str, reason: String) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("{}: {}", name, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use chrono::{Duration, Local, NaiveDate};
+
+    use crate::board::{Board, BoardBuilder, Card};
+
+    use super::{files, read, slug, write};
+
+    #[test]
+    fn directory_round_trip() -> Result<()> {
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Buy milk", |card| {
+                    card.tag("shopping")
+                        .due(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+                        .long_description("Semi-skimmed\n\n- [ ] Two bottles")
+                })
+                .card("Buy eggs")
+            })
+            .column("In review", |c| c.section("Active"))
+            .column("Done!", |c| {
+                c.card_with("Eat dinner", |card| card.completed(Local::now()))
+            })
+            .build();
+        board.update_notes("Sprint goal: groceries");
+        board.insert_card(2, 0, Card::new("Buy jam", Local::now() - Duration::days(3)));
+        board.archive_done_cards(0, Local::now());
+
+        let directory = env::temp_dir().join("rustyban-column-files").display().to_string();
+        let _ = fs::remove_dir_all(&directory);
+        write(&directory, &board)?;
+
+        let names: Vec<String> = files(&board)?.into_iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            vec!["board.md", "01-todo.md", "02-in-review.md", "03-done.md", "archive.md"],
+            names
+        );
+        let todo = fs::read_to_string(format!("{}/01-todo.md", directory))?;
+        assert!(todo.starts_with("---\nheader: \"TODO\"\n---\n\n- Buy milk\n  ---\n"));
+        assert!(todo.contains("  tags: [\"shopping\"]\n"));
+        assert!(todo.contains("  ---\n  Semi-skimmed\n\n  - [ ] Two bottles\n"));
+
+        let read_board = read(&directory)?;
+        assert_eq!(board.to_canonical_json()?, read_board.to_canonical_json()?);
+
+        fs::write(format!("{}/04-ideas.md", directory), "Not written by the board")?;
+        let board = BoardBuilder::new().column("TODO", |c| c).column("Done!", |c| c).build();
+        write(&directory, &board)?;
+        assert!(!fs::exists(format!("{}/03-done.md", directory))?, "the column moved");
+        assert!(fs::exists(format!("{}/02-done.md", directory))?);
+        assert!(fs::exists(format!("{}/04-ideas.md", directory))?, "not in the manifest");
+        assert!(!fs::exists(format!("{}/.rustyban-staging", directory))?);
+        assert_eq!(2, read(&directory)?.columns_count());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn file_names_and_errors() -> Result<()> {
+        assert_eq!("done", slug("Done!"));
+        assert_eq!("in-review", slug(" In  review "));
+        assert_eq!("column", slug("✓"));
+
+        let directory = env::temp_dir()
+            .join("rustyban-column-files-empty")
+            .display()
+            .to_string();
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        assert!(read(&directory).is_err(), "not a board");
+        fs::write(format!("{}/.rustyban-files", directory), "")?;
+        assert!(read(&directory).is_err(), "no column");
+
+        fs::write(format!("{}/01-todo.md", directory), "- Orphan card\n")?;
+        fs::write(format!("{}/.rustyban-files", directory), "01-todo.md\n")?;
+        assert!(read(&directory).is_err(), "no front matter");
+        assert!(Board::open(&format!("{}/", directory)).is_err());
+
+        fs::remove_dir_all(&directory)
+    }
+
+    #[test]
+    fn other_directories_are_left_alone() -> Result<()> {
+        let directory = env::temp_dir()
+            .join("rustyban-column-files-notes")
+            .display()
+            .to_string();
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        fs::write(format!("{}/2024-06-01.md", directory), "Notes of the day")?;
+        fs::write(format!("{}/01-meeting.md", directory), "Agenda")?;
+
+        assert!(read(&directory).is_err(), "not a board directory");
+        assert!(write(&directory, &Board::new()).is_err());
+        assert_eq!(
+            "Notes of the day",
+            fs::read_to_string(format!("{}/2024-06-01.md", directory))?
+        );
+        assert_eq!("Agenda", fs::read_to_string(format!("{}/01-meeting.md", directory))?);
+        assert!(!fs::exists(format!("{}/board.md", directory))?);
+
+        fs::remove_dir_all(&directory)
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fs, io};
+
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::board::{Board, Card};
+
+/// Fields of a card read from a CSV file, in the order of the mapping
+pub const CSV_FIELDS: [&str; 4] = ["Title", "Description", "Column", "Due date"];
+
+/// Which columns of a CSV file, by index, give the fields of the imported cards
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvMapping {
+    pub title: usize,
+    pub description: Option<usize>,
+    pub column: Option<usize>,
+    pub due_date: Option<usize>,
+}
+
+impl CsvMapping {
+    /// Guesses the mapping from the header row, by names like "Title", "Summary", "Status" or "Due"
+    pub fn guess(header: &[String]) -> Self {
+        let find = |names: &[&str]| {
+            header
+                .iter()
+                .position(|name| names.contains(&name.trim().to_lowercase().as_str()))
+        };
+
+        Self {
+            title: find(&["title", "name", "summary", "subject", "task"]).unwrap_or(0),
+            description: find(&["description", "details", "body", "notes"]),
+            column: find(&["column", "status", "state", "list"]),
+            due_date: find(&["due", "due date", "due_date", "deadline"]),
+        }
+    }
+
+    /// The mapping guessed from the header row, with fields mapped to CSV columns by name, ignoring case
+    ///
+    /// The names are given in the order of `CSV_FIELDS`, like on the command line, and fail when not in the header.
+    pub fn named(header: &[String], names: [Option<&str>; 4]) -> Result<Self, String> {
+        let mut mapping = Self::guess(header);
+        for (field, name) in names.into_iter().enumerate() {
+            let Some(name) = name else {
+                continue;
+            };
+            let column = header
+                .iter()
+                .position(|column| column.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| format!("no column \"{}\" in the CSV header", name))?;
+            mapping.set(field, Some(column));
+        }
+
+        Ok(mapping)
+    }
+
+    /// CSV column of a field, by its index in `CSV_FIELDS`
+    pub fn get(&self, field: usize) -> Option<usize> {
+        match field {
+            0 => Some(self.title),
+            1 => self.description,
+            2 => self.column,
+            _ => self.due_date,
+        }
+    }
+
+    /// Maps a field, by its index in `CSV_FIELDS`, to a CSV column, only the title cannot be left unmapped
+    pub fn set(&mut self, field: usize, column: Option<usize>) {
+        match field {
+            0 => self.title = column.unwrap_or(self.title),
+            1 => self.description = column,
+            2 => self.column = column,
+            _ => self.due_date = column,
+        }
+    }
+}
+
+/// Rows of a CSV file, fields separated by commas
+///
+/// Quoted fields may contain commas and new lines, their quotes are doubled. Empty lines are skipped.
+pub fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if field.is_empty() => quoted = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|field| !field.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    row.push(field);
+    if row.iter().any(|field| !field.is_empty()) {
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Rows of a CSV file, see `parse_csv`
+pub fn read_csv(file_name: &str) -> io::Result<Vec<Vec<String>>> {
+    fs::read_to_string(file_name).map(|content| parse_csv(&content))
+}
+
+/// Cards made from the data rows of a CSV file, the header row left out, with the board column they go to
+///
+/// A card goes to the column whose header matches its column field, ignoring case, or to the first column.
+/// Rows without a title are skipped. Due dates not written like 2024-12-24 are left out, each one reported with
+/// the number of its row in the messages returned with the cards.
+pub fn csv_cards(
+    board: &Board,
+    rows: &[Vec<String>],
+    mapping: &CsvMapping,
+    now: DateTime<Local>,
+) -> (Vec<(usize, Card)>, Vec<String>) {
+    let field = |row: &Vec<String>, column: Option<usize>| {
+        column
+            .and_then(|column| row.get(column))
+            .map(|field| field.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut cards = vec![];
+    let mut problems = vec![];
+    for (row_index, row) in rows.iter().enumerate().skip(1) {
+        let title = field(row, Some(mapping.title));
+        if title.is_empty() {
+            continue;
+        }
+
+        let mut card = Card::new(&title, now);
+        card.update_long_description(&field(row, mapping.description));
+        let due_date = field(row, mapping.due_date);
+        match NaiveDate::parse_from_str(&due_date, "%Y-%m-%d") {
+            Ok(date) => card.update_due_date(Some(date)),
+            Err(_) if due_date.is_empty() => {}
+            Err(_) => problems.push(format!(
+                "Row {}: due date \"{}\" is not like 2024-12-24, left out",
+                row_index + 1,
+                due_date
+            )),
+        }
+
+        let header = field(row, mapping.column);
+        let column_index = board
+            .columns_iter()
+            .position(|column| column.header().eq_ignore_ascii_case(&header))
+            .unwrap_or(0);
+        cards.push((column_index, card));
+    }
+
+    (cards, problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, NaiveDate};
+
+    use crate::board::Board;
+
+    use super::{csv_cards, parse_csv, CsvMapping};
+
+    #[test]
+    fn parse_quoted_fields() -> Result<()> {
+        let rows = parse_csv("Title,Notes\r\n\"Buy milk, eggs\",\"Say \"\"hi\"\"\nto the baker\"\n\nCook,\n");
+        assert_eq!(
+            vec![
+                vec!["Title".to_string(), "Notes".into()],
+                vec!["Buy milk, eggs".into(), "Say \"hi\"\nto the baker".into()],
+                vec!["Cook".into(), "".into()],
+            ],
+            rows
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_rows_to_cards() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let rows = parse_csv(
+            "Summary,Status,Due,Details\n\
+             Buy flowers,doing,2024-12-24,For the table\n\
+             ,Doing,,\n\
+             Call mum,Someday,next week,\n",
+        );
+
+        let mapping = CsvMapping::guess(&rows[0]);
+        assert_eq!(
+            CsvMapping {
+                title: 0,
+                description: Some(3),
+                column: Some(1),
+                due_date: Some(2),
+            },
+            mapping
+        );
+        assert_eq!(
+            Ok(mapping.clone()),
+            CsvMapping::named(&rows[0], [Some("summary"), None, Some("STATUS"), None])
+        );
+        assert_eq!(
+            Ok(1),
+            CsvMapping::named(&rows[0], [Some("Status"), None, None, None]).map(|mapping| mapping.title)
+        );
+        assert!(CsvMapping::named(&rows[0], [None, None, Some("Title"), None]).is_err());
+
+        let (cards, problems) = csv_cards(&board, &rows, &mapping, Local::now());
+        assert_eq!(2, cards.len(), "the row without a title is skipped");
+        assert_eq!(
+            vec!["Row 4: due date \"next week\" is not like 2024-12-24, left out".to_string()],
+            problems
+        );
+        let (column_index, card) = &cards[0];
+        assert_eq!(1, *column_index);
+        assert_eq!("Buy flowers", card.short_description());
+        assert_eq!("For the table", card.long_description());
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 24), card.due_date());
+
+        let (column_index, card) = &cards[1];
+        assert_eq!(0, *column_index, "unknown columns go to the first one");
+        assert_eq!(None, card.due_date());
+
+        Ok(())
+    }
+}
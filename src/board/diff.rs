@@ -0,0 +1,213 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::board::{Board, Card};
+
+/// A difference between two versions of a board, cards are told apart by title and creation date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardChange {
+    Added { column: String, card: String },
+    Removed { column: String, card: String },
+    Moved { from: String, to: String, card: String },
+    Edited { column: String, card: String },
+}
+
+impl BoardChange {
+    /// Changes turning the `old` board into the `new` one, column by column of the new board, removals last
+    pub fn between(old: &Board, new: &Board) -> Vec<Self> {
+        let mut old_cards = located_cards(old);
+        let mut changes = vec![];
+
+        for (column, card) in located_cards(new) {
+            let title = card.short_description().clone();
+            let position = old_cards.iter().position(|(_, old_card)| same_card(old_card, card));
+
+            match position.map(|index| old_cards.remove(index)) {
+                None => changes.push(BoardChange::Added { column, card: title }),
+                Some((from, _)) if from != column => changes.push(BoardChange::Moved {
+                    from,
+                    to: column,
+                    card: title,
+                }),
+                Some((_, old_card)) if !same_content(old_card, card) => {
+                    changes.push(BoardChange::Edited { column, card: title })
+                }
+                Some(_) => {}
+            }
+        }
+
+        changes.extend(old_cards.into_iter().map(|(column, card)| BoardChange::Removed {
+            column,
+            card: card.short_description().clone(),
+        }));
+
+        changes
+    }
+}
+
+impl Display for BoardChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardChange::Added { column, card } => write!(f, "+ '{}' in {}", card, column),
+            BoardChange::Removed { column, card } => write!(f, "- '{}' from {}", card, column),
+            BoardChange::Moved { from, to, card } => write!(f, "> '{}' from {} to {}", card, from, to),
+            BoardChange::Edited { column, card } => write!(f, "~ '{}' in {}", card, column),
+        }
+    }
+}
+
+/// Applies the changes made from `base` to `mine` on top of `theirs`, a version of `base` changed elsewhere
+///
+/// Cards added, edited or moved in `mine` end up as in `mine`, even when `theirs` changed them too, and cards
+/// removed from `mine` are removed, and so are notes edited in `mine`. Everything else is as in `theirs`.
+pub fn merge(base: &Board, mine: &Board, theirs: &Board) -> Board {
+    let base_cards = located_cards(base);
+    let mut merged = theirs.clone();
+    if mine.notes() != base.notes() {
+        merged.update_notes(mine.notes());
+    }
+
+    for (column_index, card_index, card) in positioned_cards(mine) {
+        let header = mine.column(column_index).header();
+        let unchanged = base_cards
+            .iter()
+            .any(|(base_header, base_card)| base_header == header && same_content(base_card, card));
+        if unchanged {
+            continue;
+        }
+
+        if let Some((merged_column, merged_card)) = find_card(&merged, card) {
+            merged.remove_card(merged_column, merged_card);
+        }
+        let column_index = merged
+            .columns_iter()
+            .position(|column| column.header() == header)
+            .unwrap_or(column_index.min(merged.columns_count() - 1));
+        let card_index = card_index.min(merged.column(column_index).size());
+        merged.insert_card(column_index, card_index, card.clone());
+    }
+
+    for (_, base_card) in &base_cards {
+        if find_card(mine, base_card).is_none() {
+            if let Some((column_index, card_index)) = find_card(&merged, base_card) {
+                merged.remove_card(column_index, card_index);
+            }
+        }
+    }
+
+    merged
+}
+
+fn positioned_cards(board: &Board) -> Vec<(usize, usize, &Card)> {
+    board.cards_with_positions().collect()
+}
+
+/// Position of a card on a board, told apart from the others by its title and creation date
+pub fn find_card(board: &Board, card: &Card) -> Option<(usize, usize)> {
+    positioned_cards(board)
+        .into_iter()
+        .find(|(_, _, other)| same_card(other, card))
+        .map(|(column_index, card_index, _)| (column_index, card_index))
+}
+
+/// Whether two cards hold the same data, whatever their state in the interface and their rank in the column
+fn same_content(a: &Card, b: &Card) -> bool {
+    let strip = |card: &Card| {
+        let mut card = card.clone();
+        card.set_timed(false);
+        card.set_rank(0.0);
+        card
+    };
+    strip(a) == strip(b)
+}
+
+fn located_cards(board: &Board) -> Vec<(String, &Card)> {
+    board
+        .columns_iter()
+        .flat_map(|column| column.cards_iter().map(move |card| (column.header().to_string(), card)))
+        .collect()
+}
+
+fn same_card(a: &Card, b: &Card) -> bool {
+    a.short_description() == b.short_description() && a.creation_date() == b.creation_date()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::Board;
+
+    use super::{merge, BoardChange};
+
+    #[test]
+    fn changes_between_boards() -> Result<()> {
+        let old = Board::open("res/test_board.json")?;
+        assert!(BoardChange::between(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        new.mark_card_done(0, 0);
+        new.remove_card(0, 0);
+        let mut card = new.card(1, 1).clone();
+        card.update_long_description("Do not forget the pepper");
+        new.update_card(1, 1, card);
+        let mut card = new.card(0, 0).clone();
+        card.update_short_description("Buy flour");
+        new.insert_card(0, 0, card);
+
+        let changes: Vec<String> = BoardChange::between(&old, &new)
+            .iter()
+            .map(|change| change.to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "+ 'Buy flour' in TODO",
+                "> 'Buy milk' from TODO to Doing",
+                "~ 'Cook dinner' in Doing",
+                "- 'Buy eggs' from TODO",
+            ],
+            changes
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_boards() -> Result<()> {
+        let base = Board::open("res/test_board.json")?;
+
+        let mut mine = base.clone();
+        mine.mark_card_done(0, 0);
+        mine.remove_card(2, 1);
+        let mut card = mine.card(0, 0).clone();
+        card.update_short_description("Buy flour");
+        mine.insert_card(0, 2, card);
+        mine.set_timed_card(Some((0, 0)));
+
+        let mut theirs = base.clone();
+        let mut card = theirs.card(1, 0).clone();
+        card.update_long_description("Do not forget the pepper");
+        theirs.update_card(1, 0, card);
+        theirs.remove_card(0, 1);
+
+        let merged = merge(&base, &mine, &theirs);
+        let titles = |column_index| -> Vec<String> {
+            merged
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().clone())
+                .collect()
+        };
+        assert_eq!(vec!["Buy bread", "Buy flour"], titles(0));
+        assert_eq!(vec!["Buy milk", "Cook dinner"], titles(1));
+        assert_eq!(vec!["Eat dinner"], titles(2));
+        assert_eq!("Do not forget the pepper", merged.card(1, 1).long_description());
+        assert_eq!("", merged.notes());
+
+        mine.update_notes("Dinner at 8");
+        theirs.update_notes("Dinner at 7");
+        assert_eq!("Dinner at 8", merge(&base, &mine, &theirs).notes());
+        assert_eq!("Dinner at 7", merge(&base, &base, &theirs).notes());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fs, io::Result};
+
+use crate::board::{column_files, obsidian, Board, SaveOptions};
+
+/// Reads and writes boards in one of the formats they can be stored in
+pub trait FileService {
+    fn read(&self, path: &str) -> Result<Board>;
+
+    fn write(&self, path: &str, board: &Board, options: SaveOptions) -> Result<()>;
+
+    /// What is written for the board, the files one after the other, to tell whether two boards are stored the same
+    fn content(&self, board: &Board, options: SaveOptions) -> Result<String>;
+
+    /// What is stored at the path, to notice changes made by other programs
+    fn stored_content(&self, path: &str) -> Result<Vec<u8>>;
+}
+
+/// A single JSON file, the default format
+pub struct JsonFile;
+
+/// A single markdown file as read by the Kanban plugin of Obsidian, for paths with a `.md` extension
+pub struct ObsidianFile;
+
+/// A directory with one markdown file per column, for paths ending with a slash or naming a directory
+pub struct ColumnFiles;
+
+/// The service storing boards at a path, chosen from its extension or whether it is a directory
+pub fn file_service(path: &str) -> &'static dyn FileService {
+    if column_files::is_directory(path) {
+        &ColumnFiles
+    } else if path.ends_with(".md") {
+        &ObsidianFile
+    } else {
+        &JsonFile
+    }
+}
+
+impl FileService for JsonFile {
+    fn read(&self, path: &str) -> Result<Board> {
+        Board::from_json(&fs::read_to_string(path)?)
+    }
+
+    fn write(&self, path: &str, board: &Board, options: SaveOptions) -> Result<()> {
+        fs::write(path, self.content(board, options)?)
+    }
+
+    fn content(&self, board: &Board, options: SaveOptions) -> Result<String> {
+        board.to_json(options)
+    }
+
+    fn stored_content(&self, path: &str) -> Result<Vec<u8>> {
+        fs::read(path)
+    }
+}
+
+impl FileService for ObsidianFile {
+    fn read(&self, path: &str) -> Result<Board> {
+        obsidian::from_markdown(&fs::read_to_string(path)?)
+    }
+
+    fn write(&self, path: &str, board: &Board, options: SaveOptions) -> Result<()> {
+        fs::write(path, self.content(board, options)?)
+    }
+
+    fn content(&self, board: &Board, _options: SaveOptions) -> Result<String> {
+        Ok(obsidian::to_markdown(board))
+    }
+
+    fn stored_content(&self, path: &str) -> Result<Vec<u8>> {
+        fs::read(path)
+    }
+}
+
+impl FileService for ColumnFiles {
+    fn read(&self, path: &str) -> Result<Board> {
+        column_files::read(path)
+    }
+
+    fn write(&self, path: &str, board: &Board, _options: SaveOptions) -> Result<()> {
+        column_files::write(path, board)
+    }
+
+    fn content(&self, board: &Board, _options: SaveOptions) -> Result<String> {
+        let files = column_files::files(board)?;
+        Ok(files.into_iter().map(|(_, content)| content).collect())
+    }
+
+    fn stored_content(&self, path: &str) -> Result<Vec<u8>> {
+        column_files::stored_content(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::file_service;
+
+    #[test]
+    fn service_by_path() -> Result<()> {
+        let board = file_service("res/test_board.json").read("res/test_board.json")?;
+        assert_eq!(3, board.columns_count());
+
+        let markdown = file_service("res/test_board.md").read("res/test_board.md")?;
+        assert_eq!(board.columns_count(), markdown.columns_count());
+        assert!(file_service("res/").read("res/").is_err(), "not a board directory");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt::Write;
+
+use chrono::NaiveDate;
+
+use crate::board::{Board, Card};
+
+const SVG_WIDTH: usize = 800;
+const SVG_HEIGHT: usize = 400;
+const SVG_MARGIN: usize = 40;
+const SVG_COLORS: [&str; 6] = ["#4e79a7", "#f28e2b", "#59a14f", "#e15759", "#76b7b2", "#edc948"];
+
+/// Cards in each column at the end of each day, the dataset of a cumulative flow diagram
+///
+/// Where a card was on a given day comes from its column entries. A card created before its first recorded
+/// entry is counted in the first column, a card that never moved in its current column, archived cards stay
+/// in the last column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CumulativeFlow {
+    pub columns: Vec<String>,
+    pub days: Vec<(NaiveDate, Vec<usize>)>,
+}
+
+impl CumulativeFlow {
+    /// Counts the cards of each column from day `from` to day `to`, both included
+    pub fn compute(board: &Board, from: NaiveDate, to: NaiveDate) -> Self {
+        let columns: Vec<String> = board.columns_iter().map(|column| column.header().to_string()).collect();
+        let Some(last_column) = columns.len().checked_sub(1) else {
+            return Self { columns, days: vec![] };
+        };
+
+        let mut cards: Vec<(&Card, usize)> = board
+            .cards_with_positions()
+            .map(|(column_index, _, card)| (card, column_index))
+            .collect();
+        cards.extend(board.archived_cards().iter().map(|card| (card, last_column)));
+
+        let days = from
+            .iter_days()
+            .take_while(|day| *day <= to)
+            .map(|day| {
+                let mut counts = vec![0; columns.len()];
+                for (card, current_column) in &cards {
+                    if let Some(index) = column_on(card, *current_column, &columns, day) {
+                        counts[index] += 1;
+                    }
+                }
+                (day, counts)
+            })
+            .collect();
+
+        Self { columns, days }
+    }
+
+    /// One line per day, one column per board column
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("date");
+        for column in &self.columns {
+            csv.push(',');
+            csv.push_str(&csv_field(column));
+        }
+        csv.push('\n');
+
+        for (day, counts) in &self.days {
+            csv.push_str(&day.format("%Y-%m-%d").to_string());
+            for count in counts {
+                let _ = write!(csv, ",{}", count);
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Stacked areas with the last column at the bottom, as in the usual cumulative flow diagrams
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"sans-serif\" font-size=\"12\">\n",
+            SVG_WIDTH, SVG_HEIGHT
+        );
+
+        let max_total = self
+            .days
+            .iter()
+            .map(|(_, counts)| counts.iter().sum::<usize>())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let plot_width = (SVG_WIDTH - 2 * SVG_MARGIN) as f64;
+        let plot_height = (SVG_HEIGHT - 2 * SVG_MARGIN) as f64;
+        let x = |day_index: usize| {
+            let step = plot_width / self.days.len().saturating_sub(1).max(1) as f64;
+            SVG_MARGIN as f64 + day_index as f64 * step
+        };
+        let y = |count: usize| (SVG_HEIGHT - SVG_MARGIN) as f64 - count as f64 * plot_height / max_total as f64;
+
+        // Stacks from the last column, each area goes from the top of the stack below to its own top
+        let mut below = vec![0; self.days.len()];
+        for column_index in (0..self.columns.len()).rev() {
+            let above: Vec<usize> = self
+                .days
+                .iter()
+                .zip(&below)
+                .map(|((_, counts), below)| below + counts[column_index])
+                .collect();
+
+            let mut points: Vec<String> = above
+                .iter()
+                .enumerate()
+                .map(|(day_index, count)| format!("{:.1},{:.1}", x(day_index), y(*count)))
+                .collect();
+            points.extend(
+                below
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(day_index, count)| format!("{:.1},{:.1}", x(day_index), y(*count))),
+            );
+            let _ = writeln!(
+                svg,
+                "  <polygon points=\"{}\" fill=\"{}\"><title>{}</title></polygon>",
+                points.join(" "),
+                SVG_COLORS[column_index % SVG_COLORS.len()],
+                xml_escape(&self.columns[column_index])
+            );
+
+            below = above;
+        }
+
+        for (column_index, column) in self.columns.iter().enumerate() {
+            let legend_x = SVG_MARGIN + column_index * 120;
+            let _ = writeln!(
+                svg,
+                "  <rect x=\"{}\" y=\"10\" width=\"12\" height=\"12\" fill=\"{}\"/><text x=\"{}\" y=\"21\">{}</text>",
+                legend_x,
+                SVG_COLORS[column_index % SVG_COLORS.len()],
+                legend_x + 16,
+                xml_escape(column)
+            );
+        }
+        if let (Some((first, _)), Some((last, _))) = (self.days.first(), self.days.last()) {
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{}\" y=\"{}\">{}</text>\n  <text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+                SVG_MARGIN,
+                SVG_HEIGHT - SVG_MARGIN / 2,
+                first,
+                SVG_WIDTH - SVG_MARGIN,
+                SVG_HEIGHT - SVG_MARGIN / 2,
+                last
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "  <text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+            SVG_MARGIN - 4,
+            SVG_MARGIN + 4,
+            max_total
+        );
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Index of the column the card was in at the end of the day, None if it did not exist yet
+fn column_on(card: &Card, current_column: usize, columns: &[String], day: NaiveDate) -> Option<usize> {
+    if card.creation_date().date_naive() > day {
+        return None;
+    }
+
+    let entry = card
+        .column_entries()
+        .iter()
+        .take_while(|entry| entry.date.date_naive() <= day)
+        .last();
+    match entry {
+        Some(entry) => columns.iter().position(|header| *header == entry.header),
+        None if card.column_entries().is_empty() => Some(current_column),
+        None => Some(0),
+    }
+}
+
+pub(super) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, NaiveDate, TimeZone};
+
+    use crate::board::BoardBuilder;
+
+    use super::CumulativeFlow;
+
+    #[test]
+    fn cards_per_column_and_day() -> Result<()> {
+        let date = |day| Local.with_ymd_and_hms(2024, 12, day, 12, 0, 0).unwrap();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card_with("Write", |card| card.created(date(2))))
+            .column("Doing, now", |c| c)
+            .column("Done", |c| c.card_with("Plan", |card| card.created(date(1))))
+            .build();
+        let mut card = board.card(0, 0).clone();
+        card.enter_column("Doing, now", date(3));
+        card.enter_column("Done", date(4));
+        board.remove_card(0, 0);
+        board.insert_card(2, 0, card);
+
+        let day = |day| NaiveDate::from_ymd_opt(2024, 12, day).unwrap();
+        let flow = CumulativeFlow::compute(&board, day(1), day(4));
+        assert_eq!(
+            "date,TODO,\"Doing, now\",Done\n\
+             2024-12-01,0,0,1\n\
+             2024-12-02,1,0,1\n\
+             2024-12-03,0,1,1\n\
+             2024-12-04,0,0,2\n",
+            flow.to_csv()
+        );
+
+        let svg = flow.to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(3, svg.matches("<polygon").count());
+        assert!(svg.contains("<title>Doing, now</title>"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use std::{collections::HashMap, fmt};
+
+use chrono::{DateTime, Local};
+
+use crate::board::Board;
+
+/// Days in a column in progress after which a card is reported, when the column has no stale threshold
+const OLD_IN_PROGRESS_DAYS: i64 = 30;
+
+/// Something looking wrong on a board, found when opening it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    EmptyTitle {
+        column_index: usize,
+        card_index: usize,
+        header: String,
+    },
+    /// Cards with the same title, the position is the one of the first of them
+    DuplicateTitle {
+        column_index: usize,
+        card_index: usize,
+        title: String,
+        count: usize,
+    },
+    /// A card in a column between the first and the last one for a long time
+    OldInProgress {
+        column_index: usize,
+        card_index: usize,
+        title: String,
+        header: String,
+        days: i64,
+    },
+    OverWipLimit {
+        column_index: usize,
+        header: String,
+        limit: usize,
+        cards: usize,
+    },
+}
+
+impl Finding {
+    /// The card to look at, the first card of the column for a column over its limit
+    pub fn position(&self) -> (usize, usize) {
+        match self {
+            Finding::EmptyTitle {
+                column_index,
+                card_index,
+                ..
+            }
+            | Finding::DuplicateTitle {
+                column_index,
+                card_index,
+                ..
+            }
+            | Finding::OldInProgress {
+                column_index,
+                card_index,
+                ..
+            } => (*column_index, *card_index),
+            Finding::OverWipLimit { column_index, .. } => (*column_index, 0),
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::EmptyTitle { header, .. } => write!(f, "A card of {} has no title", header),
+            Finding::DuplicateTitle { title, count, .. } => write!(f, "'{}' is the title of {} cards", title, count),
+            Finding::OldInProgress {
+                title, header, days, ..
+            } => write!(f, "'{}' is in {} for {} days", title, header, days),
+            Finding::OverWipLimit {
+                header, limit, cards, ..
+            } => write!(f, "{} has {} cards, over its WIP limit of {}", header, cards, limit),
+        }
+    }
+}
+
+/// Looks for cards without title or with the same title, cards in progress for long and columns over their limit
+///
+/// `stale_thresholds` gives the number of days after which cards in progress are reported, per column header, and
+/// `wip_limits` the maximum number of cards.
+pub fn health_check(
+    board: &Board,
+    stale_thresholds: &HashMap<String, u32>,
+    wip_limits: &HashMap<String, usize>,
+    now: DateTime<Local>,
+) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut titles: Vec<(String, (usize, usize), usize)> = vec![];
+    let last_column = board.columns_count().saturating_sub(1);
+
+    for (column_index, column) in board.columns_iter().enumerate() {
+        let header = column.header().to_string();
+        for (card_index, card) in column.cards_iter().enumerate() {
+            let title = card.short_description().trim();
+            if title.is_empty() {
+                findings.push(Finding::EmptyTitle {
+                    column_index,
+                    card_index,
+                    header: header.clone(),
+                });
+            } else if let Some((_, _, count)) = titles.iter_mut().find(|(other, _, _)| other == title) {
+                *count += 1;
+            } else {
+                titles.push((title.to_string(), (column_index, card_index), 1));
+            }
+
+            if column_index == 0 || column_index == last_column {
+                continue;
+            }
+            let threshold = stale_thresholds
+                .get(&header)
+                .map_or(OLD_IN_PROGRESS_DAYS, |&days| days as i64);
+            let days = (now - card.entered_column_date()).num_days();
+            if days > threshold {
+                findings.push(Finding::OldInProgress {
+                    column_index,
+                    card_index,
+                    title: title.to_string(),
+                    header: header.clone(),
+                    days,
+                });
+            }
+        }
+
+        if let Some(&limit) = wip_limits.get(&header) {
+            if column.size() > limit {
+                findings.push(Finding::OverWipLimit {
+                    column_index,
+                    header,
+                    limit,
+                    cards: column.size(),
+                });
+            }
+        }
+    }
+
+    let duplicates =
+        titles
+            .into_iter()
+            .filter(|(_, _, count)| *count > 1)
+            .map(|(title, (column_index, card_index), count)| Finding::DuplicateTitle {
+                column_index,
+                card_index,
+                title,
+                count,
+            });
+    findings.extend(duplicates);
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Result};
+
+    use chrono::{Duration, Local};
+
+    use crate::board::BoardBuilder;
+
+    use super::{health_check, Finding};
+
+    #[test]
+    fn find_issues() -> Result<()> {
+        let now = Local::now();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk").card(" ").card("Buy eggs"))
+            .column("Doing", |c| {
+                c.card_with("Cook dinner", |card| card.created(now - Duration::days(40)))
+                    .card_with("Buy milk", |card| card.created(now - Duration::days(3)))
+            })
+            .column("Done", |c| {
+                c.card_with("Wash dishes", |card| card.created(now - Duration::days(90)))
+            })
+            .build();
+
+        let limits = HashMap::from([("TODO".to_string(), 2)]);
+        let findings = health_check(&board, &HashMap::new(), &limits, now);
+        assert_eq!(
+            vec![
+                Finding::EmptyTitle {
+                    column_index: 0,
+                    card_index: 1,
+                    header: "TODO".into()
+                },
+                Finding::OverWipLimit {
+                    column_index: 0,
+                    header: "TODO".into(),
+                    limit: 2,
+                    cards: 3
+                },
+                Finding::OldInProgress {
+                    column_index: 1,
+                    card_index: 0,
+                    title: "Cook dinner".into(),
+                    header: "Doing".into(),
+                    days: 40
+                },
+                Finding::DuplicateTitle {
+                    column_index: 0,
+                    card_index: 0,
+                    title: "Buy milk".into(),
+                    count: 2
+                },
+            ],
+            findings
+        );
+        assert_eq!("'Cook dinner' is in Doing for 40 days", findings[2].to_string());
+        assert_eq!((0, 0), findings[1].position());
+
+        let thresholds = HashMap::from([("Doing".to_string(), 2)]);
+        let findings = health_check(&board, &thresholds, &HashMap::new(), now);
+        assert_eq!(4, findings.len(), "both cards in progress are old");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1297 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{Error, ErrorKind, Result},
+    slice,
+    time::Duration,
+};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::board::{
+    file_service, obsidian, section, Card, CardTemplate, Column, ColumnOptions, SavedSearch, Section, SortPolicy,
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Board {
+    columns: Vec<Column>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    archive: Vec<Card>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    saved_searches: Vec<SavedSearch>,
+
+    /// Free text about the board as a whole, like the goal of a sprint or meeting notes
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    notes: String,
+
+    /// Number of cards the team aims to complete each week
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weekly_target: Option<usize>,
+
+    /// Who is making the changes, recorded with the moves of the cards
+    #[serde(skip)]
+    author: Option<String>,
+
+    /// Layout of the JSON file the board was read from
+    #[serde(skip)]
+    file_layout: Option<JsonLayout>,
+}
+
+/// How the JSON of a board is laid out, pretty to be edited by hand, compact to be smaller and faster to write
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonLayout {
+    #[default]
+    Pretty,
+    Compact,
+}
+
+/// How a board is written to a JSON file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaveOptions {
+    /// Keys sorted alphabetically and a final newline, so that the file only changes where the board does
+    pub canonical: bool,
+
+    pub layout: JsonLayout,
+}
+
+/// Part of a board written by an export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportScope {
+    Board,
+    Column(usize),
+    /// Cards given by their position, like the results of a search
+    Cards(Vec<(usize, usize)>),
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a Kanban board with its basic features
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Local;
+/// use rustyban::board::{Board, Card};
+///
+/// let mut board = Board::new();
+///
+/// let now = Local::now();
+/// let card1 = Card::new("Card 1", now);
+/// let card2 = Card::new("Card 2", now);
+/// let card3 = Card::new("Card 3", now);
+///
+/// board.insert_card(0, 0, card1);
+/// board.insert_card(0, 0, card2);
+/// board.insert_card(0, 0, card3);
+///
+/// let res = board.mark_card_done(0, 1);
+/// assert_eq!((1, 0), res);
+///
+/// let res = board.mark_card_undone(0, 1);
+/// assert_eq!((0, 1), res);
+/// ```
+impl Board {
+    pub fn new() -> Self {
+        let todo = Column::new("TODO", vec![]);
+        let doing = Column::new("Doing", vec![]);
+        let done = Column::new("Done!", vec![]);
+
+        Board {
+            columns: vec![todo, doing, done],
+            archive: vec![],
+            saved_searches: vec![],
+            notes: String::new(),
+            weekly_target: None,
+            author: None,
+            file_layout: None,
+        }
+    }
+
+    pub(crate) fn from_columns(columns: Vec<Column>) -> Self {
+        Board {
+            columns,
+            archive: vec![],
+            saved_searches: vec![],
+            notes: String::new(),
+            weekly_target: None,
+            author: None,
+            file_layout: None,
+        }
+    }
+
+    /// Opens a board file, files with a `.md` extension are read as Obsidian Kanban boards, and directories as
+    /// one markdown file per column
+    pub fn open(file_name: &str) -> Result<Self> {
+        file_service(file_name).read(file_name)
+    }
+
+    /// Reads a board from the content of a JSON file
+    pub(super) fn from_json(content: &str) -> Result<Self> {
+        let mut board: Board = serde_json::from_str(content)?;
+        if board.columns.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidData, "no column found in board"));
+        }
+        board.columns.iter_mut().for_each(Column::normalize_ranks);
+        // Pretty JSON spans several lines, even for an empty board
+        board.file_layout = Some(match content.trim_end().contains('\n') {
+            true => JsonLayout::Pretty,
+            false => JsonLayout::Compact,
+        });
+        Ok(board)
+    }
+
+    /// Layout of the JSON file the board was read from, to keep writing it the same way
+    pub fn file_layout(&self) -> Option<JsonLayout> {
+        self.file_layout
+    }
+
+    /// Writes the board to a file, files with a `.md` extension are written as Obsidian Kanban boards
+    pub fn to_file(&self, file_name: &str) -> Result<()> {
+        self.to_file_with(file_name, SaveOptions::default())
+    }
+
+    /// Writes the board to a file, JSON files are laid out according to the options
+    pub fn to_file_with(&self, file_name: &str, options: SaveOptions) -> Result<()> {
+        file_service(file_name).write(file_name, &self.without_scratch_cards(), options)
+    }
+
+    /// Identifies the content of the board as written to a file, two boards written the same have the same revision
+    pub fn revision(&self, file_name: &str) -> Result<u64> {
+        let content = file_service(file_name).content(&self.without_scratch_cards(), SaveOptions::default())?;
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    pub fn has_scratch_cards(&self) -> bool {
+        self.cards_with_positions().any(|(_, _, card)| card.is_scratch())
+    }
+
+    /// The board as written to a file, without the scratch cards of the session
+    pub fn without_scratch_cards(&self) -> Cow<'_, Board> {
+        if !self.has_scratch_cards() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut board = self.clone();
+        board.columns.iter_mut().for_each(Column::remove_scratch_cards);
+        Cow::Owned(board)
+    }
+
+    /// The board as the markdown exporter writes it, without the scratch cards of the session
+    pub fn to_markdown(&self) -> String {
+        obsidian::to_markdown(&self.without_scratch_cards())
+    }
+
+    /// The board as JSON with sorted keys, only what the board holds is written, not the state of the interface
+    pub fn to_canonical_json(&self) -> Result<String> {
+        self.without_scratch_cards().to_json(SaveOptions {
+            canonical: true,
+            layout: JsonLayout::Pretty,
+        })
+    }
+
+    #[cfg(test)]
+    fn to_json_string(&self) -> Result<String> {
+        self.to_json(SaveOptions::default())
+    }
+
+    pub(super) fn to_json(&self, options: SaveOptions) -> Result<String> {
+        let json = match (options.canonical, options.layout) {
+            // Maps of serde_json values are sorted by key
+            (true, JsonLayout::Pretty) => serde_json::to_string_pretty(&serde_json::to_value(self)?)? + "\n",
+            (true, JsonLayout::Compact) => serde_json::to_string(&serde_json::to_value(self)?)? + "\n",
+            (false, JsonLayout::Pretty) => serde_json::to_string_pretty(self)?,
+            (false, JsonLayout::Compact) => serde_json::to_string(self)?,
+        };
+        Ok(json)
+    }
+
+    pub fn column(&self, index: usize) -> &Column {
+        &self.columns[index]
+    }
+
+    pub fn card(&self, column_index: usize, card_index: usize) -> &Card {
+        self.columns[column_index].get_card(card_index)
+    }
+
+    pub fn columns_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Runs of neighbouring columns of the same section, from the left, columns without section form their own runs
+    pub fn sections(&self) -> Vec<Section> {
+        section::sections(&self.columns)
+    }
+
+    /// Columns of the board, from the left
+    pub fn columns_iter(&self) -> slice::Iter<'_, Column> {
+        self.columns.iter()
+    }
+
+    /// Every card of the board with its column and card indexes, column by column
+    pub fn cards_with_positions(&self) -> impl Iterator<Item = (usize, usize, &Card)> {
+        self.columns.iter().enumerate().flat_map(|(column_index, column)| {
+            column
+                .cards_iter()
+                .enumerate()
+                .map(move |(card_index, card)| (column_index, card_index, card))
+        })
+    }
+
+    /// Tags used on the cards of the board, by name, with the number of cards using each of them
+    pub fn tag_counts(&self) -> Vec<(String, usize)> {
+        let mut counts = BTreeMap::new();
+        for (_, _, card) in self.cards_with_positions() {
+            for tag in card.tags() {
+                *counts.entry(tag.clone()).or_insert(0) += 1;
+            }
+        }
+        counts.into_iter().collect()
+    }
+
+    /// Cards taken off the board by [`Board::archive_done_cards`], most recently archived last
+    pub fn archived_cards(&self) -> &[Card] {
+        &self.archive
+    }
+
+    /// Forgets the cards archived after the first `len` ones, when archiving them is undone
+    pub fn unarchive_after(&mut self, len: usize) {
+        self.archive.truncate(len);
+    }
+
+    /// Attributes the moves of the cards made from now on to someone
+    pub fn set_author(&mut self, author: Option<String>) {
+        self.author = author;
+    }
+
+    /// Searches saved with the board, in the order they were first saved
+    pub fn saved_searches(&self) -> &[SavedSearch] {
+        &self.saved_searches
+    }
+
+    /// Saves a search under a name, replacing the query of the search already saved under that name
+    pub fn save_search(&mut self, name: &str, query: &str) {
+        match self.saved_searches.iter_mut().find(|search| search.name == name) {
+            Some(search) => search.query = query.to_string(),
+            None => self.saved_searches.push(SavedSearch {
+                name: name.to_string(),
+                query: query.to_string(),
+            }),
+        }
+    }
+
+    /// Forgets the search saved under a name, returns whether there was one
+    pub fn remove_saved_search(&mut self, name: &str) -> bool {
+        let count = self.saved_searches.len();
+        self.saved_searches.retain(|search| search.name != name);
+        self.saved_searches.len() != count
+    }
+
+    /// Moves to the archive the cards that have been in the last column for at least `after_days` days
+    ///
+    /// Cards found in the last column without a completion date (from older files) are considered
+    /// completed now. Returns the archived cards.
+    pub fn archive_done_cards(&mut self, after_days: u32, now: DateTime<Local>) -> Vec<Card> {
+        let Some(done) = self.columns.last_mut() else {
+            return vec![];
+        };
+
+        let mut archived = vec![];
+        let mut card_index = 0;
+        while card_index < done.size() {
+            let mut card = done.get_card(card_index).clone();
+            let completion_date = card.completion_date().unwrap_or(now);
+
+            if (now - completion_date).num_days() >= after_days as i64 {
+                done.remove_card(card_index);
+                archived.push(card);
+            } else {
+                if card.completion_date().is_none() {
+                    card.update_completion_date(Some(now));
+                    done.update_card(card_index, card);
+                }
+                card_index += 1;
+            }
+        }
+
+        self.archive.extend(archived.iter().cloned());
+        archived
+    }
+
+    /// Position of the card tracked by the timer, which follows it when it moves
+    pub fn timed_card(&self) -> Option<(usize, usize)> {
+        self.cards_with_positions()
+            .find(|(_, _, card)| card.is_timed())
+            .map(|(column_index, card_index, _)| (column_index, card_index))
+    }
+
+    /// Makes the timer track a card, or no card at all
+    pub fn set_timed_card(&mut self, position: Option<(usize, usize)>) {
+        if let Some((column_index, card_index)) = self.timed_card() {
+            self.columns[column_index].set_timed(card_index, false);
+        }
+        if let Some((column_index, card_index)) = position {
+            self.columns[column_index].set_timed(card_index, true);
+        }
+    }
+
+    /// Adds time spent to the card tracked by the timer, returns false when there is none
+    pub fn track_time(&mut self, duration: Duration) -> bool {
+        match self.timed_card() {
+            Some((column_index, card_index)) => {
+                let mut card = self.card(column_index, card_index).clone();
+                card.add_time_spent(duration);
+                self.columns[column_index].update_card(card_index, card);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Counts a completed pomodoro on the card tracked by the timer, returns false when there is none
+    pub fn track_pomodoro(&mut self) -> bool {
+        match self.timed_card() {
+            Some((column_index, card_index)) => {
+                let mut card = self.card(column_index, card_index).clone();
+                card.add_pomodoro();
+                self.columns[column_index].update_card(card_index, card);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn insert_card(&mut self, column_index: usize, card_index: usize, card: Card) {
+        self.columns[column_index].insert_card(card, card_index);
+    }
+
+    /// Puts back a card where it was removed from, keeping its rank when possible, when a change is undone
+    pub fn restore_card(&mut self, column_index: usize, card_index: usize, card: Card) {
+        self.columns[column_index].restore_card(card, card_index);
+    }
+
+    pub fn remove_card(&mut self, column_index: usize, card_index: usize) -> (usize, usize) {
+        let card_index = self.columns[column_index].remove_card(card_index);
+        (column_index, card_index)
+    }
+
+    pub fn update_card(&mut self, column_index: usize, card_index: usize, card: Card) {
+        self.columns[column_index].update_card(card_index, card);
+    }
+
+    pub fn increase_priority(&mut self, column_index: usize, card_index: usize) -> (usize, usize) {
+        let card_index = self.columns[column_index].increase_priority(card_index);
+        (column_index, card_index)
+    }
+
+    pub fn decrease_priority(&mut self, column_index: usize, card_index: usize) -> (usize, usize) {
+        let card_index = self.columns[column_index].decrease_priority(card_index);
+        (column_index, card_index)
+    }
+
+    /// Sorts a column with a new policy, returns the previous policy and order of the cards
+    pub fn sort_column(&mut self, column_index: usize, policy: SortPolicy) -> (SortPolicy, Vec<Card>) {
+        self.columns[column_index].sort(policy)
+    }
+
+    pub fn restore_column(&mut self, column_index: usize, policy: SortPolicy, cards: Vec<Card>) {
+        self.columns[column_index].restore(policy, cards);
+    }
+
+    /// Sets the settings of a column, see `Column::set_options`
+    pub fn set_column_options(
+        &mut self,
+        column_index: usize,
+        options: ColumnOptions,
+    ) -> (ColumnOptions, Option<Vec<Card>>) {
+        self.columns[column_index].set_options(options)
+    }
+
+    pub fn restore_column_options(&mut self, column_index: usize, options: ColumnOptions, cards: Option<Vec<Card>>) {
+        self.columns[column_index].restore_options(options, cards);
+    }
+
+    /// Sets the template of the cards inserted in a column and returns the previous one
+    pub fn set_card_template(&mut self, column_index: usize, card_template: CardTemplate) -> CardTemplate {
+        self.columns[column_index].set_card_template(card_template)
+    }
+
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Replaces the notes of the board, returns the previous ones
+    pub fn update_notes(&mut self, notes: &str) -> String {
+        std::mem::replace(&mut self.notes, notes.to_string())
+    }
+
+    pub fn weekly_target(&self) -> Option<usize> {
+        self.weekly_target
+    }
+
+    /// Sets the number of cards to complete each week, 0 or `None` for no target
+    pub fn set_weekly_target(&mut self, weekly_target: Option<usize>) {
+        self.weekly_target = weekly_target.filter(|&target| target > 0);
+    }
+
+    /// Copy of the part of the board in the scope, a column or some cards only leave out the notes and the archive
+    pub fn excerpt(&self, scope: &ExportScope) -> Board {
+        match scope {
+            ExportScope::Board => self.clone(),
+            ExportScope::Column(column_index) => Board::from_columns(vec![self.columns[*column_index].clone()]),
+            ExportScope::Cards(positions) => {
+                let columns = self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(column_index, column)| {
+                        let cards: Vec<Card> = column
+                            .cards_iter()
+                            .enumerate()
+                            .filter(|(card_index, _)| positions.contains(&(column_index, *card_index)))
+                            .map(|(_, card)| card.clone())
+                            .collect();
+                        (!cards.is_empty()).then(|| {
+                            let mut column = column.clone();
+                            column.restore(column.sort_policy(), cards);
+                            column
+                        })
+                    })
+                    .collect();
+                Board::from_columns(columns)
+            }
+        }
+    }
+
+    /// Renames a column and returns its previous header
+    pub fn rename_column(&mut self, column_index: usize, header: &str) -> String {
+        self.columns[column_index].rename(header)
+    }
+
+    /// Inserts an empty column, in the section of the column before it
+    pub fn insert_column(&mut self, column_index: usize, header: &str) {
+        let mut column = Column::new(header, vec![]);
+        if let Some(previous) = column_index.checked_sub(1).and_then(|index| self.columns.get(index)) {
+            column.set_section(previous.section());
+        }
+        self.columns.insert(column_index, column);
+    }
+
+    pub fn remove_column(&mut self, column_index: usize) -> Column {
+        self.columns.remove(column_index)
+    }
+
+    /// Moves the cards of a column from an index to the end of another column at a given date, returns the position
+    /// of the first
+    pub fn move_cards_at(
+        &mut self,
+        column_index: usize,
+        card_index: usize,
+        new_column_index: usize,
+        now: DateTime<Local>,
+    ) -> (usize, usize) {
+        let header = self.columns[new_column_index].header().to_string();
+        let cards = self.columns[column_index].split_off(card_index);
+        let first = self.columns[new_column_index].size();
+        for mut card in cards {
+            card.enter_column_by(&header, now, self.author.as_deref());
+            let column = &mut self.columns[new_column_index];
+            let index = column.insertion_index(&card, column.size());
+            column.insert_card(card, index);
+        }
+
+        (new_column_index, first)
+    }
+
+    /// Moves the cards which entered a column before a date to the end of another column, returns the moved cards
+    pub fn roll_over_cards(
+        &mut self,
+        column_index: usize,
+        new_column_index: usize,
+        before: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> Vec<Card> {
+        let header = self.columns[new_column_index].header().to_string();
+        let mut moved = vec![];
+        let mut card_index = 0;
+        while card_index < self.columns[column_index].size() {
+            if self.columns[column_index].get_card(card_index).entered_column_date() >= before {
+                card_index += 1;
+                continue;
+            }
+
+            let mut card = self.columns[column_index].get_card(card_index).clone();
+            self.columns[column_index].remove_card(card_index);
+            moved.push(card.clone());
+            card.enter_column_by(&header, now, self.author.as_deref());
+            let column = &mut self.columns[new_column_index];
+            let index = column.insertion_index(&card, column.size());
+            column.insert_card(card, index);
+        }
+
+        moved
+    }
+
+    pub fn move_column_left(&mut self, column_index: usize) -> usize {
+        if column_index > 0 && column_index < self.columns.len() {
+            self.columns.swap(column_index, column_index - 1);
+            return column_index - 1;
+        }
+
+        column_index
+    }
+
+    pub fn move_column_right(&mut self, column_index: usize) -> usize {
+        if column_index + 1 < self.columns.len() {
+            self.columns.swap(column_index, column_index + 1);
+            return column_index + 1;
+        }
+
+        column_index
+    }
+
+    pub fn mark_card_done(&mut self, column_index: usize, card_index: usize) -> (usize, usize) {
+        self.mark_card_done_at(column_index, card_index, Local::now())
+    }
+
+    /// Moves a card to the next column as done at a given date, like when redoing the move
+    ///
+    /// The card goes at the top of the column, or where the sort of the column puts it.
+    pub fn mark_card_done_at(
+        &mut self,
+        column_index: usize,
+        card_index: usize,
+        now: DateTime<Local>,
+    ) -> (usize, usize) {
+        if column_index >= self.columns.len() - 1 {
+            return (column_index, card_index);
+        }
+
+        let mut card = self.card(column_index, card_index).clone();
+        card.enter_column_by(self.columns[column_index + 1].header(), now, self.author.as_deref());
+        if column_index + 2 == self.columns.len() {
+            card.update_completion_date(Some(now));
+        }
+        self.columns[column_index].remove_card(card_index);
+        let column = &mut self.columns[column_index + 1];
+        let index = column.insertion_index(&card, 0);
+        column.insert_card(card, index);
+
+        (column_index + 1, index)
+    }
+
+    pub fn mark_card_undone(&mut self, column_index: usize, card_index: usize) -> (usize, usize) {
+        self.mark_card_undone_at(column_index, card_index, Local::now())
+    }
+
+    /// Moves a card back to the previous column at a given date, like when redoing the move
+    ///
+    /// The card goes at the top of the column, or where the sort of the column puts it.
+    pub fn mark_card_undone_at(
+        &mut self,
+        column_index: usize,
+        card_index: usize,
+        now: DateTime<Local>,
+    ) -> (usize, usize) {
+        if column_index == 0 {
+            return (column_index, card_index);
+        }
+
+        let mut card = self.card(column_index, card_index).clone();
+        card.enter_column_by(self.columns[column_index - 1].header(), now, self.author.as_deref());
+        card.update_completion_date(None);
+        self.columns[column_index].remove_card(card_index);
+        let column = &mut self.columns[column_index - 1];
+        let index = column.insertion_index(&card, 0);
+        column.insert_card(card, index);
+
+        (column_index - 1, index)
+    }
+
+    /// Moves a card to a slot of a column at a given date, returns where it lands
+    ///
+    /// The slot counts the cards as they are before the move, it is only honored when the column is sorted
+    /// manually. A card entering the last column is completed, one leaving it is not anymore.
+    pub fn move_card_at(
+        &mut self,
+        column_index: usize,
+        card_index: usize,
+        new_column_index: usize,
+        slot: usize,
+        now: DateTime<Local>,
+    ) -> (usize, usize) {
+        let mut card = self.card(column_index, card_index).clone();
+        let slot = if new_column_index == column_index && slot > card_index {
+            slot - 1
+        } else {
+            slot
+        };
+        if new_column_index != column_index {
+            card.enter_column_by(self.columns[new_column_index].header(), now, self.author.as_deref());
+            let last = self.columns.len() - 1;
+            if new_column_index == last {
+                card.update_completion_date(Some(now));
+            } else if column_index == last {
+                card.update_completion_date(None);
+            }
+        }
+        self.columns[column_index].remove_card(card_index);
+        let column = &mut self.columns[new_column_index];
+        let index = column.insertion_index(&card, slot);
+        column.insert_card(card, index);
+
+        (new_column_index, index)
+    }
+}
+
+/// Content of a board file as stored, the markdown files of a board directory one after the other
+pub fn stored_content(file_name: &str) -> Result<Vec<u8>> {
+    file_service(file_name).stored_content(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use chrono::Local;
+
+    use crate::board::BoardBuilder;
+
+    use super::*;
+
+    #[test]
+    fn open_board() -> Result<()> {
+        let path = "res/test_board.json";
+        let board = Board::open(path).expect("Cannot open board");
+
+        assert_eq!("TODO", board.columns[0].header());
+        assert_eq!("Buy milk", board.columns[0].get_card(0).short_description());
+        assert_eq!("Buy eggs", board.columns[0].get_card(1).short_description());
+        assert_eq!("Buy bread", board.columns[0].get_card(2).short_description());
+        assert_eq!("Doing", board.columns[1].header());
+        assert_eq!("Cook dinner", board.columns[1].get_card(0).short_description());
+        assert_eq!("Done!", board.columns[2].header());
+        assert_eq!("Eat dinner", board.columns[2].get_card(0).short_description());
+        assert_eq!("Wash dishes", board.columns[2].get_card(1).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_board_without_columns() -> Result<()> {
+        assert!(Board::from_json(r#"{"columns":[]}"#).is_err());
+
+        let file_name = env::temp_dir().join("rustyban-no-column.json").display().to_string();
+        fs::write(&file_name, r#"{"columns":[]}"#)?;
+        assert!(Board::open(&file_name).is_err());
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn iterate_over_columns_and_cards() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+
+        let headers: Vec<&str> = board.columns_iter().map(|column| column.header()).collect();
+        assert_eq!(vec!["TODO", "Doing", "Done!"], headers);
+
+        let titles: Vec<&str> = board
+            .column(2)
+            .cards_iter()
+            .map(|card| card.short_description().as_str())
+            .collect();
+        assert_eq!(vec!["Eat dinner", "Wash dishes"], titles);
+
+        let positions: Vec<(usize, usize)> = board
+            .cards_with_positions()
+            .map(|(column_index, card_index, _)| (column_index, card_index))
+            .collect();
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2), (1, 0), (2, 0), (2, 1)], positions);
+        assert!(board
+            .cards_with_positions()
+            .all(|(column_index, card_index, card)| card == board.card(column_index, card_index)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_board_to_file() -> Result<()> {
+        let path = "board.txt";
+        let _ = fs::remove_file(path);
+
+        let board = Board::new();
+        let res = board.to_file(path);
+
+        assert!(res.is_ok());
+        assert!(fs::metadata(path).is_ok());
+
+        let _ = fs::remove_file(path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_board_to_markdown_file() -> Result<()> {
+        let path = "board.md";
+        let _ = fs::remove_file(path);
+
+        let board = Board::open("res/test_board.json")?;
+        board.to_file(path)?;
+
+        let content = fs::read_to_string(path)?;
+        assert!(content.contains("## Doing\n\n- [ ] Cook dinner\n"));
+
+        let reloaded = Board::open(path)?;
+        assert_eq!("Cook dinner", reloaded.card(1, 0).short_description());
+        assert_eq!(content, board.to_markdown());
+
+        let _ = fs::remove_file(path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn board_to_json_string() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let result = board.to_json_string()?;
+
+        assert!(result.contains("TODO"));
+        assert!(result.contains("Buy milk"));
+        assert!(result.contains("Buy eggs"));
+        assert!(result.contains("Buy bread"));
+
+        assert!(result.contains("Doing"));
+        assert!(result.contains("Cook dinner"));
+
+        assert!(result.contains("Done!"));
+        assert!(result.contains("Eat dinner"));
+        assert!(result.contains("Wash dishes"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn increasing_priority() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+
+        let cases: Vec<((usize, usize), (usize, usize))> = vec![((0, 0), (0, 0)), ((0, 1), (0, 0)), ((0, 2), (0, 1))];
+
+        for ((column_index, card_index), expected) in cases {
+            let mut board = board.clone();
+            assert_eq!(expected, board.increase_priority(column_index, card_index));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn decreasing_priority() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+
+        let cases: Vec<((usize, usize), (usize, usize))> = vec![((0, 0), (0, 1)), ((0, 1), (0, 2)), ((0, 2), (0, 2))];
+
+        for ((column_index, card_index), expected) in cases {
+            let mut board = board.clone();
+            assert_eq!(expected, board.decrease_priority(column_index, card_index));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn timer_follows_card() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        assert!(!board.track_time(Duration::from_secs(30)));
+
+        board.set_timed_card(Some((0, 1)));
+        board.mark_card_done(0, 1);
+        assert_eq!(Some((1, 0)), board.timed_card());
+
+        assert!(board.track_time(Duration::from_secs(90)));
+        assert!(board.track_time(Duration::from_secs(30)));
+        assert_eq!(Duration::from_secs(120), board.card(1, 0).time_spent());
+        assert_eq!(Duration::ZERO, board.card(0, 0).time_spent());
+
+        assert!(board.track_pomodoro());
+        assert_eq!(1, board.card(1, 0).pomodoros());
+
+        board.set_timed_card(Some((0, 0)));
+        assert_eq!(Some((0, 0)), board.timed_card());
+        board.set_timed_card(None);
+        assert_eq!(None, board.timed_card());
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_done_cards() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let now = Local::now();
+
+        let archived = board.archive_done_cards(3, now);
+        assert!(archived.is_empty());
+        assert_eq!(Some(now), board.card(2, 0).completion_date());
+
+        board.mark_card_done(1, 0);
+        assert_eq!("Cook dinner", board.card(2, 0).short_description());
+        assert!(board.card(2, 0).completion_date().is_some());
+        board.mark_card_undone(2, 0);
+        assert_eq!(None, board.card(1, 0).completion_date());
+        board.mark_card_done(1, 0);
+
+        let archived = board.archive_done_cards(3, now + chrono::Duration::days(2));
+        assert!(archived.is_empty());
+
+        let archived = board.archive_done_cards(3, now + chrono::Duration::days(4));
+        assert_eq!(3, archived.len());
+        assert!(board.column(2).is_empty());
+        assert_eq!("Cook dinner", board.archived_cards()[0].short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn roll_over_cards() -> Result<()> {
+        let now = Local::now();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy milk"))
+            .column("Today", |c| {
+                c.card_with("Buy eggs", |card| card.created(now - chrono::Duration::days(1)))
+                    .card_with("Buy bread", |card| card.created(now))
+                    .card_with("Cook dinner", |card| card.created(now - chrono::Duration::hours(20)))
+            })
+            .build();
+
+        let moved = board.roll_over_cards(1, 0, now - chrono::Duration::hours(1), now);
+        assert_eq!(2, moved.len());
+        assert_eq!("Buy bread", board.card(1, 0).short_description());
+        assert_eq!("Buy eggs", board.card(0, 1).short_description());
+        assert_eq!("Cook dinner", board.card(0, 2).short_description());
+        assert_eq!(now, board.card(0, 2).entered_column_date());
+
+        assert!(board
+            .roll_over_cards(1, 0, now - chrono::Duration::hours(1), now)
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_json() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        board.set_timed_card(Some((0, 0)));
+        board.save_search("Shopping", "buy");
+
+        let json = board.to_canonical_json()?;
+        assert!(json.ends_with("}\n"));
+        assert!(!json.contains("is_timed"));
+        let keys: Vec<usize> = ["\"archive\"", "\"columns\"", "\"saved_searches\""]
+            .iter()
+            .filter_map(|key| json.find(key))
+            .collect();
+        assert_eq!(2, keys.len(), "the archive is empty");
+        assert!(keys[0] < keys[1]);
+        let card = ["\"creation_date\"", "\"long_description\"", "\"short_description\""].map(|key| json.find(key));
+        assert!(card[0] < card[1] && card[1] < card[2]);
+
+        let reopened: Board = serde_json::from_str(&json)?;
+        assert_eq!(json, reopened.to_canonical_json()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn scratch_cards_are_not_written() -> Result<()> {
+        let file_name = env::temp_dir()
+            .join("rustyban-scratch-cards.json")
+            .display()
+            .to_string();
+        let mut board = Board::open("res/test_board.json")?;
+        let revision = board.revision(&file_name)?;
+        let mut reminder = Card::new("Call back", Local::now());
+        reminder.set_scratch(true);
+        board.insert_card(1, 0, reminder);
+        assert!(board.has_scratch_cards());
+        assert_eq!(revision, board.revision(&file_name)?, "the file would be the same");
+
+        board.to_file(&file_name)?;
+        let reopened = Board::open(&file_name)?;
+        assert!(!reopened.has_scratch_cards());
+        assert_eq!(1, reopened.column(1).size());
+        assert_eq!(2, board.column(1).size(), "kept for the session");
+
+        let journaled: Board = serde_json::from_str(&serde_json::to_string(&board)?)?;
+        assert!(journaled.card(1, 0).is_scratch(), "kept in the journal");
+
+        fs::remove_file(file_name)
+    }
+
+    #[test]
+    fn json_layouts() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        assert_eq!(Some(JsonLayout::Pretty), board.file_layout());
+        assert_eq!(None, Board::new().file_layout());
+
+        let compact = SaveOptions {
+            canonical: false,
+            layout: JsonLayout::Compact,
+        };
+        let json = board.to_json(compact)?;
+        assert!(!json.contains('\n'));
+        assert!(json.starts_with("{\"columns\":[{\"header\":\"TODO\""));
+        let json = board.to_json(SaveOptions {
+            canonical: true,
+            ..compact
+        })?;
+        assert_eq!(Some(0), json.find("{\"columns\":[{\"cards\":"), "keys are sorted");
+
+        let file_name = env::temp_dir().join("rustyban-compact.json").display().to_string();
+        board.to_file_with(&file_name, compact)?;
+        assert_eq!(Some(JsonLayout::Compact), Board::open(&file_name)?.file_layout());
+        fs::remove_file(&file_name)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn moves_are_attributed() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        board.mark_card_done(0, 0);
+        assert_eq!(None, board.card(1, 0).column_entries()[0].by);
+
+        board.set_author(Some("alex".into()));
+        board.mark_card_done(1, 0);
+        board.mark_card_undone(2, 0);
+        let entries = board.card(1, 0).column_entries();
+        assert_eq!(Some("alex"), entries[1].by.as_deref());
+        assert_eq!(Some("alex"), entries[2].by.as_deref());
+
+        let content = board.to_json_string()?;
+        assert_eq!(2, content.matches("\"by\": \"alex\"").count());
+        let reopened: Board = serde_json::from_str(&content)?;
+        assert_eq!(entries, reopened.card(1, 0).column_entries());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_counts() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Buy milk", |card| card.tag("shopping").tag("home"))
+                    .card("Buy eggs")
+            })
+            .column("Done", |c| c.card_with("Buy bread", |card| card.tag("shopping")))
+            .build();
+
+        assert_eq!(vec![("home".into(), 1), ("shopping".into(), 2)], board.tag_counts());
+
+        Ok(())
+    }
+
+    #[test]
+    fn saved_searches() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        assert!(board.saved_searches().is_empty());
+
+        board.save_search("Shopping", "buy");
+        board.save_search("Dinner", "dinner");
+        board.save_search("Shopping", "milk");
+        let searches: Vec<(&str, &str)> = board
+            .saved_searches()
+            .iter()
+            .map(|search| (search.name.as_str(), search.query.as_str()))
+            .collect();
+        assert_eq!(vec![("Shopping", "milk"), ("Dinner", "dinner")], searches);
+
+        let content = board.to_json_string()?;
+        let reopened: Board = serde_json::from_str(&content)?;
+        assert_eq!(board.saved_searches(), reopened.saved_searches());
+
+        assert!(board.remove_saved_search("Shopping"));
+        assert!(!board.remove_saved_search("Shopping"));
+        assert_eq!(1, board.saved_searches().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ranks_written_with_the_cards() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        assert_eq!(
+            3.0,
+            board.card(0, 2).rank(),
+            "ranked when read from a file without ranks"
+        );
+
+        board.mark_card_done(0, 0);
+        assert_eq!(-1.0, board.card(1, 0).rank(), "above the first card of the column");
+
+        let reopened: Board = serde_json::from_str(&board.to_json_string()?)?;
+        let ranks: Vec<f64> = reopened
+            .cards_with_positions()
+            .map(|(_, _, card)| card.rank())
+            .collect();
+        assert_eq!(vec![2.0, 3.0, -1.0, 0.0, 1.0, 2.0], ranks);
+
+        Ok(())
+    }
+
+    #[test]
+    fn notes() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        assert_eq!("", board.notes());
+        assert!(!board.to_json_string()?.contains("\"notes\""));
+
+        assert_eq!("", board.update_notes("Sprint goal: eat\n- buy food"));
+        let reopened: Board = serde_json::from_str(&board.to_json_string()?)?;
+        assert_eq!("Sprint goal: eat\n- buy food", reopened.notes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn weekly_target() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        assert_eq!(None, board.weekly_target());
+        assert!(!board.to_json_string()?.contains("\"weekly_target\""));
+
+        board.set_weekly_target(Some(5));
+        let reopened: Board = serde_json::from_str(&board.to_json_string()?)?;
+        assert_eq!(Some(5), reopened.weekly_target());
+
+        Ok(())
+    }
+
+    #[test]
+    fn excerpts() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        board.update_notes("Notes");
+
+        assert_eq!(
+            board.to_json_string()?,
+            board.excerpt(&ExportScope::Board).to_json_string()?
+        );
+
+        let column = board.excerpt(&ExportScope::Column(2));
+        assert_eq!(1, column.columns_count());
+        assert_eq!("Done!", column.column(0).header());
+        assert_eq!(2, column.column(0).size());
+        assert_eq!("", column.notes());
+
+        let cards = board.excerpt(&ExportScope::Cards(vec![(0, 2), (2, 0), (0, 0)]));
+        let titles: Vec<(&str, Vec<&str>)> = cards
+            .columns_iter()
+            .map(|column| {
+                let titles = column
+                    .cards_iter()
+                    .map(|card| card.short_description().as_str())
+                    .collect();
+                (column.header(), titles)
+            })
+            .collect();
+        assert_eq!(
+            vec![("TODO", vec!["Buy milk", "Buy bread"]), ("Done!", vec!["Eat dinner"])],
+            titles,
+            "columns without any of the cards left out"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn moving_columns() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        assert_eq!(0, board.move_column_left(0));
+        assert_eq!(1, board.move_column_right(0));
+        assert_eq!("Doing", board.column(0).header());
+        assert_eq!("TODO", board.column(1).header());
+
+        assert_eq!(2, board.move_column_right(1));
+        assert_eq!(2, board.move_column_right(2));
+        assert_eq!("Done!", board.column(1).header());
+        assert_eq!("TODO", board.column(2).header());
+
+        assert_eq!(1, board.move_column_left(2));
+        assert_eq!("TODO", board.column(1).header());
+        assert_eq!("Buy milk", board.card(1, 0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn marking_card_done() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+
+        let cases: Vec<((usize, usize), (usize, usize))> = vec![
+            ((0, 0), (1, 0)),
+            ((0, 1), (1, 0)),
+            ((0, 2), (1, 0)),
+            ((1, 0), (2, 0)),
+            ((2, 0), (2, 0)),
+            ((2, 1), (2, 1)),
+        ];
+
+        for ((column_index, card_index), expected) in cases {
+            let mut board = board.clone();
+            assert_eq!(expected, board.mark_card_done(column_index, card_index));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sorted_columns_keep_their_order() -> Result<()> {
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Cook dinner").card("Buy milk"))
+            .column("Done", |c| c.card("Answer mail").card("Water plants"))
+            .build();
+        board.sort_column(1, SortPolicy::Name);
+
+        assert_eq!((1, 1), board.mark_card_done(0, 1));
+        assert_eq!("Buy milk", board.card(1, 1).short_description());
+        assert_eq!((1, 1), board.increase_priority(1, 1), "no manual reorder");
+        assert_eq!((1, 1), board.decrease_priority(1, 1));
+
+        board.sort_column(0, SortPolicy::Name);
+        assert_eq!((0, 1), board.mark_card_undone(1, 2));
+        assert_eq!("Water plants", board.card(0, 1).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn marking_card_undone() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+
+        let cases: Vec<((usize, usize), (usize, usize))> = vec![
+            ((0, 0), (0, 0)),
+            ((0, 1), (0, 1)),
+            ((0, 2), (0, 2)),
+            ((1, 0), (0, 0)),
+            ((2, 0), (1, 0)),
+            ((2, 1), (1, 0)),
+        ];
+
+        for ((column_index, card_index), expected) in cases {
+            let mut board = board.clone();
+            assert_eq!(expected, board.mark_card_undone(column_index, card_index));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn inserting_card() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let description = "new description";
+        let new_card = Card::new(description, Local::now());
+
+        let cases: Vec<(usize, usize, &str)> = vec![
+            (0, 0, "Buy milk"),
+            (0, 1, "Buy eggs"),
+            (0, 2, "Buy bread"),
+            (1, 0, "Cook dinner"),
+            (2, 0, "Eat dinner"),
+            (2, 1, "Wash dishes"),
+        ];
+
+        for (column_index, card_index, old_description) in cases {
+            let mut board = board.clone();
+
+            assert_eq!(
+                old_description,
+                board.card(column_index, card_index).short_description()
+            );
+            board.insert_card(column_index, card_index, new_card.clone());
+            assert_eq!(
+                old_description,
+                board.card(column_index, card_index + 1).short_description()
+            );
+            assert_eq!(description, board.card(column_index, card_index).short_description());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn appending_card() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let description = "new description";
+        let new_card = Card::new(description, Local::now());
+
+        let cases: Vec<(usize, usize)> = vec![(0, 3), (1, 1), (2, 2)];
+
+        for (column_index, card_index) in cases {
+            let mut board = board.clone();
+
+            board.insert_card(column_index, card_index, new_card.clone());
+            assert_eq!(description, board.card(column_index, card_index).short_description());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn deleting_card() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+
+        assert_eq!(3, board.column(0).size());
+        let position = board.remove_card(0, 1);
+        assert_eq!((0, 1), position);
+        assert_eq!(2, board.column(0).size());
+        let position = board.remove_card(0, 1);
+        assert_eq!((0, 0), position);
+        assert_eq!(1, board.column(0).size());
+        let position = board.remove_card(0, 0);
+        assert_eq!((0, 0), position);
+        assert_eq!(0, board.column(0).size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn moving_card_to_a_slot() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let titles = |board: &Board, column_index: usize| -> Vec<String> {
+            board
+                .column(column_index)
+                .cards_iter()
+                .map(|card| card.short_description().to_string())
+                .collect()
+        };
+
+        assert_eq!((0, 2), board.move_card_at(0, 0, 0, 3, Local::now()));
+        assert_eq!(vec!["Buy eggs", "Buy bread", "Buy milk"], titles(&board, 0));
+
+        assert_eq!((2, 1), board.move_card_at(0, 1, 2, 1, Local::now()));
+        assert_eq!(vec!["Eat dinner", "Buy bread", "Wash dishes"], titles(&board, 2));
+        assert!(board.card(2, 1).completion_date().is_some());
+
+        assert_eq!((1, 0), board.move_card_at(2, 1, 1, 0, Local::now()));
+        assert_eq!(vec!["Buy bread", "Cook dinner"], titles(&board, 1));
+        assert!(board.card(1, 0).completion_date().is_none());
+        assert_eq!(
+            Some("Doing"),
+            board
+                .card(1, 0)
+                .column_entries()
+                .last()
+                .map(|entry| entry.header.as_str())
+        );
+
+        Ok(())
+    }
+}
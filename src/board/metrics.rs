@@ -0,0 +1,188 @@
+use std::{
+    fs,
+    io::{ErrorKind, Result},
+};
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::board::Board;
+
+const METRICS_EXTENSION: &str = "metrics";
+
+/// Number of cards in a column on the day of a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnCount {
+    pub header: String,
+    pub cards: usize,
+}
+
+/// Cards in each column on a given day, as they were when the board was first written that day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailySnapshot {
+    pub date: NaiveDate,
+    pub columns: Vec<ColumnCount>,
+}
+
+/// Daily snapshots of a board, kept next to its file to chart how the columns grow and shrink over weeks
+///
+/// Unlike the cumulative flow, computed from the column entries of the cards, the history only knows the days
+/// the board was written, but it also counts the cards deleted since.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetricsHistory {
+    snapshots: Vec<DailySnapshot>,
+}
+
+impl MetricsHistory {
+    /// Reads the history of a board file, empty when it has none yet
+    pub fn open(board_file_name: &str) -> Result<Self> {
+        match fs::read_to_string(metrics_file_name(board_file_name)) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn to_file(&self, board_file_name: &str) -> Result<()> {
+        fs::write(metrics_file_name(board_file_name), serde_json::to_string(self)?)
+    }
+
+    pub fn snapshots(&self) -> &[DailySnapshot] {
+        &self.snapshots
+    }
+
+    /// Takes the snapshot of the day, returns false when there is one already
+    pub fn record(&mut self, board: &Board, today: NaiveDate) -> bool {
+        if self.snapshots.last().is_some_and(|snapshot| snapshot.date >= today) {
+            return false;
+        }
+
+        let columns = board
+            .columns_iter()
+            .map(|column| ColumnCount {
+                header: column.header().to_string(),
+                cards: column.size(),
+            })
+            .collect();
+        self.snapshots.push(DailySnapshot { date: today, columns });
+        true
+    }
+
+    /// Cards per column at the end of each of the last `weeks` weeks, the last one ending today
+    ///
+    /// Weeks before the first snapshot are left out. The columns are those of the latest snapshot, a column
+    /// missing from an older snapshot counts no card.
+    pub fn weekly_trend(&self, weeks: usize, today: NaiveDate) -> Vec<(String, Vec<usize>)> {
+        let Some(latest) = self.snapshots.last() else {
+            return vec![];
+        };
+
+        let week_snapshots: Vec<&DailySnapshot> = (0..weeks)
+            .rev()
+            .map(|week| today - Duration::weeks(week as i64))
+            .filter_map(|end| self.snapshots.iter().rev().find(|snapshot| snapshot.date <= end))
+            .collect();
+
+        latest
+            .columns
+            .iter()
+            .map(|column| {
+                let counts = week_snapshots
+                    .iter()
+                    .map(|snapshot| {
+                        snapshot
+                            .columns
+                            .iter()
+                            .find(|count| count.header == column.header)
+                            .map_or(0, |count| count.cards)
+                    })
+                    .collect();
+                (column.header.clone(), counts)
+            })
+            .collect()
+    }
+}
+
+/// Where the metrics history of a board file is kept
+pub fn metrics_file_name(board_file_name: &str) -> String {
+    format!("{}.{}", board_file_name, METRICS_EXTENSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use chrono::NaiveDate;
+
+    use crate::board::{Board, BoardBuilder};
+
+    use super::{metrics_file_name, MetricsHistory};
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn one_snapshot_per_day() -> Result<()> {
+        let mut board = Board::open("res/test_board.json")?;
+        let mut history = MetricsHistory::default();
+
+        assert!(history.record(&board, day(3, 1)));
+        board.remove_card(0, 0);
+        assert!(!history.record(&board, day(3, 1)), "already taken today");
+        assert!(history.record(&board, day(3, 2)));
+
+        let counts: Vec<Vec<usize>> = history
+            .snapshots()
+            .iter()
+            .map(|snapshot| snapshot.columns.iter().map(|column| column.cards).collect())
+            .collect();
+        assert_eq!(vec![vec![3, 1, 2], vec![2, 1, 2]], counts);
+
+        let file_name = env::temp_dir().join("rustyban-metrics.json").display().to_string();
+        history.to_file(&file_name)?;
+        assert_eq!(history, MetricsHistory::open(&file_name)?);
+        fs::remove_file(metrics_file_name(&file_name))?;
+        assert_eq!(
+            MetricsHistory::default(),
+            MetricsHistory::open(&file_name)?,
+            "no history yet"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn weekly_trend() -> Result<()> {
+        let board = |todo: usize, done: usize| {
+            BoardBuilder::new()
+                .column("TODO", |mut c| {
+                    for _ in 0..todo {
+                        c = c.card("Task");
+                    }
+                    c
+                })
+                .column("Done", |mut c| {
+                    for _ in 0..done {
+                        c = c.card("Task");
+                    }
+                    c
+                })
+                .build()
+        };
+        let mut history = MetricsHistory::default();
+        history.record(&board(5, 0), day(3, 3));
+        history.record(&board(4, 1), day(3, 5));
+        history.record(&board(3, 3), day(3, 12));
+        history.record(&board(1, 6), day(3, 20));
+
+        assert_eq!(
+            vec![("TODO".to_string(), vec![4, 3, 1]), ("Done".to_string(), vec![1, 3, 6])],
+            history.weekly_trend(4, day(3, 21)),
+            "the week before the first snapshot is left out"
+        );
+        assert!(MetricsHistory::default().weekly_trend(4, day(3, 21)).is_empty());
+
+        Ok(())
+    }
+}
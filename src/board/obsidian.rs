@@ -0,0 +1,227 @@
+use std::io::{Error, ErrorKind, Result};
+
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::board::{Board, Card, Column};
+
+const FRONT_MATTER: &str = "---\n\nkanban-plugin: basic\n\n---\n";
+const SETTINGS: &str = "%% kanban:settings\n```\n{\"kanban-plugin\":\"basic\"}\n```\n%%\n";
+const DESCRIPTION_INDENT: &str = "    ";
+
+/// Parses a board written in the Obsidian Kanban plugin markdown format.
+///
+/// Each `## Header` starts a column and each `- [ ] item` (or `- [x] item`) starts a card. Tags (`#tag`)
+/// and due dates (`@{2024-12-24}`) found in the item are extracted from the short description, and
+/// indented lines following an item make up its long description.
+pub fn from_markdown(content: &str) -> Result<Board> {
+    let mut columns: Vec<Column> = vec![];
+    let mut in_front_matter = false;
+    let now = Local::now();
+
+    for (line_number, line) in content.lines().enumerate() {
+        if line_number == 0 && line.trim() == "---" {
+            in_front_matter = true;
+            continue;
+        }
+        if in_front_matter {
+            in_front_matter = line.trim() != "---";
+            continue;
+        }
+        if line.starts_with("%% kanban:settings") {
+            break;
+        }
+
+        if let Some(header) = line.strip_prefix("## ") {
+            columns.push(Column::new(header.trim(), vec![]));
+        } else if let Some(item) = parse_item(line) {
+            let column = columns
+                .last_mut()
+                .ok_or_else(|| invalid_data(line_number, "card found before any column"))?;
+            column.insert_card(parse_card(item, now), column.size());
+        } else if let Some(description) = continuation(line) {
+            if let Some(column) = columns.last_mut().filter(|column| !column.is_empty()) {
+                let index = column.size() - 1;
+                let mut card = column.get_card(index).clone();
+                let long_description = if card.long_description().is_empty() {
+                    description.to_string()
+                } else {
+                    format!("{}\n{}", card.long_description(), description)
+                };
+                card.update_long_description(&long_description);
+                column.update_card(index, card);
+            }
+        }
+    }
+
+    if columns.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidData, "no column found in markdown board"));
+    }
+
+    Ok(Board::from_columns(columns))
+}
+
+/// Writes a board in the Obsidian Kanban plugin markdown format.
+///
+/// Cards of the last column are written as checked items, which is how Obsidian renders completed work.
+///
+/// The format only has room for the headers of the columns and for the descriptions, tags and due dates of the
+/// cards, which is all that is read back. Everything else is lost on the way: the creation and completion
+/// dates, estimates, colors, stars, time spent and column history of the cards, the settings of the columns,
+/// the notes, archive, saved searches and weekly target of the board.
+pub fn to_markdown(board: &Board) -> String {
+    let mut content = String::from(FRONT_MATTER);
+    let last_column = board.columns_count().saturating_sub(1);
+
+    for (column_index, column) in board.columns_iter().enumerate() {
+        content.push_str(&format!("\n## {}\n\n", column.header()));
+
+        let checkbox = if column_index == last_column { "[x]" } else { "[ ]" };
+        for card in column.cards_iter() {
+            content.push_str(&format!("- {} {}\n", checkbox, item_text(card)));
+
+            if !card.long_description().is_empty() {
+                for line in card.long_description().lines() {
+                    content.push_str(&format!("{}{}\n", DESCRIPTION_INDENT, line));
+                }
+            }
+        }
+    }
+
+    content.push_str("\n\n");
+    content.push_str(SETTINGS);
+    content
+}
+
+fn parse_item(line: &str) -> Option<&str> {
+    ["- [ ] ", "- [x] ", "- [X] "]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))
+}
+
+fn continuation(line: &str) -> Option<&str> {
+    line.strip_prefix('\t')
+        .or_else(|| line.strip_prefix(DESCRIPTION_INDENT))
+}
+
+fn parse_card(item: &str, now: DateTime<Local>) -> Card {
+    let mut words = vec![];
+    let mut tags = vec![];
+    let mut due_date = None;
+
+    for word in item.split_whitespace() {
+        if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+            tags.push(tag.to_string());
+        } else if let Some(date) = parse_due_date(word) {
+            due_date = Some(date);
+        } else {
+            words.push(word);
+        }
+    }
+
+    let mut card = Card::new(&words.join(" "), now);
+    card.update_tags(tags);
+    card.update_due_date(due_date);
+    card
+}
+
+fn parse_due_date(word: &str) -> Option<NaiveDate> {
+    let date = word.strip_prefix("@{")?.strip_suffix('}')?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+fn item_text(card: &Card) -> String {
+    let mut text = card.short_description().replace('\n', " ");
+
+    for tag in card.tags() {
+        text.push_str(&format!(" #{}", tag));
+    }
+
+    if let Some(date) = card.due_date() {
+        text.push_str(&format!(" @{{{}}}", date.format("%Y-%m-%d")));
+    }
+
+    text
+}
+
+fn invalid_data(line_number: usize, reason: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("line {}: {}", line_number + 1, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::NaiveDate;
+
+    use crate::board::Board;
+
+    use super::{from_markdown, to_markdown};
+
+    #[test]
+    fn parse_obsidian_board() -> Result<()> {
+        let board = Board::open("res/test_board.md")?;
+
+        assert_eq!(3, board.columns_count());
+        assert_eq!("TODO", board.column(0).header());
+        assert_eq!("Buy milk", board.card(0, 0).short_description());
+        assert_eq!(vec!["shopping".to_string()], board.card(0, 0).tags());
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 24), board.card(0, 0).due_date());
+        assert_eq!("Semi-skimmed\nTwo bottles", board.card(0, 0).long_description());
+        assert_eq!("Buy eggs", board.card(0, 1).short_description());
+        assert!(board.card(0, 1).tags().is_empty());
+        assert_eq!(None, board.card(0, 1).due_date());
+        assert_eq!("Doing", board.column(1).header());
+        assert!(board.column(1).is_empty());
+        assert_eq!("Done!", board.column(2).header());
+        assert_eq!("Eat dinner", board.card(2, 0).short_description());
+
+        Ok(())
+    }
+
+    #[test]
+    fn markdown_round_trip() -> Result<()> {
+        let board = Board::open("res/test_board.md")?;
+        let content = to_markdown(&board);
+        let reloaded = from_markdown(&content)?;
+
+        assert_eq!(board.columns_count(), reloaded.columns_count());
+        for (column, reloaded_column) in board.columns_iter().zip(reloaded.columns_iter()) {
+            assert_eq!(column.header(), reloaded_column.header());
+            assert_eq!(column.size(), reloaded_column.size());
+
+            for (card, reloaded_card) in column.cards_iter().zip(reloaded_column.cards_iter()) {
+                assert_eq!(card.short_description(), reloaded_card.short_description());
+                assert_eq!(card.long_description(), reloaded_card.long_description());
+                assert_eq!(card.tags(), reloaded_card.tags());
+                assert_eq!(card.due_date(), reloaded_card.due_date());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn completed_cards_are_checked() -> Result<()> {
+        let board = Board::open("res/test_board.json")?;
+        let content = to_markdown(&board);
+
+        assert!(content.starts_with("---\n\nkanban-plugin: basic\n\n---\n"));
+        assert!(content.contains("## TODO\n\n- [ ] Buy milk\n"));
+        assert!(content.contains("## Done!\n\n- [x] Eat dinner\n"));
+        assert!(content.contains("%% kanban:settings"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_cards_outside_columns() -> Result<()> {
+        assert!(from_markdown("- [ ] Orphan card\n").is_err());
+        assert!(from_markdown("Nothing to see here\n").is_err());
+        assert!(
+            from_markdown("---\n\nkanban-plugin: basic\n\n---\n").is_err(),
+            "no column"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use crate::board::Board;
+
+const SNIPPET_LENGTH: usize = 48;
+
+/// A card matching a search query, with where it is and why it matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub column_index: usize,
+    pub card_index: usize,
+    pub column_header: String,
+    pub short_description: String,
+    /// Line of the long description or tag containing the query, empty when only the short description matches
+    pub snippet: String,
+}
+
+/// A query kept with the board under a name, for searches run again and again like "my bugs"
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+}
+
+impl SearchMatch {
+    /// Finds the cards containing the query, ignoring case, column by column
+    pub fn find(board: &Board, query: &str) -> Vec<Self> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return vec![];
+        }
+
+        let contains = |text: &str| text.to_lowercase().contains(&query);
+        let mut matches = vec![];
+
+        for (column_index, card_index, card) in board.cards_with_positions() {
+            let snippet = card
+                .long_description()
+                .lines()
+                .find(|line| contains(line))
+                .map(|line| snippet(line.trim()))
+                .or_else(|| {
+                    card.tags()
+                        .iter()
+                        .find(|tag| contains(tag))
+                        .map(|tag| format!("#{}", tag))
+                });
+
+            if snippet.is_some() || contains(card.short_description()) {
+                matches.push(SearchMatch {
+                    column_index,
+                    card_index,
+                    column_header: board.column(column_index).header().to_string(),
+                    short_description: card.short_description().clone(),
+                    snippet: snippet.unwrap_or_default(),
+                });
+            }
+        }
+
+        matches
+    }
+}
+
+fn snippet(line: &str) -> String {
+    if line.chars().count() <= SNIPPET_LENGTH {
+        return line.to_string();
+    }
+
+    let truncated: String = line.chars().take(SNIPPET_LENGTH - 1).collect();
+    format!("{}…", truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::BoardBuilder;
+
+    use super::SearchMatch;
+
+    #[test]
+    fn find_matching_cards() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card("Buy milk")
+                    .card_with("Groceries", |card| card.long_description("Eggs\n  MILK, half skimmed"))
+            })
+            .column("Done", |c| c.card_with("Cook", |card| card.tag("milky")))
+            .build();
+
+        let matches = SearchMatch::find(&board, " Milk ");
+        assert_eq!(3, matches.len());
+        assert_eq!((0, 0), (matches[0].column_index, matches[0].card_index));
+        assert_eq!("", matches[0].snippet);
+        assert_eq!("MILK, half skimmed", matches[1].snippet);
+        assert_eq!("Done", matches[2].column_header);
+        assert_eq!("#milky", matches[2].snippet);
+
+        assert!(SearchMatch::find(&board, "").is_empty());
+        assert!(SearchMatch::find(&board, "bread").is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::ops::Range;
+
+use crate::board::Column;
+
+/// Neighbouring columns shown under the same header, to group the columns of wide boards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Name of the section, none for columns that are not in a section
+    pub name: Option<String>,
+    /// Indexes of the columns of the section
+    pub columns: Range<usize>,
+}
+
+impl Section {
+    pub fn contains(&self, column_index: usize) -> bool {
+        self.columns.contains(&column_index)
+    }
+}
+
+/// Splits the columns into runs of the same section, a section split by other columns gives several runs
+pub fn sections(columns: &[Column]) -> Vec<Section> {
+    let mut sections: Vec<Section> = vec![];
+    for (column_index, column) in columns.iter().enumerate() {
+        match sections.last_mut() {
+            Some(section) if section.name.as_deref() == column.section() => section.columns.end = column_index + 1,
+            _ => sections.push(Section {
+                name: column.section().map(String::from),
+                columns: column_index..column_index + 1,
+            }),
+        }
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use crate::board::BoardBuilder;
+
+    use super::Section;
+
+    #[test]
+    fn runs_of_columns() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("Backlog", |c| c.section("Later"))
+            .column("Ready", |c| c.section("Later"))
+            .column("Doing", |c| c.section("Active"))
+            .column("Review", |c| c.section("Active"))
+            .column("Done", |c| c)
+            .build();
+
+        let section = |name: Option<&str>, columns| Section {
+            name: name.map(String::from),
+            columns,
+        };
+        assert_eq!(
+            vec![
+                section(Some("Later"), 0..2),
+                section(Some("Active"), 2..4),
+                section(None, 4..5)
+            ],
+            board.sections()
+        );
+        assert!(board.sections()[1].contains(3));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::Card;
+
+/// How the cards of a column are ordered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortPolicy {
+    /// Cards stay where they are put, the order is the priority
+    #[default]
+    Manual,
+    /// Closest due date first, cards without due date last
+    DueDate,
+    /// Oldest card first
+    CreationDate,
+    /// Alphabetical order of the short descriptions
+    Name,
+}
+
+impl SortPolicy {
+    pub fn is_manual(&self) -> bool {
+        *self == SortPolicy::Manual
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SortPolicy::Manual => "manual",
+            SortPolicy::DueDate => "due date",
+            SortPolicy::CreationDate => "creation date",
+            SortPolicy::Name => "name",
+        }
+    }
+
+    /// The policy following this one, to cycle through all of them
+    pub fn next(&self) -> Self {
+        match self {
+            SortPolicy::Manual => SortPolicy::DueDate,
+            SortPolicy::DueDate => SortPolicy::CreationDate,
+            SortPolicy::CreationDate => SortPolicy::Name,
+            SortPolicy::Name => SortPolicy::Manual,
+        }
+    }
+
+    /// Compares two cards by the sort key, always `Equal` for the manual policy
+    pub fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        match self {
+            SortPolicy::Manual => Ordering::Equal,
+            SortPolicy::DueDate => match (a.due_date(), b.due_date()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            SortPolicy::CreationDate => a.creation_date().cmp(b.creation_date()),
+            SortPolicy::Name => a
+                .short_description()
+                .to_lowercase()
+                .cmp(&b.short_description().to_lowercase()),
+        }
+    }
+}
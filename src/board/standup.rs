@@ -0,0 +1,115 @@
+use chrono::NaiveDate;
+
+use crate::board::Board;
+
+/// A card that moved into a column, as told at a stand-up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandUpCard {
+    pub short_description: String,
+
+    /// Who moved the card, when the board is shared
+    pub by: Option<String>,
+}
+
+/// What moved into each column since a day, like the last business day, for a stand-up meeting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandUp {
+    pub since: NaiveDate,
+
+    /// Cards moved into each column since then, in the order of the columns, columns nothing moved into are left out
+    pub columns: Vec<(String, Vec<StandUpCard>)>,
+}
+
+impl StandUp {
+    /// Reads the moves recorded on the cards still on the board, the first column is left out as cards are created there
+    pub fn compute(board: &Board, since: NaiveDate) -> Self {
+        let columns = board
+            .columns_iter()
+            .skip(1)
+            .filter_map(|column| {
+                let cards: Vec<StandUpCard> = board
+                    .cards_with_positions()
+                    .filter_map(|(_, _, card)| {
+                        let entry = card
+                            .column_entries()
+                            .iter()
+                            .rev()
+                            .find(|entry| entry.header == column.header() && entry.date.date_naive() >= since)?;
+                        Some(StandUpCard {
+                            short_description: card.short_description().clone(),
+                            by: entry.by.clone(),
+                        })
+                    })
+                    .collect();
+                (!cards.is_empty()).then(|| (column.header().to_string(), cards))
+            })
+            .collect();
+
+        Self { since, columns }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Plain text to paste in a chat, one list of cards per column
+    pub fn to_text(&self) -> String {
+        let since = self.since.format("%A %Y-%m-%d");
+        if self.is_empty() {
+            return format!("Nothing moved since {}\n", since);
+        }
+
+        let mut text = format!("Since {}:\n", since);
+        for (header, cards) in &self.columns {
+            text.push_str(&format!("\n{}\n", header));
+            for card in cards {
+                match &card.by {
+                    Some(by) => text.push_str(&format!("- {} ({})\n", card.short_description, by)),
+                    None => text.push_str(&format!("- {}\n", card.short_description)),
+                }
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use chrono::{Local, NaiveDate, TimeZone};
+
+    use crate::board::BoardBuilder;
+
+    use super::StandUp;
+
+    #[test]
+    fn moves_since_a_day() -> Result<()> {
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy bread"))
+            .column("Doing", |c| c.card("Buy milk"))
+            .column("Done", |c| c.card("Buy eggs").card("Buy jam"))
+            .build();
+        let day = |day| Local.with_ymd_and_hms(2024, 5, day, 9, 0, 0).unwrap();
+        let mut move_card = |column_index, card_index, header: &str, date, by| {
+            let mut card = board.card(column_index, card_index).clone();
+            card.enter_column_by(header, date, by);
+            board.update_card(column_index, card_index, card);
+        };
+        move_card(1, 0, "Doing", day(3), Some("alice"));
+        move_card(2, 0, "Done", day(6), None);
+        move_card(2, 1, "Done", day(2), None);
+
+        let standup = StandUp::compute(&board, NaiveDate::from_ymd_opt(2024, 5, 3).unwrap());
+        assert_eq!(
+            "Since Friday 2024-05-03:\n\nDoing\n- Buy milk (alice)\n\nDone\n- Buy eggs\n",
+            standup.to_text()
+        );
+
+        let standup = StandUp::compute(&board, NaiveDate::from_ymd_opt(2024, 5, 7).unwrap());
+        assert!(standup.is_empty());
+        assert_eq!("Nothing moved since Tuesday 2024-05-07\n", standup.to_text());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Local};
+use serde::Serialize;
+
+use crate::board::{Board, Card, Checklist};
+
+/// A card that stayed longer than the threshold configured for its column
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StaleCard {
+    pub column_index: usize,
+    pub card_index: usize,
+    pub short_description: String,
+    pub age_in_days: i64,
+}
+
+/// A card with all of its checklist items done, but not in the last column yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadyCard {
+    pub column_index: usize,
+    pub card_index: usize,
+    pub short_description: String,
+    pub checklist_items: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnStatistics {
+    pub header: String,
+    pub cards_count: usize,
+    pub oldest_card_age_in_days: Option<i64>,
+    pub average_card_age_in_days: Option<i64>,
+    pub stale_threshold_in_days: Option<u32>,
+    pub stale_cards: Vec<StaleCard>,
+    pub checklist: Checklist,
+}
+
+/// Number of cards completed recently, archived ones included
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Throughput {
+    pub last_7_days: usize,
+    pub last_30_days: usize,
+}
+
+/// Distribution of the time taken by the completed cards, archived ones included
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TimeDistribution {
+    pub completed_cards: usize,
+    pub average_in_hours: Option<i64>,
+    pub median_in_hours: Option<i64>,
+    pub percentile_85_in_hours: Option<i64>,
+}
+
+impl TimeDistribution {
+    fn compute(mut hours: Vec<i64>) -> Self {
+        hours.sort();
+        Self {
+            completed_cards: hours.len(),
+            average_in_hours: average(&hours),
+            median_in_hours: hours.get(hours.len() / 2).copied(),
+            percentile_85_in_hours: hours.get(hours.len() * 85 / 100).copied(),
+        }
+    }
+}
+
+/// Statistics computed over the whole board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BoardStatistics {
+    pub columns: Vec<ColumnStatistics>,
+    pub ready_cards: Vec<ReadyCard>,
+    pub throughput: Throughput,
+    /// From the card leaving its first column to its completion
+    pub cycle_times: TimeDistribution,
+    /// From the creation of the card to its completion
+    pub lead_times: TimeDistribution,
+}
+
+impl BoardStatistics {
+    /// Computes the statistics, `stale_thresholds` gives a number of days per column header
+    pub fn compute(board: &Board, stale_thresholds: &HashMap<String, u32>, now: DateTime<Local>) -> Self {
+        let last_column = board.columns_count().saturating_sub(1);
+        let mut ready_cards = vec![];
+
+        let columns = board
+            .columns_iter()
+            .enumerate()
+            .map(|(column_index, column)| {
+                let threshold = stale_thresholds.get(column.header()).copied();
+                let ages: Vec<i64> = column
+                    .cards_iter()
+                    .map(|card| (now - *card.creation_date()).num_days())
+                    .collect();
+
+                let stale_cards = match threshold {
+                    Some(threshold) => ages
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, age)| **age > threshold as i64)
+                        .map(|(card_index, age)| StaleCard {
+                            column_index,
+                            card_index,
+                            short_description: column.get_card(card_index).short_description().clone(),
+                            age_in_days: *age,
+                        })
+                        .collect(),
+                    None => vec![],
+                };
+
+                let mut checklist = Checklist::default();
+                for (card_index, card) in column.cards_iter().enumerate() {
+                    let card_checklist = card.checklist();
+                    checklist = checklist + card_checklist;
+
+                    if column_index != last_column && card_checklist.is_complete() {
+                        ready_cards.push(ReadyCard {
+                            column_index,
+                            card_index,
+                            short_description: card.short_description().clone(),
+                            checklist_items: card_checklist.total,
+                        });
+                    }
+                }
+
+                ColumnStatistics {
+                    header: column.header().to_string(),
+                    cards_count: column.size(),
+                    oldest_card_age_in_days: ages.iter().max().copied(),
+                    average_card_age_in_days: average(&ages),
+                    stale_threshold_in_days: threshold,
+                    stale_cards,
+                    checklist,
+                }
+            })
+            .collect();
+
+        let completed_cards = completed_cards(board);
+        let throughput = Throughput {
+            last_7_days: completed_since(&completed_cards, now - Duration::days(7)),
+            last_30_days: completed_since(&completed_cards, now - Duration::days(30)),
+        };
+
+        let cycle_times = completed_cards
+            .iter()
+            .filter_map(|card| Some(card.cycle_time()?.num_hours()))
+            .collect();
+        let lead_times = completed_cards
+            .iter()
+            .filter_map(|card| Some(card.lead_time()?.num_hours()))
+            .collect();
+
+        Self {
+            columns,
+            ready_cards,
+            throughput,
+            cycle_times: TimeDistribution::compute(cycle_times),
+            lead_times: TimeDistribution::compute(lead_times),
+        }
+    }
+
+    pub fn stale_cards(&self) -> Vec<&StaleCard> {
+        self.columns
+            .iter()
+            .flat_map(|column| column.stale_cards.iter())
+            .collect()
+    }
+
+    pub fn cards_count(&self) -> usize {
+        self.columns.iter().map(|column| column.cards_count).sum()
+    }
+}
+
+/// Cards of the last column and of the archive
+pub(super) fn completed_cards(board: &Board) -> Vec<&Card> {
+    let done = board
+        .columns_iter()
+        .last()
+        .into_iter()
+        .flat_map(|column| column.cards_iter());
+
+    done.chain(board.archived_cards().iter()).collect()
+}
+
+pub(super) fn completed_since(cards: &[&Card], since: DateTime<Local>) -> usize {
+    cards
+        .iter()
+        .filter(|card| card.completion_date().is_some_and(|date| date >= since))
+        .count()
+}
+
+fn average(values: &[i64]) -> Option<i64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<i64>() / values.len() as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Result};
+
+    use chrono::{Duration, Local};
+
+    use crate::board::BoardBuilder;
+
+    use crate::board::Checklist;
+
+    use super::{BoardStatistics, ReadyCard, StaleCard, Throughput, TimeDistribution};
+
+    #[test]
+    fn stale_cards() -> Result<()> {
+        let now = Local::now();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Old idea", |card| card.created(now - Duration::days(30)))
+            })
+            .column("Doing", |c| {
+                c.card_with("Recent", |card| card.created(now - Duration::days(2)))
+                    .card_with("Stuck", |card| card.created(now - Duration::days(6)))
+            })
+            .column("Done!", |c| c)
+            .build();
+
+        let thresholds = HashMap::from([("Doing".to_string(), 5)]);
+        let statistics = BoardStatistics::compute(&board, &thresholds, now);
+
+        assert_eq!(3, statistics.cards_count());
+        assert_eq!(Some(30), statistics.columns[0].oldest_card_age_in_days);
+        assert!(statistics.columns[0].stale_cards.is_empty());
+        assert_eq!(Some(5), statistics.columns[1].stale_threshold_in_days);
+        assert_eq!(None, statistics.columns[2].oldest_card_age_in_days);
+
+        let expected = StaleCard {
+            column_index: 1,
+            card_index: 1,
+            short_description: "Stuck".into(),
+            age_in_days: 6,
+        };
+        assert_eq!(vec![&expected], statistics.stale_cards());
+
+        Ok(())
+    }
+
+    #[test]
+    fn checklist_progress() -> Result<()> {
+        let board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("Plan trip", |card| card.long_description("- [ ] Hotel\n- [ ] Train"))
+            })
+            .column("Doing", |c| {
+                c.card_with("Groceries", |card| card.long_description("- [x] Milk\n- [x] Eggs"))
+                    .card_with("Taxes", |card| card.long_description("- [x] Forms\n- [ ] Send"))
+                    .card("No checklist")
+            })
+            .column("Done!", |c| {
+                c.card_with("Cleaning", |card| card.long_description("- [x] Kitchen"))
+            })
+            .build();
+
+        let statistics = BoardStatistics::compute(&board, &HashMap::new(), Local::now());
+
+        assert_eq!(Checklist { done: 0, total: 2 }, statistics.columns[0].checklist);
+        assert_eq!(Checklist { done: 3, total: 4 }, statistics.columns[1].checklist);
+        assert_eq!(1, statistics.columns[1].checklist.remaining());
+
+        let expected = ReadyCard {
+            column_index: 1,
+            card_index: 0,
+            short_description: "Groceries".into(),
+            checklist_items: 2,
+        };
+        assert_eq!(vec![expected], statistics.ready_cards);
+
+        Ok(())
+    }
+
+    #[test]
+    fn throughput_and_cycle_times() -> Result<()> {
+        let now = Local::now();
+        let mut board = BoardBuilder::new()
+            .column("TODO", |c| {
+                c.card_with("New", |card| card.created(now - Duration::days(4)))
+            })
+            .column("Done!", |c| {
+                c.card_with("Yesterday", |card| {
+                    card.created(now - Duration::days(3)).completed(now - Duration::days(1))
+                })
+                .card_with("Last week", |card| {
+                    card.created(now - Duration::days(12))
+                        .completed(now - Duration::days(10))
+                })
+            })
+            .build();
+        let mut card = board.card(1, 0).clone();
+        card.enter_column("Done!", now - Duration::days(2));
+        board.update_card(1, 0, card);
+        board.archive_done_cards(5, now);
+
+        let statistics = BoardStatistics::compute(&board, &HashMap::new(), now);
+
+        assert_eq!(1, board.archived_cards().len());
+        assert_eq!(
+            Throughput {
+                last_7_days: 1,
+                last_30_days: 2
+            },
+            statistics.throughput
+        );
+        assert_eq!(
+            TimeDistribution {
+                completed_cards: 1,
+                average_in_hours: Some(24),
+                median_in_hours: Some(24),
+                percentile_85_in_hours: Some(24),
+            },
+            statistics.cycle_times,
+            "from the start of the work, the card completed last week never moved"
+        );
+        assert_eq!(
+            TimeDistribution {
+                completed_cards: 2,
+                average_in_hours: Some(48),
+                median_in_hours: Some(48),
+                percentile_85_in_hours: Some(48),
+            },
+            statistics.lead_times
+        );
+        assert_eq!(Some(4), statistics.columns[0].average_card_age_in_days);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Result, Write},
+};
+
+use chrono::{DateTime, Local};
+
+use crate::board::{flow::csv_field, Board};
+
+const STATS_LOG_EXTENSION: &str = "stats.csv";
+
+/// Summary of a board at the time it was written, a row of its statistics log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatsRow {
+    pub timestamp: DateTime<Local>,
+
+    /// Number of cards per column header, in the order of the columns
+    pub columns: Vec<(String, usize)>,
+
+    /// Cards of the last column and of the archive completed on the day of the timestamp
+    pub completed_today: usize,
+}
+
+impl StatsRow {
+    pub fn compute(board: &Board, now: DateTime<Local>) -> Self {
+        let columns = board
+            .columns_iter()
+            .map(|column| (column.header().to_string(), column.size()))
+            .collect();
+
+        let done = board
+            .columns_iter()
+            .last()
+            .into_iter()
+            .flat_map(|column| column.cards_iter());
+        let completed_today = done
+            .chain(board.archived_cards().iter())
+            .filter(|card| {
+                card.completion_date()
+                    .is_some_and(|date| date.date_naive() == now.date_naive())
+            })
+            .count();
+
+        Self {
+            timestamp: now,
+            columns,
+            completed_today,
+        }
+    }
+
+    pub fn csv_header(&self) -> String {
+        let mut header = String::from("timestamp");
+        for (column, _) in &self.columns {
+            header.push(',');
+            header.push_str(&csv_field(column));
+        }
+        header.push_str(",completed_today");
+        header
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut row = self.timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
+        for (_, count) in &self.columns {
+            row.push_str(&format!(",{}", count));
+        }
+        row.push_str(&format!(",{}", self.completed_today));
+        row
+    }
+}
+
+/// Where the statistics log of a board file is kept
+pub fn stats_log_file_name(board_file_name: &str) -> String {
+    format!("{}.{}", board_file_name, STATS_LOG_EXTENSION)
+}
+
+/// Appends a row to the statistics log of a board file, for long-term analysis in a spreadsheet
+///
+/// The header is written first in a new log, and again before the row when the columns of the board changed
+/// since the last one.
+pub fn append_stats_row(board_file_name: &str, row: &StatsRow) -> Result<()> {
+    let file_name = stats_log_file_name(board_file_name);
+    let last_header = match fs::read_to_string(&file_name) {
+        Ok(content) => content
+            .lines()
+            .rev()
+            .find(|line| line.starts_with("timestamp,"))
+            .map(str::to_string),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let header = row.csv_header();
+    let mut lines = String::new();
+    if last_header.as_deref() != Some(header.as_str()) {
+        lines.push_str(&header);
+        lines.push('\n');
+    }
+    lines.push_str(&row.to_csv());
+    lines.push('\n');
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&file_name)?;
+    file.write_all(lines.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use chrono::{Duration, Local, TimeZone};
+
+    use crate::board::BoardBuilder;
+
+    use super::{append_stats_row, stats_log_file_name, StatsRow};
+
+    #[test]
+    fn append_rows() -> Result<()> {
+        let now = Local.with_ymd_and_hms(2025, 3, 4, 17, 30, 0).unwrap();
+        let board = BoardBuilder::new()
+            .column("TODO", |c| c.card("Buy bread").card("Buy jam"))
+            .column("Done, really", |c| {
+                c.card_with("Buy milk", |card| card.completed(now - Duration::hours(2)))
+                    .card_with("Buy eggs", |card| card.completed(now - Duration::days(1)))
+            })
+            .build();
+
+        let row = StatsRow::compute(&board, now);
+        assert_eq!("timestamp,TODO,\"Done, really\",completed_today", row.csv_header());
+        assert_eq!("2025-03-04 17:30:00,2,2,1", row.to_csv());
+
+        let board_file = env::temp_dir().join("rustyban-stats-log.json").display().to_string();
+        let _ = fs::remove_file(stats_log_file_name(&board_file));
+        append_stats_row(&board_file, &row)?;
+        append_stats_row(&board_file, &row)?;
+        let renamed = BoardBuilder::new().column("Backlog", |c| c).build();
+        append_stats_row(&board_file, &StatsRow::compute(&renamed, now))?;
+
+        assert_eq!(
+            "timestamp,TODO,\"Done, really\",completed_today\n\
+             2025-03-04 17:30:00,2,2,1\n\
+             2025-03-04 17:30:00,2,2,1\n\
+             timestamp,Backlog,completed_today\n\
+             2025-03-04 17:30:00,0,0\n",
+            fs::read_to_string(stats_log_file_name(&board_file))?
+        );
+
+        fs::remove_file(stats_log_file_name(&board_file))
+    }
+}
@@ -0,0 +1,207 @@
+use std::io::{ErrorKind, Result};
+
+use crate::board::{merge, Board, BoardChange, SaveOptions};
+
+const SYNC_BASE_EXTENSION: &str = "sync.json";
+
+/// A remote copy of a board kept in sync both ways, like a shared board file or an issue tracker
+///
+/// Syncing is planned before it is applied: `plan` pulls the remote board and tells what would change on each
+/// side, then the merged board is pushed once the plan is accepted. The merged board becomes the last synced
+/// version only once the local board file is written with it. Adapters only move boards in and out, the merge is
+/// the same for all of them.
+pub trait SyncAdapter {
+    /// Name of the remote, as shown in the plan
+    fn name(&self) -> String;
+
+    /// The board as the remote has it now, with the changes made there since the last sync
+    fn pull_remote_changes(&mut self) -> Result<Board>;
+
+    /// Sends the merged board to the remote
+    fn push_local_changes(&mut self, board: &Board) -> Result<()>;
+
+    /// Keeps the merged board as the last synced version, once the local board file is written with it
+    fn remember_synced(&mut self, board: &Board) -> Result<()>;
+
+    /// The board as both sides had it after the last sync, none before the first one
+    fn last_synced(&self) -> Result<Option<Board>>;
+
+    /// Whether the remote board changed since the plan was made, pushing the plan would then lose those changes
+    fn changed_since(&mut self, plan: &SyncPlan) -> Result<bool> {
+        let remote = self.pull_remote_changes()?;
+        Ok(remote.to_json(SaveOptions::default())? != plan.remote_board.to_json(SaveOptions::default())?)
+    }
+
+    /// What syncing would change on each side, nothing is changed yet
+    fn plan(&mut self, local: &Board) -> Result<SyncPlan> {
+        let remote = self.pull_remote_changes()?;
+        let base = self.last_synced()?;
+        Ok(SyncPlan::new(&self.name(), base.as_ref(), local, &remote))
+    }
+}
+
+/// Changes syncing would pull into the local board and push to the remote, and the board both sides end up with
+#[derive(Debug, Clone)]
+pub struct SyncPlan {
+    pub remote: String,
+
+    /// Changes made on the remote since the last sync, brought to the local board
+    pub pulled: Vec<BoardChange>,
+
+    /// Changes made locally since the last sync, sent to the remote
+    pub pushed: Vec<BoardChange>,
+
+    /// The local board the plan was made from
+    pub local: Board,
+
+    /// The remote board the plan was made from
+    pub remote_board: Board,
+
+    pub merged: Board,
+}
+
+impl SyncPlan {
+    /// Merges the changes made on both sides since `base`, local ones win the conflicts
+    ///
+    /// Without a base, on the first sync, the boards are combined: cards of either side are kept.
+    pub fn new(remote_name: &str, base: Option<&Board>, local: &Board, remote: &Board) -> Self {
+        let empty = Board::from_columns(vec![]);
+        let merged = merge(base.unwrap_or(&empty), local, remote);
+
+        Self {
+            remote: remote_name.into(),
+            pulled: BoardChange::between(local, &merged),
+            pushed: BoardChange::between(remote, &merged),
+            local: local.clone(),
+            remote_board: remote.clone(),
+            merged,
+        }
+    }
+
+    /// Whether both sides are already the same
+    pub fn is_empty(&self) -> bool {
+        self.pulled.is_empty() && self.pushed.is_empty()
+    }
+}
+
+/// Where the last synced version of a board is kept, next to its file
+pub fn sync_base_file_name(board_file_name: &str) -> String {
+    format!("{}.{}", board_file_name.trim_end_matches('/'), SYNC_BASE_EXTENSION)
+}
+
+/// Syncs a board with another board file, like a copy in a shared folder
+#[derive(Debug, Clone)]
+pub struct FileSyncAdapter {
+    remote_file_name: String,
+    base_file_name: String,
+}
+
+impl FileSyncAdapter {
+    /// Syncs the board of `board_file_name` with the board of `remote_file_name`
+    pub fn new(board_file_name: &str, remote_file_name: &str) -> Self {
+        Self {
+            remote_file_name: remote_file_name.into(),
+            base_file_name: sync_base_file_name(board_file_name),
+        }
+    }
+}
+
+impl SyncAdapter for FileSyncAdapter {
+    fn name(&self) -> String {
+        self.remote_file_name.clone()
+    }
+
+    fn pull_remote_changes(&mut self) -> Result<Board> {
+        Board::open(&self.remote_file_name)
+    }
+
+    fn push_local_changes(&mut self, board: &Board) -> Result<()> {
+        board.without_scratch_cards().to_file(&self.remote_file_name)
+    }
+
+    fn remember_synced(&mut self, board: &Board) -> Result<()> {
+        board.without_scratch_cards().to_file(&self.base_file_name)
+    }
+
+    fn last_synced(&self) -> Result<Option<Board>> {
+        match Board::open(&self.base_file_name) {
+            Ok(board) => Ok(Some(board)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result};
+
+    use crate::board::{Board, BoardBuilder};
+
+    use super::{sync_base_file_name, FileSyncAdapter, SyncAdapter};
+
+    fn titles(board: &Board, column_index: usize) -> Vec<String> {
+        board
+            .column(column_index)
+            .cards_iter()
+            .map(|card| card.short_description().clone())
+            .collect()
+    }
+
+    #[test]
+    fn plan_and_sync_with_file() -> Result<()> {
+        let directory = env::temp_dir().join("rustyban-sync");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory)?;
+        let board_file = directory.join("board.json").display().to_string();
+        let remote_file = directory.join("shared.json").display().to_string();
+
+        let local = Board::open("res/test_board.json")?;
+        let mut remote = local.clone();
+        remote.remove_card(0, 0);
+        remote.to_file(&remote_file)?;
+
+        let mut adapter = FileSyncAdapter::new(&board_file, &remote_file);
+        let plan = adapter.plan(&local)?;
+        assert!(plan.pulled.is_empty(), "the first sync keeps the cards of both sides");
+        assert_eq!(
+            vec!["+ 'Buy milk' in TODO"],
+            plan.pushed.iter().map(|c| c.to_string()).collect::<Vec<_>>()
+        );
+
+        assert!(!adapter.changed_since(&plan)?);
+        adapter.push_local_changes(&plan.merged)?;
+        assert!(adapter.changed_since(&plan)?);
+        assert!(
+            fs::metadata(sync_base_file_name(&board_file)).is_err(),
+            "local file not written yet"
+        );
+        adapter.remember_synced(&plan.merged)?;
+        assert!(adapter.plan(&plan.merged)?.is_empty());
+
+        let mut local = plan.merged.clone();
+        local.mark_card_done(1, 0);
+        let mut remote = adapter.pull_remote_changes()?;
+        remote.remove_card(0, 0);
+        remote.insert_card(
+            0,
+            0,
+            BoardBuilder::new()
+                .column("TODO", |c| c.card("Buy tea"))
+                .build()
+                .card(0, 0)
+                .clone(),
+        );
+        remote.to_file(&remote_file)?;
+
+        let plan = adapter.plan(&local)?;
+        let pulled: Vec<String> = plan.pulled.iter().map(|c| c.to_string()).collect();
+        let pushed: Vec<String> = plan.pushed.iter().map(|c| c.to_string()).collect();
+        assert_eq!(vec!["+ 'Buy tea' in TODO", "- 'Buy milk' from TODO"], pulled);
+        assert_eq!(vec!["> 'Cook dinner' from Doing to Done!"], pushed);
+        assert_eq!(vec!["Buy tea", "Buy eggs", "Buy bread"], titles(&plan.merged, 0));
+        assert!(titles(&plan.merged, 1).is_empty());
+
+        fs::remove_dir_all(&directory)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::board::{Board, BoardBuilder};
+
+/// Predefined sets of columns to start a new board from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Basic,
+    Scrum,
+    Personal,
+}
+
+impl Template {
+    pub fn all() -> [Template; 3] {
+        [Template::Basic, Template::Scrum, Template::Personal]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Template::Basic => "basic",
+            Template::Scrum => "scrum",
+            Template::Personal => "personal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|template| template.name() == name)
+    }
+
+    pub fn headers(&self) -> &'static [&'static str] {
+        match self {
+            Template::Basic => &["TODO", "Doing", "Done!"],
+            Template::Scrum => &["Backlog", "TODO", "In progress", "Review", "Done!"],
+            Template::Personal => &["Ideas", "Next", "Doing", "Done!"],
+        }
+    }
+
+    pub fn build(&self) -> Board {
+        self.headers()
+            .iter()
+            .fold(BoardBuilder::new(), |builder, header| builder.column(header, |c| c))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::Template;
+
+    #[test]
+    fn build_from_template() -> Result<()> {
+        let board = Template::Scrum.build();
+
+        assert_eq!(5, board.columns_count());
+        assert_eq!("Backlog", board.column(0).header());
+        assert_eq!("Done!", board.column(4).header());
+        assert!(board.column(0).is_empty());
+
+        assert_eq!(Some(Template::Personal), Template::from_name("personal"));
+        assert_eq!(None, Template::from_name("unknown"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,680 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+use chrono::{Days, Local};
+
+use crate::{
+    app::{self, BoardLock, Journal, LockError},
+    board::{self, Board, BoardStatistics, CsvMapping, CumulativeFlow, SaveOptions, Template},
+    config::{self, Settings},
+};
+
+const STATS_USAGE: &str = "usage: rustyban stats [--format json] <board>";
+const CFD_USAGE: &str = "usage: rustyban cfd [--days <count>] [--svg <file>] <board>";
+const EXPORT_USAGE: &str = "usage: rustyban export <board>";
+const REPLAY_USAGE: &str = "usage: rustyban replay <board>";
+const IMPORT_USAGE: &str =
+    "usage: rustyban import <board> <csv file> [--title <name>] [--description <name>] [--column <name>] [--due <name>]";
+const TARGET_USAGE: &str = "usage: rustyban target <board> <cards per week, 0 to remove it>";
+const PRINT_USAGE: &str = "usage: rustyban print [--width <count>] <board>";
+const NEW_USAGE: &str = "usage: rustyban --new <board> [--template basic|scrum|personal]";
+const SET_USAGE: &str = "usage: rustyban --set <table>.<key>=<value> ...";
+const DEFAULT_CFD_DAYS: u64 = 30;
+
+/// The user settings, or the defaults when they cannot be read, which is reported without failing the command
+fn load_settings() -> Settings {
+    Settings::load().unwrap_or_else(|e| {
+        eprintln!("rustyban: cannot read settings, using the defaults: {}", e);
+        Settings::default()
+    })
+}
+
+/// Takes the `--set <table>.<key>=<value>` options out of the command line, returns the other arguments
+///
+/// The settings given this way take precedence over the configuration file and the environment, for the
+/// application as well as the subcommands.
+pub fn settings_overrides(args: Vec<String>) -> Result<Vec<String>, String> {
+    let (args, overrides) = split_overrides(args)?;
+    config::set_cli_overrides(overrides);
+    Ok(args)
+}
+
+/// Settings given on the command line, as dotted paths and their values
+type Overrides = Vec<(String, String)>;
+
+fn split_overrides(args: Vec<String>) -> Result<(Vec<String>, Overrides), String> {
+    let mut rest = vec![];
+    let mut overrides = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg != "--set" {
+            rest.push(arg);
+            continue;
+        }
+
+        let setting = args.next().ok_or(SET_USAGE)?;
+        let (path, value) = setting.split_once('=').ok_or(SET_USAGE)?;
+        overrides.push((path.trim().to_string(), value.to_string()));
+    }
+    Ok((rest, overrides))
+}
+
+/// Takes the `--strict` option out of the command line, wherever it is, returns the other arguments
+pub fn strict_flag(args: Vec<String>) -> (Vec<String>, bool) {
+    let count = args.len();
+    let rest: Vec<String> = args.into_iter().filter(|arg| arg != "--strict").collect();
+    let strict = rest.len() != count;
+    (rest, strict)
+}
+
+/// Runs `rustyban stats [--format json] <board>` with the arguments following `stats`
+///
+/// Returns the statistics of the board as JSON, computed like in the statistics popup, for external
+/// dashboards or scripts. Stale thresholds are read from the user settings.
+pub fn stats(args: &[String]) -> Result<String, String> {
+    stats_with(args, &load_settings())
+}
+
+fn stats_with(args: &[String], settings: &Settings) -> Result<String, String> {
+    let mut file_name = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().map(String::as_str) {
+                Some("json") => {}
+                Some(format) => return Err(format!("unsupported format \"{}\", only json is available", format)),
+                None => return Err(STATS_USAGE.to_string()),
+            },
+            _ if file_name.is_none() => file_name = Some(arg),
+            _ => return Err(STATS_USAGE.to_string()),
+        }
+    }
+
+    let file_name = file_name.ok_or(STATS_USAGE)?;
+    let board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    let statistics = BoardStatistics::compute(&board, &settings.stale_days, Local::now());
+    serde_json::to_string_pretty(&statistics).map_err(|e| e.to_string())
+}
+
+/// Runs `rustyban cfd [--days <count>] [--svg <file>] <board>` with the arguments following `cfd`
+///
+/// Returns the dataset of a cumulative flow diagram as CSV, the number of cards per column at the end of each
+/// of the last days, today included. The diagram itself is also written as SVG when a file is given.
+pub fn cfd(args: &[String]) -> Result<String, String> {
+    let mut file_name = None;
+    let mut svg_file = None;
+    let mut days = DEFAULT_CFD_DAYS;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--days" => match args.next().map(|days| days.parse::<u64>()) {
+                Some(Ok(count @ 1..)) => days = count,
+                Some(_) => return Err("the number of days must be a positive number".to_string()),
+                None => return Err(CFD_USAGE.to_string()),
+            },
+            "--svg" => svg_file = Some(args.next().ok_or(CFD_USAGE)?),
+            _ if file_name.is_none() => file_name = Some(arg),
+            _ => return Err(CFD_USAGE.to_string()),
+        }
+    }
+
+    let file_name = file_name.ok_or(CFD_USAGE)?;
+    let board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+
+    let today = Local::now().date_naive();
+    let from = today.checked_sub_days(Days::new(days - 1)).unwrap_or(today);
+    let flow = CumulativeFlow::compute(&board, from, today);
+    if let Some(svg_file) = svg_file {
+        fs::write(svg_file, flow.to_svg()).map_err(|e| format!("cannot write {}: {}", svg_file, e))?;
+    }
+
+    Ok(flow.to_csv())
+}
+
+/// Runs `rustyban export <board>` with the arguments following `export`
+///
+/// Returns the board as canonical JSON, keys sorted and without the state of the interface, whatever the format
+/// of the file. Its output only changes where the board does, which makes it suitable as a git diff driver.
+pub fn export(args: &[String]) -> Result<String, String> {
+    let [file_name] = args else {
+        return Err(EXPORT_USAGE.to_string());
+    };
+
+    let board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    board.to_canonical_json().map_err(|e| e.to_string())
+}
+
+/// Runs `rustyban print [--width <count>] <board>` with the arguments following `print`
+///
+/// Returns the columns of the board side by side as plain text, 80 characters wide by default, to pipe into a
+/// pager or paste in an email or a ticket.
+pub fn print(args: &[String]) -> Result<String, String> {
+    let mut file_name = None;
+    let mut width = app::DEFAULT_PRINT_WIDTH;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--width" => match args.next().map(|width| width.parse::<usize>()) {
+                Some(Ok(count @ 1..)) => width = count,
+                Some(_) => return Err("the width must be a positive number".to_string()),
+                None => return Err(PRINT_USAGE.to_string()),
+            },
+            _ if file_name.is_none() => file_name = Some(arg),
+            _ => return Err(PRINT_USAGE.to_string()),
+        }
+    }
+    let file_name = file_name.ok_or(PRINT_USAGE)?;
+
+    let board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    Ok(app::print_board(&board, width))
+}
+
+/// Runs `rustyban import <board> <csv file>` with the arguments following `import`
+///
+/// Adds a card per data row of the CSV file at the bottom of its column and writes the board. The CSV columns
+/// giving the title, the description, the column and the due date of the cards are guessed from the header row,
+/// like "Title" or "Status", or given by name with `--title`, `--description`, `--column` and `--due`.
+pub fn import(args: &[String]) -> Result<String, String> {
+    import_with(args, &load_settings())
+}
+
+fn import_with(args: &[String], settings: &Settings) -> Result<String, String> {
+    let mut files = vec![];
+    let mut names: [Option<&str>; 4] = [None; 4];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let field = match arg.as_str() {
+            "--title" => 0,
+            "--description" => 1,
+            "--column" => 2,
+            "--due" => 3,
+            _ => {
+                files.push(arg);
+                continue;
+            }
+        };
+        names[field] = Some(args.next().ok_or(IMPORT_USAGE)?);
+    }
+    let [file_name, csv_file] = files[..] else {
+        return Err(IMPORT_USAGE.to_string());
+    };
+
+    // Held until the board is written, like by the application
+    let _lock = match BoardLock::acquire(file_name) {
+        Ok(lock) => Some(lock),
+        Err(LockError::Locked(owner)) => {
+            return Err(format!(
+                "{} is opened by {}, import from there with <M>",
+                file_name, owner
+            ))
+        }
+        Err(LockError::Io(_)) => None,
+    };
+    let mut board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    let rows = board::read_csv(csv_file).map_err(|e| format!("cannot read {}: {}", csv_file, e))?;
+    let header = rows.first().ok_or(format!("no header in {}", csv_file))?;
+    let mapping = CsvMapping::named(header, names)?;
+
+    let (cards, problems) = board::csv_cards(&board, &rows, &mapping, Local::now());
+    if settings.backup.before_bulk_changes {
+        app::write_backup(file_name, &board, settings.backup.keep)
+            .map_err(|e| format!("cannot back up {}: {}", file_name, e))?;
+    }
+    let count = cards.len();
+    for (column_index, card) in cards {
+        let column = board.column(column_index);
+        let card_index = column.insertion_index(&card, column.size());
+        board.insert_card(column_index, card_index, card);
+    }
+
+    let options = SaveOptions {
+        canonical: settings.files.canonical_json,
+        layout: settings.files.layout_for(board.file_layout()),
+    };
+    board
+        .to_file_with(file_name, options)
+        .map_err(|e| format!("cannot write {}: {}", file_name, e))?;
+
+    let imported = format!("Imported {} card(s) from {} into {}", count, csv_file, file_name);
+    Ok([vec![imported], problems].concat().join("\n"))
+}
+
+/// Runs `rustyban replay <board>` with the arguments following `replay`
+///
+/// Applies the changes journaled since the board was last written, like before a crash, writes the board to its
+/// file and removes the journal. Should the journal not match the board, nothing is written.
+pub fn replay(args: &[String]) -> Result<String, String> {
+    replay_with(args, &load_settings())
+}
+
+fn replay_with(args: &[String], settings: &Settings) -> Result<String, String> {
+    let [file_name] = args else {
+        return Err(REPLAY_USAGE.to_string());
+    };
+
+    let mut board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    let journal = Journal::new(file_name);
+    let entries = journal
+        .entries()
+        .map_err(|e| format!("cannot read {}: {}", journal.file_name(), e))?;
+    if entries.is_empty() {
+        return Err(format!("no change journaled for {}", file_name));
+    }
+    let base = app::file_revision(file_name);
+    if !journal
+        .matches(base)
+        .map_err(|e| format!("cannot read {}: {}", journal.file_name(), e))?
+    {
+        return Err(format!(
+            "the changes of {} were made on another version of {}, nothing replayed",
+            journal.file_name(),
+            file_name
+        ));
+    }
+
+    let count = app::replay(&mut board, entries)?;
+    let options = SaveOptions {
+        canonical: settings.files.canonical_json,
+        layout: settings.files.layout_for(board.file_layout()),
+    };
+    board
+        .to_file_with(file_name, options)
+        .map_err(|e| format!("cannot write {}: {}", file_name, e))?;
+    journal
+        .clear()
+        .map_err(|e| format!("cannot remove {}: {}", journal.file_name(), e))?;
+
+    Ok(format!("Replayed {} changes onto {}", count, file_name))
+}
+
+/// Runs `rustyban target <board> <count>` with the arguments following `target`
+///
+/// Sets the number of cards to complete each week shown against the cards completed since Monday, 0 removes it.
+pub fn target(args: &[String]) -> Result<String, String> {
+    target_with(args, &load_settings())
+}
+
+fn target_with(args: &[String], settings: &Settings) -> Result<String, String> {
+    let [file_name, count] = args else {
+        return Err(TARGET_USAGE.to_string());
+    };
+    let count: usize = count.parse().map_err(|_| TARGET_USAGE.to_string())?;
+
+    // Held until the board is written, like by the application
+    let _lock = match BoardLock::acquire(file_name) {
+        Ok(lock) => Some(lock),
+        Err(LockError::Locked(owner)) => return Err(format!("{} is opened by {}", file_name, owner)),
+        Err(LockError::Io(_)) => None,
+    };
+    let mut board = Board::open(file_name).map_err(|e| format!("cannot read {}: {}", file_name, e))?;
+    board.set_weekly_target(Some(count));
+    let options = SaveOptions {
+        canonical: settings.files.canonical_json,
+        layout: settings.files.layout_for(board.file_layout()),
+    };
+    board
+        .to_file_with(file_name, options)
+        .map_err(|e| format!("cannot write {}: {}", file_name, e))?;
+
+    match board.weekly_target() {
+        Some(target) => Ok(format!("Weekly target of {} set to {} card(s)", file_name, target)),
+        None => Ok(format!("Weekly target of {} removed", file_name)),
+    }
+}
+
+/// Runs `rustyban --new <board> [--template <name>]` with the arguments following `--new`
+///
+/// Writes a new board made from the template, the basic one by default, and returns the file and the template so
+/// the board can be opened. An existing file is never overwritten, a typo in its name is noticed right away.
+pub fn new_board(args: &[String]) -> Result<(String, Template), String> {
+    new_board_with(args, &load_settings())
+}
+
+fn new_board_with(args: &[String], settings: &Settings) -> Result<(String, Template), String> {
+    let mut file_name = None;
+    let mut template = Template::Basic;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--template" => match args.next() {
+                Some(name) => {
+                    template = Template::from_name(name).ok_or_else(|| {
+                        let names: Vec<&str> = Template::all().iter().map(Template::name).collect();
+                        format!("unknown template \"{}\", use one of {}", name, names.join(", "))
+                    })?
+                }
+                None => return Err(NEW_USAGE.to_string()),
+            },
+            _ if file_name.is_none() => file_name = Some(arg),
+            _ => return Err(NEW_USAGE.to_string()),
+        }
+    }
+
+    let file_name = file_name.ok_or(NEW_USAGE)?;
+    let options = SaveOptions {
+        canonical: settings.files.canonical_json,
+        layout: settings.files.layout_for(None),
+    };
+    // The content is ready before the file is created, so that a failure leaves no empty file behind
+    let content = board::file_service(file_name)
+        .content(&template.build(), options)
+        .map_err(|e| format!("cannot write {}: {}", file_name, e))?;
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(file_name)
+        .map_err(|e| format!("cannot create {}: {}", file_name, e))?;
+    if let Err(e) = file.write_all(content.as_bytes()) {
+        let _ = fs::remove_file(file_name);
+        return Err(format!("cannot write {}: {}", file_name, e));
+    }
+
+    Ok((file_name.clone(), template))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Result, path::Path};
+
+    use chrono::Local;
+
+    use crate::{
+        app::{file_revision, BoardLock, Journal, JournalEntry},
+        board::{Board, Card, Template},
+        config::Settings,
+    };
+
+    use super::{cfd, export, print, split_overrides, strict_flag};
+
+    // The subcommands run with the default settings, whatever the configuration of the machine running the tests
+    fn stats(args: &[String]) -> std::result::Result<String, String> {
+        super::stats_with(args, &Settings::default())
+    }
+
+    fn import(args: &[String]) -> std::result::Result<String, String> {
+        super::import_with(args, &Settings::default())
+    }
+
+    fn replay(args: &[String]) -> std::result::Result<String, String> {
+        super::replay_with(args, &Settings::default())
+    }
+
+    fn target(args: &[String]) -> std::result::Result<String, String> {
+        super::target_with(args, &Settings::default())
+    }
+
+    fn new_board(args: &[String]) -> std::result::Result<(String, Template), String> {
+        super::new_board_with(args, &Settings::default())
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn stats_as_json() -> Result<()> {
+        let output = stats(&args(&["--format", "json", "res/test_board.json"])).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output)?;
+
+        assert_eq!("TODO", value["columns"][0]["header"]);
+        assert_eq!(3, value["columns"][0]["cards_count"]);
+        assert_eq!(0, value["throughput"]["last_7_days"]);
+        assert!(value["cycle_times"]["median_in_hours"].is_null());
+
+        assert_eq!(output, stats(&args(&["res/test_board.json"])).unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn settings_on_the_command_line() -> Result<()> {
+        let (rest, overrides) = split_overrides(args(&[
+            "rustyban",
+            "--set",
+            "view.density=compact",
+            "board.json",
+            "--set",
+            "a.b=c=d",
+        ]))
+        .unwrap();
+        assert_eq!(args(&["rustyban", "board.json"]), rest);
+        assert_eq!(
+            vec![
+                ("view.density".to_string(), "compact".to_string()),
+                ("a.b".to_string(), "c=d".to_string())
+            ],
+            overrides
+        );
+
+        assert!(split_overrides(args(&["rustyban", "--set"])).is_err());
+        assert!(split_overrides(args(&["rustyban", "--set", "density"])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_anywhere() -> Result<()> {
+        let expected = (args(&["rustyban", "board.json"]), true);
+        assert_eq!(expected, strict_flag(args(&["rustyban", "--strict", "board.json"])));
+        assert_eq!(expected, strict_flag(args(&["rustyban", "board.json", "--strict"])));
+        assert_eq!(
+            (args(&["rustyban", "board.json"]), false),
+            strict_flag(args(&["rustyban", "board.json"]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_arguments() -> Result<()> {
+        assert!(stats(&args(&[])).is_err());
+        assert!(stats(&args(&["--format", "csv", "res/test_board.json"])).is_err());
+        assert!(stats(&args(&["res/test_board.json", "other.json"])).is_err());
+        assert!(stats(&args(&["res/missing.json"])).unwrap_err().contains("cannot read"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cfd_as_csv_and_svg() -> Result<()> {
+        let svg_file = env::temp_dir().join("rustyban-cfd.svg").display().to_string();
+        let output = cfd(&args(&["--days", "7", "--svg", &svg_file, "res/test_board.json"])).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(8, lines.len());
+        assert_eq!("date,TODO,Doing,Done!", lines[0]);
+        assert!(lines[7].ends_with(",3,1,2"));
+        assert!(fs::read_to_string(&svg_file)?.contains("<title>Done!</title>"));
+        fs::remove_file(&svg_file)?;
+
+        assert!(cfd(&args(&["--days", "0", "res/test_board.json"])).is_err());
+        assert!(cfd(&args(&["--svg"])).is_err());
+        assert!(cfd(&args(&[])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_canonical_json() -> Result<()> {
+        let output = export(&args(&["res/test_board.json"])).unwrap();
+        assert!(output.starts_with("{\n  \"columns\": ["));
+        assert!(!output.contains("\"is_selected\""));
+
+        assert!(export(&args(&[])).is_err());
+        assert!(export(&args(&["res/test_board.json", "other.json"])).is_err());
+
+        let file_name = env::temp_dir().join("rustyban-export-empty.json").display().to_string();
+        fs::write(&file_name, r#"{"columns": []}"#)?;
+        assert!(export(&args(&[&file_name])).unwrap_err().contains("no column"));
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn print_as_plain_text() -> Result<()> {
+        let output = print(&args(&["--width", "60", "res/test_board.json"])).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("TODO (3)"));
+        assert!(lines[0].contains("Doing (1)"));
+        assert!(lines.iter().all(|line| line.chars().count() <= 60));
+        assert!(!output.contains('\x1b'));
+        assert_eq!(5, lines.len());
+
+        assert!(print(&args(&["--width", "0", "res/test_board.json"])).is_err());
+        assert!(print(&args(&["--width"])).is_err());
+        assert!(print(&args(&[])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_weekly_target() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-target.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+
+        let output = target(&args(&[&file_name, "5"])).unwrap();
+        assert_eq!(format!("Weekly target of {} set to 5 card(s)", file_name), output);
+        assert_eq!(Some(5), Board::open(&file_name)?.weekly_target());
+
+        target(&args(&[&file_name, "0"])).unwrap();
+        assert_eq!(None, Board::open(&file_name)?.weekly_target());
+        assert!(target(&args(&[&file_name, "many"])).is_err());
+        assert!(target(&args(&[&file_name])).is_err());
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn new_board_from_template() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-new.json").display().to_string();
+        let _ = fs::remove_file(&file_name);
+
+        let (created, template) = new_board(&args(&[&file_name, "--template", "scrum"])).unwrap();
+        assert_eq!(file_name, created);
+        assert_eq!("scrum", template.name());
+        assert_eq!("Backlog", Board::open(&file_name)?.column(0).header());
+
+        assert!(
+            new_board(&args(&[&file_name])).unwrap_err().contains("cannot create"),
+            "an existing file is kept"
+        );
+        let other = env::temp_dir().join("rustyban-new-other.json").display().to_string();
+        assert!(new_board(&args(&["--template", "kanban", &other]))
+            .unwrap_err()
+            .contains("unknown template"));
+        assert!(!Path::new(&other).exists(), "no file is left behind");
+        assert!(new_board(&args(&["--template"])).is_err());
+        assert!(new_board(&args(&[])).is_err());
+
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn import_csv_rows() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-import.json").display().to_string();
+        let csv_file = env::temp_dir().join("rustyban-import.csv").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        fs::write(
+            &csv_file,
+            "Task,State,Owner,Due\nBuy flowers,Doing,Ann,\nWater plants,Later,Bob,soon\n",
+        )?;
+
+        {
+            let _lock = BoardLock::acquire(&file_name).unwrap();
+            assert!(import(&args(&[&file_name, &csv_file]))
+                .unwrap_err()
+                .contains("is opened by"));
+        }
+        assert_eq!(
+            format!(
+                "Imported 2 card(s) from {} into {}\nRow 3: due date \"soon\" is not like 2024-12-24, left out",
+                csv_file, file_name
+            ),
+            import(&args(&[&file_name, &csv_file, "--column", "state", "--due", "Due"])).unwrap()
+        );
+        let board = Board::open(&file_name)?;
+        assert_eq!("Buy flowers", board.card(1, 1).short_description());
+        assert_eq!("Water plants", board.card(0, 3).short_description());
+        assert_eq!(None, board.card(0, 3).due_date());
+        let backup_prefix = format!("{}.", file_name);
+        let backups: Vec<_> = fs::read_dir(env::temp_dir())?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().display().to_string())
+            .filter(|path| path.starts_with(&backup_prefix) && path.ends_with(".bak"))
+            .collect();
+        assert_eq!(1, backups.len(), "backed up before the import");
+        for backup in backups {
+            fs::remove_file(backup)?;
+        }
+
+        assert!(import(&args(&[&file_name, &csv_file, "--due", "Deadline"]))
+            .unwrap_err()
+            .contains("no column \"Deadline\""));
+        assert!(import(&args(&[&file_name, &csv_file, "--title"])).is_err());
+        assert!(import(&args(&[&file_name])).is_err());
+
+        fs::write(&file_name, r#"{"columns": []}"#)?;
+        assert!(import(&args(&[&file_name, &csv_file]))
+            .unwrap_err()
+            .contains("no column found in board"));
+        fs::remove_file(&csv_file)?;
+        fs::remove_file(&file_name)
+    }
+
+    #[test]
+    fn replay_journal() -> Result<()> {
+        let file_name = env::temp_dir().join("rustyban-replay.json").display().to_string();
+        fs::copy("res/test_board.json", &file_name)?;
+        let journal = Journal::new(&file_name);
+        journal.clear()?;
+        assert!(replay(&args(&[&file_name]))
+            .unwrap_err()
+            .contains("no change journaled"));
+
+        let base = file_revision(&file_name);
+        journal.append(
+            base,
+            &JournalEntry::InsertCard {
+                column_index: 1,
+                card_index: 0,
+                card: Card::new("Buy flowers", Local::now()),
+            },
+        )?;
+        journal.append(
+            base,
+            &JournalEntry::RemoveCard {
+                column_index: 0,
+                card_index: 2,
+            },
+        )?;
+        assert_eq!(
+            format!("Replayed 2 changes onto {}", file_name),
+            replay(&args(&[&file_name])).unwrap()
+        );
+
+        let board = Board::open(&file_name)?;
+        assert_eq!("Buy flowers", board.card(1, 0).short_description());
+        assert_eq!(2, board.column(0).size());
+        assert_eq!(0, journal.len()?);
+
+        journal.append(
+            base,
+            &JournalEntry::RemoveCard {
+                column_index: 0,
+                card_index: 0,
+            },
+        )?;
+        assert!(
+            replay(&args(&[&file_name])).unwrap_err().contains("another version"),
+            "written after the changes were journaled"
+        );
+        assert_eq!(2, Board::open(&file_name)?.column(0).size());
+        journal.clear()?;
+        fs::remove_file(&file_name)?;
+
+        assert!(replay(&args(&[])).is_err());
+
+        Ok(())
+    }
+}
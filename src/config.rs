@@ -0,0 +1,982 @@
+mod toml;
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    Datelike, Days, NaiveDate, NaiveTime, Weekday,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::board::{CardColor, JsonLayout};
+
+/// Prefix of the environment variables overriding the settings
+const ENV_PREFIX: &str = "RUSTYBAN_";
+
+/// Tables whose keys are chosen by the user, like column headers or tags, rather than setting names
+const KEYED_TABLES: [&str; 4] = ["stale_days", "wip_limits", "snippets", "view.tag_colors"];
+
+static CLI_OVERRIDES: OnceLock<Vec<(String, String)>> = OnceLock::new();
+
+/// User settings, read from `~/.config/rustyban/config.toml`
+///
+/// Every setting has a default value, so the file only needs to contain the ones to change.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub dates: DateSettings,
+
+    /// Number of days after which a card is considered stale, per column header
+    pub stale_days: HashMap<String, u32>,
+
+    /// Maximum number of cards in progress, per column header
+    pub wip_limits: HashMap<String, usize>,
+
+    pub advice: AdviceSettings,
+
+    pub health: HealthSettings,
+
+    pub archive: ArchiveSettings,
+
+    pub priorities: PrioritySettings,
+
+    pub timer: TimerSettings,
+
+    pub pomodoro: PomodoroSettings,
+
+    pub history: HistorySettings,
+
+    pub session: SessionSettings,
+
+    pub deletion: DeletionSettings,
+
+    pub user: UserSettings,
+
+    pub files: FileSettings,
+
+    pub links: LinkSettings,
+
+    pub debug: DebugSettings,
+
+    pub view: ViewSettings,
+
+    pub editor: EditorSettings,
+
+    pub standup: StandUpSettings,
+
+    pub stats_log: StatsLogSettings,
+
+    pub backup: BackupSettings,
+
+    pub split: SplitSettings,
+
+    pub sync: SyncSettings,
+
+    pub rollover: RolloverSettings,
+
+    /// Texts inserted in the card editor, by the name typed before them with a `;` prefix
+    pub snippets: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct AdviceSettings {
+    /// Whether suggestions to balance the columns, like a WIP limit exceeded, are logged with the board checks
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct HealthSettings {
+    /// Whether opening a board lists what looks wrong on it, like cards without title or columns over their limit
+    pub check_on_open: bool,
+}
+
+impl Default for HealthSettings {
+    fn default() -> Self {
+        Self { check_on_open: true }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct BackupSettings {
+    /// Whether the board is written to a timestamped backup next to its file before changes touching many cards,
+    /// like sorting a column, renaming a tag or merging the changes made on disk
+    pub before_bulk_changes: bool,
+    /// Number of backups kept for each board, the oldest ones are removed past it, the last one is always kept
+    pub keep: usize,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            before_bulk_changes: true,
+            keep: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct SplitSettings {
+    /// Number of cards beyond which splitting a column is offered, the cards after it move to a new column
+    pub max_cards: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct SyncSettings {
+    /// Board file the board is synced with, like a copy in a shared folder, no syncing when not set
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct RolloverSettings {
+    /// Header of the column planning the day, like "Today", its unfinished cards are moved back each day
+    pub column: Option<String>,
+
+    /// Header of the column the unfinished cards go back to, the first column when not set
+    pub to: Option<String>,
+
+    /// Local time of the day at which the cards are moved back, midnight by default
+    pub at: NaiveTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct StatsLogSettings {
+    /// Whether a row of statistics is appended to a CSV file next to the board each time it is written
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct StandUpSettings {
+    /// Day from which the moves of the cards are reported in the stand-up summary
+    pub since: StandUpSince,
+}
+
+/// Day from which the stand-up summary reports the moves of the cards
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StandUpSince {
+    Yesterday,
+    /// The previous weekday, Friday on Mondays
+    #[default]
+    LastBusinessDay,
+}
+
+impl StandUpSince {
+    pub fn day(self, today: NaiveDate) -> NaiveDate {
+        let days_back = match (self, today.weekday()) {
+            (StandUpSince::Yesterday, _) => 1,
+            (StandUpSince::LastBusinessDay, Weekday::Mon) => 3,
+            (StandUpSince::LastBusinessDay, Weekday::Sun) => 2,
+            (StandUpSince::LastBusinessDay, _) => 1,
+        };
+        today - Days::new(days_back)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct EditorSettings {
+    /// Length of short descriptions beyond which the card editor warns, 0 for no limit
+    pub title_soft_limit: usize,
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self { title_soft_limit: 60 }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct ViewSettings {
+    /// Age in days of the completed cards of the last column hidden on demand, they stay on the board
+    pub hide_done_after_days: u32,
+
+    /// How much of each card is shown at startup, it can be changed while running
+    pub density: Density,
+
+    /// Colors of the column headers by tag, a column mostly holding cards with one of the tags gets its color
+    pub tag_colors: HashMap<String, CardColor>,
+}
+
+impl Default for ViewSettings {
+    fn default() -> Self {
+        Self {
+            hide_done_after_days: 14,
+            density: Density::Normal,
+            tag_colors: HashMap::new(),
+        }
+    }
+}
+
+/// How much of each card the board shows, from a single line to most of its fields
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    Compact,
+    #[default]
+    Normal,
+    Detailed,
+}
+
+impl Density {
+    /// The next density, from compact to detailed and back
+    pub fn next(self) -> Self {
+        match self {
+            Density::Compact => Density::Normal,
+            Density::Normal => Density::Detailed,
+            Density::Detailed => Density::Compact,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Normal => "normal",
+            Density::Detailed => "detailed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct DebugSettings {
+    /// Whether the consistency of the board is checked after each change in release builds, debug builds always do
+    pub check_invariants: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct LinkSettings {
+    /// Command opening the links found in cards, given the link as argument, the default browser when not set
+    pub opener: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct FileSettings {
+    /// Whether JSON boards are written with sorted keys, for minimal diffs in version control
+    pub canonical_json: bool,
+
+    /// Layout of the JSON of the boards written, when not set existing boards keep the layout of their file and
+    /// new boards are pretty
+    pub json_layout: Option<JsonLayout>,
+}
+
+impl FileSettings {
+    /// Layout to write a board in, given the layout of the file it was read from
+    pub fn layout_for(&self, file_layout: Option<JsonLayout>) -> JsonLayout {
+        self.json_layout.or(file_layout).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct UserSettings {
+    /// Name recorded with the changes made to the board, `$USER` when not set
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct DeletionSettings {
+    /// Seconds during which a deleted card can be brought back before automatic changes, like archiving, resume
+    pub grace_seconds: u64,
+}
+
+impl Default for DeletionSettings {
+    fn default() -> Self {
+        Self { grace_seconds: 5 }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct SessionSettings {
+    /// Whether to print what was done during the session when quitting
+    pub summary_on_quit: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct HistorySettings {
+    /// Number of changes that can be undone
+    pub max_commands: usize,
+
+    /// Memory the changes that can be undone may use, the oldest ones are forgotten beyond it
+    pub max_kilobytes: usize,
+
+    /// Whether undo and redo only apply to the changes of the selected column, leaving the other columns as they are
+    pub per_column: bool,
+
+    /// Whether changes undone before making other changes are kept as branches to switch to, instead of being lost
+    pub tree: bool,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self {
+            max_commands: 100,
+            max_kilobytes: 16 * 1024,
+            per_column: false,
+            tree: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct TimerSettings {
+    /// Minutes without any key pressed after which the running timer is paused, 0 to never pause it
+    pub idle_minutes: u32,
+}
+
+impl Default for TimerSettings {
+    fn default() -> Self {
+        Self { idle_minutes: 10 }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct PomodoroSettings {
+    /// Minutes of a pomodoro, during which the timer runs on the card
+    pub work_minutes: u32,
+
+    /// Minutes of the break after each pomodoro
+    pub break_minutes: u32,
+
+    /// Whether the end of a pomodoro or of a break pops up a notification, it is only logged otherwise
+    pub notify: bool,
+}
+
+impl Default for PomodoroSettings {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            break_minutes: 5,
+            notify: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct PrioritySettings {
+    /// Number of cards at the top of each column shown with a rank badge, 0 to hide the badges
+    pub committed_cards: usize,
+}
+
+impl Default for PrioritySettings {
+    fn default() -> Self {
+        Self { committed_cards: 3 }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct ArchiveSettings {
+    /// Number of days after which cards of the last column are archived, never archived when not set
+    pub after_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct DateSettings {
+    /// Format of dates with a time, like the card creation date
+    pub datetime_format: String,
+
+    /// Format of dates without a time, like due dates
+    pub date_format: String,
+
+    /// Whether card ages are displayed relative to now or as the creation date
+    pub display: DateDisplay,
+
+    /// Country whose formats are used instead of `datetime_format` and `date_format`, when set
+    pub locale: Option<DateLocale>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateDisplay {
+    Relative,
+    Absolute,
+}
+
+/// Dates written the way a country does, with numbers only
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DateLocale {
+    #[serde(rename = "en-US")]
+    EnUs,
+    #[serde(rename = "en-GB")]
+    EnGb,
+    #[serde(rename = "de-DE")]
+    DeDe,
+    #[serde(rename = "fr-FR")]
+    FrFr,
+    #[serde(rename = "ja-JP")]
+    JaJp,
+}
+
+impl DateLocale {
+    /// Formats of the dates with a time and without
+    pub fn formats(self) -> (&'static str, &'static str) {
+        match self {
+            DateLocale::EnUs => ("%m/%d/%Y %I:%M %p", "%m/%d/%Y"),
+            DateLocale::EnGb | DateLocale::FrFr => ("%d/%m/%Y %H:%M", "%d/%m/%Y"),
+            DateLocale::DeDe => ("%d.%m.%Y %H:%M", "%d.%m.%Y"),
+            DateLocale::JaJp => ("%Y/%m/%d %H:%M", "%Y/%m/%d"),
+        }
+    }
+}
+
+impl Default for DateSettings {
+    fn default() -> Self {
+        Self {
+            datetime_format: "%Y-%m-%d %H:%M".into(),
+            date_format: "%Y-%m-%d".into(),
+            display: DateDisplay::Relative,
+            locale: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Loads the settings in layers, each one taking precedence over the previous ones: the defaults, the user
+    /// configuration file, the `RUSTYBAN_` environment variables, then the `--set` options of the command line
+    pub fn load() -> Result<Self> {
+        let mut overrides: Vec<(String, String)> = env::vars().filter_map(env_override).collect();
+        overrides.extend(CLI_OVERRIDES.get().into_iter().flatten().cloned());
+        Self::load_layers(config_path(), &overrides)
+    }
+
+    /// Loads the settings from a configuration file, when there is one, then applies the overrides in order
+    fn load_layers(config_path: Option<PathBuf>, overrides: &[(String, String)]) -> Result<Self> {
+        let invalid = |source: &dyn Display, e| Error::new(ErrorKind::InvalidData, format!("{}: {}", source, e));
+        let mut value = match config_path {
+            Some(path) if path.exists() => {
+                toml::parse(&fs::read_to_string(&path)?).map_err(|e| invalid(&path.display(), e))?
+            }
+            _ => Value::Object(Map::new()),
+        };
+        for (path, setting) in overrides {
+            toml::set(&mut value, path, setting).map_err(|e| invalid(path, e))?;
+        }
+
+        Self::from_value(value).map_err(|e| invalid(&"settings", e))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content).map_err(|e| Error::new(ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn parse(content: &str) -> std::result::Result<Self, String> {
+        Self::from_value(toml::parse(content)?)
+    }
+
+    fn from_value(value: Value) -> std::result::Result<Self, String> {
+        let settings: Settings = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// The effective value of every setting, as dotted paths like `view.density` and values written as in the
+    /// configuration file, settings without a value are left empty
+    pub fn entries(&self) -> Vec<(String, String)> {
+        serde_json::to_value(self)
+            .map(|value| toml::flatten(&value))
+            .unwrap_or_default()
+    }
+
+    /// The settings with a value changed, an empty value brings back the default
+    pub fn with_setting(&self, path: &str, value: &str) -> std::result::Result<Self, String> {
+        let mut settings = serde_json::to_value(self).map_err(|e| e.to_string())?;
+        match value.trim() {
+            "" => toml::remove(&mut settings, path)?,
+            value => toml::set(&mut settings, path, value)?,
+        }
+        Self::from_value(settings)
+    }
+
+    /// Name of the person making the changes, as configured or from the environment
+    pub fn user_name(&self) -> Option<String> {
+        self.user
+            .name
+            .clone()
+            .or_else(|| env::var("USER").ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        for format in [&self.dates.datetime_format, &self.dates.date_format] {
+            if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                return Err(format!("invalid date format \"{}\"", format));
+            }
+        }
+        if self.pomodoro.work_minutes == 0 {
+            return Err("pomodoro.work_minutes must be at least 1".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a setting to the user configuration file, keeping the other lines of the file as they are
+///
+/// The environment variables and the `--set` options still take precedence over the file at the next start.
+pub fn persist(path: &str, value: &str) -> Result<PathBuf> {
+    let file = config_path().ok_or_else(|| Error::new(ErrorKind::NotFound, "no configuration directory"))?;
+    persist_to(&file, path, value)?;
+    Ok(file)
+}
+
+fn persist_to(file: &Path, path: &str, value: &str) -> Result<()> {
+    let content = match fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    let invalid = |e| Error::new(ErrorKind::InvalidData, format!("{}: {}", file.display(), e));
+    let content = toml::update(&content, path, value).map_err(invalid)?;
+    Settings::parse(&content).map_err(invalid)?;
+
+    if let Some(directory) = file.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(file, content)
+}
+
+/// Settings given on the command line, which take precedence over the configuration file and the environment
+pub fn set_cli_overrides(overrides: Vec<(String, String)>) {
+    // Only set once, at startup
+    let _ = CLI_OVERRIDES.set(overrides);
+}
+
+/// Path and value of the setting overridden by an environment variable, like `view.density` for
+/// `RUSTYBAN_VIEW__DENSITY`, where a double underscore separates the names of the tables and keys
+///
+/// Table and key names are lowercased, the keys of the tables in [`KEYED_TABLES`] are kept as written, like the
+/// column header in `RUSTYBAN_WIP_LIMITS__Doing`.
+fn env_override((name, value): (String, String)) -> Option<(String, String)> {
+    let path = name.strip_prefix(ENV_PREFIX)?;
+    if !path.contains("__") {
+        return None;
+    }
+
+    let mut segments = path.split("__");
+    let mut names = vec![];
+    for segment in segments.by_ref() {
+        names.push(segment.to_lowercase());
+        if KEYED_TABLES.contains(&names.join(".").as_str()) {
+            break;
+        }
+    }
+    names.extend(segments.map(str::to_string));
+    Some((names.join("."), value))
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join("rustyban").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::board::{CardColor, JsonLayout};
+
+    use super::{env_override, persist_to, DateDisplay, DateLocale, Density, Settings, StandUpSince};
+
+    #[test]
+    fn default_settings() -> Result<(), String> {
+        let settings = Settings::parse("")?;
+
+        assert_eq!(Settings::default(), settings);
+        assert_eq!("%Y-%m-%d %H:%M", settings.dates.datetime_format);
+        assert_eq!(DateDisplay::Relative, settings.dates.display);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stats_log_settings() -> Result<(), String> {
+        assert!(!Settings::parse("")?.stats_log.enabled);
+        assert!(Settings::parse("[stats_log]\nenabled = true")?.stats_log.enabled);
+
+        Ok(())
+    }
+
+    #[test]
+    fn backup_settings() -> Result<(), String> {
+        assert!(Settings::parse("")?.backup.before_bulk_changes);
+        assert!(
+            !Settings::parse("[backup]\nbefore_bulk_changes = false")?
+                .backup
+                .before_bulk_changes
+        );
+        assert_eq!(10, Settings::parse("")?.backup.keep);
+        assert_eq!(3, Settings::parse("[backup]\nkeep = 3")?.backup.keep);
+
+        Ok(())
+    }
+
+    #[test]
+    fn health_settings() -> Result<(), String> {
+        assert!(Settings::parse("")?.health.check_on_open);
+        assert!(!Settings::parse("[health]\ncheck_on_open = false")?.health.check_on_open);
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_settings() -> Result<(), String> {
+        assert_eq!(None, Settings::parse("")?.split.max_cards);
+        assert_eq!(Some(20), Settings::parse("[split]\nmax_cards = 20")?.split.max_cards);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rollover_settings() -> Result<(), String> {
+        let settings = Settings::parse("")?.rollover;
+        assert_eq!(None, settings.column);
+        assert_eq!(NaiveTime::MIN, settings.at);
+
+        let settings = Settings::parse("[rollover]\ncolumn = \"Today\"\nat = \"05:30\"")?.rollover;
+        assert_eq!(Some("Today".to_string()), settings.column);
+        assert_eq!(None, settings.to);
+        assert_eq!(NaiveTime::from_hms_opt(5, 30, 0), Some(settings.at));
+        assert!(Settings::parse("[rollover]\nat = \"late\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn edit_settings() -> Result<(), String> {
+        let settings = Settings::parse("[view]\ndensity = \"compact\"")?;
+        let entries = settings.entries();
+        assert!(entries.contains(&("view.density".into(), "\"compact\"".into())));
+        assert!(entries.contains(&("user.name".into(), "".into())));
+
+        let changed = settings.with_setting("history.max_commands", "20")?;
+        assert_eq!(20, changed.history.max_commands);
+        let changed = changed.with_setting("view.density", "")?;
+        assert_eq!(Density::Normal, changed.view.density, "back to the default");
+        assert!(settings.with_setting("view.density", "huge").is_err());
+
+        let file = std::env::temp_dir().join("rustyban-persist").join("config.toml");
+        let _ = std::fs::remove_file(&file);
+        persist_to(&file, "view.density", "detailed").map_err(|e| e.to_string())?;
+        persist_to(&file, "wip_limits.Doing", "3").map_err(|e| e.to_string())?;
+        assert!(persist_to(&file, "history.max_commands", "many").is_err());
+        let persisted = Settings::from_file(&file).map_err(|e| e.to_string())?;
+        assert_eq!(Density::Detailed, persisted.view.density);
+        assert_eq!(Some(&3), persisted.wip_limits.get("Doing"));
+        assert_eq!(100, persisted.history.max_commands);
+
+        Ok(())
+    }
+
+    #[test]
+    fn environment_overrides() -> Result<(), String> {
+        let var = |name: &str, value: &str| (name.to_string(), value.to_string());
+        assert_eq!(
+            Some(var("view.density", "compact")),
+            env_override(var("RUSTYBAN_VIEW__DENSITY", "compact"))
+        );
+        assert_eq!(
+            Some(var("history.max_commands", "10")),
+            env_override(var("RUSTYBAN_HISTORY__MAX_COMMANDS", "10"))
+        );
+        assert_eq!(
+            Some(var("wip_limits.Doing", "3")),
+            env_override(var("RUSTYBAN_WIP_LIMITS__Doing", "3"))
+        );
+        assert_eq!(
+            Some(var("view.tag_colors.Urgent", "red")),
+            env_override(var("RUSTYBAN_VIEW__TAG_COLORS__Urgent", "red"))
+        );
+        assert_eq!(None, env_override(var("RUSTYBAN_LOG", "debug")));
+        assert_eq!(None, env_override(var("VIEW__DENSITY", "compact")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn overrides_in_layers() -> Result<(), String> {
+        let file = std::env::temp_dir().join("rustyban-layers.toml");
+        fs::write(&file, "[view]\ndensity = \"detailed\"\n[wip_limits]\nDoing = 2").map_err(|e| e.to_string())?;
+        let overrides: Vec<(String, String)> = [
+            ("RUSTYBAN_WIP_LIMITS__Doing", "3"),
+            ("RUSTYBAN_VIEW__DENSITY", "compact"),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| env_override((name.to_string(), value.to_string())))
+        .chain([("view.density".to_string(), "normal".to_string())])
+        .collect();
+
+        let settings = Settings::load_layers(Some(file.clone()), &overrides).map_err(|e| e.to_string())?;
+        assert_eq!(Some(&3), settings.wip_limits.get("Doing"));
+        assert_eq!(Density::Normal, settings.view.density, "the last override wins");
+
+        let settings = Settings::load_layers(Some(file.clone()), &[]).map_err(|e| e.to_string())?;
+        assert_eq!(Density::Detailed, settings.view.density);
+        assert_eq!(
+            Settings::default(),
+            Settings::load_layers(None, &[]).map_err(|e| e.to_string())?
+        );
+
+        fs::remove_file(file).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn date_settings() -> Result<(), String> {
+        let settings = Settings::parse(
+            r#"
+            [dates]
+            datetime_format = "%d/%m/%Y %H:%M"
+            display = "absolute"
+            "#,
+        )?;
+
+        assert_eq!("%d/%m/%Y %H:%M", settings.dates.datetime_format);
+        assert_eq!("%Y-%m-%d", settings.dates.date_format);
+        assert_eq!(DateDisplay::Absolute, settings.dates.display);
+        assert_eq!(None, settings.dates.locale);
+
+        let settings = Settings::parse("[dates]\nlocale = \"de-DE\"")?;
+        assert_eq!(Some(DateLocale::DeDe), settings.dates.locale);
+        assert!(Settings::parse("[dates]\nlocale = \"xx\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn advice_settings() -> Result<(), String> {
+        let settings = Settings::parse("")?;
+        assert!(!settings.advice.enabled);
+        assert!(settings.wip_limits.is_empty());
+
+        let settings = Settings::parse("[advice]\nenabled = true\n[wip_limits]\nDoing = 3")?;
+        assert!(settings.advice.enabled);
+        assert_eq!(Some(&3), settings.wip_limits.get("Doing"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn stale_thresholds() -> Result<(), String> {
+        let settings = Settings::parse("[stale_days]\nDoing = 5\n\"In review\" = 2")?;
+
+        assert_eq!(Some(&5), settings.stale_days.get("Doing"));
+        assert_eq!(Some(&2), settings.stale_days.get("In review"));
+        assert_eq!(None, settings.stale_days.get("TODO"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_settings() -> Result<(), String> {
+        assert_eq!(None, Settings::parse("")?.archive.after_days);
+        assert_eq!(
+            Some(14),
+            Settings::parse("[archive]\nafter_days = 14")?.archive.after_days
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn priority_settings() -> Result<(), String> {
+        assert_eq!(3, Settings::parse("")?.priorities.committed_cards);
+        assert_eq!(
+            0,
+            Settings::parse("[priorities]\ncommitted_cards = 0")?
+                .priorities
+                .committed_cards
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn timer_settings() -> Result<(), String> {
+        assert_eq!(10, Settings::parse("")?.timer.idle_minutes);
+        assert_eq!(0, Settings::parse("[timer]\nidle_minutes = 0")?.timer.idle_minutes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pomodoro_settings() -> Result<(), String> {
+        let settings = Settings::parse("[pomodoro]\nwork_minutes = 50\nnotify = false")?;
+
+        assert_eq!(50, settings.pomodoro.work_minutes);
+        assert_eq!(5, settings.pomodoro.break_minutes);
+        assert!(!settings.pomodoro.notify);
+
+        Ok(())
+    }
+
+    #[test]
+    fn history_settings() -> Result<(), String> {
+        let settings = Settings::parse("[history]\nmax_commands = 20")?;
+
+        assert_eq!(20, settings.history.max_commands);
+        assert_eq!(16 * 1024, settings.history.max_kilobytes);
+        assert!(!settings.history.per_column);
+        assert!(Settings::parse("[history]\nper_column = true")?.history.per_column);
+        assert!(Settings::parse("[history]\ntree = true")?.history.tree);
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_settings() -> Result<(), String> {
+        assert!(!Settings::parse("")?.session.summary_on_quit);
+        assert!(
+            Settings::parse("[session]\nsummary_on_quit = true")?
+                .session
+                .summary_on_quit
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deletion_settings() -> Result<(), String> {
+        assert_eq!(5, Settings::parse("")?.deletion.grace_seconds);
+        assert_eq!(
+            30,
+            Settings::parse("[deletion]\ngrace_seconds = 30")?
+                .deletion
+                .grace_seconds
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn user_settings() -> Result<(), String> {
+        let settings = Settings::parse("[user]\nname = \"Alex \"")?;
+
+        assert_eq!(Some("Alex ".to_string()), settings.user.name);
+        assert_eq!(Some("Alex".to_string()), settings.user_name());
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_settings() -> Result<(), String> {
+        assert!(!Settings::parse("")?.files.canonical_json);
+        assert!(Settings::parse("[files]\ncanonical_json = true")?.files.canonical_json);
+        let files = Settings::parse("")?.files;
+        assert_eq!(JsonLayout::Pretty, files.layout_for(None));
+        assert_eq!(JsonLayout::Compact, files.layout_for(Some(JsonLayout::Compact)));
+        let files = Settings::parse("[files]\njson_layout = \"compact\"")?.files;
+        assert_eq!(JsonLayout::Compact, files.layout_for(None));
+        assert_eq!(JsonLayout::Compact, files.layout_for(Some(JsonLayout::Pretty)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn link_settings() -> Result<(), String> {
+        assert_eq!(None, Settings::parse("")?.links.opener);
+        assert_eq!(
+            Some("firefox".to_string()),
+            Settings::parse("[links]\nopener = \"firefox\"")?.links.opener
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_settings() -> Result<(), String> {
+        assert_eq!(Density::Normal, Settings::parse("")?.view.density);
+        assert_eq!(
+            Density::Compact,
+            Settings::parse("[view]\ndensity = \"compact\"")?.view.density
+        );
+        assert!(Settings::parse("[view]\ndensity = \"huge\"").is_err());
+
+        let settings = Settings::parse("[view.tag_colors]\nbug = \"red\"")?;
+        assert_eq!(Some(&CardColor::Red), settings.view.tag_colors.get("bug"));
+        assert!(Settings::parse("[view.tag_colors]\nbug = \"crimson\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn standup_settings() -> Result<(), String> {
+        let monday = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
+        let since = Settings::parse("")?.standup.since;
+        assert_eq!(StandUpSince::LastBusinessDay, since);
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 5, 3), Some(since.day(monday)));
+        assert_eq!(monday, since.day(NaiveDate::from_ymd_opt(2024, 5, 7).unwrap()));
+
+        let since = Settings::parse("[standup]\nsince = \"yesterday\"")?.standup.since;
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 5, 5), Some(since.day(monday)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn editor_settings() -> Result<(), String> {
+        assert_eq!(60, Settings::parse("")?.editor.title_soft_limit);
+        assert_eq!(
+            0,
+            Settings::parse("[editor]\ntitle_soft_limit = 0")?
+                .editor
+                .title_soft_limit
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn snippets() -> Result<(), String> {
+        assert!(Settings::parse("")?.snippets.is_empty());
+        let settings = Settings::parse("[snippets]\nbug = \"Steps to reproduce:\\n1. \"")?;
+        assert_eq!(
+            Some(&"Steps to reproduce:\n1. ".to_string()),
+            settings.snippets.get("bug")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_settings() -> Result<(), String> {
+        assert!(Settings::parse("[dates]\ndisplay = \"sideways\"").is_err());
+        assert!(Settings::parse("[dates]\ndate_format = \"%Q\"").is_err());
+        assert!(Settings::parse("[pomodoro]\nwork_minutes = 0").is_err());
+
+        Ok(())
+    }
+}
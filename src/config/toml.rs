@@ -0,0 +1,458 @@
+use serde_json::{Map, Number, Value};
+
+/// Parses the subset of TOML used by configuration files into a JSON value
+///
+/// Supported: comments, `[table]` and `[nested.table]` headers, bare and quoted keys, strings, integers,
+/// floats, booleans and single line arrays of those.
+pub fn parse(content: &str) -> Result<Value, String> {
+    let mut root = Map::new();
+    let mut table: Vec<String> = vec![];
+
+    for (line_number, line) in content.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let error = |reason: &str| format!("line {}: {}", line_number + 1, reason);
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.strip_suffix(']').ok_or_else(|| error("unclosed table header"))?;
+            table = header.split('.').map(|key| unquote(key.trim())).collect();
+            if table.iter().any(|key| key.is_empty()) {
+                return Err(error("empty table name"));
+            }
+            continue;
+        }
+
+        let (key, value) = split_key_value(line).ok_or_else(|| error("expected key = value"))?;
+        let value = parse_value(value).ok_or_else(|| error("invalid value"))?;
+
+        let target = table_mut(&mut root, &table).ok_or_else(|| error("key is not a table"))?;
+        target.insert(key, value);
+    }
+
+    Ok(Value::Object(root))
+}
+
+/// Sets the value at a dotted path of the tables, like `view.density`, creating the missing tables
+///
+/// The value is read as in a file, except that a bare word is taken as a string: `compact` is `"compact"`.
+pub fn set(root: &mut Value, path: &str, value: &str) -> Result<(), String> {
+    let mut keys = split_path(path)?;
+    let key = keys.pop().unwrap_or_default();
+
+    let value = parse_value(value.trim()).unwrap_or_else(|| Value::String(value.trim().to_string()));
+    let root = root.as_object_mut().ok_or("not a table")?;
+    let table = table_mut(root, &keys).ok_or("key is not a table")?;
+    table.insert(key, value);
+    Ok(())
+}
+
+/// Removes the value at a dotted path of the tables, nothing happens when it is not set
+pub fn remove(root: &mut Value, path: &str) -> Result<(), String> {
+    let mut keys = split_path(path)?;
+    let key = keys.pop().unwrap_or_default();
+
+    let root = root.as_object_mut().ok_or("not a table")?;
+    let table = table_mut(root, &keys).ok_or("key is not a table")?;
+    table.remove(&key);
+    Ok(())
+}
+
+/// The values of the tables as dotted paths and values written as in a file, unset values are left empty
+pub fn flatten(root: &Value) -> Vec<(String, String)> {
+    let mut values = vec![];
+    flatten_into(root, String::new(), &mut values);
+    values
+}
+
+/// Changes the value at a dotted path in the content of a file, keeping its other lines and comments
+///
+/// The key is replaced where it is, keeping its comment, added at the end of its table, even one left empty, or in
+/// a new table at the end of the file. An empty value removes the key, bringing back the default.
+pub fn update(content: &str, path: &str, value: &str) -> Result<String, String> {
+    let mut keys = split_path(path)?;
+    let key = keys.pop().unwrap_or_default();
+    let line = match value.trim() {
+        "" => None,
+        value => {
+            let value = parse_value(value).unwrap_or_else(|| Value::String(value.to_string()));
+            Some(format!("{} = {}", format_key(&key), format_value(&value)))
+        }
+    };
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let mut table: Vec<String> = vec![];
+    let mut table_end = (keys.is_empty()).then_some(0);
+    for index in 0..lines.len() {
+        let trimmed = strip_comment(&lines[index]).trim();
+        if let Some(header) = trimmed.strip_prefix('[').and_then(|header| header.strip_suffix(']')) {
+            table = header.split('.').map(|key| unquote(key.trim())).collect();
+            if table == keys {
+                table_end = Some(index + 1);
+            }
+            continue;
+        }
+        if table != keys || trimmed.is_empty() {
+            continue;
+        }
+
+        table_end = Some(index + 1);
+        if split_key_value(trimmed).is_some_and(|(found, _)| found == key) {
+            match line {
+                Some(line) => {
+                    let comment = &lines[index][strip_comment(&lines[index]).len()..];
+                    lines[index] = if comment.is_empty() {
+                        line
+                    } else {
+                        format!("{} {}", line, comment)
+                    };
+                }
+                None => {
+                    lines.remove(index);
+                }
+            }
+            return Ok(join_lines(lines));
+        }
+    }
+
+    let Some(line) = line else {
+        return Ok(content.to_string());
+    };
+    match table_end {
+        Some(index) => lines.insert(index, line),
+        None => {
+            if lines.last().is_some_and(|last| !last.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            let header: Vec<String> = keys.iter().map(|key| format_key(key)).collect();
+            lines.push(format!("[{}]", header.join(".")));
+            lines.push(line);
+        }
+    }
+    Ok(join_lines(lines))
+}
+
+fn join_lines(lines: Vec<String>) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+fn split_path(path: &str) -> Result<Vec<String>, String> {
+    let keys: Vec<String> = path.split('.').map(|key| unquote(key.trim())).collect();
+    if keys.iter().any(|key| key.is_empty()) {
+        return Err("empty key".into());
+    }
+    Ok(keys)
+}
+
+fn flatten_into(value: &Value, path: String, values: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(table) => {
+            for (key, value) in table {
+                let key = format_key(key);
+                let path = if path.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten_into(value, path, values);
+            }
+        }
+        Value::Null => values.push((path, String::new())),
+        value => values.push((path, format_value(value))),
+    }
+}
+
+/// A key as written in a file, quoted when it is not only letters, digits, underscores and dashes
+fn format_key(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        key.to_string()
+    } else {
+        format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(string) => format!(
+            "\"{}\"",
+            string
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\t', "\\t")
+        ),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(format_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+        value => value.to_string(),
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn split_key_value(line: &str) -> Option<(String, &str)> {
+    let (key, value) = if let Some(quoted) = line.strip_prefix('"') {
+        let (key, rest) = quoted.split_once('"')?;
+        (key.to_string(), rest.trim_start().strip_prefix('=')?)
+    } else {
+        let (key, value) = line.split_once('=')?;
+        (key.trim().to_string(), value)
+    };
+
+    if key.is_empty() {
+        return None;
+    }
+
+    Some((key, value.trim()))
+}
+
+fn unquote(key: &str) -> String {
+    key.strip_prefix('"')
+        .and_then(|key| key.strip_suffix('"'))
+        .unwrap_or(key)
+        .to_string()
+}
+
+fn parse_value(value: &str) -> Option<Value> {
+    if let Some(array) = value.strip_prefix('[') {
+        let items = array.strip_suffix(']')?.trim();
+        if items.is_empty() {
+            return Some(Value::Array(vec![]));
+        }
+        return split_array(items)
+            .iter()
+            .map(|item| parse_value(item.trim()))
+            .collect::<Option<Vec<Value>>>()
+            .map(Value::Array);
+    }
+
+    if let Some(string) = value.strip_prefix('"') {
+        return parse_string(string.strip_suffix('"')?).map(Value::String);
+    }
+
+    match value {
+        "true" => return Some(Value::Bool(true)),
+        "false" => return Some(Value::Bool(false)),
+        _ => {}
+    }
+
+    let number = value.replace('_', "");
+    if let Ok(integer) = number.parse::<i64>() {
+        return Some(Value::Number(integer.into()));
+    }
+    number.parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number)
+}
+
+fn split_array(items: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut current = String::new();
+    let mut in_string = false;
+
+    for c in items.chars() {
+        match c {
+            '"' => {
+                in_string = !in_string;
+                current.push(c);
+            }
+            ',' if !in_string => result.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        result.push(current);
+    }
+
+    result
+}
+
+fn parse_string(string: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = string.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            '"' => result.push('"'),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+fn table_mut<'a>(root: &'a mut Map<String, Value>, path: &[String]) -> Option<&'a mut Map<String, Value>> {
+    let mut table = root;
+    for key in path {
+        table = table
+            .entry(key.clone())
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()?;
+    }
+    Some(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{flatten, parse, remove, set, update};
+
+    #[test]
+    fn parse_tables_and_values() -> Result<(), String> {
+        let content = r#"
+            # A comment
+            title = "Board # not a comment"
+
+            [dates]
+            display = "absolute" # trailing comment
+            width = 1_000
+            ratio = 0.5
+            enabled = true
+
+            [columns.stale_days]
+            "Doing" = 5
+            Review = 2
+
+            [tags]
+            colors = ["red", "blue, green"]
+            empty = []
+        "#;
+
+        let expected = json!({
+            "title": "Board # not a comment",
+            "dates": { "display": "absolute", "width": 1000, "ratio": 0.5, "enabled": true },
+            "columns": { "stale_days": { "Doing": 5, "Review": 2 } },
+            "tags": { "colors": ["red", "blue, green"], "empty": [] },
+        });
+
+        assert_eq!(expected, parse(content)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_values_at_paths() -> Result<(), String> {
+        let mut value = parse("[view]\ndensity = \"normal\"")?;
+        set(&mut value, "view.density", "compact")?;
+        set(&mut value, "view.hide_done_after_days", "7")?;
+        set(&mut value, "stale_days.\"In review\"", "2")?;
+        assert_eq!(
+            json!({
+                "view": { "density": "compact", "hide_done_after_days": 7 },
+                "stale_days": { "In review": 2 }
+            }),
+            value
+        );
+
+        assert_eq!(Err("empty key".into()), set(&mut value, "view.", "1"));
+        assert_eq!(Err("key is not a table".into()), set(&mut value, "view.density.x", "1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn flatten_and_remove_values() -> Result<(), String> {
+        let mut value = json!({
+            "view": { "density": "compact", "hide_done_after_days": 7 },
+            "stale_days": { "In review": 2 },
+            "user": { "name": null }
+        });
+        assert_eq!(
+            vec![
+                ("stale_days.\"In review\"".to_string(), "2".to_string()),
+                ("user.name".to_string(), "".to_string()),
+                ("view.density".to_string(), "\"compact\"".to_string()),
+                ("view.hide_done_after_days".to_string(), "7".to_string()),
+            ],
+            flatten(&value)
+        );
+
+        remove(&mut value, "view.density")?;
+        remove(&mut value, "view.missing")?;
+        assert_eq!(json!({ "hide_done_after_days": 7 }), value["view"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_file_content() -> Result<(), String> {
+        let content = "# My settings\n[view]\ndensity = \"normal\" # at startup\n\n[history]\nmax_commands = 20\n";
+
+        assert_eq!(
+            "# My settings\n[view]\ndensity = \"compact\" # at startup\n\n[history]\nmax_commands = 20\n",
+            update(content, "view.density", "compact")?
+        );
+        assert_eq!(
+            "# My settings\n[view]\ndensity = \"normal\" # at startup\n\n[history]\nmax_commands = 20\nper_column = true\n",
+            update(content, "history.per_column", "true")?
+        );
+        assert_eq!(
+            "# My settings\n[view]\n\n[history]\nmax_commands = 20\n",
+            update(content, "view.density", "")?
+        );
+        assert_eq!(
+            "# My settings\n[view]\ndensity = \"normal\" # at startup\n\n[history]\nmax_commands = 20\n\n[wip_limits]\n\"In review\" = 2\n",
+            update(content, "wip_limits.\"In review\"", "2")?
+        );
+        let reset = update(content, "view.density", "")?;
+        assert_eq!(
+            "# My settings\n[view]\ndensity = \"detailed\"\n\n[history]\nmax_commands = 20\n",
+            update(&reset, "view.density", "detailed")?,
+            "set again in the table left empty"
+        );
+        assert_eq!("[user]\nname = \"alex\"\n", update("", "user.name", "alex")?);
+        assert_eq!(
+            parse(&update(content, "view.density", "compact")?)?["view"]["density"],
+            "compact"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_strings() -> Result<(), String> {
+        let value = parse(r#"text = "line\nnext \"quoted\"""#)?;
+        assert_eq!(json!({ "text": "line\nnext \"quoted\"" }), value);
+
+        let value = parse(r##"text = "a \"#1\" # not a comment" # a comment"##)?;
+        assert_eq!(json!({ "text": "a \"#1\" # not a comment" }), value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_errors_with_line_numbers() -> Result<(), String> {
+        assert_eq!(Err("line 2: expected key = value".into()), parse("a = 1\nnot valid"));
+        assert_eq!(Err("line 1: unclosed table header".into()), parse("[dates"));
+        assert_eq!(Err("line 1: invalid value".into()), parse("a = \"unterminated"));
+        assert_eq!(Err("line 3: key is not a table".into()), parse("a = 1\n[a]\nb = 2"));
+
+        Ok(())
+    }
+}
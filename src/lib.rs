@@ -1,5 +1,8 @@
 mod app;
 pub mod board; // Public because of documentation tests
+mod cli;
+mod config;
 mod utils;
 
-pub use app::AppRunner;
+pub use app::{App, AppOperations, AppRunner, InsertPosition, TestDriver};
+pub use cli::{cfd, export, import, new_board, print, replay, settings_overrides, stats, strict_flag, target};
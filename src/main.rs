@@ -1,17 +1,76 @@
-use std::error::Error;
+use std::{error::Error, process};
 
 use rustyban::AppRunner;
 
+/// Runs without the user interface, with the arguments following the name of the subcommand
+type Subcommand = fn(&[String]) -> Result<String, String>;
+
 fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    let file_name = match args.get(1) {
-        Some(name) => name.clone(),
-        None => String::new(),
+    let args = match rustyban::settings_overrides(std::env::args().collect()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("rustyban: {}", e);
+            process::exit(1);
+        }
+    };
+    let command: Option<Subcommand> = match args.get(1).map(String::as_str) {
+        Some("stats") => Some(rustyban::stats),
+        Some("cfd") => Some(rustyban::cfd),
+        Some("export") => Some(rustyban::export),
+        Some("import") => Some(rustyban::import),
+        Some("print") => Some(rustyban::print),
+        Some("replay") => Some(rustyban::replay),
+        Some("target") => Some(rustyban::target),
+        _ => None,
+    };
+    if let Some(command) = command {
+        match command(&args[2..]) {
+            Ok(output) => println!("{}", output.trim_end()),
+            Err(e) => {
+                eprintln!("rustyban: {}", e);
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    let (args, strict) = rustyban::strict_flag(args);
+    let mut runner = match args.get(1).map(String::as_str) {
+        Some("--tutorial") => AppRunner::tutorial(),
+        Some("--new") => match rustyban::new_board(&args[2..]) {
+            Ok((file_name, template)) => AppRunner::created(file_name, template),
+            Err(e) => {
+                eprintln!("rustyban: {}", e);
+                process::exit(1);
+            }
+        },
+        Some(name) if strict => match AppRunner::strict(name.to_string()) {
+            Ok(runner) => runner,
+            Err(e) => {
+                eprintln!("rustyban: {}", e);
+                process::exit(1);
+            }
+        },
+        None if strict => {
+            eprintln!("rustyban: usage: rustyban --strict <board>");
+            process::exit(1);
+        }
+        Some(name) => AppRunner::new(name.to_string()),
+        None => AppRunner::new(String::new()),
     };
 
     let mut terminal = ratatui::init();
-    let app_result = AppRunner::new(file_name).run(&mut terminal);
+    let app_result = runner.run(&mut terminal);
     ratatui::restore();
 
+    if let Some(report) = runner.crash_report() {
+        eprint!("{}", report);
+        process::exit(101);
+    }
+
+    if let Some(summary) = runner.quit_summary() {
+        println!("{}", summary);
+    }
+
     Ok(app_result?)
 }
@@ -1 +1,5 @@
+pub mod clipboard;
+#[cfg(test)]
+pub(crate) mod random;
+pub mod text;
 pub mod time;
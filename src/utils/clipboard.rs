@@ -0,0 +1,57 @@
+use std::io::{self, Write};
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Copies text to the system clipboard through the terminal, with the OSC 52 escape sequence written to `out`,
+/// the backend of the terminal, so that it goes out with what is drawn
+///
+/// Most terminals support it, over SSH too, the others ignore it.
+pub fn copy(out: &mut impl Write, text: &str) -> io::Result<()> {
+    out.write_all(osc52(text).as_bytes())?;
+    out.flush()
+}
+
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (i, byte)| group | (*byte as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::{base64, copy, osc52};
+
+    #[test]
+    fn encode() -> Result<()> {
+        assert_eq!("", base64(b""));
+        assert_eq!("TQ==", base64(b"M"));
+        assert_eq!("TWE=", base64(b"Ma"));
+        assert_eq!("TWFu", base64(b"Man"));
+        assert_eq!("RG9uZQotIEJ1eSBtaWxr", base64("Done\n- Buy milk".as_bytes()));
+        assert_eq!("\x1b]52;c;TWFu\x07", osc52("Man"));
+
+        let mut out = vec![];
+        copy(&mut out, "Man")?;
+        assert_eq!(b"\x1b]52;c;TWFu\x07".to_vec(), out);
+
+        Ok(())
+    }
+}
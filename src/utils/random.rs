@@ -0,0 +1,60 @@
+/// Small deterministic pseudo-random generator (SplitMix64), for generated boards and property tests
+///
+/// The same seed always gives the same sequence, so a failing case can be replayed from its seed.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, 0 when the bound is 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        match bound {
+            0 => 0,
+            bound => (self.next_u64() % bound as u64) as usize,
+        }
+    }
+
+    /// True once in `times` on average
+    pub fn one_in(&mut self, times: usize) -> bool {
+        self.below(times) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::Random;
+
+    #[test]
+    fn same_seed_same_sequence() -> Result<()> {
+        let numbers = |seed| {
+            let mut random = Random::new(seed);
+            (0..5).map(|_| random.below(100)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(42), numbers(42));
+        assert_ne!(numbers(42), numbers(43));
+        assert!(numbers(7).iter().all(|number| *number < 100));
+        assert_eq!(0, Random::new(1).below(0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+
+/// Removes ANSI escape sequences (colors, cursor moves, hyperlinks...) from a text
+pub fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != ESC {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // Control sequence: parameters and intermediate bytes, then a final byte in '@'..='~'
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // Operating system command: terminated by BEL or by ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == BEL {
+                        break;
+                    }
+                    if c == ESC && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Cleans up pasted text: escape sequences are removed, line endings are normalized and other control
+/// characters are dropped
+pub fn sanitize_paste(text: &str) -> String {
+    strip_ansi(text)
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .chars()
+        .filter(|c| !c.is_control() || *c == '\n' || *c == '\t')
+        .collect()
+}
+
+/// Splits a text in lines of at most `width` characters, breaking between words when possible
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![];
+    }
+
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut line_length = 0;
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        loop {
+            let separator = usize::from(line_length > 0);
+            if line_length + separator + word.len() <= width {
+                if separator > 0 {
+                    line.push(' ');
+                }
+                line.extend(&word);
+                line_length += separator + word.len();
+                break;
+            }
+
+            if line_length > 0 {
+                lines.push(std::mem::take(&mut line));
+                line_length = 0;
+            } else {
+                // A word longer than a line is cut
+                lines.push(word[..width].iter().collect());
+                word.drain(..width);
+            }
+        }
+    }
+    if line_length > 0 || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Wraps a text in at most `max_lines` lines, the last one ends with an ellipsis when the text is cut
+pub fn wrap_truncated(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines = wrap(text, width);
+    if lines.len() <= max_lines {
+        return lines;
+    }
+
+    lines.truncate(max_lines);
+    if let Some(last) = lines.last_mut() {
+        let mut kept: String = last.chars().take(width.saturating_sub(1)).collect();
+        kept.push('…');
+        *last = kept;
+    }
+
+    lines
+}
+
+/// Web links found in a text, in order of appearance and without duplicates
+///
+/// Punctuation ending a sentence or closing brackets right after a link are not part of it.
+pub fn find_urls(text: &str) -> Vec<&str> {
+    let mut urls = vec![];
+    for word in text.split_whitespace() {
+        let Some(start) = word.find("https://").or_else(|| word.find("http://")) else {
+            continue;
+        };
+        let url = word[start..].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']', '>', '"', '\'']);
+        if !url.ends_with("//") && !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Result;
+
+    use super::{find_urls, sanitize_paste, strip_ansi, wrap, wrap_truncated};
+
+    #[test]
+    fn strip_escape_sequences() -> Result<()> {
+        assert_eq!("plain text", strip_ansi("plain text"));
+        assert_eq!(
+            "red and bold",
+            strip_ansi("\u{1b}[31mred\u{1b}[0m and \u{1b}[1;4mbold\u{1b}[m")
+        );
+        assert_eq!(
+            "a link",
+            strip_ansi("\u{1b}]8;;https://example.com\u{1b}\\a link\u{1b}]8;;\u{7}")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sanitize_pasted_text() -> Result<()> {
+        assert_eq!("line 1\nline 2\nline 3", sanitize_paste("line 1\r\nline 2\rline 3"));
+        assert_eq!("tab\there\n", sanitize_paste("\u{1b}[32mtab\there\u{1b}[0m\u{7}\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_between_words() -> Result<()> {
+        assert_eq!(vec!["Buy milk"], wrap("Buy milk", 10));
+        assert_eq!(vec!["Buy milk", "and eggs"], wrap("Buy milk and eggs", 10));
+        assert_eq!(vec!["Buy", "milk"], wrap("Buy   milk", 4));
+        assert_eq!(vec!["Supercali", "fragilist", "ic"], wrap("Supercalifragilistic", 9));
+        assert_eq!(
+            vec!["a", "Supercali", "fragilist", "ic"],
+            wrap("a Supercalifragilistic", 9)
+        );
+        assert_eq!(vec![""], wrap("", 10));
+        assert!(wrap("Buy milk", 0).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_with_ellipsis() -> Result<()> {
+        assert_eq!(vec!["Buy milk", "and eggs"], wrap_truncated("Buy milk and eggs", 10, 2));
+        assert_eq!(
+            vec!["Buy milk", "and eggs…"],
+            wrap_truncated("Buy milk and eggs for", 10, 2)
+        );
+        assert_eq!(vec!["Buy milk…"], wrap_truncated("Buy milk and eggs", 9, 1));
+        assert_eq!(vec!["Buy milk…"], wrap_truncated("Buy milk and eggs", 10, 1));
+        assert!(wrap_truncated("Buy milk", 10, 0).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn urls_in_text() -> Result<()> {
+        let text = "See https://example.com/a?b=1, and (http://example.org/docs).\n\
+                    Again <https://example.com/a?b=1> or http:// alone";
+        assert_eq!(
+            vec!["https://example.com/a?b=1", "http://example.org/docs"],
+            find_urls(text)
+        );
+        assert!(find_urls("no link here").is_empty());
+
+        Ok(())
+    }
+}
@@ -1,27 +1,215 @@
-use chrono::{DateTime, Local};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
 
-pub fn format(date: &DateTime<Local>) -> String {
-    date.format("%Y-%m-%d %H:%M").to_string()
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
+
+use crate::config::{DateDisplay, DateSettings};
+
+/// Age labels are not trusted longer than this without a refresh, as the runner only ticks when idle
+const AGE_LABELS_TTL: Duration = Duration::from_secs(60);
+
+static FORMATTER: RwLock<Option<Arc<DateFormatter>>> = RwLock::new(None);
+
+thread_local! {
+    static AGE_LABELS: RefCell<AgeLabels> = RefCell::new(AgeLabels::default());
+}
+
+/// Formats every date shown in the application, following the user settings
+#[derive(Debug, Clone)]
+pub struct DateFormatter {
+    datetime_format: String,
+    date_format: String,
+    display: DateDisplay,
+}
+
+impl Default for DateFormatter {
+    fn default() -> Self {
+        Self::new(&DateSettings::default())
+    }
+}
+
+impl DateFormatter {
+    pub fn new(settings: &DateSettings) -> Self {
+        let (datetime_format, date_format) = match settings.locale {
+            Some(locale) => locale.formats(),
+            None => (settings.datetime_format.as_str(), settings.date_format.as_str()),
+        };
+        Self {
+            datetime_format: datetime_format.to_string(),
+            date_format: date_format.to_string(),
+            display: settings.display,
+        }
+    }
+
+    pub fn datetime(&self, date: &DateTime<Local>) -> String {
+        date.format(&self.datetime_format).to_string()
+    }
+
+    pub fn date(&self, date: &NaiveDate) -> String {
+        date.format(&self.date_format).to_string()
+    }
+
+    /// How long something has been in a column it entered at `from`, like "in column for 3d"
+    pub fn time_in_column(&self, from: DateTime<Local>, to: DateTime<Local>) -> String {
+        match self.display {
+            DateDisplay::Relative => format!("in column for {}", short_diff(from, to)),
+            DateDisplay::Absolute => format!("in column since {}", self.datetime(&from)),
+        }
+    }
+}
+
+/// Sets the formatter used by the whole application, at startup and when the date settings change
+pub fn init(formatter: DateFormatter) {
+    *FORMATTER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(formatter));
+    AGE_LABELS.with_borrow_mut(|labels| *labels = AgeLabels::default());
+}
+
+pub fn formatter() -> Arc<DateFormatter> {
+    FORMATTER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Labels of how long cards have been in their column, by the date they entered it
+///
+/// Ages are shown with a resolution of a minute at best, so the labels are computed once per minute
+/// instead of for every card at every frame.
+#[derive(Debug, Default)]
+pub struct AgeLabels {
+    /// Time the labels are computed at, and when it was taken
+    now: Option<(DateTime<Local>, Instant)>,
+    labels: HashMap<DateTime<Local>, String>,
 }
 
-pub fn pretty_diff(from: DateTime<Local>, to: DateTime<Local>) -> String {
-    let diff = to - from;
+impl AgeLabels {
+    /// Forgets the labels once the minute they were computed in is over
+    pub fn refresh(&mut self, now: DateTime<Local>, instant: Instant) {
+        let same_minute = self
+            .now
+            .is_some_and(|(previous, _)| previous.timestamp() / 60 == now.timestamp() / 60);
+        if !same_minute {
+            self.labels.clear();
+            self.now = Some((now, instant));
+        }
+    }
 
-    match diff.num_seconds() {
-        s if s < 60 => format!("{} seconds", s),
-        s if s < 3600 => format!("{} minutes", s / 60),
-        s if s < 86400 => format!("{} hours", s / 3600),
-        _ => format!("{} days", diff.num_days()),
+    /// Label of something that entered a column at `from`, the labels younger than a minute are not cached
+    pub fn time_in_column(&mut self, formatter: &DateFormatter, from: DateTime<Local>) -> String {
+        match self.now {
+            Some((now, instant)) if instant.elapsed() < AGE_LABELS_TTL && now - from >= TimeDelta::minutes(1) => self
+                .labels
+                .entry(from)
+                .or_insert_with(|| formatter.time_in_column(from, now))
+                .clone(),
+            _ => formatter.time_in_column(from, Local::now()),
+        }
+    }
+}
+
+/// How long something has been in a column it entered at `from`, as labelled since the last refresh
+pub fn time_in_column(from: DateTime<Local>) -> String {
+    AGE_LABELS.with_borrow_mut(|labels| labels.time_in_column(&formatter(), from))
+}
+
+/// Refreshes the age labels when a new minute started, called on each tick
+pub fn refresh_age_labels() {
+    AGE_LABELS.with_borrow_mut(|labels| labels.refresh(Local::now(), Instant::now()));
+}
+
+/// A time difference in its largest unit, like "3d", "5h", "12m" or "40s"
+pub fn short_diff(from: DateTime<Local>, to: DateTime<Local>) -> String {
+    match (to - from).num_seconds().max(0) {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
+    }
+}
+
+/// The time at a local date and time, or the first one after it when clocks moved forward over it
+pub fn first_valid_time<Tz: TimeZone>(tz: &Tz, naive: NaiveDateTime) -> DateTime<Tz> {
+    (0..=24 * 60)
+        .find_map(|minutes| {
+            tz.from_local_datetime(&(naive + TimeDelta::minutes(minutes)))
+                .earliest()
+        })
+        .unwrap_or_else(|| tz.from_utc_datetime(&naive))
+}
+
+/// A duration like "2d 04h", "1h 05m", "12m" or "40s"
+pub fn pretty_duration(duration: Duration) -> String {
+    match duration.as_secs() {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h {:02}m", s / 3600, s % 3600 / 60),
+        s => format!("{}d {:02}h", s / 86400, s % 86400 / 3600),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Result;
+    use std::{
+        io::Result,
+        time::{Duration, Instant},
+    };
+
+    use chrono::{DateTime, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
 
-    use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+    use crate::{
+        config::{DateDisplay, DateLocale, DateSettings},
+        utils::time::{self, AgeLabels, DateFormatter},
+    };
 
-    use crate::utils::time;
+    /// Central European time around the 31st of March 2024, when clocks moved from 2:00 to 3:00
+    #[derive(Clone)]
+    struct SpringForward;
+
+    impl SpringForward {
+        fn change() -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(2024, 3, 31)
+                .unwrap()
+                .and_hms_opt(2, 0, 0)
+                .unwrap()
+        }
+
+        fn offset(summer: bool) -> FixedOffset {
+            FixedOffset::east_opt(if summer { 7200 } else { 3600 }).unwrap()
+        }
+    }
+
+    impl TimeZone for SpringForward {
+        type Offset = FixedOffset;
+
+        fn from_offset(_offset: &FixedOffset) -> Self {
+            SpringForward
+        }
+
+        fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<FixedOffset> {
+            LocalResult::None
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            match *local {
+                local if local < Self::change() => LocalResult::Single(Self::offset(false)),
+                local if local < Self::change() + TimeDelta::hours(1) => LocalResult::None,
+                _ => LocalResult::Single(Self::offset(true)),
+            }
+        }
+
+        fn offset_from_utc_date(&self, _utc: &NaiveDate) -> FixedOffset {
+            Self::offset(false)
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            Self::offset(*utc >= Self::change() - TimeDelta::hours(1))
+        }
+    }
 
     fn local_date_from_string(date: &str) -> DateTime<Local> {
         let naive_datetime =
@@ -38,7 +226,7 @@ mod tests {
     #[test]
     fn format() -> Result<()> {
         let expected = "2024-12-16 15:30".to_string();
-        let result = time::format(&local_date_from_string("2024-12-16T15:30:42"));
+        let result = DateFormatter::default().datetime(&local_date_from_string("2024-12-16T15:30:42"));
 
         assert_eq!(expected, result);
 
@@ -46,17 +234,117 @@ mod tests {
     }
 
     #[test]
-    fn diff_pretty() -> Result<()> {
+    fn diff_short() -> Result<()> {
         let t4 = local_date_from_string("2024-12-06T15:30:42");
         let t3 = local_date_from_string("2024-12-15T15:31:42");
         let t2 = local_date_from_string("2024-12-16T14:31:42");
         let t1 = local_date_from_string("2024-12-16T15:29:44");
         let t0 = local_date_from_string("2024-12-16T15:30:42");
 
-        assert_eq!("58 seconds", time::pretty_diff(t1, t0));
-        assert_eq!("59 minutes", time::pretty_diff(t2, t0));
-        assert_eq!("23 hours", time::pretty_diff(t3, t0));
-        assert_eq!("10 days", time::pretty_diff(t4, t0));
+        assert_eq!("58s", time::short_diff(t1, t0));
+        assert_eq!("59m", time::short_diff(t2, t0));
+        assert_eq!("23h", time::short_diff(t3, t0));
+        assert_eq!("10d", time::short_diff(t4, t0));
+        assert_eq!("0s", time::short_diff(t0, t1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_pretty() -> Result<()> {
+        assert_eq!("0s", time::pretty_duration(Duration::ZERO));
+        assert_eq!("40s", time::pretty_duration(Duration::from_secs(40)));
+        assert_eq!("12m", time::pretty_duration(Duration::from_secs(12 * 60 + 5)));
+        assert_eq!("1h 05m", time::pretty_duration(Duration::from_secs(3900)));
+        assert_eq!(
+            "2d 04h",
+            time::pretty_duration(Duration::from_secs(2 * 86400 + 4 * 3600 + 60))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cached_age_labels() -> Result<()> {
+        let formatter = DateFormatter::default();
+        let now = local_date_from_string("2024-12-16T15:30:42");
+        let entered = local_date_from_string("2024-12-13T15:30:50");
+        let mut labels = AgeLabels::default();
+
+        labels.refresh(now, Instant::now());
+        assert_eq!("in column for 2d", labels.time_in_column(&formatter, entered));
+        assert!(
+            labels.time_in_column(&formatter, Local::now()).ends_with('s'),
+            "too recent to be cached"
+        );
+
+        labels.refresh(now + TimeDelta::seconds(10), Instant::now());
+        assert_eq!(
+            "in column for 2d",
+            labels.time_in_column(&formatter, entered),
+            "same minute"
+        );
+
+        labels.refresh(now + TimeDelta::minutes(1), Instant::now());
+        assert_eq!("in column for 3d", labels.time_in_column(&formatter, entered));
+
+        Ok(())
+    }
+
+    #[test]
+    fn configured_formats() -> Result<()> {
+        let settings = DateSettings {
+            datetime_format: "%d/%m/%Y %Hh%M".into(),
+            date_format: "%d %b %Y".into(),
+            display: DateDisplay::Absolute,
+            locale: None,
+        };
+        let formatter = DateFormatter::new(&settings);
+        let from = local_date_from_string("2024-12-06T15:30:42");
+        let to = local_date_from_string("2024-12-16T15:30:42");
+
+        assert_eq!("06/12/2024 15h30", formatter.datetime(&from));
+        assert_eq!(
+            "24 Dec 2024",
+            formatter.date(&NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+        );
+        assert_eq!("in column since 06/12/2024 15h30", formatter.time_in_column(from, to));
+
+        let formatter = DateFormatter::default();
+        assert_eq!("in column for 10d", formatter.time_in_column(from, to));
+        assert_eq!("2024-12-06 15:30", formatter.datetime(&from));
+
+        let formatter = DateFormatter::new(&DateSettings {
+            locale: Some(DateLocale::EnUs),
+            ..settings
+        });
+        assert_eq!("12/06/2024 03:30 PM", formatter.datetime(&from));
+        assert_eq!(
+            "12/24/2024",
+            formatter.date(&NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_in_a_gap() -> Result<()> {
+        let at = |hour: u32, minute: u32| {
+            NaiveDate::from_ymd_opt(2024, 3, 31)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+        };
+
+        assert_eq!(
+            at(1, 30),
+            time::first_valid_time(&SpringForward, at(1, 30)).naive_local()
+        );
+        assert_eq!(
+            at(3, 0),
+            time::first_valid_time(&SpringForward, at(2, 30)).naive_local()
+        );
+        assert_eq!(at(4, 0), time::first_valid_time(&SpringForward, at(4, 0)).naive_local());
 
         Ok(())
     }